@@ -0,0 +1,59 @@
+#include "zarrs.h"
+
+#include <chrono>
+#include <iostream>
+#include <sys/resource.h>
+#include <vector>
+
+// A single 256 MiB uint8 chunk
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [16384, 16384],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [16384, 16384]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+static long max_rss_kib() {
+  struct rusage usage;
+  getrusage(RUSAGE_SELF, &usage);
+  return usage.ru_maxrss;
+}
+
+int main() {
+  const int iterations = 8;
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  uint64_t chunk_indices[] = {0, 0};
+  size_t chunk_size = 0;
+  zarrs_assert(zarrsArrayGetChunkSize(array, 2, chunk_indices, &chunk_size));
+  std::vector<uint8_t> chunk(chunk_size, 1);
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, chunk.size(), chunk.data()));
+
+  // The stored chunk and the output buffer are resident before retrieval
+  const long rss_before = max_rss_kib();
+  const auto start = std::chrono::steady_clock::now();
+  for (int i = 0; i < iterations; ++i) {
+    zarrs_assert(zarrsArrayRetrieveChunk(array, 2, chunk_indices, chunk.size(), chunk.data()));
+  }
+  const std::chrono::duration<double> elapsed = std::chrono::steady_clock::now() - start;
+  const long rss_after = max_rss_kib();
+
+  std::cout << "zarrsArrayRetrieveChunk (" << chunk_size / (1024 * 1024) << " MiB chunk): " << elapsed.count() / iterations * 1e3
+            << " ms/iter, " << chunk_size / (elapsed.count() / iterations) / 1e9 << " GB/s" << std::endl;
+  std::cout << "peak RSS increase during retrieval: " << (rss_after - rss_before) / 1024 << " MiB" << std::endl;
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
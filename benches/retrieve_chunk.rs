@@ -0,0 +1,15 @@
+//! Benchmark the retrieval of a large chunk into a caller-provided buffer.
+//!
+//! Reports the time per retrieval and the increase in peak resident memory during retrieval, which is near zero when chunks are decoded directly into the output buffer.
+//!
+//! Run with `cargo bench --bench retrieve_chunk`.
+
+fn main() {
+    let output = inline_c::run(inline_c::Language::Cxx, include_str!("retrieve_chunk.cpp"))
+        .map_err(|e| panic!("{}", e))
+        .unwrap()
+        .success()
+        .get_output()
+        .clone();
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+}
@@ -1,21 +1,47 @@
+pub mod array_additional_fields;
+pub mod array_async;
+pub mod array_chunk_cache;
+pub mod array_info;
+pub mod array_json;
+pub mod array_list;
+pub mod array_metadata_v2;
 pub mod array_read;
+pub mod array_read_selection;
 pub mod array_read_write;
 pub mod array_sharded;
 pub mod array_write;
+pub mod chunk_grid;
 pub mod data_type;
 
-use std::ffi::{CString, c_char};
+use std::{
+    ffi::{CStr, CString, c_char},
+    sync::Arc,
+};
 
 use ffi_support::FfiStr;
-use zarrs::array::{
-    Array, ArrayMetadata, ArraySubset, chunk_shape_to_array_shape, data_type as dt,
+use unsafe_cell_slice::UnsafeCellSlice;
+use zarrs::{
+    array::{
+        Array, ArrayBytesFixedDisjointView, ArrayMetadata, ArraySubset, CodecOptions, DataType,
+        DimensionName,
+        chunk_grid::ChunkGridTraits,
+        chunk_shape_to_array_shape,
+        codec::ArrayToBytesCodecTraits,
+        concurrency::{RecommendedConcurrency, calc_concurrency_outer_inner},
+    },
+    config::MetadataRetrieveVersion,
+    storage::{ReadableListableStorageTraits, ReadableStorageTraits, WritableStorageTraits},
 };
 
 use crate::{
-    LAST_ERROR, ZarrsDataType, ZarrsResult,
-    storage::{ZarrsStorage, ZarrsStorageEnum},
+    LAST_ERROR, ZarrsChunkGridType, ZarrsDataType, ZarrsResult, buffer_into_raw,
+    metadata_zarr_format,
+    stats::{StatsRecorder, num_chunks_in_subset},
+    storage::{ZarrsStorage, ZarrsStorageCapabilityFlags, ZarrsStorageEnum},
 };
 
+use data_type::zarrs_data_type;
+
 #[doc(hidden)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum ZarrsArrayEnum {
@@ -52,8 +78,308 @@ macro_rules! array_fn {
 
 pub(crate) use array_fn;
 
+/// Validate element indices and return the single element subset they address.
+///
+/// Sets the last error on failure.
+pub(crate) fn element_subset(
+    array: &ZarrsArrayEnum,
+    element_indices: &[u64],
+) -> Result<ArraySubset, ZarrsResult> {
+    let shape = array_fn!(array, shape);
+    if shape.len() != element_indices.len() {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "element indices {element_indices:?} do not match the dimensionality of the array shape {shape:?}"
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+    }
+    if std::iter::zip(element_indices, shape).any(|(&index, &size)| index >= size) {
+        *LAST_ERROR.lock().unwrap() =
+            format!("element indices {element_indices:?} are out of bounds of {shape:?}");
+        return Err(ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+    }
+    Ok(ArraySubset::from(
+        element_indices.iter().map(|&index| index..index + 1),
+    ))
+}
+
+/// Validate that the array subset with start `subset_start` and shape `subset_shape` is within the bounds of an array and return it.
+///
+/// Sets the last error on failure.
+pub(crate) fn validated_array_subset(
+    array: &ZarrsArrayEnum,
+    subset_start: &[u64],
+    subset_shape: &[u64],
+) -> Result<ArraySubset, ZarrsResult> {
+    let array_shape = array_fn!(array, shape);
+    if subset_start.len() != array_shape.len() || subset_shape.len() != array_shape.len() {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {} does not match the array dimensionality {}",
+            subset_start.len(),
+            array_shape.len()
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+    }
+    for (dim, ((&start, &shape), &size)) in
+        std::iter::zip(std::iter::zip(subset_start, subset_shape), array_shape).enumerate()
+    {
+        if start.checked_add(shape).is_none_or(|end| end > size) {
+            *LAST_ERROR.lock().unwrap() = format!(
+                "the subset with start {start} and shape {shape} in dimension {dim} is out of bounds of the array extent {size}"
+            );
+            return Err(ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+        }
+    }
+    Ok(ArraySubset::from(
+        std::iter::zip(subset_start, subset_shape).map(|(&start, &shape)| start..start + shape),
+    ))
+}
+
+/// Validate that chunk indices are within the chunk grid of an array.
+///
+/// Sets the last error on failure.
+pub(crate) fn validate_chunk_indices(
+    array: &ZarrsArrayEnum,
+    chunk_indices: &[u64],
+) -> Result<(), ZarrsResult> {
+    let chunk_grid_shape = array_fn!(array, chunk_grid_shape);
+    if chunk_grid_shape.len() != chunk_indices.len()
+        || std::iter::zip(chunk_indices, chunk_grid_shape).any(|(&index, &size)| index >= size)
+    {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "chunk indices {chunk_indices:?} are invalid for a chunk grid with shape {chunk_grid_shape:?}"
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+    }
+    Ok(())
+}
+
+/// Validate a range of chunks against the chunk grid of an array and return it as an [`ArraySubset`].
+///
+/// Sets the last error on failure.
+pub(crate) fn validated_chunks(
+    array: &ZarrsArrayEnum,
+    chunks_start: &[u64],
+    chunks_shape: &[u64],
+) -> Result<ArraySubset, ZarrsResult> {
+    let chunk_grid_shape = array_fn!(array, chunk_grid_shape);
+    if chunks_start.len() != chunk_grid_shape.len() || chunks_shape.len() != chunk_grid_shape.len()
+    {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {} does not match the array dimensionality {}",
+            chunks_start.len(),
+            chunk_grid_shape.len()
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+    }
+    let out_of_grid = std::iter::zip(std::iter::zip(chunks_start, chunks_shape), chunk_grid_shape)
+        .any(|((&start, &shape), &size)| start.checked_add(shape).is_none_or(|end| end > size));
+    if out_of_grid {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "chunk range with start {chunks_start:?} and shape {chunks_shape:?} is out of bounds of a chunk grid with shape {chunk_grid_shape:?}"
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+    }
+    Ok(ArraySubset::from(
+        std::iter::zip(chunks_start, chunks_shape).map(|(&start, &shape)| start..start + shape),
+    ))
+}
+
+/// Call `f` with the byte offset of each element of a strided buffer with `shape`, in C order.
+pub(crate) fn for_each_strided_offset(shape: &[u64], strides: &[i64], mut f: impl FnMut(isize)) {
+    let num_elements: u64 = shape.iter().product();
+    let mut indices = vec![0u64; shape.len()];
+    let mut offset: i64 = 0;
+    for _ in 0..num_elements {
+        f(offset as isize);
+        for dim in (0..shape.len()).rev() {
+            indices[dim] += 1;
+            offset += strides[dim];
+            if indices[dim] < shape[dim] {
+                break;
+            }
+            offset -= strides[dim] * shape[dim] as i64;
+            indices[dim] = 0;
+        }
+    }
+}
+
+/// Get the byte offset of the first element of a strided buffer with `shape` and `strides` from the start of the buffer.
+///
+/// Returns [`None`] if the buffer has no elements.
+/// Sets the last error and returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if any element is outside a buffer of length `buffer_length`.
+pub(crate) fn strided_first_offset(
+    shape: &[u64],
+    strides: &[i64],
+    element_size: usize,
+    buffer_length: usize,
+) -> Result<Option<usize>, ZarrsResult> {
+    if shape.contains(&0) {
+        return Ok(None);
+    }
+    let (mut min_offset, mut max_offset) = (0i128, 0i128);
+    for (&shape, &stride) in std::iter::zip(shape, strides) {
+        let extent = i128::from(stride) * i128::from(shape - 1);
+        if extent < 0 {
+            min_offset += extent;
+        } else {
+            max_offset += extent;
+        }
+    }
+    let required_length = max_offset - min_offset + element_size as i128;
+    if required_length > buffer_length as i128 {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "the strided elements span {required_length} bytes, which exceeds the buffer length {buffer_length}"
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+    }
+    Ok(Some((-min_offset) as usize))
+}
+
+/// The memory layout of a multidimensional buffer.
+#[repr(i32)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ZarrsOrder {
+    /// C (row-major) order, where the last dimension is contiguous.
+    ZARRS_ORDER_C = 0,
+    /// Fortran (column-major) order, where the first dimension is contiguous.
+    ZARRS_ORDER_F = 1,
+}
+
+/// Copy the elements of `input` with `shape` to `output`, converting between C and Fortran order.
+///
+/// If `to_fortran` is true, `input` is in C order and `output` is in Fortran order, otherwise the reverse.
+/// The first and last dimensions are transposed in blocks so that both buffers are accessed with good locality.
+pub(crate) fn transpose_order(
+    input: &[u8],
+    output: &mut [u8],
+    shape: &[usize],
+    element_size: usize,
+    to_fortran: bool,
+) {
+    const BLOCK_SIZE: usize = 32;
+    let dimensionality = shape.len();
+    if dimensionality < 2 {
+        output.copy_from_slice(input);
+        return;
+    }
+
+    // Element strides of each dimension
+    let mut c_strides = vec![1; dimensionality];
+    let mut f_strides = vec![1; dimensionality];
+    for dim in (0..dimensionality - 1).rev() {
+        c_strides[dim] = c_strides[dim + 1] * shape[dim + 1];
+    }
+    for dim in 1..dimensionality {
+        f_strides[dim] = f_strides[dim - 1] * shape[dim - 1];
+    }
+    let (input_strides, output_strides) = if to_fortran {
+        (c_strides, f_strides)
+    } else {
+        (f_strides, c_strides)
+    };
+
+    let (first, last) = (shape[0], shape[dimensionality - 1]);
+    let inner_shape = &shape[1..dimensionality - 1];
+    let num_inner: usize = inner_shape.iter().product();
+    let mut inner_indices = vec![0; inner_shape.len()];
+    for _ in 0..num_inner {
+        let (input_base, output_base) = inner_indices.iter().enumerate().fold(
+            (0, 0),
+            |(input_base, output_base), (i, &index)| {
+                (
+                    input_base + index * input_strides[i + 1],
+                    output_base + index * output_strides[i + 1],
+                )
+            },
+        );
+        for first_block in (0..first).step_by(BLOCK_SIZE) {
+            for last_block in (0..last).step_by(BLOCK_SIZE) {
+                for i in first_block..(first_block + BLOCK_SIZE).min(first) {
+                    for j in last_block..(last_block + BLOCK_SIZE).min(last) {
+                        let input_offset = (input_base
+                            + i * input_strides[0]
+                            + j * input_strides[dimensionality - 1])
+                            * element_size;
+                        let output_offset = (output_base
+                            + i * output_strides[0]
+                            + j * output_strides[dimensionality - 1])
+                            * element_size;
+                        output[output_offset..output_offset + element_size]
+                            .copy_from_slice(&input[input_offset..input_offset + element_size]);
+                    }
+                }
+            }
+        }
+        for dim in (0..inner_shape.len()).rev() {
+            inner_indices[dim] += 1;
+            if inner_indices[dim] < inner_shape[dim] {
+                break;
+            }
+            inner_indices[dim] = 0;
+        }
+    }
+}
+
+/// Create a view over `output` for decoding elements with `shape` and a fixed size `data_type` directly into it.
+///
+/// `what` names the output (e.g. `chunk` or `subset`) in error messages.
+/// Sets the last error on failure.
+pub(crate) fn output_view<'a>(
+    data_type: &DataType,
+    shape: &[u64],
+    output: &'a mut [u8],
+    what: &str,
+) -> Result<ArrayBytesFixedDisjointView<'a>, ZarrsResult> {
+    output_region_view(
+        data_type,
+        shape,
+        ArraySubset::new_with_shape(shape.to_vec()),
+        output,
+        what,
+    )
+}
+
+/// Create a view over the `region` of `output` for decoding elements with a fixed size `data_type` directly into it.
+///
+/// `output` holds elements with `shape` in C order, and `region` must be within `shape`.
+/// `what` names the output (e.g. `chunk` or `subset`) in error messages.
+/// Sets the last error on failure.
+pub(crate) fn output_region_view<'a>(
+    data_type: &DataType,
+    shape: &[u64],
+    region: ArraySubset,
+    output: &'a mut [u8],
+    what: &str,
+) -> Result<ArrayBytesFixedDisjointView<'a>, ZarrsResult> {
+    let Some(data_type_size) = data_type.fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return Err(ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+    };
+    let expected_length = shape.iter().product::<u64>() as usize * data_type_size;
+    if output.len() != expected_length {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "{what}_bytes_length {} does not match decoded {what} size {expected_length}",
+            output.len()
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+    }
+    // SAFETY: the view is the only view of the output buffer.
+    unsafe {
+        ArrayBytesFixedDisjointView::new(
+            UnsafeCellSlice::new(output),
+            data_type_size,
+            shape,
+            region,
+        )
+    }
+    .map_err(|err| {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH
+    })
+}
+
 #[doc(hidden)]
-pub struct ZarrsArray_T(pub ZarrsArrayEnum);
+pub struct ZarrsArray_T(pub ZarrsArrayEnum, pub(crate) StatsRecorder);
 
 impl std::ops::Deref for ZarrsArray_T {
     type Target = ZarrsArrayEnum;
@@ -71,6 +397,59 @@ impl std::ops::DerefMut for ZarrsArray_T {
 /// An opaque handle to a zarr array.
 pub type ZarrsArray = *mut ZarrsArray_T;
 
+/// Create a handle to an existing array (read capability).
+///
+/// The list capability of the storage is retained.
+/// `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array could not be opened.
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle.
+/// `pArray` must be a valid pointer to a `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsOpenArrayR(
+    storage: ZarrsStorage,
+    path: FfiStr,
+    pArray: *mut ZarrsArray,
+) -> ZarrsResult {
+    if storage.is_null() {
+        *LAST_ERROR.lock().unwrap() = "storage is null".to_string();
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &*storage };
+    let (storage, stats) = StatsRecorder::new(storage);
+    let path = path.as_str();
+
+    let array = match &storage {
+        ZarrsStorageEnum::R(storage) => Array::open(storage.clone(), path).map(ZarrsArrayEnum::R),
+        ZarrsStorageEnum::RL(storage) => Array::open(storage.clone(), path).map(ZarrsArrayEnum::RL),
+        ZarrsStorageEnum::RW(storage) => {
+            let storage: Arc<dyn ReadableStorageTraits> = storage.clone();
+            Array::open(storage, path).map(ZarrsArrayEnum::R)
+        }
+        ZarrsStorageEnum::RWL(storage) => {
+            let storage: Arc<dyn ReadableListableStorageTraits> = storage.clone();
+            Array::open(storage, path).map(ZarrsArrayEnum::RL)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    };
+    let array = array.map_err(|err| {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        ZarrsResult::ZARRS_ERROR_ARRAY
+    });
+    // SAFETY: pArray is a valid pointer per the function's safety contract.
+    unsafe { array_into_handle(array, stats, pArray) }
+}
+
 /// Create a handle to an existing array (read/write capability).
 ///
 /// `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
@@ -89,26 +468,221 @@ pub unsafe extern "C" fn zarrsOpenArrayRW(
     }
 
     // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
-    let storage = unsafe { &**storage };
-
-    if let ZarrsStorageEnum::RW(storage) = storage {
-        match Array::open(storage.clone(), path.into()) {
-            Ok(array) => {
-                // SAFETY: pArray is a valid pointer per the function's safety contract.
-                unsafe {
-                    *pArray = Box::into_raw(Box::new(ZarrsArray_T(ZarrsArrayEnum::RW(array))));
-                }
-                ZarrsResult::ZARRS_SUCCESS
+    let storage = unsafe { &*storage };
+    let (storage, stats) = StatsRecorder::new(storage);
+
+    let array = match &storage {
+        ZarrsStorageEnum::RW(storage) => {
+            Array::open(storage.clone(), path.into()).map(ZarrsArrayEnum::RW)
+        }
+        ZarrsStorageEnum::RWL(storage) => {
+            Array::open(storage.clone(), path.into()).map(ZarrsArrayEnum::RWL)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not support read and write".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    };
+    match array {
+        Ok(array) => {
+            // SAFETY: pArray is a valid pointer per the function's safety contract.
+            unsafe {
+                *pArray = Box::into_raw(Box::new(ZarrsArray_T(array, stats)));
             }
-            Err(err) => {
-                *LAST_ERROR.lock().unwrap() = err.to_string();
-                ZarrsResult::ZARRS_ERROR_ARRAY
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        }
+    }
+}
+
+/// Open the Zarr V2 array at `path`, ignoring any Zarr V3 metadata.
+///
+/// Sets the last error on failure.
+fn open_array_v2<T: ReadableStorageTraits + ?Sized + 'static>(
+    storage: Arc<T>,
+    path: &str,
+) -> Result<Array<T>, ZarrsResult> {
+    Array::open_opt(storage, path, &MetadataRetrieveVersion::V2).map_err(|err| {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+    })
+}
+
+/// Box an opened array into a `ZarrsArray` handle.
+///
+/// # Safety
+/// `pArray` must be a valid pointer to a `ZarrsArray` handle.
+unsafe fn array_into_handle(
+    array: Result<ZarrsArrayEnum, ZarrsResult>,
+    stats: StatsRecorder,
+    pArray: *mut ZarrsArray,
+) -> ZarrsResult {
+    match array {
+        Ok(array) => {
+            // SAFETY: pArray is a valid pointer per the function's safety contract.
+            unsafe {
+                *pArray = Box::into_raw(Box::new(ZarrsArray_T(array, stats)));
             }
+            ZarrsResult::ZARRS_SUCCESS
         }
-    } else {
-        *LAST_ERROR.lock().unwrap() = "storage does not support read and write".to_string();
-        ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        Err(result) => result,
+    }
+}
+
+/// Create a handle to an existing Zarr V2 array (read-only capability).
+///
+/// The array metadata is read from `.zarray` (and `.zattrs`), even if Zarr V3 metadata is present at `path`.
+/// `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if there is no valid Zarr V2 array metadata at `path`.
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle.
+/// `pArray` must be a valid pointer to a `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsOpenArrayV2R(
+    storage: ZarrsStorage,
+    path: FfiStr,
+    pArray: *mut ZarrsArray,
+) -> ZarrsResult {
+    if storage.is_null() {
+        *LAST_ERROR.lock().unwrap() = "storage is null".to_string();
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &*storage };
+    let (storage, stats) = StatsRecorder::new(storage);
+    let path = path.as_str();
+
+    let array = match &storage {
+        ZarrsStorageEnum::R(storage) => open_array_v2(storage.clone(), path).map(ZarrsArrayEnum::R),
+        ZarrsStorageEnum::RL(storage) => {
+            open_array_v2(storage.clone(), path).map(ZarrsArrayEnum::RL)
+        }
+        ZarrsStorageEnum::RW(storage) => {
+            let storage: Arc<dyn ReadableStorageTraits> = storage.clone();
+            open_array_v2(storage, path).map(ZarrsArrayEnum::R)
+        }
+        ZarrsStorageEnum::RWL(storage) => {
+            let storage: Arc<dyn ReadableListableStorageTraits> = storage.clone();
+            open_array_v2(storage, path).map(ZarrsArrayEnum::RL)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    };
+    // SAFETY: pArray is a valid pointer per the function's safety contract.
+    unsafe { array_into_handle(array, stats, pArray) }
+}
+
+/// Create a handle to an existing Zarr V2 array (read/write capability).
+///
+/// The array metadata is read from `.zarray` (and `.zattrs`), even if Zarr V3 metadata is present at `path`.
+/// All array functions operate on the returned handle as they would for a Zarr V3 array.
+/// `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read and write capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if there is no valid Zarr V2 array metadata at `path`.
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle.
+/// `pArray` must be a valid pointer to a `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsOpenArrayV2RW(
+    storage: ZarrsStorage,
+    path: FfiStr,
+    pArray: *mut ZarrsArray,
+) -> ZarrsResult {
+    if storage.is_null() {
+        *LAST_ERROR.lock().unwrap() = "storage is null".to_string();
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &*storage };
+    let (storage, stats) = StatsRecorder::new(storage);
+    let path = path.as_str();
+
+    let array = match &storage {
+        ZarrsStorageEnum::RW(storage) => {
+            open_array_v2(storage.clone(), path).map(ZarrsArrayEnum::RW)
+        }
+        ZarrsStorageEnum::RWL(storage) => {
+            open_array_v2(storage.clone(), path).map(ZarrsArrayEnum::RWL)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not support read and write".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    };
+    // SAFETY: pArray is a valid pointer per the function's safety contract.
+    unsafe { array_into_handle(array, stats, pArray) }
+}
+
+/// Create a handle to an existing Zarr V2 array (write-only capability).
+///
+/// The array metadata is read from `.zarray` (and `.zattrs`), so `storage` must also have read capability.
+/// The returned handle only has write capability.
+/// `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read and write capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if there is no valid Zarr V2 array metadata at `path`.
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle.
+/// `pArray` must be a valid pointer to a `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsOpenArrayV2W(
+    storage: ZarrsStorage,
+    path: FfiStr,
+    pArray: *mut ZarrsArray,
+) -> ZarrsResult {
+    if storage.is_null() {
+        *LAST_ERROR.lock().unwrap() = "storage is null".to_string();
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
+
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &*storage };
+    let (storage, stats) = StatsRecorder::new(storage);
+    let path = path.as_str();
+
+    let (metadata, storage): (_, Arc<dyn WritableStorageTraits>) = match &storage {
+        ZarrsStorageEnum::RW(storage) => (
+            open_array_v2(storage.clone(), path).map(|array| array.metadata().clone()),
+            storage.clone(),
+        ),
+        ZarrsStorageEnum::RWL(storage) => (
+            open_array_v2(storage.clone(), path).map(|array| array.metadata().clone()),
+            storage.clone(),
+        ),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not support read and write".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    };
+    let array = metadata.and_then(|metadata| {
+        Array::new_with_metadata(storage, path, metadata)
+            .map(ZarrsArrayEnum::W)
+            .map_err(|err| {
+                *LAST_ERROR.lock().unwrap() = err.to_string();
+                ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+            })
+    });
+    // SAFETY: pArray is a valid pointer per the function's safety contract.
+    unsafe { array_into_handle(array, stats, pArray) }
 }
 
 /// Create a handle to a new array (read/write capability).
@@ -131,7 +705,8 @@ pub unsafe extern "C" fn zarrsCreateArrayRW(
     }
 
     // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
-    let storage = unsafe { &**storage };
+    let storage = unsafe { &*storage };
+    let (storage, stats) = StatsRecorder::new(storage);
 
     let metadata = match ArrayMetadata::try_from(metadata.as_str()) {
         Ok(metadata) => metadata,
@@ -141,23 +716,31 @@ pub unsafe extern "C" fn zarrsCreateArrayRW(
         }
     };
 
-    if let ZarrsStorageEnum::RW(storage) = storage {
-        match Array::new_with_metadata(storage.clone(), path.into(), metadata) {
-            Ok(array) => {
-                // SAFETY: pArray is a valid pointer per the function's safety contract.
-                unsafe {
-                    *pArray = Box::into_raw(Box::new(ZarrsArray_T(ZarrsArrayEnum::RW(array))));
-                }
-                ZarrsResult::ZARRS_SUCCESS
-            }
-            Err(err) => {
-                *LAST_ERROR.lock().unwrap() = err.to_string();
-                ZarrsResult::ZARRS_ERROR_ARRAY
+    let array = match &storage {
+        ZarrsStorageEnum::RW(storage) => {
+            Array::new_with_metadata(storage.clone(), path.into(), metadata).map(ZarrsArrayEnum::RW)
+        }
+        ZarrsStorageEnum::RWL(storage) => {
+            Array::new_with_metadata(storage.clone(), path.into(), metadata)
+                .map(ZarrsArrayEnum::RWL)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not support read and write".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    };
+    match array {
+        Ok(array) => {
+            // SAFETY: pArray is a valid pointer per the function's safety contract.
+            unsafe {
+                *pArray = Box::into_raw(Box::new(ZarrsArray_T(array, stats)));
             }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
         }
-    } else {
-        *LAST_ERROR.lock().unwrap() = "storage does not support read and write".to_string();
-        ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
     }
 }
 
@@ -179,253 +762,2733 @@ pub unsafe extern "C" fn zarrsDestroyArray(array: ZarrsArray) -> ZarrsResult {
     }
 }
 
-/// Returns the dimensionality of the array.
+/// Returns the Zarr format (2 or 3) of the array metadata.
 ///
 /// # Errors
 /// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
 ///
 /// # Safety
 /// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pVersion` must be a valid pointer to a `uint32_t`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayGetDimensionality(
+pub unsafe extern "C" fn zarrsArrayGetZarrFormat(
     array: ZarrsArray,
-    dimensionality: *mut usize,
+    pVersion: *mut u32,
 ) -> ZarrsResult {
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
     let array = unsafe { &**array };
-    // SAFETY: dimensionality is a valid pointer per the function's safety contract.
-    unsafe { *dimensionality = array_fn!(array, dimensionality) };
+    let version = match array_fn!(array, metadata) {
+        ArrayMetadata::V3(_) => 3,
+        ArrayMetadata::V2(_) => 2,
+    };
+    // SAFETY: pVersion is a valid pointer per the function's safety contract.
+    unsafe { *pVersion = version };
     ZarrsResult::ZARRS_SUCCESS
 }
 
-/// Returns the shape of the array.
+/// Returns the Zarr version (2 or 3) of the array metadata.
+///
+/// This is equivalent to `zarrsArrayGetZarrFormat`.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pVersion` must be a valid pointer to a `uint32_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetZarrVersion(
+    array: ZarrsArray,
+    pVersion: *mut u32,
+) -> ZarrsResult {
+    // SAFETY: The safety contract is identical to that of zarrsArrayGetZarrFormat.
+    unsafe { zarrsArrayGetZarrFormat(array, pVersion) }
+}
+
+/// Returns the `"zarr_format"` of the array metadata.
+///
+/// Unlike `zarrsArrayGetZarrFormat`, this reads the raw value of the `"zarr_format"` field of the serialized metadata rather than deriving it from the metadata variant.
 ///
 /// # Errors
 /// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
-/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the metadata does not have a `"zarr_format"` that is a positive integer.
 ///
 /// # Safety
 /// If not null, `array` must be a valid `ZarrsArray` handle.
-/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pShape`.
+/// `pVersion` must be a valid pointer to a `uint32_t`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayGetShape(
+pub unsafe extern "C" fn zarrsArrayGetMetadataVersion(
     array: ZarrsArray,
-    dimensionality: usize,
-    pShape: *mut u64,
+    pVersion: *mut u32,
 ) -> ZarrsResult {
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
     let array = unsafe { &**array };
-    let shape = array_fn!(array, shape);
-    if shape.len() != dimensionality {
-        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    match metadata_zarr_format(serde_json::to_value(array_fn!(array, metadata))) {
+        Ok(version) => {
+            // SAFETY: pVersion is a valid pointer per the function's safety contract.
+            unsafe { *pVersion = version };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
     }
-    // SAFETY: pShape points to an array of length dimensionality per the function's safety contract.
-    let pShape = unsafe { std::slice::from_raw_parts_mut(pShape, dimensionality) };
-    pShape.copy_from_slice(shape);
-    ZarrsResult::ZARRS_SUCCESS
 }
 
-/// Returns the data type of the array.
+/// Get the store key of the array metadata.
+///
+/// This is `zarr.json` for Zarr V3 metadata and `.zarray` for Zarr V2 metadata under the array path, e.g. `my/array/zarr.json` for an array at `/my/array`.
+/// Zarr V2 attributes are stored separately in `.zattrs` under the same path.
+/// The string must be freed with `zarrsFreeString`.
 ///
 /// # Errors
-/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pKey` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if the array path is not a valid store prefix.
 ///
 /// # Safety
-/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pKey` must be a valid pointer to a `char*`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayGetDataType(
+pub unsafe extern "C" fn zarrsArrayGetMetadataStorageKey(
     array: ZarrsArray,
-    pDataType: *mut ZarrsDataType,
+    pKey: *mut *mut c_char,
 ) -> ZarrsResult {
-    if array.is_null() {
+    if array.is_null() || pKey.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
     let array = unsafe { &**array };
-    let data_type = array_fn!(array, data_type);
-    let zarrs_data_type = if data_type.is::<dt::BoolDataType>() {
-        ZarrsDataType::ZARRS_BOOL
-    } else if data_type.is::<dt::Int8DataType>() {
-        ZarrsDataType::ZARRS_INT8
-    } else if data_type.is::<dt::Int16DataType>() {
-        ZarrsDataType::ZARRS_INT16
-    } else if data_type.is::<dt::Int32DataType>() {
-        ZarrsDataType::ZARRS_INT32
-    } else if data_type.is::<dt::Int64DataType>() {
-        ZarrsDataType::ZARRS_INT64
-    } else if data_type.is::<dt::UInt8DataType>() {
-        ZarrsDataType::ZARRS_UINT8
-    } else if data_type.is::<dt::UInt16DataType>() {
-        ZarrsDataType::ZARRS_UINT16
-    } else if data_type.is::<dt::UInt32DataType>() {
-        ZarrsDataType::ZARRS_UINT32
-    } else if data_type.is::<dt::UInt64DataType>() {
-        ZarrsDataType::ZARRS_UINT64
-    } else if data_type.is::<dt::Float16DataType>() {
-        ZarrsDataType::ZARRS_FLOAT16
-    } else if data_type.is::<dt::Float32DataType>() {
-        ZarrsDataType::ZARRS_FLOAT32
-    } else if data_type.is::<dt::Float64DataType>() {
-        ZarrsDataType::ZARRS_FLOAT64
-    } else if data_type.is::<dt::BFloat16DataType>() {
-        ZarrsDataType::ZARRS_BFLOAT16
-    } else if data_type.is::<dt::Complex64DataType>() {
-        ZarrsDataType::ZARRS_COMPLEX64
-    } else if data_type.is::<dt::Complex128DataType>() {
-        ZarrsDataType::ZARRS_COMPLEX128
-    } else if data_type.is::<dt::RawBitsDataType>() {
-        ZarrsDataType::ZARRS_RAW_BITS
-    } else {
-        ZarrsDataType::ZARRS_UNDEFINED
+    let prefix = match array_list::node_prefix(array_fn!(array, path).as_str()) {
+        Ok(prefix) => prefix,
+        Err(result) => return result,
     };
+    let name = match array_fn!(array, metadata) {
+        ArrayMetadata::V3(_) => "zarr.json",
+        ArrayMetadata::V2(_) => ".zarray",
+    };
+    let key = format!("{}{name}", prefix.as_str());
+    // SAFETY: pKey is not null, and the caller guarantees it is a valid pointer.
+    unsafe { *pKey = CString::new(key).unwrap().into_raw() };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the capabilities of the storage underlying the array.
+///
+/// `pFlags` is set to a bitfield of `ZarrsStorageCapabilityFlags`.
+/// These are the capabilities retained by the array handle, which may be fewer than those of the storage it was opened with.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pFlags` must be a valid pointer to a `uint32_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetStorageCapabilityFlags(
+    array: ZarrsArray,
+    pFlags: *mut u32,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    const READ: u32 = ZarrsStorageCapabilityFlags::ZARRS_CAP_READ as u32;
+    const WRITE: u32 = ZarrsStorageCapabilityFlags::ZARRS_CAP_WRITE as u32;
+    const LIST: u32 = ZarrsStorageCapabilityFlags::ZARRS_CAP_LIST as u32;
+    let flags = match array {
+        ZarrsArrayEnum::R(_) => READ,
+        ZarrsArrayEnum::W(_) => WRITE,
+        ZarrsArrayEnum::L(_) => LIST,
+        ZarrsArrayEnum::RL(_) => READ | LIST,
+        ZarrsArrayEnum::RW(_) => READ | WRITE,
+        ZarrsArrayEnum::RWL(_) => READ | WRITE | LIST,
+    };
+    // SAFETY: pFlags is a valid pointer per the function's safety contract.
+    unsafe { *pFlags = flags };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Returns the dimensionality of the array.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetDimensionality(
+    array: ZarrsArray,
+    dimensionality: *mut usize,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: dimensionality is a valid pointer per the function's safety contract.
+    unsafe { *dimensionality = array_fn!(array, dimensionality) };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Returns the dimensionality and the dimension names of the array.
+///
+/// This combines `zarrsArrayGetDimensionality` and `zarrsArrayGetDimensionNames` in a single call.
+/// If `ppDimensionNames` is not null, it is set to an array of `*pDimensionality` dimension names, which must be freed with `zarrsFreeStringArray`.
+/// Unnamed dimensions are null, and every dimension is unnamed if the array has no dimension names.
+/// If `ppDimensionNames` is null, this is identical to `zarrsArrayGetDimensionality`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pDimensionality` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if a dimension name cannot be converted to a C string.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// If not null, `pDimensionality` must be a valid pointer to a `size_t` and `ppDimensionNames` must be a valid pointer to a `char**`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetDimensionalityWithNames(
+    array: ZarrsArray,
+    pDimensionality: *mut usize,
+    ppDimensionNames: *mut *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() || pDimensionality.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    let dimensionality = {
+        // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+        let array = unsafe { &**array };
+        array_fn!(array, dimensionality)
+    };
+    if !ppDimensionNames.is_null() {
+        // SAFETY: array and ppDimensionNames are valid per the function's safety contract.
+        let result =
+            unsafe { zarrsArrayGetDimensionNames(array, dimensionality, ppDimensionNames) };
+        if result != ZarrsResult::ZARRS_SUCCESS {
+            return result;
+        }
+    }
+    // SAFETY: pDimensionality is not null, and the caller guarantees it is a valid pointer.
+    unsafe { *pDimensionality = dimensionality };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Returns the shape of the array.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetShape(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pShape: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let shape = array_fn!(array, shape);
+    if shape.len() != dimensionality {
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pShape points to an array of length dimensionality per the function's safety contract.
+    let pShape = unsafe { std::slice::from_raw_parts_mut(pShape, dimensionality) };
+    pShape.copy_from_slice(shape);
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Returns the number of elements in the array, the product of its shape.
+///
+/// This does not access storage.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of elements overflows a `uint64_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pTotalElements` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetShapeAsProduct(
+    array: ZarrsArray,
+    pTotalElements: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let shape = array_fn!(array, shape);
+    let Some(num_elements) = num_elements(shape) else {
+        *LAST_ERROR.lock().unwrap() = format!("the number of elements in {shape:?} overflows");
+        return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+    };
+    // SAFETY: pTotalElements is a valid pointer per the function's safety contract.
+    unsafe { *pTotalElements = num_elements };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Replace the shape of an array in memory.
+///
+/// If `checked`, the new shape must not reduce the number of chunks along any dimension.
+/// Sets the last error on failure.
+fn update_shape(array: &mut ZarrsArrayEnum, new_shape: &[u64], checked: bool) -> ZarrsResult {
+    let dimensionality = array_fn!(array, dimensionality);
+    if new_shape.len() != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "the new shape {new_shape:?} does not match the array dimensionality {dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+
+    let shape = array_fn!(array, shape).to_vec();
+    let chunk_grid_shape = array_fn!(array, chunk_grid_shape).to_vec();
+    if let Err(err) = array_fn!(array, set_shape, new_shape.to_vec()) {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        return ZarrsResult::ZARRS_ERROR_ARRAY;
+    }
+    if checked {
+        let new_chunk_grid_shape = array_fn!(array, chunk_grid_shape).to_vec();
+        if std::iter::zip(&new_chunk_grid_shape, &chunk_grid_shape).any(|(new, old)| new < old) {
+            // Restore the original shape, which was valid
+            let _ = array_fn!(array, set_shape, shape);
+            *LAST_ERROR.lock().unwrap() = format!(
+                "the new shape {new_shape:?} has a chunk grid shape {new_chunk_grid_shape:?} smaller than the current chunk grid shape {chunk_grid_shape:?}"
+            );
+            return ZarrsResult::ZARRS_ERROR_ARRAY;
+        }
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Replace the shape of the array in memory without validation.
+///
+/// This is intended for expert use, such as updating the shape to reflect data that has already been written with a larger extent.
+/// Chunks outside of the new shape are not erased, and the new shape is not checked against the chunks already written.
+/// This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the chunk grid does not support the new shape (e.g. it exceeds the extent of a rectangular chunk grid).
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pNewShape` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayUpdateShape(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pNewShape: *const u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &mut **array };
+    // SAFETY: pNewShape points to an array of length dimensionality per the function's safety contract.
+    let new_shape = unsafe { std::slice::from_raw_parts(pNewShape, dimensionality) };
+    update_shape(array, new_shape, false)
+}
+
+/// Replace the shape of the array in memory, checking that it covers the current chunk grid.
+///
+/// The new shape must not reduce the number of chunks along any dimension, so every chunk in the current chunk grid remains within the array.
+/// The array is unchanged on failure.
+/// This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the new shape would reduce the chunk grid shape, or the chunk grid does not support the new shape.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pNewShape` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayUpdateShapeChecked(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pNewShape: *const u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &mut **array };
+    // SAFETY: pNewShape points to an array of length dimensionality per the function's safety contract.
+    let new_shape = unsafe { std::slice::from_raw_parts(pNewShape, dimensionality) };
+    update_shape(array, new_shape, true)
+}
+
+/// Returns the number of elements in the array.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of elements overflows a `uint64_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetNumElements(
+    array: ZarrsArray,
+    pNumElements: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let shape = array_fn!(array, shape);
+    let Some(num_elements) = shape.iter().try_fold(1u64, |acc, &d| acc.checked_mul(d)) else {
+        *LAST_ERROR.lock().unwrap() = format!("the number of elements in {shape:?} overflows");
+        return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+    };
+    // SAFETY: pNumElements is a valid pointer per the function's safety contract.
+    unsafe { *pNumElements = num_elements };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Returns the decoded (uncompressed) size of the array in bytes.
+///
+/// This is the number of elements multiplied by the data type size, regardless of which chunks are stored.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the size overflows a `uint64_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetDecodedSizeBytes(
+    array: ZarrsArray,
+    pBytes: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let Some(data_type_size) = array_fn!(array, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let shape = array_fn!(array, shape);
+    let Some(size) = shape
+        .iter()
+        .try_fold(data_type_size as u64, |acc, &d| acc.checked_mul(d))
+    else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the decoded size of an array with shape {shape:?} overflows");
+        return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+    };
+    // SAFETY: pBytes is a valid pointer per the function's safety contract.
+    unsafe { *pBytes = size };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Returns the data type of the array.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetDataType(
+    array: ZarrsArray,
+    pDataType: *mut ZarrsDataType,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let data_type = zarrs_data_type(array_fn!(array, data_type));
     // SAFETY: pDataType is a valid pointer per the function's safety contract.
-    unsafe { *pDataType = zarrs_data_type };
+    unsafe { *pDataType = data_type };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Return the number of chunks in the chunk grid.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkGridShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkGridShape(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkGridShape: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let chunk_grid_shape = array_fn!(array, chunk_grid_shape);
+    if chunk_grid_shape.len() != dimensionality {
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pChunkGridShape points to an array of length dimensionality per the function's safety contract.
+    let pChunkGridShape =
+        unsafe { std::slice::from_raw_parts_mut(pChunkGridShape, dimensionality) };
+    pChunkGridShape.copy_from_slice(chunk_grid_shape);
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Write the indices of the first or last chunk of the chunk grid to `pChunkIndices`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+unsafe fn chunk_grid_corner_indices(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *mut u64,
+    last: bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let chunk_grid_shape = array_fn!(array, chunk_grid_shape);
+    if chunk_grid_shape.len() != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            chunk_grid_shape.len()
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    if chunk_grid_shape.contains(&0) {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the chunk grid with shape {chunk_grid_shape:?} has no chunks");
+        return ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE;
+    }
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts_mut(pChunkIndices, dimensionality) };
+    for (chunk_index, &num_chunks) in std::iter::zip(chunk_indices, chunk_grid_shape) {
+        *chunk_index = if last { num_chunks - 1 } else { 0 };
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Return the indices of the first chunk of the chunk grid.
+///
+/// `pChunkIndices` is set to zero in every dimension.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the chunk grid has no chunks.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetFirstChunkIndices(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *mut u64,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of chunk_grid_corner_indices.
+    unsafe { chunk_grid_corner_indices(array, dimensionality, pChunkIndices, false) }
+}
+
+/// Return the indices of the last chunk of the chunk grid.
+///
+/// `pChunkIndices` is set to the chunk grid shape minus one in every dimension.
+/// Iterating from `zarrsArrayGetFirstChunkIndices` to `zarrsArrayGetLastChunkIndices` (inclusive) visits every chunk.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the chunk grid has no chunks.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetLastChunkIndices(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *mut u64,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of chunk_grid_corner_indices.
+    unsafe { chunk_grid_corner_indices(array, dimensionality, pChunkIndices, true) }
+}
+
+/// Returns the number of chunks in the chunk grid.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of chunks overflows a `uint64_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetNumChunks(
+    array: ZarrsArray,
+    pNumChunks: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let chunk_grid_shape = array_fn!(array, chunk_grid_shape);
+    let Some(num_chunks) = chunk_grid_shape
+        .iter()
+        .try_fold(1u64, |acc, &d| acc.checked_mul(d))
+    else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the number of chunks in {chunk_grid_shape:?} overflows");
+        return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+    };
+    // SAFETY: pNumChunks is a valid pointer per the function's safety contract.
+    unsafe { *pNumChunks = num_chunks };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the chunk grid shape of an array with a regular or rectangular chunk grid.
+///
+/// Sets the last error and returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` for other chunk grids.
+fn rectangular_chunk_grid_shape(array: &ZarrsArrayEnum) -> Result<&[u64], ZarrsResult> {
+    let chunk_grid_metadata = array_fn!(array, chunk_grid).create_metadata();
+    match chunk_grid_metadata.name() {
+        "regular" | "rectangular" => Ok(array_fn!(array, chunk_grid_shape)),
+        name => {
+            *LAST_ERROR.lock().unwrap() =
+                format!("the number of chunks of a {name} chunk grid is not supported");
+            Err(ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE)
+        }
+    }
+}
+
+/// Return the number of chunks along each dimension of the chunk grid.
+///
+/// This is identical to `zarrsArrayGetChunkGridShape`, which is also the number of chunks along each dimension, but is restricted to regular and rectangular chunk grids.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the chunk grid is not regular or rectangular.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pChunkCount` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkCount(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkCount: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let chunk_grid_shape = match rectangular_chunk_grid_shape(array) {
+        Ok(chunk_grid_shape) => chunk_grid_shape,
+        Err(result) => return result,
+    };
+    if chunk_grid_shape.len() != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            chunk_grid_shape.len()
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pChunkCount points to an array of length dimensionality per the function's safety contract.
+    let pChunkCount = unsafe { std::slice::from_raw_parts_mut(pChunkCount, dimensionality) };
+    pChunkCount.copy_from_slice(chunk_grid_shape);
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Return the total number of chunks in the chunk grid.
+///
+/// This is the product of the chunk counts returned by `zarrsArrayGetChunkCount`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the chunk grid is not regular or rectangular.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of chunks overflows a `uint64_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pTotalChunkCount` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkCountProduct(
+    array: ZarrsArray,
+    pTotalChunkCount: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array_ref = unsafe { &**array };
+    if let Err(result) = rectangular_chunk_grid_shape(array_ref) {
+        return result;
+    }
+    // SAFETY: array is a valid ZarrsArray handle and pTotalChunkCount is a valid pointer per the function's safety contract.
+    unsafe { zarrsArrayGetNumChunks(array, pTotalChunkCount) }
+}
+
+/// The number of elements in `shape`, or [`None`] if it overflows.
+fn num_elements(shape: &[u64]) -> Option<u64> {
+    shape.iter().try_fold(1u64, |acc, &d| acc.checked_mul(d))
+}
+
+/// Compute the C order element strides of `shape`, or [`None`] if they overflow.
+fn c_order_strides(shape: &[u64]) -> Option<Vec<u64>> {
+    let mut strides = vec![1u64; shape.len()];
+    for dim in (0..shape.len().saturating_sub(1)).rev() {
+        strides[dim] = strides[dim + 1].checked_mul(shape[dim + 1])?;
+    }
+    Some(strides)
+}
+
+/// Get the element stride between chunk boundaries along each dimension of a C order buffer holding the entire array.
+///
+/// For dimension `i`, the stride is the chunk shape along dimension `i` multiplied by the product of the array shape along dimensions `i+1` through `N-1`.
+/// `pChunkStride` is a pointer to an array of length `dimensionality` in which the strides are returned.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the array does not have a regular chunk grid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if a stride overflows a `uint64_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pChunkStride` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkStride(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkStride: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_shape = array_fn!(array, shape);
+    if array_shape.len() != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            array_shape.len()
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+
+    let is_regular_chunk_grid = array_fn!(array, chunk_grid).create_metadata().name() == "regular";
+    let chunk_shape = match array_fn!(array, chunk_shape, &vec![0; dimensionality]) {
+        Ok(chunk_shape) if is_regular_chunk_grid => chunk_shape_to_array_shape(&chunk_shape),
+        _ => {
+            *LAST_ERROR.lock().unwrap() =
+                "chunk strides are only defined for a regular chunk grid".to_string();
+            return ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE;
+        }
+    };
+    let Some(chunk_stride) = c_order_strides(array_shape).and_then(|strides| {
+        std::iter::zip(strides, chunk_shape)
+            .map(|(stride, chunk_size)| stride.checked_mul(chunk_size))
+            .collect::<Option<Vec<_>>>()
+    }) else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the chunk strides of an array with shape {array_shape:?} overflow");
+        return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+    };
+    // SAFETY: pChunkStride points to an array of length dimensionality per the function's safety contract.
+    let pChunkStride = unsafe { std::slice::from_raw_parts_mut(pChunkStride, dimensionality) };
+    pChunkStride.copy_from_slice(&chunk_stride);
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the element strides of a C order buffer holding a subset of an array.
+///
+/// For dimension `i`, the stride is the product of the subset shape along dimensions `i+1` through `N-1`.
+/// `pSubsetShape` is a pointer to an array of length `dimensionality` holding the subset shape.
+/// `pSubsetStride` is a pointer to an array of length `dimensionality` in which the strides are returned.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if a stride overflows a `uint64_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pSubsetShape` and `pSubsetStride` must point to arrays of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubsetStride(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetShape: *const u64,
+    pSubsetStride: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    if array_fn!(array, dimensionality) != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            array_fn!(array, dimensionality)
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+
+    // SAFETY: pSubsetShape points to an array of length dimensionality per the function's safety contract.
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let Some(subset_stride) = c_order_strides(subset_shape) else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the strides of a subset with shape {subset_shape:?} overflow");
+        return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+    };
+    // SAFETY: pSubsetStride points to an array of length dimensionality per the function's safety contract.
+    let pSubsetStride = unsafe { std::slice::from_raw_parts_mut(pSubsetStride, dimensionality) };
+    pSubsetStride.copy_from_slice(&subset_stride);
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// The byte offset of the element at `element_indices` in a C order buffer holding the entire array.
+///
+/// Sets the last error on failure.
+fn byte_offset_in_array(
+    array: &ZarrsArrayEnum,
+    element_indices: &[u64],
+) -> Result<u64, ZarrsResult> {
+    let Some(data_type_size) = array_fn!(array, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return Err(ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+    };
+    let array_shape = array_fn!(array, shape);
+    if std::iter::zip(element_indices, array_shape).any(|(&index, &size)| index >= size) {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "element indices {element_indices:?} are out of bounds of the array shape {array_shape:?}"
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+    }
+    c_order_strides(array_shape)
+        .and_then(|strides| {
+            std::iter::zip(element_indices, strides).try_fold(0u64, |offset, (&index, stride)| {
+                offset.checked_add(index.checked_mul(stride)?)
+            })
+        })
+        .and_then(|offset| offset.checked_mul(data_type_size as u64))
+        .ok_or_else(|| {
+            *LAST_ERROR.lock().unwrap() = format!(
+                "the byte offset of element {element_indices:?} in an array with shape {array_shape:?} overflows"
+            );
+            ZarrsResult::ZARRS_ERROR_OVERFLOW
+        })
+}
+
+/// Get the byte offset of the first element of a chunk in a C order buffer holding the entire array.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pByteOffset` is set to the byte offset of the chunk origin in a buffer holding the entire decoded array.
+/// Combined with `zarrsArrayGetChunkStride`, this can be used to decode chunks directly into a buffer holding the entire array.
+/// This is a pure computation and does not access storage.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pByteOffset` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the byte offset overflows a `uint64_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pByteOffset` must be a valid pointer to a `uint64_t`.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkByteOffsetInArray(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pByteOffset: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() || pByteOffset.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    if array_fn!(array, dimensionality) != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            array_fn!(array, dimensionality)
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    if let Err(result) = validate_chunk_indices(array, chunk_indices) {
+        return result;
+    }
+    let chunk_origin = match array_fn!(array, chunk_origin, chunk_indices) {
+        Ok(chunk_origin) => chunk_origin,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+        }
+    };
+    match byte_offset_in_array(array, &chunk_origin) {
+        Ok(byte_offset) => {
+            // SAFETY: pByteOffset is not null, and the caller guarantees it is a valid pointer to a uint64_t.
+            unsafe { *pByteOffset = byte_offset };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Get the byte offset of an element in a C order buffer holding the entire array.
+///
+/// `pSubsetStart` is a pointer to an array of length `dimensionality` holding the element indices, such as the start of a subset.
+/// `pByteOffset` is set to the byte offset of the element in a buffer holding the entire decoded array.
+/// This is a pure computation and does not access storage.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pByteOffset` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pSubsetStart` is out of bounds of the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the byte offset overflows a `uint64_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pByteOffset` must be a valid pointer to a `uint64_t`.
+/// `pSubsetStart` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubsetByteOffsetInArray(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pByteOffset: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() || pByteOffset.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    if array_fn!(array, dimensionality) != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            array_fn!(array, dimensionality)
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pSubsetStart points to an array of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    match byte_offset_in_array(array, subset_start) {
+        Ok(byte_offset) => {
+            // SAFETY: pByteOffset is not null, and the caller guarantees it is a valid pointer to a uint64_t.
+            unsafe { *pByteOffset = byte_offset };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Get the name and separator of the chunk key encoding.
+///
+/// `pEncodingName` is set to the chunk key encoding name, such as `default` or `v2`.
+/// `pSeparator` is set to the separator character, or `\0` if the chunk key encoding has no separator.
+/// The name must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array`, `pEncodingName`, or `pSeparator` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the chunk key encoding metadata cannot be interpreted.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkKeyEncodingString(
+    array: ZarrsArray,
+    pEncodingName: *mut *mut c_char,
+    pSeparator: *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() || pEncodingName.is_null() || pSeparator.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let chunk_key_encoding_metadata = array_fn!(array, chunk_key_encoding).create_metadata();
+    let name = chunk_key_encoding_metadata.name();
+    let separator = match chunk_key_encoding_metadata
+        .configuration()
+        .and_then(|configuration| configuration.get("separator"))
+    {
+        Some(serde_json::Value::String(separator)) => separator.bytes().next(),
+        Some(_) => None,
+        None => match name {
+            "default" => Some(b'/'),
+            "v2" => Some(b'.'),
+            _ => Some(b'\0'),
+        },
+    };
+    let (Some(separator), Ok(cstring)) = (separator, CString::new(name)) else {
+        *LAST_ERROR.lock().unwrap() =
+            "error interpreting the chunk key encoding metadata".to_string();
+        return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+    };
+    // SAFETY: pEncodingName and pSeparator are not null, and the caller guarantees they are valid pointers.
+    unsafe {
+        *pEncodingName = cstring.into_raw();
+        *pSeparator = separator as c_char;
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the chunk key encoding metadata as a JSON string.
+///
+/// The chunk key encoding metadata holds the chunk key encoding `name` and `configuration`.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pJson` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the metadata cannot be serialised.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkKeyEncodingMetadataString(
+    array: ZarrsArray,
+    pretty: bool,
+    pJson: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() || pJson.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let chunk_key_encoding_metadata = array_fn!(array, chunk_key_encoding).create_metadata();
+    let chunk_key_encoding_str = if pretty {
+        serde_json::to_string_pretty(&chunk_key_encoding_metadata)
+    } else {
+        serde_json::to_string(&chunk_key_encoding_metadata)
+    };
+    if let Ok(chunk_key_encoding_str) = chunk_key_encoding_str
+        && let Ok(cstring) = CString::new(chunk_key_encoding_str)
+    {
+        // SAFETY: pJson is not null, and the caller guarantees it is a valid pointer.
+        unsafe { *pJson = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() =
+        "error converting chunk key encoding metadata to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
+
+/// Get the chunk grid metadata as a JSON string.
+///
+/// The chunk grid metadata holds the chunk grid `name` and `configuration`.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkGridString(
+    array: ZarrsArray,
+    pChunkGridJson: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let chunk_grid_metadata = array_fn!(array, chunk_grid).create_metadata();
+    if let Ok(chunk_grid_str) = serde_json::to_string(&chunk_grid_metadata)
+        && let Ok(cstring) = CString::new(chunk_grid_str)
+    {
+        // SAFETY: pChunkGridJson is a valid pointer per the function's safety contract.
+        unsafe { *pChunkGridJson = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() =
+        "error converting chunk grid metadata to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
+
+/// Returns the chunk grid type of the array.
+///
+/// Chunk grids other than `regular` and `rectangular` are reported as `ZARRS_CHUNK_GRID_OTHER`.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkGridType(
+    array: ZarrsArray,
+    pType: *mut ZarrsChunkGridType,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let chunk_grid_metadata = array_fn!(array, chunk_grid).create_metadata();
+    let chunk_grid_type = match chunk_grid_metadata.name() {
+        "regular" => ZarrsChunkGridType::ZARRS_CHUNK_GRID_REGULAR,
+        "rectangular" => ZarrsChunkGridType::ZARRS_CHUNK_GRID_RECTANGULAR,
+        _ => ZarrsChunkGridType::ZARRS_CHUNK_GRID_OTHER,
+    };
+    // SAFETY: pType is a valid pointer per the function's safety contract.
+    unsafe { *pType = chunk_grid_type };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the range of chunks intersecting the array subset with start `subset_start` and shape `subset_shape`.
+///
+/// Sets the last error on failure.
+fn chunks_intersecting_subset(
+    array: &ZarrsArrayEnum,
+    subset_start: &[u64],
+    subset_shape: &[u64],
+) -> Result<ArraySubset, ZarrsResult> {
+    let array_subset = validated_array_subset(array, subset_start, subset_shape)?;
+    match array_fn!(array, chunks_in_array_subset, &array_subset) {
+        Ok(Some(chunks_subset)) => Ok(chunks_subset),
+        Ok(None) => {
+            *LAST_ERROR.lock().unwrap() =
+                "the chunks intersecting the subset cannot be determined".to_string();
+            Err(ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS)
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            Err(ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY)
+        }
+    }
+}
+
+/// Return the chunks indicating the chunks intersecting `array_subset`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart`, `pSubsetShape`, `pChunksStart`, and `pChunksShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunksInSubset(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pChunksStart: *mut u64,
+    pChunksShape: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    match chunks_intersecting_subset(array, subset_start, subset_shape) {
+        Ok(chunks_subset) => {
+            // SAFETY: pChunksStart and pChunksShape point to arrays of length dimensionality per the function's safety contract.
+            let pChunksStart =
+                unsafe { std::slice::from_raw_parts_mut(pChunksStart, dimensionality) };
+            pChunksStart.copy_from_slice(chunks_subset.start());
+            let pChunksShape =
+                unsafe { std::slice::from_raw_parts_mut(pChunksShape, dimensionality) };
+            pChunksShape.copy_from_slice(chunks_subset.shape());
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Return the list of chunks intersecting an array subset.
+///
+/// Unlike `zarrsArrayGetChunksInSubset`, which returns the bounding box of the intersecting chunks, this enumerates every chunk that intersects the subset.
+/// `pChunkCount` is set to the number of intersecting chunks, which is 0 if the subset is empty.
+/// `ppChunkIndicesList` is set to a buffer of `pChunkCount * dimensionality` chunk indices, with one row of `dimensionality` indices per chunk.
+/// Chunks are in C order (the last dimension varies fastest).
+/// The buffer must be freed with `zarrsFreeBytes`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array`, `pChunkCount`, or `ppChunkIndicesList` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined (e.g. for an irregular chunk grid).
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// If not null, `pChunkCount` must be a valid pointer to a `size_t` and `ppChunkIndicesList` must be a valid pointer to a `uint64_t*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunksInSubsetList(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pChunkCount: *mut usize,
+    ppChunkIndicesList: *mut *mut u64,
+) -> ZarrsResult {
+    if array.is_null() || pChunkCount.is_null() || ppChunkIndicesList.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    let chunks_subset = match chunks_intersecting_subset(array, subset_start, subset_shape) {
+        Ok(chunks_subset) => chunks_subset,
+        Err(result) => return result,
+    };
+
+    let mut chunk_count = 0;
+    let mut chunk_indices_list = Vec::new();
+    if array_subset.num_elements() > 0 {
+        for chunk_indices in chunks_subset.indices() {
+            // Only keep chunks that actually overlap the subset
+            let intersects = array_fn!(array, chunk_subset, &chunk_indices)
+                .ok()
+                .and_then(|chunk_subset| chunk_subset.overlap(&array_subset).ok())
+                .is_some_and(|overlap| overlap.num_elements() > 0);
+            if intersects {
+                chunk_count += 1;
+                chunk_indices_list.extend_from_slice(&chunk_indices);
+            }
+        }
+    }
+    // SAFETY: pChunkCount and ppChunkIndicesList are not null, and are valid pointers per the function's safety contract.
+    unsafe {
+        *pChunkCount = chunk_count;
+        *ppChunkIndicesList = buffer_into_raw(chunk_indices_list);
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Return the bounding box of the chunks intersecting an array subset and the number of chunks it contains.
+///
+/// This is equivalent to `zarrsArrayGetChunksInSubset`, and additionally sets `pChunkCount` to the product of `pChunksShape`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pSubsetStart`, `pSubsetShape`, `pChunksStart`, and `pChunksShape`.
+/// `pChunkCount` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunksIntersectingBounds(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pChunksStart: *mut u64,
+    pChunksShape: *mut u64,
+    pChunkCount: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    match chunks_intersecting_subset(array, subset_start, subset_shape) {
+        Ok(chunks_subset) => {
+            // SAFETY: the output pointers are valid per the function's safety contract.
+            unsafe {
+                std::slice::from_raw_parts_mut(pChunksStart, dimensionality)
+                    .copy_from_slice(chunks_subset.start());
+                std::slice::from_raw_parts_mut(pChunksShape, dimensionality)
+                    .copy_from_slice(chunks_subset.shape());
+                *pChunkCount = chunks_subset.num_elements();
+            }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Return the number of chunks in the bounding box of the chunks intersecting an array subset.
+///
+/// This is a count-only equivalent of `zarrsArrayGetChunksIntersectingBounds`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// `pCount` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunksIntersectingBoundsCount(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pCount: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    match chunks_intersecting_subset(array, subset_start, subset_shape) {
+        Ok(chunks_subset) => {
+            // SAFETY: pCount is a valid pointer per the function's safety contract.
+            unsafe { *pCount = chunks_subset.num_elements() };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Return the array subset covered by a range of chunks.
+///
+/// `pChunksStart` and `pChunksShape` are pointers to arrays of length `dimensionality` holding the start and shape of the chunk range.
+/// `pArrayStart` and `pArrayShape` are set to the start and shape of the array subset covered by the chunks.
+/// The array subset is not clipped to the array shape.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk range extends beyond the chunk grid.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pChunksStart`, `pChunksShape`, `pArrayStart`, and `pArrayShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunksSubset(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunksStart: *const u64,
+    pChunksShape: *const u64,
+    pArrayStart: *mut u64,
+    pArrayShape: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunksStart and pChunksShape point to arrays of length dimensionality per the function's safety contract.
+    let chunks_start = unsafe { std::slice::from_raw_parts(pChunksStart, dimensionality) };
+    let chunks_shape = unsafe { std::slice::from_raw_parts(pChunksShape, dimensionality) };
+    let chunks = match validated_chunks(array, chunks_start, chunks_shape) {
+        Ok(chunks) => chunks,
+        Err(result) => return result,
+    };
+    match array_fn!(array, chunks_subset, &chunks) {
+        Ok(array_subset) => {
+            // SAFETY: pArrayStart and pArrayShape point to arrays of length dimensionality per the function's safety contract.
+            let pArrayStart =
+                unsafe { std::slice::from_raw_parts_mut(pArrayStart, dimensionality) };
+            pArrayStart.copy_from_slice(array_subset.start());
+            let pArrayShape =
+                unsafe { std::slice::from_raw_parts_mut(pArrayShape, dimensionality) };
+            pArrayShape.copy_from_slice(array_subset.shape());
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+        }
+    }
+}
+
+/// Return the indices of every chunk intersecting an array subset.
+///
+/// `pChunkCount` is set to the number of intersecting chunks.
+/// `ppChunkIndices` is set to a buffer of `pChunkCount * dimensionality` chunk indices, with one row of `dimensionality` indices per chunk.
+/// Chunks are in C order (the last dimension varies fastest).
+/// The buffer must be freed with `zarrsFreeBytes`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of chunk indices overflows a `size_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubsetChunkIndices(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pChunkCount: *mut usize,
+    ppChunkIndices: *mut *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    match chunks_intersecting_subset(array, subset_start, subset_shape) {
+        Ok(chunks_subset) => {
+            let Some(chunk_count) = num_elements(chunks_subset.shape())
+                .and_then(|chunk_count| usize::try_from(chunk_count).ok())
+                .filter(|chunk_count| chunk_count.checked_mul(dimensionality).is_some())
+            else {
+                *LAST_ERROR.lock().unwrap() =
+                    "the number of chunk indices overflows a size_t".to_string();
+                return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+            };
+            let chunk_indices: Vec<u64> = chunks_subset.indices().into_iter().flatten().collect();
+            // SAFETY: pChunkCount and ppChunkIndices are valid pointers per the function's safety contract.
+            unsafe {
+                *pChunkCount = chunk_count;
+                *ppChunkIndices = buffer_into_raw(chunk_indices);
+            }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Return the number of chunks intersecting an array subset.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of chunks overflows a `size_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubsetChunkCount(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pCount: *mut usize,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    match chunks_intersecting_subset(array, subset_start, subset_shape) {
+        Ok(chunks_subset) => {
+            let Some(chunk_count) = num_elements(chunks_subset.shape())
+                .and_then(|chunk_count| usize::try_from(chunk_count).ok())
+            else {
+                *LAST_ERROR.lock().unwrap() = "the number of chunks overflows a size_t".to_string();
+                return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+            };
+            // SAFETY: pCount is a valid pointer per the function's safety contract.
+            unsafe { *pCount = chunk_count };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Get the recommended concurrency for retrieving or storing a subset of an array.
+///
+/// `pSubsetShape` is a pointer to an array of length `dimensionality` holding the shape of the subset.
+/// `pChunkConcurrency` is set to the number of chunks zarrs would process concurrently for a subset of this shape at the array origin.
+/// `pCodecConcurrency` is set to the concurrency zarrs would use within the codecs of each chunk, including the inner chunks of a sharding codec.
+/// These are derived from the codec chain and the global concurrency target, and are informational only.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the codec concurrency cannot be determined.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the array pointed to by `pSubsetShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetRecommendedConcurrency(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetShape: *const u64,
+    pChunkConcurrency: *mut usize,
+    pCodecConcurrency: *mut usize,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pSubsetShape points to an array of length dimensionality per the function's safety contract.
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let array_subset = ArraySubset::new_with_shape(subset_shape.to_vec());
+
+    let chunk_representation =
+        match array_fn!(array, chunk_array_representation, &vec![0; dimensionality]) {
+            Ok(chunk_representation) => chunk_representation,
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err.to_string();
+                return ZarrsResult::ZARRS_ERROR_ARRAY;
+            }
+        };
+    let codec_concurrency =
+        match array_fn!(array, codecs).recommended_concurrency(&chunk_representation) {
+            Ok(codec_concurrency) => codec_concurrency,
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err.to_string();
+                return ZarrsResult::ZARRS_ERROR_ARRAY;
+            }
+        };
+    let concurrent_target = CodecOptions::default().concurrent_target();
+    let num_chunks =
+        usize::try_from(num_chunks_in_subset(array, &array_subset)).unwrap_or(usize::MAX);
+    let (chunk_concurrency, codec_concurrency) = calc_concurrency_outer_inner(
+        concurrent_target,
+        &RecommendedConcurrency::new_maximum(num_chunks.min(concurrent_target)),
+        &codec_concurrency,
+    );
+    // SAFETY: pChunkConcurrency and pCodecConcurrency are valid pointers per the function's safety contract.
+    unsafe {
+        *pChunkConcurrency = chunk_concurrency;
+        *pCodecConcurrency = codec_concurrency;
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the chunk containing an element and the offset of the element within that chunk.
+///
+/// `pElementIndices` is a pointer to an array of length `dimensionality` holding the element indices.
+/// `pChunkIndices` is set to the indices of the chunk containing the element.
+/// `pWithinChunkOffset` is set to the indices of the element relative to the origin of the chunk.
+/// This is computed from the chunk grid, so it supports irregular chunk grids.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pElementIndices` are outside the array shape.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pElementIndices`, `pChunkIndices`, and `pWithinChunkOffset`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkIndicesForElement(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pElementIndices: *const u64,
+    pChunkIndices: *mut u64,
+    pWithinChunkOffset: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pElementIndices points to an array of length dimensionality per the function's safety contract.
+    let element_indices = unsafe { std::slice::from_raw_parts(pElementIndices, dimensionality) };
+    if let Err(result) = element_subset(array, element_indices) {
+        return result;
+    }
+
+    let chunk_grid = array_fn!(array, chunk_grid);
+    let (Ok(Some(chunk_indices)), Ok(Some(within_chunk_offset))) = (
+        chunk_grid.chunk_indices(element_indices),
+        chunk_grid.chunk_element_indices(element_indices),
+    ) else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the chunk containing element {element_indices:?} cannot be determined");
+        return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+    };
+    // SAFETY: pChunkIndices and pWithinChunkOffset point to arrays of length dimensionality per the function's safety contract.
+    let pChunkIndices = unsafe { std::slice::from_raw_parts_mut(pChunkIndices, dimensionality) };
+    pChunkIndices.copy_from_slice(&chunk_indices);
+    let pWithinChunkOffset =
+        unsafe { std::slice::from_raw_parts_mut(pWithinChunkOffset, dimensionality) };
+    pWithinChunkOffset.copy_from_slice(&within_chunk_offset);
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the size of a chunk in bytes.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkSize(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    chunkSize: *mut usize,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+
+    // Get the chunk size
+    let chunk_shape = array_fn!(array, chunk_shape, chunk_indices);
+    match chunk_shape {
+        Ok(chunk_shape) => {
+            let data_type = array_fn!(array, data_type);
+            if let Some(data_type_size) = data_type.fixed_size() {
+                let num_elements: u64 = chunk_shape.iter().map(|d| d.get()).product();
+                // SAFETY: chunkSize is a valid pointer per the function's safety contract.
+                unsafe { *chunkSize = usize::try_from(num_elements).unwrap() * data_type_size };
+                ZarrsResult::ZARRS_SUCCESS
+            } else {
+                *LAST_ERROR.lock().unwrap() =
+                    "variable size data types are not supported".to_string();
+                ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE
+            }
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+        }
+    }
+}
+
+/// Get the number of elements in a chunk.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// Unlike `zarrsArrayGetChunkSize`, which returns the size of a chunk in bytes, this is independent of the data type.
+/// This does not access storage.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk indices are invalid.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+/// `pNumElements` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkSizeElements(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pNumElements: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    match array_fn!(array, chunk_shape, chunk_indices) {
+        Ok(chunk_shape) => {
+            let num_elements = chunk_shape.iter().map(|d| d.get()).product();
+            // SAFETY: pNumElements is a valid pointer per the function's safety contract.
+            unsafe { *pNumElements = num_elements };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+        }
+    }
+}
+
+/// Get the origin of a chunk.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices` and `pChunkOrigin`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkOrigin(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pChunkOrigin: *mut u64,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+
+    // Get the chunk origin
+    let chunk_origin = array_fn!(array, chunk_origin, chunk_indices);
+    match chunk_origin {
+        Ok(chunk_origin) => {
+            // SAFETY: pChunkOrigin points to an array of length dimensionality per the function's safety contract.
+            let pChunkOrigin =
+                unsafe { std::slice::from_raw_parts_mut(pChunkOrigin, dimensionality) };
+            pChunkOrigin.copy_from_slice(&chunk_origin);
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+        }
+    }
+}
+
+/// Get the shape of a chunk.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices` and `pChunkShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkShape(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pChunkShape: *mut u64,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+
+    // Get the chunk shape
+    let chunk_shape = array_fn!(array, chunk_shape, chunk_indices);
+    match chunk_shape {
+        Ok(chunk_shape) => {
+            // SAFETY: pChunkShape points to an array of length dimensionality per the function's safety contract.
+            let pChunkShape =
+                unsafe { std::slice::from_raw_parts_mut(pChunkShape, dimensionality) };
+            pChunkShape.copy_from_slice(&chunk_shape_to_array_shape(&chunk_shape));
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+        }
+    }
+}
+
+/// Get the chunk shape of a regular chunk grid from the chunk grid metadata.
+///
+/// Sets the last error and returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the array does not have a regular chunk grid.
+fn regular_chunk_shape(array: &ZarrsArrayEnum) -> Result<Vec<u64>, ZarrsResult> {
+    let chunk_grid_metadata = array_fn!(array, chunk_grid).create_metadata();
+    let chunk_shape = serde_json::to_value(&chunk_grid_metadata)
+        .ok()
+        .filter(|_| chunk_grid_metadata.name() == "regular")
+        .and_then(|metadata| {
+            serde_json::from_value::<Vec<u64>>(metadata["configuration"]["chunk_shape"].clone())
+                .ok()
+        })
+        .filter(|chunk_shape| {
+            chunk_shape.len() == array_fn!(array, dimensionality)
+                && chunk_shape.iter().all(|&size| size > 0)
+        });
+    chunk_shape.ok_or_else(|| {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "the array does not have a regular chunk grid: {}",
+            chunk_grid_metadata.name()
+        );
+        ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE
+    })
+}
+
+/// Get the chunk shape of a regular chunk grid.
+///
+/// `pChunkShape` is a pointer to an array of length `dimensionality` in which the `chunk_shape` of the regular chunk grid configuration is returned.
+/// This is the shape returned by `zarrsArrayGetChunkShape` for the first chunk, but it is read from the chunk grid metadata without reference to any chunk indices.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the array does not have a regular chunk grid.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pChunkShape` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetRegularChunkShape(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkShape: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    if array_fn!(array, dimensionality) != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            array_fn!(array, dimensionality)
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+
+    let chunk_shape = match regular_chunk_shape(array) {
+        Ok(chunk_shape) => chunk_shape,
+        Err(result) => return result,
+    };
+    // SAFETY: pChunkShape points to an array of length dimensionality per the function's safety contract.
+    let pChunkShape = unsafe { std::slice::from_raw_parts_mut(pChunkShape, dimensionality) };
+    pChunkShape.copy_from_slice(&chunk_shape);
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the shape of the chunks within the array bounds on the upper boundary of the array.
+///
+/// `pRegularChunkShape` is set to the chunk shape of the regular chunk grid, as returned by `zarrsArrayGetRegularChunkShape`.
+/// `pEdgeChunkShape` is set to the shape of the last chunk along each dimension that lies within the array, which equals `pRegularChunkShape` along dimensions where the chunk shape divides the array shape.
+/// `pHasEdgeChunks` is set to true if the chunk shape does not divide the array shape along any dimension.
+///
+/// Chunks are always retrieved and stored with the regular chunk shape (see `zarrsArrayGetChunkSize`), including elements outside of the array bounds.
+/// The edge chunk shape is the part of those chunks within the array, as returned by `zarrsArrayGetChunkSubset` with `clipToArrayShape`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the array does not have a regular chunk grid.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pRegularChunkShape` and `pEdgeChunkShape` must point to arrays of length `dimensionality`.
+/// `pHasEdgeChunks` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkSizeForBoundaryChunks(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pRegularChunkShape: *mut u64,
+    pEdgeChunkShape: *mut u64,
+    pHasEdgeChunks: *mut bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    if array_fn!(array, dimensionality) != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            array_fn!(array, dimensionality)
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+
+    let chunk_shape = match regular_chunk_shape(array) {
+        Ok(chunk_shape) => chunk_shape,
+        Err(result) => return result,
+    };
+    let edge_chunk_shape: Vec<u64> = std::iter::zip(array_fn!(array, shape), &chunk_shape)
+        .map(|(&size, &chunk_size)| match size % chunk_size {
+            0 => chunk_size,
+            remainder => remainder,
+        })
+        .collect();
+    // SAFETY: the output pointers are valid per the function's safety contract.
+    unsafe {
+        *pHasEdgeChunks = edge_chunk_shape != chunk_shape;
+        std::slice::from_raw_parts_mut(pRegularChunkShape, dimensionality)
+            .copy_from_slice(&chunk_shape);
+        std::slice::from_raw_parts_mut(pEdgeChunkShape, dimensionality)
+            .copy_from_slice(&edge_chunk_shape);
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the maximum size of a chunk of an array in bytes.
+///
+/// This is the size of the largest buffer passed to `zarrsArrayRetrieveChunk` or `zarrsArrayStoreChunk` for any chunk of the array.
+/// For a regular chunk grid, this is the size of every chunk.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the chunk grid is not regular or rectangular.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the size of a chunk overflows a `size_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pMaxSize` must be a valid pointer to a `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetMaxChunkSizeBytes(
+    array: ZarrsArray,
+    pMaxSize: *mut usize,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let chunk_grid_shape = match rectangular_chunk_grid_shape(array) {
+        Ok(chunk_grid_shape) => chunk_grid_shape,
+        Err(result) => return result,
+    };
+    let Some(data_type_size) = array_fn!(array, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+
+    // The chunk grid is the Cartesian product of the chunk extents along each dimension, so the largest chunk has the largest extent along every dimension
+    let mut max_chunk_shape = vec![0u64; chunk_grid_shape.len()];
+    for (dim, &grid_size) in chunk_grid_shape.iter().enumerate() {
+        let mut chunk_indices = vec![0u64; chunk_grid_shape.len()];
+        for index in 0..grid_size {
+            chunk_indices[dim] = index;
+            match array_fn!(array, chunk_shape, &chunk_indices) {
+                Ok(chunk_shape) => {
+                    max_chunk_shape[dim] = max_chunk_shape[dim].max(chunk_shape[dim].get());
+                }
+                Err(err) => {
+                    *LAST_ERROR.lock().unwrap() = err.to_string();
+                    return ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE;
+                }
+            }
+        }
+    }
+    let max_size = max_chunk_shape
+        .iter()
+        .try_fold(data_type_size as u64, |size, &chunk_size| {
+            size.checked_mul(chunk_size)
+        })
+        .and_then(|size| usize::try_from(size).ok());
+    let Some(max_size) = max_size else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the size of a chunk with shape {max_chunk_shape:?} overflows a size_t");
+        return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+    };
+    // SAFETY: pMaxSize is a valid pointer per the function's safety contract.
+    unsafe { *pMaxSize = max_size };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the origin and shape of a chunk.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pChunkOrigin` and `pChunkShape` are set to the same values as `zarrsArrayGetChunkOrigin` and `zarrsArrayGetChunkShape` respectively.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunkIndices`, `pChunkOrigin`, and `pChunkShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkOriginAndShape(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pChunkOrigin: *mut u64,
+    pChunkShape: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    if let Err(result) = validate_chunk_indices(array, chunk_indices) {
+        return result;
+    }
+
+    let chunk_origin = array_fn!(array, chunk_origin, chunk_indices);
+    let chunk_shape = array_fn!(array, chunk_shape, chunk_indices);
+    match (chunk_origin, chunk_shape) {
+        (Ok(chunk_origin), Ok(chunk_shape)) => {
+            // SAFETY: pChunkOrigin and pChunkShape point to arrays of length dimensionality per the function's safety contract.
+            let pChunkOrigin =
+                unsafe { std::slice::from_raw_parts_mut(pChunkOrigin, dimensionality) };
+            pChunkOrigin.copy_from_slice(&chunk_origin);
+            let pChunkShape =
+                unsafe { std::slice::from_raw_parts_mut(pChunkShape, dimensionality) };
+            pChunkShape.copy_from_slice(&chunk_shape_to_array_shape(&chunk_shape));
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        (Err(err), _) | (_, Err(err)) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+        }
+    }
+}
+
+/// Get the bounds of a chunk as an array subset.
+///
+/// This is equivalent to `zarrsArrayGetChunkOriginAndShape`, with `pSubsetStart` and `pSubsetShape` set to the chunk origin and shape.
+/// The shape of chunks overhanging the array bounds is not clipped, see `zarrsArrayGetChunkSubset`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunkIndices`, `pSubsetStart`, and `pSubsetShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkBoundsAsSubset(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pSubsetStart: *mut u64,
+    pSubsetShape: *mut u64,
+) -> ZarrsResult {
+    // SAFETY: The safety contract is identical to that of zarrsArrayGetChunkOriginAndShape.
+    unsafe {
+        zarrsArrayGetChunkOriginAndShape(
+            array,
+            dimensionality,
+            pChunkIndices,
+            pSubsetStart,
+            pSubsetShape,
+        )
+    }
+}
+
+/// Get the origin and shape of a chunk as the start and shape of an array subset.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pSubsetStart` and `pSubsetShape` are set to the chunk origin and shape, ready to be passed to `zarrsArrayRetrieveSubset` or `zarrsArrayStoreSubset` when iterating over chunks.
+/// This is equivalent to calling `zarrsArrayGetChunkOrigin` and `zarrsArrayGetChunkShape`.
+/// The shape of chunks overhanging the array bounds is not clipped, see `zarrsArrayGetChunkSubset`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunkIndices`, `pSubsetStart`, and `pSubsetShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkSubsetFromIndices(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pSubsetStart: *mut u64,
+    pSubsetShape: *mut u64,
+) -> ZarrsResult {
+    // SAFETY: The safety contract is identical to that of zarrsArrayGetChunkOriginAndShape.
+    unsafe {
+        zarrsArrayGetChunkOriginAndShape(
+            array,
+            dimensionality,
+            pChunkIndices,
+            pSubsetStart,
+            pSubsetShape,
+        )
+    }
+}
+
+/// Get the array subset of a chunk.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pSubsetStart` and `pSubsetShape` are set to the origin and shape of the chunk in the array.
+/// If `clipToArrayShape` is true, the shape of chunks overhanging the array bounds is clipped to the array shape.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are outside the chunk grid.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunkIndices`, `pSubsetStart`, and `pSubsetShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkSubset(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    clipToArrayShape: bool,
+    pSubsetStart: *mut u64,
+    pSubsetShape: *mut u64,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    if let Err(result) = validate_chunk_indices(array, chunk_indices) {
+        return result;
+    }
+
+    // Get the chunk subset
+    let chunk_subset = if clipToArrayShape {
+        array_fn!(array, chunk_subset_bounded, chunk_indices)
+    } else {
+        array_fn!(array, chunk_subset, chunk_indices)
+    };
+    match chunk_subset {
+        Ok(chunk_subset) => {
+            // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+            let pSubsetStart =
+                unsafe { std::slice::from_raw_parts_mut(pSubsetStart, dimensionality) };
+            pSubsetStart.copy_from_slice(chunk_subset.start());
+            let pSubsetShape =
+                unsafe { std::slice::from_raw_parts_mut(pSubsetShape, dimensionality) };
+            pSubsetShape.copy_from_slice(chunk_subset.shape());
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+        }
+    }
+}
+
+/// Get the intersection of the array subset of a chunk with an array subset.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the array subset start and shape respectively.
+/// `pIntersectStart` and `pIntersectShape` are set to the start and shape of the intersection in the array.
+/// If the chunk does not intersect the array subset, `pIntersectShape` will contain a zero.
+/// The intersection is within the array shape, even if the chunk extends past it.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are outside the chunk grid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pChunkIndices`, `pSubsetStart`, `pSubsetShape`, `pIntersectStart`, and `pIntersectShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkIntersectWithSubset(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pIntersectStart: *mut u64,
+    pIntersectShape: *mut u64,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pChunkIndices, pSubsetStart, and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    if let Err(result) = validate_chunk_indices(array, chunk_indices) {
+        return result;
+    }
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+
+    // Get the chunk subset
+    let chunk_subset = match array_fn!(array, chunk_subset, chunk_indices) {
+        Ok(chunk_subset) => chunk_subset,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+        }
+    };
+
+    // Intersect with the array subset
+    match chunk_subset.overlap(&array_subset) {
+        Ok(intersection) => {
+            // SAFETY: pIntersectStart and pIntersectShape point to arrays of length dimensionality per the function's safety contract.
+            let pIntersectStart =
+                unsafe { std::slice::from_raw_parts_mut(pIntersectStart, dimensionality) };
+            pIntersectStart.copy_from_slice(intersection.start());
+            let pIntersectShape =
+                unsafe { std::slice::from_raw_parts_mut(pIntersectShape, dimensionality) };
+            pIntersectShape.copy_from_slice(intersection.shape());
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY
+        }
+    }
+}
+
+/// Get the intersection of a chunk with an array subset for partial chunk I/O.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the array subset start and shape respectively.
+/// `pIntersectStart` and `pIntersectShape` are set to the start and shape of the intersection in the array.
+/// Unlike `zarrsArrayGetChunkIntersectWithSubset`, if the chunk does not intersect the array subset, `pIntersectShape` is set to all zeros.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are outside the chunk grid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pChunkIndices`, `pSubsetStart`, `pSubsetShape`, `pIntersectStart`, and `pIntersectShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkIntersectionWithSubset(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pIntersectStart: *mut u64,
+    pIntersectShape: *mut u64,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of zarrsArrayGetChunkIntersectWithSubset.
+    let result = unsafe {
+        zarrsArrayGetChunkIntersectWithSubset(
+            array,
+            dimensionality,
+            pChunkIndices,
+            pSubsetStart,
+            pSubsetShape,
+            pIntersectStart,
+            pIntersectShape,
+        )
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        // SAFETY: pIntersectShape points to an array of length dimensionality per the function's safety contract.
+        let intersect_shape =
+            unsafe { std::slice::from_raw_parts_mut(pIntersectShape, dimensionality) };
+        if intersect_shape.contains(&0) {
+            intersect_shape.fill(0);
+        }
+    }
+    result
+}
+
+/// Validate that an array subset is within the bounds of an array.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// This applies the same validation as `zarrsArrayRetrieveSubset` and `zarrsArrayStoreSubset`, and does not access storage.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape (or its end overflows a `uint64_t`), and the last error names the offending dimension.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pSubsetStart` and `pSubsetShape` must point to arrays of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayValidateSubset(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(_) => ZarrsResult::ZARRS_SUCCESS,
+        Err(result) => result,
+    }
+}
+
+/// The intersection of the subset with start `subset_start` and shape `subset_shape` with an array of shape `array_shape`.
+///
+/// The shape is zero in every dimension if the intersection is empty.
+fn subset_intersection(
+    array_shape: &[u64],
+    subset_start: &[u64],
+    subset_shape: &[u64],
+) -> (Vec<u64>, Vec<u64>) {
+    let (clipped_start, mut clipped_shape): (Vec<u64>, Vec<u64>) =
+        std::iter::zip(std::iter::zip(subset_start, subset_shape), array_shape)
+            .map(|((&start, &shape), &size)| {
+                let clipped_start = start.min(size);
+                let clipped_end = start.saturating_add(shape).min(size);
+                (clipped_start, clipped_end - clipped_start)
+            })
+            .unzip();
+    if clipped_shape.contains(&0) {
+        clipped_shape.fill(0);
+    }
+    (clipped_start, clipped_shape)
+}
+
+/// Get the intersection of a subset with the array bounds.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively, which may extend past the array shape.
+/// `pClippedStart` and `pClippedShape` are pointers to arrays of length `dimensionality` in which the start and shape of the intersection are returned.
+/// If the intersection is empty, every element of `pClippedShape` is set to zero.
+/// The end of the subset saturates rather than overflows, so the clipped subset is always valid for `zarrsArrayRetrieveSubset` and `zarrsArrayStoreSubset`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pSubsetStart`, `pSubsetShape`, `pClippedStart`, and `pClippedShape` must point to arrays of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubsetIntersection(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pClippedStart: *mut u64,
+    pClippedShape: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_shape = array_fn!(array, shape);
+    if array_shape.len() != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            array_shape.len()
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let (clipped_start, clipped_shape) =
+        subset_intersection(array_shape, subset_start, subset_shape);
+    // SAFETY: pClippedStart and pClippedShape point to arrays of length dimensionality per the function's safety contract.
+    let pClippedStart = unsafe { std::slice::from_raw_parts_mut(pClippedStart, dimensionality) };
+    pClippedStart.copy_from_slice(&clipped_start);
+    let pClippedShape = unsafe { std::slice::from_raw_parts_mut(pClippedShape, dimensionality) };
+    pClippedShape.copy_from_slice(&clipped_shape);
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Check whether the intersection of a subset with the array bounds is empty.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// `pIsEmpty` is set to true if the subset has a zero-length dimension or lies entirely outside of the array shape, i.e. if `zarrsArrayGetSubsetIntersection` returns an empty intersection.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pIsEmpty` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pIsEmpty` must be a valid pointer to a `bool`.
+/// `pSubsetStart` and `pSubsetShape` must point to arrays of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayIsSubsetEmpty(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pIsEmpty: *mut bool,
+) -> ZarrsResult {
+    if array.is_null() || pIsEmpty.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_shape = array_fn!(array, shape);
+    if array_shape.len() != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            array_shape.len()
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let (_, clipped_shape) = subset_intersection(array_shape, subset_start, subset_shape);
+    // SAFETY: pIsEmpty is not null, and the caller guarantees it is a valid pointer to a bool.
+    unsafe { *pIsEmpty = clipped_shape.contains(&0) };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the size of a subset in bytes.
+///
+/// `pSubsetShape` is a pointer to an array of length `dimensionality` holding the shape of the subset.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pSubsetShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubsetSize(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetShape: *const u64,
+    subsetSize: *mut usize,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pSubsetShape points to an array of length dimensionality per the function's safety contract.
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+
+    // Get the data type
+    let data_type = array_fn!(array, data_type);
+    let Some(data_type_size) = data_type.fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+
+    // Get the subset size
+    // SAFETY: subsetSize is a valid pointer per the function's safety contract.
+    unsafe {
+        *subsetSize =
+            usize::try_from(subset_shape.iter().product::<u64>()).unwrap() * data_type_size
+    };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the number of elements in a subset.
+///
+/// `pSubsetShape` is a pointer to an array of length `dimensionality` holding the shape of the subset.
+/// Unlike `zarrsArrayGetSubsetSize`, which returns the size of a subset in bytes, this is independent of the data type.
+/// This does not access storage.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of elements overflows a `uint64_t`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pSubsetShape` must point to an array of length `dimensionality`.
+/// `pNumElements` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubsetSizeElements(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetShape: *const u64,
+    pNumElements: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: pSubsetShape points to an array of length dimensionality per the function's safety contract.
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let Some(num_elements) = num_elements(subset_shape) else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the number of elements in {subset_shape:?} overflows");
+        return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+    };
+    // SAFETY: pNumElements is a valid pointer per the function's safety contract.
+    unsafe { *pNumElements = num_elements };
     ZarrsResult::ZARRS_SUCCESS
 }
 
-/// Return the number of chunks in the chunk grid.
+/// Fill `bytes` with repetitions of the fill value of `array`, truncating the last repetition if necessary.
+fn fill_with_fill_value(array: &ZarrsArrayEnum, bytes: &mut [u8]) {
+    let fill_value = array_fn!(array, fill_value).as_ne_bytes();
+    for (byte, fill_value_byte) in std::iter::zip(bytes, fill_value.iter().cycle()) {
+        *byte = *fill_value_byte;
+    }
+}
+
+/// Fill a subset buffer with the fill value of the array.
+///
+/// `pSubsetShape` is a pointer to an array of length `dimensionality` holding the shape of the subset.
+/// `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+///
+/// This function does not access storage.
 ///
 /// # Errors
 /// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
 /// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the size of the subset overflows a `size_t`.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `subsetBytesCount` does not match the expected size of the subset.
 ///
 /// # Safety
-/// If not null, `array` must be a valid `ZarrsArray` handle.
-/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkGridShape`.
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pSubsetShape` must point to an array of length `dimensionality`.
+/// `pSubsetBytes` must point to an array of length `subsetBytesCount`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayGetChunkGridShape(
+pub unsafe extern "C" fn zarrsArrayGetFilledSubset(
     array: ZarrsArray,
     dimensionality: usize,
-    pChunkGridShape: *mut u64,
+    pSubsetShape: *const u64,
+    subsetBytesCount: usize,
+    pSubsetBytes: *mut u8,
 ) -> ZarrsResult {
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
     let array = unsafe { &**array };
-    let chunk_grid_shape = array_fn!(array, chunk_grid_shape);
-    if chunk_grid_shape.len() != dimensionality {
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
         return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
     }
-    // SAFETY: pChunkGridShape points to an array of length dimensionality per the function's safety contract.
-    let pChunkGridShape =
-        unsafe { std::slice::from_raw_parts_mut(pChunkGridShape, dimensionality) };
-    pChunkGridShape.copy_from_slice(chunk_grid_shape);
+    // SAFETY: pSubsetShape points to an array of length dimensionality per the function's safety contract.
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+
+    let Some(data_type_size) = array_fn!(array, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let Some(subset_size) = num_elements(subset_shape)
+        .and_then(|num_elements| usize::try_from(num_elements).ok())
+        .and_then(|num_elements| num_elements.checked_mul(data_type_size))
+    else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the size of a subset with shape {subset_shape:?} overflows");
+        return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+    };
+    if subsetBytesCount != subset_size {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "subset_bytes_length {subsetBytesCount} does not match expected length {subset_size}"
+        );
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+
+    // SAFETY: pSubsetBytes points to an array of length subsetBytesCount per the function's safety contract.
+    let subset_bytes = unsafe { std::slice::from_raw_parts_mut(pSubsetBytes, subsetBytesCount) };
+    fill_with_fill_value(array, subset_bytes);
     ZarrsResult::ZARRS_SUCCESS
 }
 
-/// Return the chunks indicating the chunks intersecting `array_subset`.
+/// Fill a buffer with the fill value of the array.
+///
+/// `pBytes` is a pointer to an array of bytes of length `bytesCount`.
+/// The fill value is repeated to fill the buffer.
+/// If `bytesCount` is not a multiple of the data type size, the last element is truncated.
+///
+/// This function does not access storage.
 ///
 /// # Errors
 /// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
-/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
-/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
 ///
 /// # Safety
-/// If not null, `array` must be a valid `ZarrsArray` handle.
-/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart`, `pSubsetShape`, `pChunksStart`, and `pChunksShape`.
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pBytes` must point to an array of length `bytesCount`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayGetChunksInSubset(
+pub unsafe extern "C" fn zarrsArrayFillBuffer(
     array: ZarrsArray,
-    dimensionality: usize,
-    pSubsetStart: *const u64,
-    pSubsetShape: *const u64,
-    pChunksStart: *mut u64,
-    pChunksShape: *mut u64,
+    bytesCount: usize,
+    pBytes: *mut u8,
 ) -> ZarrsResult {
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
     let array = unsafe { &**array };
-    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
-    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
-    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
-    let array_subset = ArraySubset::from(
-        std::iter::zip(subset_start, subset_shape).map(|(&start, &shape)| start..start + shape),
-    );
-    let shape = array_fn!(array, chunks_in_array_subset, &array_subset);
-    match shape {
-        Ok(Some(chunks_subset)) => {
-            // SAFETY: pChunksStart and pChunksShape point to arrays of length dimensionality per the function's safety contract.
-            let pChunksStart =
-                unsafe { std::slice::from_raw_parts_mut(pChunksStart, dimensionality) };
-            pChunksStart.copy_from_slice(chunks_subset.start());
-            let pChunksShape =
-                unsafe { std::slice::from_raw_parts_mut(pChunksShape, dimensionality) };
-            pChunksShape.copy_from_slice(chunks_subset.shape());
-            ZarrsResult::ZARRS_SUCCESS
-        }
-        Ok(None) => ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS,
-        Err(_) => ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY,
+    if array_fn!(array, data_type).fixed_size().is_none() {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
     }
+    // SAFETY: pBytes points to an array of length bytesCount per the function's safety contract.
+    let bytes = unsafe { std::slice::from_raw_parts_mut(pBytes, bytesCount) };
+    fill_with_fill_value(array, bytes);
+    ZarrsResult::ZARRS_SUCCESS
 }
 
-/// Get the size of a chunk in bytes.
+/// Get the fill value of the array as a JSON string.
 ///
-/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// The fill value is encoded as it appears in the array metadata (e.g. `0`, `3.14`, `"NaN"`, `false`).
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the fill value cannot be converted to a JSON string.
 ///
 /// # Safety
-/// `array` must be a valid `ZarrsArray` handle.
-/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pFillValueJson` must be a valid pointer to a `char*`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayGetChunkSize(
+pub unsafe extern "C" fn zarrsArrayGetFillValueString(
     array: ZarrsArray,
-    dimensionality: usize,
-    pChunkIndices: *const u64,
-    chunkSize: *mut usize,
+    pFillValueJson: *mut *mut c_char,
 ) -> ZarrsResult {
-    // Validation
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
     let array = unsafe { &**array };
-    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
-    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
 
-    // Get the chunk size
-    let chunk_shape = array_fn!(array, chunk_shape, chunk_indices);
-    match chunk_shape {
-        Ok(chunk_shape) => {
-            let data_type = array_fn!(array, data_type);
-            if let Some(data_type_size) = data_type.fixed_size() {
-                let num_elements: u64 = chunk_shape.iter().map(|d| d.get()).product();
-                // SAFETY: chunkSize is a valid pointer per the function's safety contract.
-                unsafe { *chunkSize = usize::try_from(num_elements).unwrap() * data_type_size };
-                ZarrsResult::ZARRS_SUCCESS
-            } else {
-                *LAST_ERROR.lock().unwrap() =
-                    "variable size data types are not supported".to_string();
-                ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE
-            }
-        }
+    if let Ok(metadata) = serde_json::to_value(array_fn!(array, metadata))
+        && let Ok(fill_value) = serde_json::to_string(&metadata["fill_value"])
+        && let Ok(cstring) = CString::new(fill_value)
+    {
+        // SAFETY: pFillValueJson is a valid pointer per the function's safety contract.
+        unsafe { *pFillValueJson = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() = "error converting the fill value to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
+
+/// Create an array with the same storage, path, and metadata as `array` except for the fill value.
+///
+/// Returns an error if `fill_value` is not a valid fill value for the data type of the array.
+fn array_with_fill_value<T: ?Sized + 'static>(
+    array: &Array<T>,
+    fill_value: serde_json::Value,
+) -> Result<Array<T>, String> {
+    let mut metadata = serde_json::to_value(array.metadata()).map_err(|err| err.to_string())?;
+    metadata["fill_value"] = fill_value;
+    let metadata: ArrayMetadata =
+        serde_json::from_value(metadata).map_err(|err| err.to_string())?;
+    Array::new_with_metadata(array.storage(), array.path().as_str(), metadata)
+        .map_err(|err| err.to_string())
+}
+
+/// Set the fill value of the array from a JSON string.
+///
+/// `fillValueJson` uses the same representation as the fill value in the array metadata (see `zarrsArrayGetFillValueString`).
+/// This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+/// The array handle is unchanged if the fill value is invalid.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if `fillValueJson` is not valid JSON or is not a valid fill value for the data type of the array.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArraySetFillValueFromString(
+    array: ZarrsArray,
+    fillValueJson: FfiStr,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &mut **array };
+
+    let fill_value = match serde_json::from_str::<serde_json::Value>(fillValueJson.as_str()) {
+        Ok(fill_value) => fill_value,
         Err(err) => {
             *LAST_ERROR.lock().unwrap() = err.to_string();
-            ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+            return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+        }
+    };
+    let updated = match array {
+        ZarrsArrayEnum::R(array) => array_with_fill_value(array, fill_value).map(|a| *array = a),
+        ZarrsArrayEnum::W(array) => array_with_fill_value(array, fill_value).map(|a| *array = a),
+        ZarrsArrayEnum::L(array) => array_with_fill_value(array, fill_value).map(|a| *array = a),
+        ZarrsArrayEnum::RL(array) => array_with_fill_value(array, fill_value).map(|a| *array = a),
+        ZarrsArrayEnum::RW(array) => array_with_fill_value(array, fill_value).map(|a| *array = a),
+        ZarrsArrayEnum::RWL(array) => array_with_fill_value(array, fill_value).map(|a| *array = a),
+    };
+    match updated {
+        Ok(()) => ZarrsResult::ZARRS_SUCCESS,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err;
+            ZarrsResult::ZARRS_ERROR_INVALID_METADATA
         }
     }
 }
 
-/// Get the origin of a chunk.
+/// Get the array metadata as a JSON string.
 ///
-/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// The string must be freed with `zarrsFreeString`.
 ///
 /// # Safety
 /// `array` must be a valid `ZarrsArray` handle.
-/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices` and `pChunkOrigin`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayGetChunkOrigin(
+pub unsafe extern "C" fn zarrsArrayGetMetadataString(
     array: ZarrsArray,
-    dimensionality: usize,
-    pChunkIndices: *const u64,
-    pChunkOrigin: *mut u64,
+    pretty: bool,
+    pMetadataString: *mut *mut c_char,
 ) -> ZarrsResult {
     // Validation
     if array.is_null() {
@@ -433,139 +3496,448 @@ pub unsafe extern "C" fn zarrsArrayGetChunkOrigin(
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
     let array = unsafe { &**array };
-    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
-    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
 
-    // Get the chunk origin
-    let chunk_origin = array_fn!(array, chunk_origin, chunk_indices);
-    match chunk_origin {
-        Ok(chunk_origin) => {
-            // SAFETY: pChunkOrigin points to an array of length dimensionality per the function's safety contract.
-            let pChunkOrigin =
-                unsafe { std::slice::from_raw_parts_mut(pChunkOrigin, dimensionality) };
-            pChunkOrigin.copy_from_slice(&chunk_origin);
+    let metadata = array_fn!(array, metadata);
+    let metadata_str = if pretty {
+        serde_json::to_string_pretty(&metadata)
+    } else {
+        serde_json::to_string(&metadata)
+    };
+    if let Ok(metadata_str) = metadata_str
+        && let Ok(cstring) = CString::new(metadata_str)
+    {
+        // SAFETY: pMetadataString is a valid pointer per the function's safety contract.
+        unsafe { *pMetadataString = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() = "error converting metadata to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
+
+/// Get the permutation order of the `transpose` codec in the codec pipeline of the array.
+///
+/// `pHasTranspose` is set to true if the codec pipeline includes a `transpose` codec, otherwise false.
+/// If the codec pipeline does not include a `transpose` codec, the contents of `pPermutation` will be undefined.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the `transpose` codec configuration cannot be interpreted.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pPermutation`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetTransposeCodecOrder(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pPermutation: *mut usize,
+    pHasTranspose: *mut bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+
+    let codecs = array_fn!(array, codecs).create_metadatas();
+    let Some(transpose) = codecs.iter().find(|codec| codec.name() == "transpose") else {
+        // SAFETY: pHasTranspose is a valid pointer per the function's safety contract.
+        unsafe { *pHasTranspose = false };
+        return ZarrsResult::ZARRS_SUCCESS;
+    };
+
+    let order: Option<Vec<usize>> = serde_json::to_value(transpose)
+        .ok()
+        .and_then(|transpose| transpose["configuration"]["order"].as_array().cloned())
+        .and_then(|order| {
+            order
+                .iter()
+                .map(|axis| axis.as_u64().and_then(|axis| usize::try_from(axis).ok()))
+                .collect()
+        });
+    match order {
+        Some(order) if order.len() == dimensionality => {
+            // SAFETY: pPermutation points to an array of length dimensionality per the function's safety contract.
+            let pPermutation =
+                unsafe { std::slice::from_raw_parts_mut(pPermutation, dimensionality) };
+            pPermutation.copy_from_slice(&order);
+            // SAFETY: pHasTranspose is a valid pointer per the function's safety contract.
+            unsafe { *pHasTranspose = true };
             ZarrsResult::ZARRS_SUCCESS
         }
-        Err(err) => {
-            *LAST_ERROR.lock().unwrap() = err.to_string();
-            ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+        _ => {
+            *LAST_ERROR.lock().unwrap() =
+                "error interpreting the transpose codec order".to_string();
+            ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+        }
+    }
+}
+
+/// Get the layout of the elements within encoded chunks from the codec pipeline of an array.
+///
+/// Sets the last error on failure.
+fn chunk_representation(array: &ZarrsArrayEnum) -> Result<serde_json::Value, ZarrsResult> {
+    let dimensionality = array_fn!(array, dimensionality);
+    let codecs = array_fn!(array, codecs).create_metadatas();
+    let codec_metadata = |name: &str| {
+        codecs
+            .iter()
+            .find(|codec| codec.name() == name)
+            .map(|codec| serde_json::to_value(codec).unwrap_or_default())
+    };
+
+    let order = match codec_metadata("transpose") {
+        None => serde_json::json!("C"),
+        Some(transpose) => {
+            let permutation: Option<Vec<usize>> = transpose["configuration"]["order"]
+                .as_array()
+                .and_then(|order| {
+                    order
+                        .iter()
+                        .map(|axis| axis.as_u64().and_then(|axis| usize::try_from(axis).ok()))
+                        .collect()
+                });
+            match permutation {
+                Some(permutation) if permutation.len() == dimensionality => {
+                    if permutation.iter().copied().eq(0..dimensionality) {
+                        serde_json::json!("C")
+                    } else if permutation.iter().copied().eq((0..dimensionality).rev()) {
+                        serde_json::json!("F")
+                    } else {
+                        serde_json::json!(permutation)
+                    }
+                }
+                _ => {
+                    *LAST_ERROR.lock().unwrap() =
+                        "error interpreting the transpose codec order".to_string();
+                    return Err(ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+                }
+            }
         }
+    };
+    let endian = codec_metadata("bytes").map_or(serde_json::Value::Null, |bytes| {
+        bytes["configuration"]["endian"].clone()
+    });
+    Ok(serde_json::json!({ "order": order, "endian": endian }))
+}
+
+/// Get the layout of the elements within the encoded chunks of an array as a JSON string.
+///
+/// The layout is determined from the codec pipeline of the array, and is a JSON object with the fields:
+/// - `order`: `"C"` (row-major) if there is no `transpose` codec or it is the identity permutation, `"F"` (column-major) if the `transpose` codec reverses the dimensions, or otherwise the permutation of the `transpose` codec.
+/// - `endian`: the endianness of the `bytes` codec (`"little"` or `"big"`), or null if there is no `bytes` codec or it has no endianness.
+///
+/// This describes the chunks as they are encoded in the store.
+/// Chunks and subsets decoded by zarrs are always in C order and native endianness, irrespective of the layout of the encoded chunks.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pJson` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the codec metadata cannot be interpreted.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pJson` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkRepresentationString(
+    array: ZarrsArray,
+    pretty: bool,
+    pJson: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() || pJson.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let chunk_representation = match chunk_representation(array) {
+        Ok(chunk_representation) => chunk_representation,
+        Err(result) => return result,
+    };
+    let chunk_representation_str = if pretty {
+        serde_json::to_string_pretty(&chunk_representation)
+    } else {
+        serde_json::to_string(&chunk_representation)
+    };
+    if let Ok(chunk_representation_str) = chunk_representation_str
+        && let Ok(cstring) = CString::new(chunk_representation_str)
+    {
+        // SAFETY: pJson is not null, and the caller guarantees it is a valid pointer.
+        unsafe { *pJson = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() =
+        "error converting chunk representation to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
+
+/// Get the order of the elements within the encoded chunks of an array.
+///
+/// `pLayoutString` is set to `"C"` (row-major) or `"F"` (column-major), as reported in the `order` of `zarrsArrayGetChunkRepresentationString`.
+/// Chunks and subsets decoded by zarrs are always in C order, irrespective of this layout.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pLayoutString` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the codec metadata cannot be interpreted, or the `transpose` codec permutation is neither C nor F order.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pLayoutString` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetDataLayout(
+    array: ZarrsArray,
+    pLayoutString: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() || pLayoutString.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let chunk_representation = match chunk_representation(array) {
+        Ok(chunk_representation) => chunk_representation,
+        Err(result) => return result,
+    };
+    let Some(layout) = chunk_representation["order"].as_str() else {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "the transpose codec order {} is neither C nor F order",
+            chunk_representation["order"]
+        );
+        return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+    };
+    // SAFETY: pLayoutString is not null, and the caller guarantees it is a valid pointer.
+    unsafe { *pLayoutString = CString::new(layout).unwrap().into_raw() };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the dimension names of the array.
+///
+/// `ppNames` is set to an array of `dimensionality` dimension names.
+/// Unnamed dimensions are null, and every dimension is unnamed if the array has no dimension names.
+/// The names must be freed with `zarrsFreeStringArray`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if a dimension name cannot be converted to a C string.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `ppNames` must be a valid pointer to a `char**`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetDimensionNames(
+    array: ZarrsArray,
+    dimensionality: usize,
+    ppNames: *mut *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
     }
+
+    let names = match array_fn!(array, dimension_names) {
+        Some(dimension_names) => dimension_names
+            .iter()
+            .map(|name| name.as_str().map(CString::new).transpose())
+            .collect::<Result<Vec<_>, _>>(),
+        None => Ok(vec![None; dimensionality]),
+    };
+    let Ok(names) = names else {
+        *LAST_ERROR.lock().unwrap() = "error converting dimension names to strings".to_string();
+        return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+    };
+
+    let names: Box<[*mut c_char]> = names
+        .into_iter()
+        .map(|name| name.map_or(std::ptr::null_mut(), CString::into_raw))
+        .collect();
+    // SAFETY: ppNames is a valid pointer per the function's safety contract.
+    unsafe { *ppNames = Box::into_raw(names).cast::<*mut c_char>() };
+    ZarrsResult::ZARRS_SUCCESS
 }
 
-/// Get the shape of a chunk.
+/// Set the dimension names of the array.
 ///
-/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pNames` is a pointer to an array of length `dimensionality` holding the dimension names.
+/// Null entries are unnamed dimensions.
+/// If `pNames` is null, the dimension names are removed.
+///
+/// This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if a dimension name is not valid UTF-8.
 ///
 /// # Safety
-/// `array` must be a valid `ZarrsArray` handle.
-/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices` and `pChunkShape`.
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// If not null, `pNames` must point to an array of length `dimensionality` of null or valid C strings.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayGetChunkShape(
+pub unsafe extern "C" fn zarrsArraySetDimensionNames(
     array: ZarrsArray,
     dimensionality: usize,
-    pChunkIndices: *const u64,
-    pChunkShape: *mut u64,
+    pNames: *const *const c_char,
 ) -> ZarrsResult {
-    // Validation
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
-    let array = unsafe { &**array };
-    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
-    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    let array = unsafe { &mut **array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
 
-    // Get the chunk shape
-    let chunk_shape = array_fn!(array, chunk_shape, chunk_indices);
-    match chunk_shape {
-        Ok(chunk_shape) => {
-            // SAFETY: pChunkShape points to an array of length dimensionality per the function's safety contract.
-            let pChunkShape =
-                unsafe { std::slice::from_raw_parts_mut(pChunkShape, dimensionality) };
-            pChunkShape.copy_from_slice(&chunk_shape_to_array_shape(&chunk_shape));
-            ZarrsResult::ZARRS_SUCCESS
+    let dimension_names = if pNames.is_null() {
+        None
+    } else {
+        // SAFETY: pNames points to an array of length dimensionality per the function's safety contract.
+        let names = unsafe { std::slice::from_raw_parts(pNames, dimensionality) };
+        let names = names
+            .iter()
+            .map(|&name| {
+                if name.is_null() {
+                    Ok(DimensionName::new(None::<String>))
+                } else {
+                    // SAFETY: name is not null, and the caller guarantees it is a valid C string.
+                    let name = unsafe { CStr::from_ptr(name) };
+                    name.to_str().map(|name| DimensionName::new(Some(name)))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>();
+        match names {
+            Ok(names) => Some(names),
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err.to_string();
+                return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+            }
         }
-        Err(err) => {
-            *LAST_ERROR.lock().unwrap() = err.to_string();
-            ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+    };
+    // set_dimension_names returns the array itself, so the arms cannot share a type through array_fn!
+    match array {
+        ZarrsArrayEnum::R(array) => {
+            array.set_dimension_names(dimension_names);
+        }
+        ZarrsArrayEnum::W(array) => {
+            array.set_dimension_names(dimension_names);
+        }
+        ZarrsArrayEnum::L(array) => {
+            array.set_dimension_names(dimension_names);
+        }
+        ZarrsArrayEnum::RL(array) => {
+            array.set_dimension_names(dimension_names);
+        }
+        ZarrsArrayEnum::RW(array) => {
+            array.set_dimension_names(dimension_names);
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            array.set_dimension_names(dimension_names);
         }
     }
+    ZarrsResult::ZARRS_SUCCESS
 }
 
-/// Get the size of a subset in bytes.
+/// Get the names of the codecs in the codec pipeline of the array.
 ///
-/// `pSubsetShape` is a pointer to an array of length `dimensionality` holding the shape of the subset.
+/// `pCount` is set to the number of codecs.
+/// `ppNames` is set to an array of `pCount` codec names in pipeline order.
+/// The names must be freed with `zarrsFreeStringArray`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if a codec name cannot be converted to a C string.
 ///
 /// # Safety
-/// `array` must be a valid `ZarrsArray` handle.
-/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pSubsetShape`.
+/// If not null, `array` must be a valid `ZarrsArray` handle.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayGetSubsetSize(
+pub unsafe extern "C" fn zarrsArrayGetCodecNames(
     array: ZarrsArray,
-    dimensionality: usize,
-    pSubsetShape: *const u64,
-    subsetSize: *mut usize,
+    pCount: *mut usize,
+    ppNames: *mut *mut *mut c_char,
 ) -> ZarrsResult {
-    // Validation
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
     let array = unsafe { &**array };
-    // SAFETY: pSubsetShape points to an array of length dimensionality per the function's safety contract.
-    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
 
-    // Get the data type
-    let data_type = array_fn!(array, data_type);
-    let Some(data_type_size) = data_type.fixed_size() else {
-        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
-        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    let codecs = array_fn!(array, codecs).create_metadatas();
+    let Ok(names) = codecs
+        .iter()
+        .map(|codec| CString::new(codec.name()))
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        *LAST_ERROR.lock().unwrap() = "error converting codec names to strings".to_string();
+        return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
     };
 
-    // Get the subset size
-    // SAFETY: subsetSize is a valid pointer per the function's safety contract.
+    let names: Box<[*mut c_char]> = names.into_iter().map(CString::into_raw).collect();
+    // SAFETY: pCount and ppNames are valid pointers per the function's safety contract.
     unsafe {
-        *subsetSize =
-            usize::try_from(subset_shape.iter().product::<u64>()).unwrap() * data_type_size
-    };
+        *pCount = names.len();
+        *ppNames = Box::into_raw(names).cast::<*mut c_char>();
+    }
     ZarrsResult::ZARRS_SUCCESS
 }
 
-/// Get the array metadata as a JSON string.
+/// Get the storage transformers of the array as a JSON string.
 ///
+/// `pJson` is set to the `storage_transformers` metadata array, which is empty if the array has no storage transformers.
+/// Zarr V2 arrays do not support storage transformers and always return an empty array.
 /// The string must be freed with `zarrsFreeString`.
 ///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the storage transformers cannot be converted to a JSON string.
+///
 /// # Safety
-/// `array` must be a valid `ZarrsArray` handle.
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pJson` must be a valid pointer to a `char*`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayGetMetadataString(
+pub unsafe extern "C" fn zarrsArrayGetStorageTransformersString(
     array: ZarrsArray,
-    pretty: bool,
-    pMetadataString: *mut *mut c_char,
+    pJson: *mut *mut c_char,
 ) -> ZarrsResult {
-    // Validation
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
     let array = unsafe { &**array };
 
-    let metadata = array_fn!(array, metadata);
-    let metadata_str = if pretty {
-        serde_json::to_string_pretty(&metadata)
-    } else {
-        serde_json::to_string(&metadata)
+    let storage_transformers_str = match array_fn!(array, metadata) {
+        ArrayMetadata::V3(metadata) => serde_json::to_string(&metadata.storage_transformers),
+        ArrayMetadata::V2(_) => Ok("[]".to_string()),
     };
-    if let Ok(metadata_str) = metadata_str
-        && let Ok(cstring) = CString::new(metadata_str)
+    if let Ok(storage_transformers_str) = storage_transformers_str
+        && let Ok(cstring) = CString::new(storage_transformers_str)
     {
-        // SAFETY: pMetadataString is a valid pointer per the function's safety contract.
-        unsafe { *pMetadataString = cstring.into_raw() };
+        // SAFETY: pJson is a valid pointer per the function's safety contract.
+        unsafe { *pJson = cstring.into_raw() };
         return ZarrsResult::ZARRS_SUCCESS;
     }
 
-    *LAST_ERROR.lock().unwrap() = "error converting metadata to a json string".to_string();
+    *LAST_ERROR.lock().unwrap() =
+        "error converting storage transformers to a json string".to_string();
     ZarrsResult::ZARRS_ERROR_INVALID_METADATA
 }
 
@@ -622,6 +3994,175 @@ pub unsafe extern "C" fn zarrsArrayGetAttributesString(
     unsafe { zarrsArrayGetAttributes(array, pretty, pAttributesString) }
 }
 
+/// Get an array attribute as a JSON string.
+///
+/// `jsonPointer` is a JSON pointer (RFC 6901) resolved against the attributes, such as `/multiscales/0/axes`.
+/// The resolved value may be any JSON value, including a scalar.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_NOT_FOUND` if `jsonPointer` does not resolve to a value.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pValueJson` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetAttribute(
+    array: ZarrsArray,
+    jsonPointer: FfiStr,
+    pretty: bool,
+    pValueJson: *mut *mut c_char,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let json_pointer: &str = jsonPointer.into();
+
+    let attributes = serde_json::Value::Object(array_fn!(array, attributes).clone());
+    let Some(value) = attributes.pointer(json_pointer) else {
+        *LAST_ERROR.lock().unwrap() = format!("attribute {json_pointer} not found");
+        return ZarrsResult::ZARRS_ERROR_NOT_FOUND;
+    };
+    let value_str = if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    };
+    if let Ok(value_str) = value_str
+        && let Ok(cstring) = CString::new(value_str)
+    {
+        // SAFETY: pValueJson is a valid pointer per the function's safety contract.
+        unsafe { *pValueJson = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() = "error converting attribute to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
+
+/// Check whether the array has a top-level attribute.
+///
+/// `pExists` is set to true if the attributes contain `key`, otherwise false.
+/// The attribute value is not serialised, use `zarrsArrayGetAttribute` to retrieve it.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pExists` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayAttributeExists(
+    array: ZarrsArray,
+    key: FfiStr,
+    pExists: *mut bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let exists = array_fn!(array, attributes).contains_key(key.as_str());
+    // SAFETY: pExists is a valid pointer per the function's safety contract.
+    unsafe { *pExists = exists };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the number of top-level array attributes.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pCount` must be a valid pointer to a `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetAttributeCount(
+    array: ZarrsArray,
+    pCount: *mut usize,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let count = array_fn!(array, attributes).len();
+    // SAFETY: pCount is a valid pointer per the function's safety contract.
+    unsafe { *pCount = count };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Set a single top-level array attribute from a JSON string.
+///
+/// `valueJson` may be any JSON value, and replaces the existing value of `key` if present.
+/// This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if `valueJson` is not valid JSON.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArraySetAttribute(
+    array: ZarrsArray,
+    key: FfiStr,
+    valueJson: FfiStr,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &mut **array };
+
+    let value = match serde_json::from_str::<serde_json::Value>(valueJson.into()) {
+        Ok(value) => value,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+        }
+    };
+    array_fn!(array, attributes_mut).insert(key.as_str().to_string(), value);
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Remove a single top-level array attribute.
+///
+/// `pRemoved` is set to true if `key` was present and has been removed, otherwise false.
+/// This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pRemoved` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRemoveAttribute(
+    array: ZarrsArray,
+    key: FfiStr,
+    pRemoved: *mut bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &mut **array };
+
+    let removed = array_fn!(array, attributes_mut)
+        .remove(key.as_str())
+        .is_some();
+    // SAFETY: pRemoved is a valid pointer per the function's safety contract.
+    unsafe { *pRemoved = removed };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
 /// Set the array attributes from a JSON string.
 ///
 /// # Errors
@@ -656,3 +4197,67 @@ pub unsafe extern "C" fn zarrsArraySetAttributes(
 
     ZarrsResult::ZARRS_SUCCESS
 }
+
+/// Merge `patch` into `target` following JSON merge patch (RFC 7396) semantics.
+pub(crate) fn merge_attributes(
+    target: &mut serde_json::Map<String, serde_json::Value>,
+    patch: serde_json::Map<String, serde_json::Value>,
+) {
+    for (key, value) in patch {
+        match value {
+            serde_json::Value::Null => {
+                target.remove(&key);
+            }
+            serde_json::Value::Object(patch) => {
+                if let Some(serde_json::Value::Object(target)) = target.get_mut(&key) {
+                    merge_attributes(target, patch);
+                } else {
+                    let mut object = serde_json::Map::new();
+                    merge_attributes(&mut object, patch);
+                    target.insert(key, serde_json::Value::Object(object));
+                }
+            }
+            value => {
+                target.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Update the array attributes by merging a JSON string.
+///
+/// `attributesJson` must be a JSON object, which is merged into the existing attributes following JSON merge patch (RFC 7396) semantics:
+/// - object values are merged recursively into existing object values,
+/// - `null` values remove the key, and
+/// - all other values (including arrays) replace the existing value.
+///
+/// Attributes not present in `attributesJson` are unchanged.
+/// This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if `attributesJson` is not a valid JSON object (map).
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayUpdateAttributes(
+    array: ZarrsArray,
+    attributesJson: FfiStr,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &mut **array };
+
+    let Ok(serde_json::Value::Object(attributes)) =
+        serde_json::from_str::<serde_json::Value>(attributesJson.into())
+    else {
+        *LAST_ERROR.lock().unwrap() = "error interpreting attributes to a json map".to_string();
+        return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+    };
+
+    merge_attributes(array_fn!(array, attributes_mut), attributes);
+    ZarrsResult::ZARRS_SUCCESS
+}
@@ -0,0 +1,205 @@
+use std::ffi::{CString, c_char};
+
+use ffi_support::FfiStr;
+use serde_json::Value;
+use zarrs::metadata::v3::AdditionalFieldV3;
+
+use crate::{LAST_ERROR, ZarrsResult};
+
+use super::{ZarrsArray, array_fn};
+
+/// Top-level array metadata fields defined by the Zarr V2 and V3 specifications.
+///
+/// These cannot be set as additional fields.
+const CORE_METADATA_FIELDS: &[&str] = &[
+    "zarr_format",
+    "node_type",
+    "shape",
+    "data_type",
+    "chunk_grid",
+    "chunk_key_encoding",
+    "fill_value",
+    "codecs",
+    "attributes",
+    "storage_transformers",
+    "dimension_names",
+    "chunks",
+    "dtype",
+    "compressor",
+    "filters",
+    "order",
+    "dimension_separator",
+];
+
+/// Set an additional (extension) top-level field in the array metadata from a JSON string.
+///
+/// `valueJson` replaces the existing value of the field `name` if present.
+/// If `mustUnderstand` is false, `valueJson` must be a JSON object, and it is written with `"must_understand": false` so that implementations that do not support the extension can still open the array.
+/// If `mustUnderstand` is true, implementations that do not support the extension will refuse to open the array.
+/// This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if:
+///   - `name` is empty or is a core metadata field (e.g. `shape`, `data_type`, `attributes`),
+///   - `valueJson` is not valid JSON, or
+///   - `mustUnderstand` is false and `valueJson` is not a JSON object.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArraySetAdditionalField(
+    array: ZarrsArray,
+    name: FfiStr,
+    valueJson: FfiStr,
+    mustUnderstand: bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &mut **array };
+
+    let name = name.as_str();
+    if name.is_empty() || CORE_METADATA_FIELDS.contains(&name) {
+        *LAST_ERROR.lock().unwrap() =
+            format!("{name:?} is not a valid additional metadata field name");
+        return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+    }
+
+    let value = match serde_json::from_str::<Value>(valueJson.into()) {
+        Ok(Value::Object(mut value)) => {
+            // The must_understand flag is set by the argument
+            value.remove("must_understand");
+            Value::Object(value)
+        }
+        Ok(value) if mustUnderstand => value,
+        Ok(_) => {
+            *LAST_ERROR.lock().unwrap() =
+                "additional fields that are not must_understand must be a JSON object".to_string();
+            return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+        }
+    };
+    array_fn!(array, additional_fields_mut).insert(
+        name.to_string(),
+        AdditionalFieldV3::new(value, mustUnderstand),
+    );
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the additional (extension) top-level fields of the array metadata as a JSON object string.
+///
+/// The object is empty if the array has no additional fields.
+/// Fields that are not `must_understand` include `"must_understand": false`, as they appear in the array metadata.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the additional fields cannot be converted to a JSON string.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pJson` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetAdditionalFieldsString(
+    array: ZarrsArray,
+    pJson: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    if let Ok(json) = serde_json::to_string(array_fn!(array, additional_fields))
+        && let Ok(cstring) = CString::new(json)
+    {
+        // SAFETY: pJson is a valid pointer per the function's safety contract.
+        unsafe { *pJson = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() = "error converting additional fields to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
+
+/// Check whether the array metadata has an extension.
+///
+/// Extensions are additional top-level fields in the array metadata (see `zarrsArraySetAdditionalField`).
+/// `pHas` is set to true if the array metadata has a field named `extensionName` that is not a core metadata field, otherwise false.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pHas` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayHasExtension(
+    array: ZarrsArray,
+    extensionName: FfiStr,
+    pHas: *mut bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let has = array_fn!(array, additional_fields).contains_key(extensionName.as_str());
+    // SAFETY: pHas is a valid pointer per the function's safety contract.
+    unsafe { *pHas = has };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the metadata of an array extension as a JSON string.
+///
+/// Extensions that are not `must_understand` include `"must_understand": false`, as they appear in the array metadata.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the array metadata does not have the extension `extensionName`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pExtensionJson` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetExtensionString(
+    array: ZarrsArray,
+    extensionName: FfiStr,
+    pretty: bool,
+    pExtensionJson: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let extension_name = extensionName.as_str();
+
+    let Some(extension) = array_fn!(array, additional_fields).get(extension_name) else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("array metadata does not have the extension {extension_name:?}");
+        return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+    };
+    let extension_str = if pretty {
+        serde_json::to_string_pretty(extension)
+    } else {
+        serde_json::to_string(extension)
+    };
+    if let Ok(extension_str) = extension_str
+        && let Ok(cstring) = CString::new(extension_str)
+    {
+        // SAFETY: pExtensionJson is a valid pointer per the function's safety contract.
+        unsafe { *pExtensionJson = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() = "error converting extension to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
@@ -0,0 +1,218 @@
+use std::{
+    ffi::c_void,
+    sync::{
+        Arc, Condvar, Mutex,
+        mpsc::{Receiver, Sender, channel},
+    },
+};
+
+use once_cell::sync::Lazy;
+
+use crate::ZarrsResult;
+
+use super::{ZarrsArray, array_read::zarrsArrayRetrieveSubset};
+
+/// A callback invoked when an asynchronous operation completes.
+///
+/// `result` is the result of the operation and `userData` is the user data passed to the function that started it.
+pub type ZarrsCompletionCallback =
+    Option<unsafe extern "C" fn(result: ZarrsResult, userData: *mut c_void)>;
+
+/// The completion state of an asynchronous operation.
+type OperationState = Arc<(Mutex<Option<ZarrsResult>>, Condvar)>;
+
+/// A job run by the [`ASYNC_WORKERS`].
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A fixed pool of worker threads that run asynchronous operations in the order they are started.
+///
+/// The pool has one thread per available CPU, so starting many operations queues them rather than spawning a thread per operation.
+static ASYNC_WORKERS: Lazy<Mutex<Sender<Job>>> = Lazy::new(|| {
+    let (sender, receiver) = channel::<Job>();
+    let receiver = Arc::new(Mutex::new(receiver));
+    let num_workers = std::thread::available_parallelism().map_or(1, std::num::NonZero::get);
+    for worker in 0..num_workers {
+        let receiver = receiver.clone();
+        std::thread::Builder::new()
+            .name(format!("zarrs-async-{worker}"))
+            .spawn(move || run_jobs(&receiver))
+            .expect("failed to spawn an asynchronous operation worker thread");
+    }
+    Mutex::new(sender)
+});
+
+fn run_jobs(receiver: &Mutex<Receiver<Job>>) {
+    loop {
+        // The lock is released before the job runs, so other workers can take the next job
+        let job = receiver.lock().unwrap().recv();
+        match job {
+            Ok(job) => job(),
+            Err(_) => break,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct ZarrsOperation_T {
+    state: OperationState,
+}
+
+impl ZarrsOperation_T {
+    /// Block until the operation completes and return its result.
+    fn wait(&self) -> ZarrsResult {
+        let (completed, condvar) = &*self.state;
+        let completed = condvar
+            .wait_while(completed.lock().unwrap(), |result| result.is_none())
+            .unwrap();
+        completed.expect("the operation is complete")
+    }
+}
+
+/// An opaque handle to an asynchronous operation.
+pub type ZarrsOperation = *mut ZarrsOperation_T;
+
+/// The raw pointers used by an asynchronous subset retrieval on a worker thread.
+struct RetrieveSubsetAsyncPointers {
+    array: ZarrsArray,
+    bytes: *mut u8,
+    user_data: *mut c_void,
+}
+
+// SAFETY: the caller of zarrsArrayRetrieveSubsetAsync guarantees that the array, buffer, and user data remain valid until the operation completes and that the user data can be used from another thread.
+unsafe impl Send for RetrieveSubsetAsyncPointers {}
+
+/// Retrieve a subset from an array on a background thread.
+///
+/// This is the asynchronous counterpart of `zarrsArrayRetrieveSubset`.
+/// `pSubsetStart` and `pSubsetShape` are copied, so they only need to be valid for the duration of the call.
+/// `pOp` is a pointer to a handle in which the created `ZarrsOperation` is returned, which must be destroyed with `zarrsOperationDestroy`.
+///
+/// The retrieval runs on a shared pool of background worker threads, so the calling thread never blocks on storage (including HTTP storage).
+/// The pool has one thread per available CPU and is created on first use.
+/// Operations started while all workers are busy are queued and started in order as workers become free.
+/// When the retrieval finishes, `onDone` (if not null) is invoked exactly once with the result of the retrieval and `userData`:
+/// - `onDone` is always invoked on a worker thread, never on the calling thread.
+/// - `onDone` must not wait for another operation, as that operation may be queued behind it.
+/// - `pSubsetBytes` is fully written before `onDone` is invoked.
+/// - `onDone` returns before `zarrsOperationWait` returns for the operation.
+/// - Callbacks of different operations may be invoked concurrently and in any order.
+///
+/// If the retrieval fails, the last error is set before `onDone` is invoked, but it may be overwritten by other operations running concurrently.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pOp` is a null pointer, in which case no operation is started and `onDone` is not invoked.
+/// Errors from the retrieval itself (see `zarrsArrayRetrieveSubset`) are passed to `onDone` and returned by `zarrsOperationWait`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// `pSubsetBytes` must be a valid pointer to an array of bytes of length `subsetBytesCount`.
+/// **`array`, `pSubsetBytes`, and `userData` must remain valid until the operation completes**, i.e. until `onDone` is invoked, `zarrsOperationWait` returns, or `zarrsOperationDestroy` returns.
+/// `pSubsetBytes` must not be accessed by the caller until then.
+/// `onDone` and `userData` must be safe to use from another thread.
+/// If not null, `pOp` must be a valid pointer to a `ZarrsOperation` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetAsync(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    subsetBytesCount: usize,
+    pSubsetBytes: *mut u8,
+    onDone: ZarrsCompletionCallback,
+    userData: *mut c_void,
+    pOp: *mut ZarrsOperation,
+) -> ZarrsResult {
+    if array.is_null() || pOp.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: the caller guarantees pSubsetStart and pSubsetShape are valid for dimensionality elements.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) }.to_vec();
+    // SAFETY: as above.
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) }.to_vec();
+    let pointers = RetrieveSubsetAsyncPointers {
+        array,
+        bytes: pSubsetBytes,
+        user_data: userData,
+    };
+
+    let state = OperationState::default();
+    let job_state = state.clone();
+    let job: Job = Box::new(move || {
+        let pointers = pointers;
+        // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveSubset, and the array and buffer remain valid until the operation completes.
+        let result = unsafe {
+            zarrsArrayRetrieveSubset(
+                pointers.array,
+                dimensionality,
+                subset_start.as_ptr(),
+                subset_shape.as_ptr(),
+                subsetBytesCount,
+                pointers.bytes,
+            )
+        };
+        if let Some(on_done) = onDone {
+            // SAFETY: the caller guarantees that onDone and userData can be used from this thread.
+            unsafe { on_done(result, pointers.user_data) };
+        }
+        let (completed, condvar) = &*job_state;
+        *completed.lock().unwrap() = Some(result);
+        condvar.notify_all();
+    });
+    ASYNC_WORKERS
+        .lock()
+        .unwrap()
+        .send(job)
+        .expect("the asynchronous operation workers never exit");
+
+    // SAFETY: pOp is not null, and the caller guarantees it is a valid pointer to a ZarrsOperation handle.
+    unsafe {
+        *pOp = Box::into_raw(Box::new(ZarrsOperation_T { state }));
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Wait for an asynchronous operation to complete.
+///
+/// `pResult` is set to the result of the operation.
+/// This can be called multiple times and from multiple threads, and returns immediately if the operation has already completed.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `op` or `pResult` is a null pointer.
+///
+/// # Safety
+/// If not null, `op` must be a valid `ZarrsOperation` handle and `pResult` must be a valid pointer to a `ZarrsResult`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsOperationWait(
+    op: ZarrsOperation,
+    pResult: *mut ZarrsResult,
+) -> ZarrsResult {
+    if op.is_null() || pResult.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: op is not null, and the caller guarantees it is a valid ZarrsOperation handle.
+    let op = unsafe { &*op };
+    // SAFETY: pResult is not null, and the caller guarantees it is a valid pointer to a ZarrsResult.
+    unsafe { *pResult = op.wait() };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Destroy an asynchronous operation.
+///
+/// If the operation has not completed, this waits for it to complete (including invoking its completion callback) before returning.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `op` is a null pointer.
+///
+/// # Safety
+/// If not null, `op` must be a valid `ZarrsOperation` handle that is not used after this call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsOperationDestroy(op: ZarrsOperation) -> ZarrsResult {
+    if op.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: op is not null, and the caller guarantees it is a valid ZarrsOperation handle that is not used after this call.
+    let op = unsafe { Box::from_raw(op) };
+    op.wait();
+    ZarrsResult::ZARRS_SUCCESS
+}
@@ -0,0 +1,699 @@
+use std::{
+    borrow::Cow,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use zarrs::{
+    array::{Array, ArrayBytes, ArraySubset, CodecOptions, codec::ArrayToBytesCodecTraits},
+    storage::ReadableStorageTraits,
+};
+
+use crate::{LAST_ERROR, ZarrsResult, lru::LruCache};
+
+use super::{
+    ZarrsArray, ZarrsArray_T, ZarrsArrayEnum, array_fn, for_each_strided_offset,
+    validate_chunk_indices, validated_array_subset,
+};
+
+/// The capacity of a chunk cache.
+#[derive(Clone, Copy)]
+enum ChunkCacheCapacity {
+    /// The maximum total size of the cached chunks in bytes.
+    Bytes(u64),
+    /// The maximum number of cached chunks.
+    Chunks(u64),
+}
+
+impl ChunkCacheCapacity {
+    /// Returns true if `chunks` exceed the capacity.
+    fn is_exceeded_by(self, chunks: &CachedChunks) -> bool {
+        match self {
+            Self::Bytes(capacity) => chunks.size_bytes > capacity,
+            Self::Chunks(capacity) => chunks.chunks.len() as u64 > capacity,
+        }
+    }
+}
+
+/// The representation of the chunks held by a chunk cache.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ChunkCacheKind {
+    /// Decoded chunks, which are copied directly into the output.
+    Decoded,
+    /// Encoded chunks as stored, which are decoded on every retrieval.
+    ///
+    /// A chunk that does not exist in the store is cached as an empty buffer.
+    Encoded,
+}
+
+/// Cached chunks keyed by their chunk indices.
+#[derive(Default)]
+struct CachedChunks {
+    chunks: LruCache<Vec<u64>, Arc<Vec<u8>>>,
+    size_bytes: u64,
+}
+
+#[doc(hidden)]
+pub struct ZarrsChunkCache_T {
+    /// The address of the array handle the cache was created for.
+    array: usize,
+    capacity: ChunkCacheCapacity,
+    kind: ChunkCacheKind,
+    chunks: Mutex<CachedChunks>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ZarrsChunkCache_T {
+    /// Get the cached chunk at `chunk_indices`, marking it as most recently used.
+    ///
+    /// The lookup is recorded as a hit or miss in the cache statistics.
+    fn get(&self, chunk_indices: &[u64]) -> Option<Arc<Vec<u8>>> {
+        let mut chunks = self.chunks.lock().unwrap();
+        let Some(bytes) = chunks.chunks.get(&chunk_indices.to_vec()) else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(bytes.clone())
+    }
+
+    /// Insert the chunk at `chunk_indices`, evicting the least recently used chunks until the cache is within its capacity.
+    ///
+    /// A chunk larger than the capacity in bytes is not inserted, rather than evicting every other chunk.
+    fn insert(&self, chunk_indices: &[u64], bytes: Arc<Vec<u8>>) {
+        if let ChunkCacheCapacity::Bytes(capacity) = self.capacity
+            && bytes.len() as u64 > capacity
+        {
+            return;
+        }
+        let mut chunks = self.chunks.lock().unwrap();
+        let chunk_indices = chunk_indices.to_vec();
+        if chunks.chunks.contains(&chunk_indices) {
+            // Another thread retrieved the chunk concurrently
+            return;
+        }
+        chunks.size_bytes += bytes.len() as u64;
+        chunks.chunks.insert(chunk_indices, bytes);
+        while self.capacity.is_exceeded_by(&chunks) {
+            if let Some((_, bytes)) = chunks.chunks.pop_lru() {
+                chunks.size_bytes -= bytes.len() as u64;
+            }
+        }
+    }
+
+    /// Returns the number of chunks decoded by `hits` and `misses` lookups.
+    fn chunks_decoded(&self, hits: u64, misses: u64) -> u64 {
+        match self.kind {
+            ChunkCacheKind::Decoded => misses,
+            ChunkCacheKind::Encoded => hits + misses,
+        }
+    }
+
+    /// Check that the cache was created for `array`.
+    ///
+    /// Sets the last error on failure.
+    fn check_array(&self, array: ZarrsArray) -> Result<(), ZarrsResult> {
+        if self.array == array as usize {
+            Ok(())
+        } else {
+            *LAST_ERROR.lock().unwrap() =
+                "the chunk cache was created for a different array handle".to_string();
+            Err(ZarrsResult::ZARRS_ERROR_ARRAY)
+        }
+    }
+}
+
+/// An opaque handle to a chunk cache.
+///
+/// The cache holds decoded or encoded chunks of the array it was created for, and evicts the least recently used chunks when it is full.
+pub type ZarrsChunkCache = *mut ZarrsChunkCache_T;
+
+/// Create a chunk cache for an array.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pCache` must be a valid pointer to a `ZarrsChunkCache` handle.
+unsafe fn create_chunk_cache(
+    array: ZarrsArray,
+    capacity: ChunkCacheCapacity,
+    kind: ChunkCacheKind,
+    pCache: *mut ZarrsChunkCache,
+) -> ZarrsResult {
+    if array.is_null() || pCache.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array_handle = unsafe { &**array };
+
+    if matches!(
+        capacity,
+        ChunkCacheCapacity::Bytes(0) | ChunkCacheCapacity::Chunks(0)
+    ) {
+        *LAST_ERROR.lock().unwrap() =
+            "the capacity of a chunk cache must be at least one".to_string();
+        return ZarrsResult::ZARRS_ERROR_ARRAY;
+    }
+    if !matches!(
+        array_handle,
+        ZarrsArrayEnum::R(_)
+            | ZarrsArrayEnum::RL(_)
+            | ZarrsArrayEnum::RW(_)
+            | ZarrsArrayEnum::RWL(_)
+    ) {
+        *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+        return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+    }
+    if array_fn!(array_handle, data_type).fixed_size().is_none() {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    }
+
+    let cache = ZarrsChunkCache_T {
+        array: array as usize,
+        capacity,
+        kind,
+        chunks: Mutex::default(),
+        hits: AtomicU64::new(0),
+        misses: AtomicU64::new(0),
+    };
+    // SAFETY: pCache is not null, and the caller guarantees it is a valid pointer to a ZarrsChunkCache handle.
+    unsafe { *pCache = Box::into_raw(Box::new(cache)) };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Create a handle to a decoded chunk cache holding at most `capacityBytes` bytes of decoded chunks.
+///
+/// The cache is used with `zarrsArrayRetrieveChunkCached` and `zarrsArrayRetrieveSubsetCached`, which only retrieve and decode chunks that are not in the cache.
+/// When the cache is full, the least recently used chunks are evicted, and a chunk larger than `capacityBytes` is never cached.
+/// The cache can be shared by concurrent reads of `array` from multiple threads.
+///
+/// The cache is not updated when the array is written, so it should be destroyed (or not used) after chunks it may hold are stored.
+/// The cache must only be used with `array` and must be destroyed with `zarrsDestroyChunkCache` before `array` is destroyed.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pCache` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `capacityBytes` is zero.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pCache` must be a valid pointer to a `ZarrsChunkCache` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCreateChunkCacheDecodedLru(
+    array: ZarrsArray,
+    capacityBytes: u64,
+    pCache: *mut ZarrsChunkCache,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of create_chunk_cache.
+    unsafe {
+        create_chunk_cache(
+            array,
+            ChunkCacheCapacity::Bytes(capacityBytes),
+            ChunkCacheKind::Decoded,
+            pCache,
+        )
+    }
+}
+
+/// Create a handle to a decoded chunk cache holding at most `capacityChunks` decoded chunks.
+///
+/// This is equivalent to `zarrsCreateChunkCacheDecodedLru`, except that the capacity is a number of chunks rather than bytes.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pCache` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `capacityChunks` is zero.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pCache` must be a valid pointer to a `ZarrsChunkCache` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCreateChunkCacheDecodedLruChunkLimit(
+    array: ZarrsArray,
+    capacityChunks: u64,
+    pCache: *mut ZarrsChunkCache,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of create_chunk_cache.
+    unsafe {
+        create_chunk_cache(
+            array,
+            ChunkCacheCapacity::Chunks(capacityChunks),
+            ChunkCacheKind::Decoded,
+            pCache,
+        )
+    }
+}
+
+/// Create a handle to an encoded chunk cache holding at most `capacityBytes` bytes of encoded chunks.
+///
+/// This is equivalent to `zarrsCreateChunkCacheDecodedLru`, except that chunks are cached as they are stored (still encoded) and are decoded on every retrieval.
+/// This avoids repeatedly retrieving chunks from slow (e.g. remote) storage while using less memory than caching decoded chunks.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pCache` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `capacityBytes` is zero.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pCache` must be a valid pointer to a `ZarrsChunkCache` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCreateChunkCacheEncodedLru(
+    array: ZarrsArray,
+    capacityBytes: u64,
+    pCache: *mut ZarrsChunkCache,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of create_chunk_cache.
+    unsafe {
+        create_chunk_cache(
+            array,
+            ChunkCacheCapacity::Bytes(capacityBytes),
+            ChunkCacheKind::Encoded,
+            pCache,
+        )
+    }
+}
+
+/// Destroy a chunk cache.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `cache` is a null pointer.
+///
+/// # Safety
+/// If not null, `cache` must be a valid `ZarrsChunkCache` handle that is not used after this call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsDestroyChunkCache(cache: ZarrsChunkCache) -> ZarrsResult {
+    if cache.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsChunkCache handle that is not used after this call.
+    drop(unsafe { Box::from_raw(cache) });
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the statistics of a chunk cache.
+///
+/// `pHits` and `pMisses` are set to the number of chunk lookups that were and were not in the cache since it was created.
+/// `pCurrentBytes` is set to the total size of the chunks in the cache, which are decoded or encoded depending on the cache.
+/// `pEntryCount` is set to the number of chunks in the cache.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `cache` is a null pointer.
+///
+/// # Safety
+/// If not null, `cache` must be a valid `ZarrsChunkCache` handle.
+/// `pHits`, `pMisses`, `pCurrentBytes`, and `pEntryCount` must be valid pointers to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsChunkCacheStats(
+    cache: ZarrsChunkCache,
+    pHits: *mut u64,
+    pMisses: *mut u64,
+    pCurrentBytes: *mut u64,
+    pEntryCount: *mut u64,
+) -> ZarrsResult {
+    if cache.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsChunkCache handle.
+    let cache = unsafe { &*cache };
+    let chunks = cache.chunks.lock().unwrap();
+    // SAFETY: the output pointers are valid per the function's safety contract.
+    unsafe {
+        *pHits = cache.hits.load(Ordering::Relaxed);
+        *pMisses = cache.misses.load(Ordering::Relaxed);
+        *pCurrentBytes = chunks.size_bytes;
+        *pEntryCount = chunks.chunks.len() as u64;
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Evict all chunks from a chunk cache.
+///
+/// The hit and miss counts reported by `zarrsChunkCacheStats` are not reset.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `cache` is a null pointer.
+///
+/// # Safety
+/// If not null, `cache` must be a valid `ZarrsChunkCache` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsChunkCacheClear(cache: ZarrsChunkCache) -> ZarrsResult {
+    if cache.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsChunkCache handle.
+    let cache = unsafe { &*cache };
+    *cache.chunks.lock().unwrap() = CachedChunks::default();
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Decode the encoded chunk at `chunk_indices`, or fill it with the fill value if `encoded` is empty.
+///
+/// Sets the last error on failure.
+fn decode_chunk<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunk_indices: &[u64],
+    encoded: &[u8],
+) -> Result<Vec<u8>, ZarrsResult> {
+    if encoded.is_empty() {
+        let chunk_subset = array.chunk_subset(chunk_indices).map_err(|err| {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        })?;
+        return Ok(array
+            .fill_value()
+            .as_ne_bytes()
+            .repeat(chunk_subset.num_elements_usize()));
+    }
+    let chunk_representation = array
+        .chunk_array_representation(chunk_indices)
+        .map_err(|err| {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        })?;
+    let bytes = array
+        .codecs()
+        .decode(
+            Cow::Borrowed(encoded),
+            &chunk_representation,
+            &CodecOptions::default(),
+        )
+        .map_err(|err| {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        })?
+        .into_fixed()
+        .map_err(|_| {
+            *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+            ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE
+        })?;
+    Ok(bytes.into_owned())
+}
+
+/// Get the decoded chunk at `chunk_indices` using the cache, retrieving it and inserting it into the cache if it is not present.
+///
+/// Returns the decoded chunk and whether it was in the cache.
+/// Sets the last error on failure.
+fn retrieve_chunk_cached<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    cache: &ZarrsChunkCache_T,
+    chunk_indices: &[u64],
+) -> Result<(Arc<Vec<u8>>, bool), ZarrsResult> {
+    let cached = cache.get(chunk_indices);
+    let hit = cached.is_some();
+    match cache.kind {
+        ChunkCacheKind::Decoded => {
+            if let Some(bytes) = cached {
+                return Ok((bytes, true));
+            }
+            let bytes = array
+                .retrieve_chunk::<ArrayBytes>(chunk_indices)
+                .map_err(|err| {
+                    *LAST_ERROR.lock().unwrap() = err.to_string();
+                    ZarrsResult::ZARRS_ERROR_ARRAY
+                })?
+                .into_fixed()
+                .map_err(|_| {
+                    *LAST_ERROR.lock().unwrap() =
+                        "variable size data types are not supported".to_string();
+                    ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE
+                })?;
+            let bytes = Arc::new(bytes.into_owned());
+            cache.insert(chunk_indices, bytes.clone());
+            Ok((bytes, false))
+        }
+        ChunkCacheKind::Encoded => {
+            let encoded = if let Some(encoded) = cached {
+                encoded
+            } else {
+                let encoded = array
+                    .retrieve_encoded_chunk(chunk_indices)
+                    .map_err(|err| {
+                        *LAST_ERROR.lock().unwrap() = err.to_string();
+                        ZarrsResult::ZARRS_ERROR_ARRAY
+                    })?
+                    .unwrap_or_default();
+                let encoded = Arc::new(encoded);
+                cache.insert(chunk_indices, encoded.clone());
+                encoded
+            };
+            let bytes = decode_chunk(array, chunk_indices, &encoded)?;
+            Ok((Arc::new(bytes), hit))
+        }
+    }
+}
+
+/// Retrieve a subset of an array with a fixed size data type one chunk at a time, using a chunk cache.
+///
+/// The intersection of the subset with each chunk is copied into its position in `subset_bytes`.
+/// Returns the number of cache hits and misses.
+/// Sets the last error on failure.
+fn retrieve_subset_cached<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    cache: &ZarrsChunkCache_T,
+    array_subset: &ArraySubset,
+    subset_bytes: &mut [u8],
+) -> Result<(u64, u64), ZarrsResult> {
+    let Some(data_type_size) = array.data_type().fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return Err(ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+    };
+    let expected_length = array_subset.num_elements_usize() * data_type_size;
+    if expected_length != subset_bytes.len() {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "subset_bytes_length {} does not match decoded subset size {expected_length}",
+            subset_bytes.len()
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+    }
+    let chunks = match array.chunks_in_array_subset(array_subset) {
+        Ok(Some(chunks)) => chunks,
+        Ok(None) => {
+            *LAST_ERROR.lock().unwrap() =
+                "the chunks intersecting the subset cannot be determined".to_string();
+            return Err(ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS);
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return Err(ZarrsResult::ZARRS_ERROR_ARRAY);
+        }
+    };
+
+    /// The C order byte strides of a buffer with `shape`.
+    fn byte_strides(shape: &[u64], data_type_size: usize) -> Vec<i64> {
+        let mut strides = vec![data_type_size as i64; shape.len()];
+        for dim in (0..strides.len().saturating_sub(1)).rev() {
+            strides[dim] = strides[dim + 1] * shape[dim + 1] as i64;
+        }
+        strides
+    }
+    let subset_strides = byte_strides(array_subset.shape(), data_type_size);
+
+    let (mut hits, mut misses) = (0, 0);
+    for chunk_indices in chunks.indices() {
+        let overlap = array
+            .chunk_subset(&chunk_indices)
+            .map_err(|err| err.to_string())
+            .and_then(|chunk_subset| {
+                chunk_subset
+                    .overlap(array_subset)
+                    .map(|overlap| (chunk_subset, overlap))
+                    .map_err(|err| err.to_string())
+            });
+        let (chunk_subset, overlap) = match overlap {
+            Ok(overlap) => overlap,
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err;
+                return Err(ZarrsResult::ZARRS_ERROR_ARRAY);
+            }
+        };
+        let (chunk_bytes, hit) = retrieve_chunk_cached(array, cache, &chunk_indices)?;
+        if hit {
+            hits += 1;
+        } else {
+            misses += 1;
+        }
+
+        // Gather the overlap from the chunk
+        let chunk_strides = byte_strides(chunk_subset.shape(), data_type_size);
+        let chunk_start_offset: i64 = std::iter::zip(overlap.start(), chunk_subset.start())
+            .zip(&chunk_strides)
+            .map(|((&start, &chunk_start), &stride)| (start - chunk_start) as i64 * stride)
+            .sum();
+        let mut overlap_bytes = Vec::with_capacity(overlap.num_elements_usize() * data_type_size);
+        for_each_strided_offset(overlap.shape(), &chunk_strides, |offset| {
+            let offset = (chunk_start_offset + offset as i64) as usize;
+            overlap_bytes.extend_from_slice(&chunk_bytes[offset..offset + data_type_size]);
+        });
+
+        // Scatter the overlap into the subset
+        let subset_start_offset: i64 = std::iter::zip(overlap.start(), array_subset.start())
+            .zip(&subset_strides)
+            .map(|((&start, &subset_start), &stride)| (start - subset_start) as i64 * stride)
+            .sum();
+        let mut elements = overlap_bytes.chunks_exact(data_type_size);
+        for_each_strided_offset(overlap.shape(), &subset_strides, |offset| {
+            if let Some(element) = elements.next() {
+                let offset = (subset_start_offset + offset as i64) as usize;
+                subset_bytes[offset..offset + data_type_size].copy_from_slice(element);
+            }
+        });
+    }
+    Ok((hits, misses))
+}
+
+/// Retrieve a chunk from an array using a chunk cache.
+///
+/// This is equivalent to `zarrsArrayRetrieveChunk`, except that the chunk is taken from `cache` if present, and otherwise it is retrieved and inserted into `cache`.
+/// Cache lookups are recorded in the `cacheHits` and `cacheMisses` storage statistics, and only cache misses of a decoded chunk cache count towards `chunksDecoded`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `cache` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `chunkBytesCount` does not match the size of the chunk.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `cache` was created for a different array handle or the chunk cannot be retrieved.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `cache` must be a valid `ZarrsChunkCache` handle.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+/// `pChunkBytes` must point to an array of length `chunkBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveChunkCached(
+    array: ZarrsArray,
+    cache: ZarrsChunkCache,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    chunkBytesCount: usize,
+    pChunkBytes: *mut u8,
+) -> ZarrsResult {
+    if array.is_null() || cache.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsChunkCache handle.
+    let cache = unsafe { &*cache };
+    if let Err(result) = cache.check_array(array) {
+        return result;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    if array_fn!(array, dimensionality) != dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {}",
+            array_fn!(array, dimensionality)
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    if let Err(result) = validate_chunk_indices(array, chunk_indices) {
+        return result;
+    }
+
+    let chunk = match array {
+        ZarrsArrayEnum::R(array) => retrieve_chunk_cached(array, cache, chunk_indices),
+        ZarrsArrayEnum::RL(array) => retrieve_chunk_cached(array, cache, chunk_indices),
+        ZarrsArrayEnum::RW(array) => retrieve_chunk_cached(array, cache, chunk_indices),
+        ZarrsArrayEnum::RWL(array) => retrieve_chunk_cached(array, cache, chunk_indices),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            Err(ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY)
+        }
+    };
+    let (chunk_bytes, hit) = match chunk {
+        Ok(chunk) => chunk,
+        Err(result) => return result,
+    };
+    stats.record_cache(u64::from(hit), u64::from(!hit));
+    if chunk_bytes.len() != chunkBytesCount {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "chunk_bytes_length {chunkBytesCount} does not match decoded chunk size {}",
+            chunk_bytes.len()
+        );
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+    // SAFETY: pChunkBytes points to an array of length chunkBytesCount per the function's safety contract.
+    let pChunkBytes = unsafe { std::slice::from_raw_parts_mut(pChunkBytes, chunkBytesCount) };
+    pChunkBytes.copy_from_slice(&chunk_bytes);
+    stats.record_decoded(cache.chunks_decoded(u64::from(hit), u64::from(!hit)));
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Retrieve a subset from an array using a chunk cache.
+///
+/// This is equivalent to `zarrsArrayRetrieveSubset`, except that each chunk intersecting the subset is taken from `cache` if present, and otherwise it is retrieved and inserted into `cache`.
+/// Repeated reads of subsets intersecting the same chunks therefore only retrieve each chunk once while it remains in the cache (and, for a decoded chunk cache, only decode it once).
+/// Cache lookups are recorded in the `cacheHits` and `cacheMisses` storage statistics, and only cache misses of a decoded chunk cache count towards `chunksDecoded`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `cache` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `subsetBytesCount` does not match the size of the subset.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `cache` was created for a different array handle or a chunk cannot be retrieved.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `cache` must be a valid `ZarrsChunkCache` handle.
+/// `pSubsetStart` and `pSubsetShape` must point to arrays of length `dimensionality`.
+/// `pSubsetBytes` must point to an array of length `subsetBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetCached(
+    array: ZarrsArray,
+    cache: ZarrsChunkCache,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    subsetBytesCount: usize,
+    pSubsetBytes: *mut u8,
+) -> ZarrsResult {
+    if array.is_null() || cache.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsChunkCache handle.
+    let cache = unsafe { &*cache };
+    if let Err(result) = cache.check_array(array) {
+        return result;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    // SAFETY: pSubsetBytes points to an array of length subsetBytesCount per the function's safety contract.
+    let subset_bytes = unsafe { std::slice::from_raw_parts_mut(pSubsetBytes, subsetBytesCount) };
+
+    let lookups = match array {
+        ZarrsArrayEnum::R(array) => {
+            retrieve_subset_cached(array, cache, &array_subset, subset_bytes)
+        }
+        ZarrsArrayEnum::RL(array) => {
+            retrieve_subset_cached(array, cache, &array_subset, subset_bytes)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            retrieve_subset_cached(array, cache, &array_subset, subset_bytes)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            retrieve_subset_cached(array, cache, &array_subset, subset_bytes)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            Err(ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY)
+        }
+    };
+    match lookups {
+        Ok((hits, misses)) => {
+            stats.record_cache(hits, misses);
+            stats.record_decoded(cache.chunks_decoded(hits, misses));
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
@@ -0,0 +1,92 @@
+use zarrs::array::chunk_shape_to_array_shape;
+
+use crate::{LAST_ERROR, ZarrsDataType, ZarrsResult};
+
+use super::{ZarrsArray, array_fn, data_type::zarrs_data_type};
+
+/// The maximum dimensionality of an array described by [`ZarrsArrayInfo`].
+pub const ZARRS_MAX_DIMS: usize = 32;
+
+/// A summary of the properties of an array.
+///
+/// Populated by `zarrsArrayGetInfo`.
+/// Only the first `dimensionality` elements of each shape are meaningful, the remaining elements are zero.
+#[repr(C)]
+pub struct ZarrsArrayInfo {
+    /// The dimensionality of the array.
+    pub dimensionality: u64,
+    /// The shape of the array.
+    pub shape: [u64; ZARRS_MAX_DIMS],
+    /// The data type of the array.
+    pub dataType: ZarrsDataType,
+    /// The size of an element in bytes, or zero if the data type has a variable size.
+    pub dataTypeSize: u64,
+    /// The number of chunks along each dimension.
+    pub chunkGridShape: [u64; ZARRS_MAX_DIMS],
+    /// True if the array has a regular chunk grid.
+    pub isRegularChunkGrid: bool,
+    /// The chunk shape if the array has a regular chunk grid, otherwise zero.
+    pub chunkShape: [u64; ZARRS_MAX_DIMS],
+    /// True if the array is sharded.
+    pub isSharded: bool,
+}
+
+/// Get a summary of the properties of an array.
+///
+/// This populates `pInfo` with the dimensionality, shape, data type, chunk grid shape, regular chunk shape, and whether the array is sharded in a single call.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pInfo` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if the array dimensionality exceeds `ZARRS_MAX_DIMS`.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pInfo` must be a valid pointer to a `ZarrsArrayInfo`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetInfo(
+    array: ZarrsArray,
+    pInfo: *mut ZarrsArrayInfo,
+) -> ZarrsResult {
+    if array.is_null() || pInfo.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let dimensionality = array_fn!(array, dimensionality);
+    if dimensionality > ZARRS_MAX_DIMS {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "array dimensionality {dimensionality} exceeds the maximum of {ZARRS_MAX_DIMS}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+
+    let mut shape = [0; ZARRS_MAX_DIMS];
+    shape[..dimensionality].copy_from_slice(array_fn!(array, shape));
+
+    let mut chunk_grid_shape = [0; ZARRS_MAX_DIMS];
+    chunk_grid_shape[..dimensionality].copy_from_slice(&array_fn!(array, chunk_grid_shape));
+
+    let data_type = array_fn!(array, data_type);
+    let is_regular_chunk_grid = array_fn!(array, chunk_grid).create_metadata().name() == "regular";
+    let mut chunk_shape = [0; ZARRS_MAX_DIMS];
+    if is_regular_chunk_grid
+        && let Ok(regular_chunk_shape) = array_fn!(array, chunk_shape, &vec![0; dimensionality])
+    {
+        chunk_shape[..dimensionality]
+            .copy_from_slice(&chunk_shape_to_array_shape(&regular_chunk_shape));
+    }
+
+    let info = ZarrsArrayInfo {
+        dimensionality: dimensionality as u64,
+        shape,
+        dataType: zarrs_data_type(data_type),
+        dataTypeSize: data_type.fixed_size().unwrap_or(0) as u64,
+        chunkGridShape: chunk_grid_shape,
+        isRegularChunkGrid: is_regular_chunk_grid,
+        chunkShape: chunk_shape,
+        isSharded: array_fn!(array, subchunk_shape).is_some(),
+    };
+    // SAFETY: pInfo is not null, and the caller guarantees it is a valid pointer to a ZarrsArrayInfo.
+    unsafe { *pInfo = info };
+    ZarrsResult::ZARRS_SUCCESS
+}
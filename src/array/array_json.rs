@@ -0,0 +1,223 @@
+use std::ffi::{CString, c_char};
+
+use zarrs::{
+    array::{Array, ArrayBytes, ArraySubset, DataType, chunk_shape_to_array_shape},
+    storage::ReadableStorageTraits,
+};
+
+use crate::{LAST_ERROR, ZarrsResult, stats::num_chunks_in_subset};
+
+use super::{
+    ZarrsArray, ZarrsArray_T, ZarrsArrayEnum, data_type::element_to_json, validated_array_subset,
+};
+
+/// Convert C order element bytes with `shape` to a nested JSON array.
+fn elements_to_json(
+    data_type: &DataType,
+    shape: &[u64],
+    bytes: &[u8],
+    element_size: usize,
+) -> Option<serde_json::Value> {
+    let Some((&size, inner_shape)) = shape.split_first() else {
+        return element_to_json(data_type, bytes);
+    };
+    let stride = usize::try_from(inner_shape.iter().product::<u64>()).ok()? * element_size;
+    (0..usize::try_from(size).ok()?)
+        .map(|i| {
+            let elements = bytes.get(i * stride..(i + 1) * stride)?;
+            elements_to_json(data_type, inner_shape, elements, element_size)
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(serde_json::Value::Array)
+}
+
+/// Convert decoded array bytes with `shape` to a JSON string.
+///
+/// Sets the last error on failure.
+fn array_bytes_to_json_string(
+    data_type: &DataType,
+    shape: &[u64],
+    bytes: ArrayBytes,
+) -> Result<CString, ZarrsResult> {
+    let (Some(element_size), Ok(bytes)) = (data_type.fixed_size(), bytes.into_fixed()) else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return Err(ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+    };
+    let Some(json) = elements_to_json(data_type, shape, &bytes, element_size) else {
+        *LAST_ERROR.lock().unwrap() = "the data type cannot be converted to JSON".to_string();
+        return Err(ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+    };
+    Ok(CString::new(json.to_string()).expect("serialized JSON does not contain nul bytes"))
+}
+
+fn zarrsArrayGetChunkAsJSONImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunk_indices: &[u64],
+    json_string: *mut *mut c_char,
+) -> ZarrsResult {
+    let chunk_shape = match array.chunk_shape(chunk_indices) {
+        Ok(chunk_shape) => chunk_shape_to_array_shape(&chunk_shape),
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+        }
+    };
+    let bytes = match array.retrieve_chunk::<ArrayBytes>(chunk_indices) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_ARRAY;
+        }
+    };
+    match array_bytes_to_json_string(array.data_type(), &chunk_shape, bytes) {
+        Ok(cstring) => {
+            // SAFETY: json_string is a valid pointer per the safety contract of zarrsArrayGetChunkAsJSON.
+            unsafe { *json_string = cstring.into_raw() };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Retrieve a chunk from an array as a JSON string.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pJsonString` is set to a JSON array of the chunk elements, with one level of nesting per dimension.
+/// Non-finite floats are represented as the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// This function is intended for diagnostics.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length or cannot be converted to JSON.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the chunk could not be retrieved.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+/// `pJsonString` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkAsJSON(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pJsonString: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    let result = match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayGetChunkAsJSONImpl(array, chunk_indices, pJsonString),
+        ZarrsArrayEnum::RL(array) => {
+            zarrsArrayGetChunkAsJSONImpl(array, chunk_indices, pJsonString)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayGetChunkAsJSONImpl(array, chunk_indices, pJsonString)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayGetChunkAsJSONImpl(array, chunk_indices, pJsonString)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(1);
+    }
+    result
+}
+
+fn zarrsArrayGetSubsetAsJSONImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    array_subset: &ArraySubset,
+    json_string: *mut *mut c_char,
+) -> ZarrsResult {
+    let bytes = match array.retrieve_array_subset::<ArrayBytes>(array_subset) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_ARRAY;
+        }
+    };
+    match array_bytes_to_json_string(array.data_type(), array_subset.shape(), bytes) {
+        Ok(cstring) => {
+            // SAFETY: json_string is a valid pointer per the safety contract of zarrsArrayGetSubsetAsJSON.
+            unsafe { *json_string = cstring.into_raw() };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Retrieve a subset from an array as a JSON string.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// `pJsonString` is set to a JSON array of the subset elements, with one level of nesting per dimension.
+/// Non-finite floats are represented as the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// This function is intended for diagnostics.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length or cannot be converted to JSON.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the subset could not be retrieved.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// `pJsonString` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubsetAsJSON(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pJsonString: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    let result = match array {
+        ZarrsArrayEnum::R(array) => {
+            zarrsArrayGetSubsetAsJSONImpl(array, &array_subset, pJsonString)
+        }
+        ZarrsArrayEnum::RL(array) => {
+            zarrsArrayGetSubsetAsJSONImpl(array, &array_subset, pJsonString)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayGetSubsetAsJSONImpl(array, &array_subset, pJsonString)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayGetSubsetAsJSONImpl(array, &array_subset, pJsonString)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(num_chunks_in_subset(array, &array_subset));
+    }
+    result
+}
@@ -0,0 +1,530 @@
+use zarrs::{
+    array::{Array, ArrayShardedExt, ArrayShardedReadableExtCache},
+    storage::{
+        ListableStorageTraits, ReadableListableStorageTraits, StorageError, StoreKey, StorePrefix,
+    },
+};
+
+use crate::{LAST_ERROR, ZarrsResult};
+
+use super::{ZarrsArray, ZarrsArrayEnum, array_sharded::stored_subchunk_indices};
+
+/// Metadata keys that may be stored alongside chunks under an array prefix.
+const METADATA_KEYS: [&str; 5] = ["zarr.json", ".zarray", ".zattrs", ".zgroup", ".zmetadata"];
+
+/// The store prefix of the node at `path`.
+///
+/// Sets the last error on failure.
+pub(crate) fn node_prefix(path: &str) -> Result<StorePrefix, ZarrsResult> {
+    let prefix = match path.strip_prefix('/') {
+        Some("") | None => String::new(),
+        Some(path) => format!("{path}/"),
+    };
+    StorePrefix::new(prefix).map_err(|err| {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        ZarrsResult::ZARRS_ERROR_STORE_PREFIX
+    })
+}
+
+/// List all keys under the prefix of an array.
+///
+/// Sets the last error on failure.
+pub(crate) fn list_array_keys<T: ListableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+) -> Result<Vec<StoreKey>, ZarrsResult> {
+    let prefix = node_prefix(array.path().as_str())?;
+    match array.storage().list_prefix(&prefix) {
+        Ok(keys) => Ok(keys),
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            Err(ZarrsResult::ZARRS_ERROR_STORAGE)
+        }
+    }
+}
+
+/// Returns true if `key` is a metadata key.
+fn is_metadata_key(key: &StoreKey) -> bool {
+    let name = key.as_str().rsplit('/').next().unwrap_or_default();
+    METADATA_KEYS.contains(&name)
+}
+
+/// List all keys under the prefix of an array, excluding metadata.
+///
+/// Sets the last error on failure.
+fn list_array_chunk_keys<T: ListableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+) -> Result<Vec<StoreKey>, ZarrsResult> {
+    let mut keys = list_array_keys(array)?;
+    keys.retain(|key| !is_metadata_key(key));
+    Ok(keys)
+}
+
+/// The maximum number of directories listed by `zarrsArrayGetStoredChunkCountApproximate`.
+const APPROXIMATE_COUNT_MAX_LISTINGS: usize = 1024;
+
+/// Returns the chunk indices of `key` if it is a chunk key of `array`, where `prefix` is the array prefix.
+///
+/// The decimal integers in `key` after the array prefix are taken as candidate chunk indices.
+/// They are accepted if they are within the chunk grid and encode to `key` with the chunk key encoding of the array, so unrelated keys and the keys of child nodes are rejected.
+fn chunk_key_indices<T: ?Sized>(
+    array: &Array<T>,
+    prefix: &StorePrefix,
+    key: &StoreKey,
+) -> Option<Vec<u64>> {
+    let chunk_grid_shape = array.chunk_grid_shape();
+    let name = key.as_str().strip_prefix(prefix.as_str())?;
+    let chunk_indices = if chunk_grid_shape.is_empty() {
+        Vec::new()
+    } else {
+        name.split(|c: char| !c.is_ascii_digit())
+            .filter(|index| !index.is_empty())
+            .map(|index| index.parse().ok())
+            .collect::<Option<Vec<u64>>>()?
+    };
+    (chunk_indices.len() == chunk_grid_shape.len()
+        && std::iter::zip(&chunk_indices, chunk_grid_shape).all(|(&index, &size)| index < size)
+        && array.chunk_key(&chunk_indices) == *key)
+        .then_some(chunk_indices)
+}
+
+/// The chunks stored directly under `dir`, a directory under the array prefix `prefix`, and the subdirectories of `dir`.
+///
+/// Returns [`None`] if `dir` holds a child node (i.e. it has a metadata key), which is not traversed.
+fn list_chunk_dir<T: ListableStorageTraits + ?Sized>(
+    array: &Array<T>,
+    prefix: &StorePrefix,
+    dir: &StorePrefix,
+) -> Result<Option<(Vec<Vec<u64>>, Vec<StorePrefix>)>, StorageError> {
+    let keys_prefixes = array.storage().list_dir(dir)?;
+    if dir != prefix && keys_prefixes.keys().iter().any(is_metadata_key) {
+        return Ok(None);
+    }
+    let chunks = keys_prefixes
+        .keys()
+        .iter()
+        .filter_map(|key| chunk_key_indices(array, prefix, key))
+        .collect();
+    Ok(Some((chunks, keys_prefixes.prefixes().clone())))
+}
+
+/// Call `f` with the indices of each chunk stored under the array prefix `prefix`.
+///
+/// The prefix is traversed one directory at a time, so the keys of the whole array are never collected at once.
+/// Returns false if `max_listings` directories are listed before the traversal completes, in which case only some of the stored chunks were visited.
+///
+/// Sets the last error on failure.
+fn for_each_stored_chunk<T: ListableStorageTraits + ?Sized>(
+    array: &Array<T>,
+    prefix: &StorePrefix,
+    max_listings: Option<usize>,
+    mut f: impl FnMut(&[u64]) -> Result<(), ZarrsResult>,
+) -> Result<bool, ZarrsResult> {
+    let mut listings = 0;
+    let mut dirs = vec![prefix.clone()];
+    while let Some(dir) = dirs.pop() {
+        if max_listings.is_some_and(|max_listings| listings == max_listings) {
+            return Ok(false);
+        }
+        listings += 1;
+        match list_chunk_dir(array, prefix, &dir) {
+            Ok(Some((chunks, subdirs))) => {
+                for chunk_indices in chunks {
+                    f(&chunk_indices)?;
+                }
+                dirs.extend(subdirs);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err.to_string();
+                return Err(ZarrsResult::ZARRS_ERROR_STORAGE);
+            }
+        }
+    }
+    Ok(true)
+}
+
+fn zarrsArrayGetStoredChunkCountImpl<T: ListableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    max_listings: Option<usize>,
+) -> Result<(u64, bool), ZarrsResult> {
+    let prefix = node_prefix(array.path().as_str())?;
+    let mut count = 0;
+    let is_exact = for_each_stored_chunk(array, &prefix, max_listings, |_| {
+        count += 1;
+        Ok(())
+    })?;
+    Ok((count, is_exact))
+}
+
+/// Count the chunks stored for a `ZarrsArray` handle with list capability.
+///
+/// Sets the last error on failure.
+fn stored_chunk_count(
+    array: ZarrsArray,
+    max_listings: Option<usize>,
+) -> Result<(u64, bool), ZarrsResult> {
+    if array.is_null() {
+        return Err(ZarrsResult::ZARRS_ERROR_NULL_PTR);
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    match array {
+        ZarrsArrayEnum::L(array) => zarrsArrayGetStoredChunkCountImpl(array, max_listings),
+        ZarrsArrayEnum::RL(array) => zarrsArrayGetStoredChunkCountImpl(array, max_listings),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayGetStoredChunkCountImpl(array, max_listings),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have list capability".to_string();
+            Err(ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY)
+        }
+    }
+}
+
+/// Get the number of chunks stored in the store.
+///
+/// The array prefix is listed one directory at a time and the keys that are chunk keys of the array (per its chunk key encoding and chunk grid) are counted.
+/// Metadata, other keys, and child nodes under the array prefix are not counted.
+/// The keys of the whole array are not collected at once, but every directory under the array prefix is listed.
+/// For a sharded array, this is the number of stored shards, see `zarrsArrayGetStoredInnerChunkCount` to count the stored inner chunks.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have list capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be listed.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pCount` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetStoredChunkCount(
+    array: ZarrsArray,
+    pCount: *mut u64,
+) -> ZarrsResult {
+    match stored_chunk_count(array, None) {
+        Ok((count, _)) => {
+            // SAFETY: pCount is a valid pointer per the function's safety contract.
+            unsafe { *pCount = count };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Get the number of chunks stored in the store, which may be approximate.
+///
+/// This is equal to `zarrsArrayGetStoredChunkCount`, except that the traversal of the array prefix stops after 1024 directories have been listed.
+/// `pIsExact` is set to false if the traversal stopped early, in which case the count is a lower bound on the number of stored chunks.
+/// This bounds the number of listing requests made to storage backends where each directory listing is a paginated request (e.g. object storage).
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have list capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be listed.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pCount` must be a valid pointer to a `uint64_t`.
+/// `pIsExact` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetStoredChunkCountApproximate(
+    array: ZarrsArray,
+    pCount: *mut u64,
+    pIsExact: *mut bool,
+) -> ZarrsResult {
+    match stored_chunk_count(array, Some(APPROXIMATE_COUNT_MAX_LISTINGS)) {
+        Ok((count, is_exact)) => {
+            // SAFETY: pCount and pIsExact are valid pointers per the function's safety contract.
+            unsafe {
+                *pCount = count;
+                *pIsExact = is_exact;
+            }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+fn zarrsArrayGetStoredInnerChunkCountImpl<T: ReadableListableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+) -> Result<u64, ZarrsResult> {
+    if array.subchunk_shape().is_none() {
+        *LAST_ERROR.lock().unwrap() = "array is not sharded".to_string();
+        return Err(ZarrsResult::ZARRS_ERROR_ARRAY);
+    }
+    let prefix = node_prefix(array.path().as_str())?;
+    let mut count = 0;
+    for_each_stored_chunk(array, &prefix, None, |shard_indices| {
+        // Each shard has its own cache, so the indexes of all shards are not held at once
+        let cache = ArrayShardedReadableExtCache::new(array);
+        count += stored_subchunk_indices(array, &cache, shard_indices)?.len() as u64;
+        Ok(())
+    })?;
+    Ok(count)
+}
+
+/// Get the number of inner chunks stored in the store for a sharded array.
+///
+/// The stored shards are found as in `zarrsArrayGetStoredChunkCount`, and the index of each stored shard is read to count its stored inner chunks.
+/// An inner chunk is stored if its entry in the shard index has a byte offset and size, so inner chunks that were entirely the fill value when the shard was written are not counted.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read and list capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array is not sharded or a shard index could not be read.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be listed.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pCount` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetStoredInnerChunkCount(
+    array: ZarrsArray,
+    pCount: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let count = match array {
+        ZarrsArrayEnum::RL(array) => zarrsArrayGetStoredInnerChunkCountImpl(array),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayGetStoredInnerChunkCountImpl(array),
+        _ => {
+            *LAST_ERROR.lock().unwrap() =
+                "storage does not have read and list capability".to_string();
+            Err(ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY)
+        }
+    };
+    match count {
+        Ok(count) => {
+            // SAFETY: pCount is a valid pointer per the function's safety contract.
+            unsafe { *pCount = count };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Returns true if a chunk is stored under `dir`, a directory under the array prefix `prefix`.
+///
+/// The directory is traversed one directory at a time, stopping at the first chunk found.
+fn has_stored_chunk<T: ListableStorageTraits + ?Sized>(
+    array: &Array<T>,
+    prefix: &StorePrefix,
+    dir: &StorePrefix,
+) -> Result<bool, StorageError> {
+    let Some((chunks, subdirs)) = list_chunk_dir(array, prefix, dir)? else {
+        return Ok(false);
+    };
+    if !chunks.is_empty() {
+        return Ok(true);
+    }
+    for subdir in &subdirs {
+        if has_stored_chunk(array, prefix, subdir)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn zarrsArrayIsEmptyImpl<T: ListableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    empty: *mut bool,
+) -> ZarrsResult {
+    let prefix = match node_prefix(array.path().as_str()) {
+        Ok(prefix) => prefix,
+        Err(result) => return result,
+    };
+    match has_stored_chunk(array, &prefix, &prefix) {
+        Ok(has_chunk) => {
+            // SAFETY: empty is a valid pointer per the safety contract of the calling function.
+            unsafe { *empty = !has_chunk };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE
+        }
+    }
+}
+
+/// Check if an array has no stored chunks.
+///
+/// Unlike `zarrsArrayGetStoredChunkCount`, this does not list every key under the array prefix.
+/// The store is listed one directory at a time and the check stops as soon as a stored chunk is found.
+/// As in `zarrsArrayGetStoredChunkCount`, only chunk keys of the array are considered, so an array with only child nodes or other keys under its prefix is empty.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have list capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be listed.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pEmpty` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayIsEmpty(array: ZarrsArray, pEmpty: *mut bool) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    match array {
+        ZarrsArrayEnum::L(array) => zarrsArrayIsEmptyImpl(array, pEmpty),
+        ZarrsArrayEnum::RL(array) => zarrsArrayIsEmptyImpl(array, pEmpty),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayIsEmptyImpl(array, pEmpty),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have list capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    }
+}
+
+fn zarrsArrayGetTotalStoredSizeBytesImpl<T: ReadableListableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    bytes: *mut u64,
+) -> ZarrsResult {
+    let keys = match list_array_chunk_keys(array) {
+        Ok(keys) => keys,
+        Err(result) => return result,
+    };
+    let storage = array.storage();
+    let mut total = 0;
+    for key in keys {
+        match storage.size_key(&key) {
+            Ok(size) => total += size.unwrap_or_default(),
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err.to_string();
+                return ZarrsResult::ZARRS_ERROR_STORAGE;
+            }
+        }
+    }
+    // SAFETY: bytes is a valid pointer per the safety contract of zarrsArrayGetTotalStoredSizeBytes.
+    unsafe { *bytes = total };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the total size in bytes of the encoded chunks stored in the store.
+///
+/// Keys under the array prefix are listed, excluding metadata, and their sizes are summed.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read and list capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pBytes` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetTotalStoredSizeBytes(
+    array: ZarrsArray,
+    pBytes: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    match array {
+        ZarrsArrayEnum::RL(array) => zarrsArrayGetTotalStoredSizeBytesImpl(array, pBytes),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayGetTotalStoredSizeBytesImpl(array, pBytes),
+        _ => {
+            *LAST_ERROR.lock().unwrap() =
+                "storage does not have read and list capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    }
+}
+
+fn zarrsArrayGetStoredChunkBytesTotalImpl<T: ReadableListableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    bytes: *mut u64,
+) -> ZarrsResult {
+    let prefix = match node_prefix(array.path().as_str()) {
+        Ok(prefix) => prefix,
+        Err(result) => return result,
+    };
+    // The storage computes the size of the prefix, which may avoid a full listing, and metadata is then excluded
+    let storage = array.storage();
+    let total = storage.size_prefix(&prefix).and_then(|total| {
+        METADATA_KEYS.iter().try_fold(total, |total, name| {
+            let key = StoreKey::new(format!("{}{name}", prefix.as_str()))
+                .map_err(|err| StorageError::Other(err.to_string()))?;
+            Ok(total.saturating_sub(storage.size_key(&key)?.unwrap_or_default()))
+        })
+    });
+    match total {
+        Ok(total) => {
+            // SAFETY: bytes is a valid pointer per the safety contract of the calling function.
+            unsafe { *bytes = total };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE
+        }
+    }
+}
+
+/// Get the total size in bytes of the encoded chunks stored in the store.
+///
+/// This is equal to `zarrsArrayGetTotalStoredSizeBytes`, but the size of the array prefix is queried from the storage, which can be cheaper than listing and querying every key for storage backends that track the total size of a prefix.
+/// Otherwise, the storage falls back to listing every key under the array prefix.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read and list capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pTotalBytes` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetStoredChunkBytesTotal(
+    array: ZarrsArray,
+    pTotalBytes: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    match array {
+        ZarrsArrayEnum::RL(array) => zarrsArrayGetStoredChunkBytesTotalImpl(array, pTotalBytes),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayGetStoredChunkBytesTotalImpl(array, pTotalBytes),
+        _ => {
+            *LAST_ERROR.lock().unwrap() =
+                "storage does not have read and list capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    }
+}
+
+/// Get the total size in bytes of the encoded chunks stored in the store, which may be approximate.
+///
+/// `pIsExact` is set to false if the size is an estimate, which may be the case for storage backends that only track the approximate size of a prefix.
+/// The storage backends currently supported by zarrs_ffi compute the size of a prefix exactly, so the size is currently always exact and equal to `zarrsArrayGetStoredChunkBytesTotal`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read and list capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pTotalBytes` must be a valid pointer to a `uint64_t`.
+/// `pIsExact` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetStoredChunkBytesFast(
+    array: ZarrsArray,
+    pTotalBytes: *mut u64,
+    pIsExact: *mut bool,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of zarrsArrayGetStoredChunkBytesTotal.
+    let result = unsafe { zarrsArrayGetStoredChunkBytesTotal(array, pTotalBytes) };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        // SAFETY: pIsExact is a valid pointer per the function's safety contract.
+        unsafe { *pIsExact = true };
+    }
+    result
+}
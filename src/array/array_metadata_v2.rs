@@ -0,0 +1,222 @@
+use std::ffi::{CString, c_char};
+
+use serde_json::{Map, Value, json};
+use zarrs::array::ArrayMetadata;
+
+use crate::{LAST_ERROR, ZarrsResult};
+
+use super::{ZarrsArray, array_fn};
+
+/// Convert a Zarr V3 data type name to a Zarr V2 `dtype` with the given byte order.
+fn data_type_v3_to_v2(data_type: &Value, byte_order: char) -> Result<String, String> {
+    let dtype = match data_type.as_str() {
+        Some("bool") => "|b1".to_string(),
+        Some("int8") => "|i1".to_string(),
+        Some("uint8") => "|u1".to_string(),
+        Some("int16") => format!("{byte_order}i2"),
+        Some("int32") => format!("{byte_order}i4"),
+        Some("int64") => format!("{byte_order}i8"),
+        Some("uint16") => format!("{byte_order}u2"),
+        Some("uint32") => format!("{byte_order}u4"),
+        Some("uint64") => format!("{byte_order}u8"),
+        Some("float16") => format!("{byte_order}f2"),
+        Some("float32") => format!("{byte_order}f4"),
+        Some("float64") => format!("{byte_order}f8"),
+        Some("complex64") => format!("{byte_order}c8"),
+        Some("complex128") => format!("{byte_order}c16"),
+        _ => {
+            return Err(format!(
+                "data type {data_type} cannot be expressed in Zarr V2"
+            ));
+        }
+    };
+    Ok(dtype)
+}
+
+/// Convert a Zarr V3 bytes to bytes codec to a Zarr V2 compressor.
+fn compressor_v3_to_v2(codec: &Value) -> Result<Value, String> {
+    let name = codec
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let configuration = codec.get("configuration").cloned().unwrap_or(json!({}));
+    let compressor = match name {
+        "gzip" => json!({"id": "gzip", "level": configuration["level"]}),
+        "bz2" => json!({"id": "bz2", "level": configuration["level"]}),
+        "zstd" => json!({
+            "id": "zstd",
+            "level": configuration["level"],
+            "checksum": configuration["checksum"],
+        }),
+        "blosc" => {
+            let shuffle = match configuration["shuffle"].as_str() {
+                Some("noshuffle") => 0,
+                Some("shuffle") => 1,
+                Some("bitshuffle") => 2,
+                _ => return Err("blosc codec has an invalid shuffle mode".to_string()),
+            };
+            json!({
+                "id": "blosc",
+                "cname": configuration["cname"],
+                "clevel": configuration["clevel"],
+                "shuffle": shuffle,
+                "blocksize": configuration["blocksize"],
+            })
+        }
+        _ => {
+            return Err(format!(
+                "codec {name} cannot be expressed as a Zarr V2 compressor"
+            ));
+        }
+    };
+    Ok(compressor)
+}
+
+/// Convert Zarr V3 array metadata to a Zarr V2 `.zarray` document.
+///
+/// Returns a description of the first feature that cannot be expressed in Zarr V2 on failure.
+fn array_metadata_v3_to_v2(metadata: &Map<String, Value>) -> Result<Value, String> {
+    if metadata
+        .get("storage_transformers")
+        .and_then(Value::as_array)
+        .is_some_and(|storage_transformers| !storage_transformers.is_empty())
+    {
+        return Err("storage transformers cannot be expressed in Zarr V2".to_string());
+    }
+
+    let chunk_grid = &metadata["chunk_grid"];
+    if chunk_grid["name"] != "regular" {
+        return Err(format!(
+            "chunk grid {} cannot be expressed in Zarr V2",
+            chunk_grid["name"]
+        ));
+    }
+    let chunks = chunk_grid["configuration"]["chunk_shape"].clone();
+
+    let dimension_separator = match &metadata["chunk_key_encoding"]["configuration"]["separator"] {
+        Value::Null => match metadata["chunk_key_encoding"]["name"].as_str() {
+            Some("v2") => ".".into(),
+            _ => "/".into(),
+        },
+        separator => separator.clone(),
+    };
+
+    let mut order = "C";
+    let mut byte_order = '<';
+    let mut compressor = Value::Null;
+    let mut bytes_codec_seen = false;
+    let codecs = metadata["codecs"].as_array().map_or(&[][..], Vec::as_slice);
+    for codec in codecs {
+        let name = codec["name"].as_str().unwrap_or_default();
+        match name {
+            "transpose" if !bytes_codec_seen => {
+                let permutation: Option<Vec<u64>> = codec["configuration"]["order"]
+                    .as_array()
+                    .and_then(|order| order.iter().map(Value::as_u64).collect());
+                let dimensionality = chunks.as_array().map_or(0, Vec::len) as u64;
+                if permutation != Some((0..dimensionality).rev().collect()) {
+                    return Err(
+                        "transpose codec order other than a full reversal cannot be expressed in Zarr V2"
+                            .to_string(),
+                    );
+                }
+                order = "F";
+            }
+            "bytes" if !bytes_codec_seen => {
+                bytes_codec_seen = true;
+                if codec["configuration"]["endian"] == "big" {
+                    byte_order = '>';
+                }
+            }
+            _ if bytes_codec_seen && compressor.is_null() => {
+                compressor = compressor_v3_to_v2(codec)?;
+            }
+            "sharding_indexed" => {
+                return Err("sharding cannot be expressed in Zarr V2".to_string());
+            }
+            _ if bytes_codec_seen => {
+                return Err("multiple compressors cannot be expressed in Zarr V2".to_string());
+            }
+            _ => return Err(format!("codec {name} cannot be expressed in Zarr V2")),
+        }
+    }
+
+    Ok(json!({
+        "zarr_format": 2,
+        "shape": metadata["shape"],
+        "chunks": chunks,
+        "dtype": data_type_v3_to_v2(&metadata["data_type"], byte_order)?,
+        "compressor": compressor,
+        "fill_value": metadata["fill_value"],
+        "order": order,
+        "filters": null,
+        "dimension_separator": dimension_separator,
+    }))
+}
+
+/// Convert array metadata to a Zarr V2 `.zarray` document.
+///
+/// Zarr V2 metadata is returned as is.
+/// Returns a description of the first feature that cannot be expressed in Zarr V2 on failure.
+pub(crate) fn array_metadata_to_v2(metadata: &ArrayMetadata) -> Result<Value, String> {
+    match metadata {
+        ArrayMetadata::V2(metadata) => {
+            serde_json::to_value(metadata).map_err(|err| err.to_string())
+        }
+        ArrayMetadata::V3(metadata) => match serde_json::to_value(metadata) {
+            Ok(Value::Object(metadata)) => array_metadata_v3_to_v2(&metadata),
+            Ok(_) => Err("array metadata is not a JSON object".to_string()),
+            Err(err) => Err(err.to_string()),
+        },
+    }
+}
+
+/// Get the array metadata converted to a Zarr V2 `.zarray` JSON string.
+///
+/// The Zarr V2 metadata of a Zarr V2 array is returned as is.
+/// Attributes are not included, as they are stored separately in `.zattrs` in Zarr V2.
+/// The `dimension_separator` is taken from the chunk key encoding, but note that the `default` chunk key encoding prefixes chunk keys with `c`.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the array uses features that cannot be expressed in Zarr V2 (e.g. sharding, an irregular chunk grid, or an extension data type).
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pMetadataJson` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetMetadataV2String(
+    array: ZarrsArray,
+    pretty: bool,
+    pMetadataJson: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    let metadata = match array_metadata_to_v2(&array_fn!(array, metadata)) {
+        Ok(metadata) => metadata,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err;
+            return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+        }
+    };
+    let metadata_str = if pretty {
+        serde_json::to_string_pretty(&metadata)
+    } else {
+        serde_json::to_string(&metadata)
+    };
+    if let Ok(metadata_str) = metadata_str
+        && let Ok(cstring) = CString::new(metadata_str)
+    {
+        // SAFETY: pMetadataJson is a valid pointer per the function's safety contract.
+        unsafe { *pMetadataJson = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() = "error converting metadata to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
@@ -1,20 +1,387 @@
+use std::{collections::HashSet, ffi::c_void};
+
+use rayon::iter::{IntoParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
+
 use zarrs::{
-    array::{Array, ArrayBytes, ArraySubset},
-    storage::ReadableStorageTraits,
+    array::{Array, ArrayBytes, ArraySubset, CodecOptions, chunk_shape_to_array_shape},
+    storage::{ReadableListableStorageTraits, ReadableStorageTraits},
 };
 
-use crate::{LAST_ERROR, ZarrsResult};
+use crate::{
+    LAST_ERROR, ZarrsDataType, ZarrsResult, buffer_into_raw,
+    codec_options::{ZarrsCodecOptions, ZarrsCodecOptions_T, codec_options_or_default},
+    stats::num_chunks_in_subset,
+};
 
-use super::{ZarrsArray, ZarrsArrayEnum};
+use super::{
+    ZarrsArray, ZarrsArray_T, ZarrsArrayEnum, ZarrsOrder, array_fn,
+    array_list::list_array_keys,
+    data_type::{ElementConverter, element_converter, element_to_f64, zarrs_data_type},
+    element_subset, for_each_strided_offset, output_region_view, output_view, strided_first_offset,
+    transpose_order, validate_chunk_indices, validated_array_subset, validated_chunks,
+    zarrsArrayGetChunkSize, zarrsArrayGetSubsetSize,
+};
 
 fn zarrsArrayRetrieveChunkImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunk_indices: &[u64],
+    chunk_bytes: &mut [u8],
+    codec_options: &CodecOptions,
+) -> ZarrsResult {
+    let chunk_shape = match array.chunk_shape(chunk_indices) {
+        Ok(chunk_shape) => chunk_shape_to_array_shape(&chunk_shape),
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_ARRAY;
+        }
+    };
+    let mut chunk_view = match output_view(array.data_type(), &chunk_shape, chunk_bytes, "chunk") {
+        Ok(chunk_view) => chunk_view,
+        Err(result) => return result,
+    };
+    // Decode directly into the output buffer, avoiding an intermediate allocation and copy
+    match array.retrieve_chunk_into(chunk_indices, &mut chunk_view, codec_options) {
+        Ok(()) => ZarrsResult::ZARRS_SUCCESS,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        }
+    }
+}
+
+/// Retrieve a chunk from an array.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
+/// The chunk is decoded directly into `pChunkBytes` without an intermediate copy.
+///
+/// # Errors
+/// Returns an error if the array does not have read capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+/// `pChunkBytes` must point to an array of length `chunkBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveChunk(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    chunkBytesCount: usize,
+    pChunkBytes: *mut u8,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveChunkOpt, and null codec options are valid.
+    unsafe {
+        zarrsArrayRetrieveChunkOpt(
+            array,
+            dimensionality,
+            pChunkIndices,
+            chunkBytesCount,
+            pChunkBytes,
+            std::ptr::null_mut(),
+        )
+    }
+}
+
+/// Retrieve a chunk from an array with codec options.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
+/// The chunk is decoded directly into `pChunkBytes` without an intermediate copy.
+/// If `codecOptions` is null, the default codec options are used.
+///
+/// # Errors
+/// Returns an error if the array does not have read capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+/// `pChunkBytes` must point to an array of length `chunkBytesCount`.
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveChunkOpt(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    chunkBytesCount: usize,
+    pChunkBytes: *mut u8,
+    codecOptions: ZarrsCodecOptions,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    // SAFETY: pChunkBytes points to an array of length chunkBytesCount per the function's safety contract.
+    let chunk_bytes = unsafe { std::slice::from_raw_parts_mut(pChunkBytes, chunkBytesCount) };
+    let default_codec_options = CodecOptions::default();
+    // SAFETY: the caller guarantees codecOptions is null or a valid ZarrsCodecOptions handle.
+    let codec_options = unsafe { codec_options_or_default(codecOptions, &default_codec_options) };
+
+    // Decode the chunk bytes
+    let result = match array {
+        ZarrsArrayEnum::R(array) => {
+            zarrsArrayRetrieveChunkImpl(array, chunk_indices, chunk_bytes, codec_options)
+        }
+        ZarrsArrayEnum::RL(array) => {
+            zarrsArrayRetrieveChunkImpl(array, chunk_indices, chunk_bytes, codec_options)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayRetrieveChunkImpl(array, chunk_indices, chunk_bytes, codec_options)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayRetrieveChunkImpl(array, chunk_indices, chunk_bytes, codec_options)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(1);
+    }
+    result
+}
+
+/// Retrieve a chunk from an array into a buffer allocated by zarrs.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `ppBytes` is set to a buffer holding the decoded chunk and `pBytesLen` is set to its length in bytes.
+/// This avoids querying the chunk size with `zarrsArrayGetChunkSize()` and allocating the output buffer in advance.
+///
+/// The buffer must be freed with `zarrsFreeBytes`, never with `free()`.
+/// Use `zarrsArrayRetrieveChunk` to decode into a buffer owned by the caller.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk indices are invalid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+/// `ppBytes` must be a valid pointer to a `uint8_t*` and `pBytesLen` must be a valid pointer to a `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveChunkAlloc(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    ppBytes: *mut *mut u8,
+    pBytesLen: *mut usize,
+) -> ZarrsResult {
+    let mut chunk_size = 0;
+    // SAFETY: the caller upholds the safety contract of zarrsArrayGetChunkSize.
+    let result =
+        unsafe { zarrsArrayGetChunkSize(array, dimensionality, pChunkIndices, &mut chunk_size) };
+    if !matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        return result;
+    }
+    let mut chunk_bytes = vec![0u8; chunk_size];
+    // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveChunk, and chunk_bytes has length chunk_size.
+    let result = unsafe {
+        zarrsArrayRetrieveChunk(
+            array,
+            dimensionality,
+            pChunkIndices,
+            chunk_size,
+            chunk_bytes.as_mut_ptr(),
+        )
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        // SAFETY: ppBytes and pBytesLen are valid pointers per the function's safety contract.
+        unsafe {
+            *pBytesLen = chunk_size;
+            *ppBytes = buffer_into_raw(chunk_bytes);
+        }
+    }
+    result
+}
+
+fn zarrsArrayRetrieveChunkSubsetIntoBufferImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunk_indices: &[u64],
+    chunk_subset: &ArraySubset,
+    buffer: &mut [u8],
+    buffer_stride: usize,
+    buffer_offset: usize,
+) -> ZarrsResult {
+    let Some(element_size) = array.data_type().fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let chunk_shape = match array.chunk_shape(chunk_indices) {
+        Ok(chunk_shape) => chunk_shape_to_array_shape(&chunk_shape),
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+        }
+    };
+    let subset_start = chunk_subset.start();
+    let subset_shape = chunk_subset.shape();
+    if std::iter::zip(std::iter::zip(subset_start, subset_shape), &chunk_shape)
+        .any(|((&start, &shape), &size)| start.checked_add(shape).is_none_or(|end| end > size))
+    {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "subset with start {subset_start:?} and shape {subset_shape:?} exceeds the chunk shape {chunk_shape:?}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+    }
+    if chunk_subset.num_elements() == 0 {
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    // The sub-region is written as rows spanning its last dimension
+    let row_length = subset_shape.last().map_or(1, |&size| size as usize) * element_size;
+    let num_rows =
+        usize::try_from(chunk_subset.num_elements()).unwrap() * element_size / row_length;
+    if num_rows > 1 && buffer_stride < row_length {
+        *LAST_ERROR.lock().unwrap() =
+            format!("buffer stride {buffer_stride} is less than the row length {row_length}");
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+    let buffer_end = (num_rows - 1)
+        .checked_mul(buffer_stride)
+        .and_then(|last_row| last_row.checked_add(buffer_offset))
+        .and_then(|last_row| last_row.checked_add(row_length));
+    if buffer_end.is_none_or(|buffer_end| buffer_end > buffer.len()) {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "{num_rows} rows of {row_length} bytes with stride {buffer_stride} at offset {buffer_offset} exceed the buffer length {}",
+            buffer.len()
+        );
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+
+    let subset_bytes = match array.retrieve_chunk_subset::<ArrayBytes>(chunk_indices, chunk_subset)
+    {
+        Ok(subset_bytes) => subset_bytes,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_ARRAY;
+        }
+    };
+    let Ok(subset_bytes) = subset_bytes.into_fixed() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    for (row, row_bytes) in subset_bytes.chunks_exact(row_length).enumerate() {
+        let offset = buffer_offset + row * buffer_stride;
+        buffer[offset..offset + row_length].copy_from_slice(row_bytes);
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Retrieve a sub-region of a chunk into a position within a larger buffer.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pSubsetStartInChunk` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the start (relative to the chunk origin) and shape of the sub-region.
+///
+/// The sub-region is written to `pBuffer` as rows spanning its last dimension, in C order.
+/// Row `r` is written at byte offset `bufferOffset + r * bufferStride`, so `bufferStride` is the number of bytes between the starts of consecutive rows.
+/// Bytes of `pBuffer` between rows are left unchanged, which allows tiles to be composited into a larger image without a temporary buffer.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk indices are invalid or `pSubsetStartInChunk + pSubsetShape` exceeds the chunk shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bufferStride` is less than the length of a row or the rows exceed `bufferBytesCount`.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pChunkIndices`, `pSubsetStartInChunk`, and `pSubsetShape`.
+/// `pBuffer` must point to an array of length `bufferBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveChunkSubsetIntoBuffer(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pSubsetStartInChunk: *const u64,
+    pSubsetShape: *const u64,
+    bufferStride: usize,
+    bufferOffset: usize,
+    bufferBytesCount: usize,
+    pBuffer: *mut u8,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pChunkIndices, pSubsetStartInChunk, and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStartInChunk, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let chunk_subset = ArraySubset::from(
+        std::iter::zip(subset_start, subset_shape)
+            .map(|(&start, &shape)| start..start.saturating_add(shape)),
+    );
+    // SAFETY: pBuffer points to an array of length bufferBytesCount per the function's safety contract.
+    let buffer = unsafe { std::slice::from_raw_parts_mut(pBuffer, bufferBytesCount) };
+
+    let result = match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayRetrieveChunkSubsetIntoBufferImpl(
+            array,
+            chunk_indices,
+            &chunk_subset,
+            buffer,
+            bufferStride,
+            bufferOffset,
+        ),
+        ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveChunkSubsetIntoBufferImpl(
+            array,
+            chunk_indices,
+            &chunk_subset,
+            buffer,
+            bufferStride,
+            bufferOffset,
+        ),
+        ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveChunkSubsetIntoBufferImpl(
+            array,
+            chunk_indices,
+            &chunk_subset,
+            buffer,
+            bufferStride,
+            bufferOffset,
+        ),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveChunkSubsetIntoBufferImpl(
+            array,
+            chunk_indices,
+            &chunk_subset,
+            buffer,
+            bufferStride,
+            bufferOffset,
+        ),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(1);
+    }
+    result
+}
+
+fn zarrsArrayRetrieveChunkIfExistsImpl<T: ReadableStorageTraits + ?Sized + 'static>(
     array: &Array<T>,
     chunk_indices: &[u64],
     chunk_bytes_length: usize,
     chunk_bytes: *mut u8,
+    exists: &mut bool,
 ) -> ZarrsResult {
-    match array.retrieve_chunk::<ArrayBytes>(chunk_indices) {
-        Ok(bytes) => {
+    match array.retrieve_chunk_if_exists::<ArrayBytes>(chunk_indices) {
+        Ok(Some(bytes)) => {
             let Ok(bytes) = bytes.into_fixed() else {
                 *LAST_ERROR.lock().unwrap() =
                     "variable size data types are not supported".to_string();
@@ -28,9 +395,14 @@ fn zarrsArrayRetrieveChunkImpl<T: ReadableStorageTraits + ?Sized + 'static>(
                 ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH
             } else {
                 unsafe { std::ptr::copy(bytes.as_ptr(), chunk_bytes, chunk_bytes_length) };
+                *exists = true;
                 ZarrsResult::ZARRS_SUCCESS
             }
         }
+        Ok(None) => {
+            *exists = false;
+            ZarrsResult::ZARRS_SUCCESS
+        }
         Err(err) => {
             *LAST_ERROR.lock().unwrap() = err.to_string();
             ZarrsResult::ZARRS_ERROR_ARRAY
@@ -38,75 +410,110 @@ fn zarrsArrayRetrieveChunkImpl<T: ReadableStorageTraits + ?Sized + 'static>(
     }
 }
 
-/// Retrieve a chunk from an array.
+/// Retrieve a chunk from an array if it exists.
 ///
 /// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
 /// `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
 ///
+/// `pExists` is set to true if the chunk exists and has been decoded into `pChunkBytes`.
+/// If the chunk does not exist, `pExists` is set to false and `pChunkBytes` is left untouched, rather than being filled with the fill value as in `zarrsArrayRetrieveChunk`.
+///
 /// # Errors
-/// Returns an error if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if the chunk exists and `chunkBytesCount` does not match its decoded size.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the chunk indices are invalid or the chunk cannot be retrieved or decoded.
 ///
 /// # Safety
-/// `array` must be a valid `ZarrsArray` handle.
+/// If not null, `array` must be a valid `ZarrsArray` handle.
 /// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+/// `pChunkBytes` must point to an array of length `chunkBytesCount`.
+/// `pExists` must be a valid pointer to a `bool`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayRetrieveChunk(
+pub unsafe extern "C" fn zarrsArrayRetrieveChunkIfExists(
     array: ZarrsArray,
     dimensionality: usize,
     pChunkIndices: *const u64,
     chunkBytesCount: usize,
     pChunkBytes: *mut u8,
+    pExists: *mut bool,
 ) -> ZarrsResult {
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
-    let array = unsafe { &**array };
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
     // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
     let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
 
     // Get the chunk bytes
-    match array {
-        ZarrsArrayEnum::R(array) => {
-            zarrsArrayRetrieveChunkImpl(array, chunk_indices, chunkBytesCount, pChunkBytes)
-        }
-        ZarrsArrayEnum::RL(array) => {
-            zarrsArrayRetrieveChunkImpl(array, chunk_indices, chunkBytesCount, pChunkBytes)
-        }
-        ZarrsArrayEnum::RW(array) => {
-            zarrsArrayRetrieveChunkImpl(array, chunk_indices, chunkBytesCount, pChunkBytes)
-        }
-        ZarrsArrayEnum::RWL(array) => {
-            zarrsArrayRetrieveChunkImpl(array, chunk_indices, chunkBytesCount, pChunkBytes)
-        }
+    let mut exists = false;
+    let result = match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayRetrieveChunkIfExistsImpl(
+            array,
+            chunk_indices,
+            chunkBytesCount,
+            pChunkBytes,
+            &mut exists,
+        ),
+        ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveChunkIfExistsImpl(
+            array,
+            chunk_indices,
+            chunkBytesCount,
+            pChunkBytes,
+            &mut exists,
+        ),
+        ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveChunkIfExistsImpl(
+            array,
+            chunk_indices,
+            chunkBytesCount,
+            pChunkBytes,
+            &mut exists,
+        ),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveChunkIfExistsImpl(
+            array,
+            chunk_indices,
+            chunkBytesCount,
+            pChunkBytes,
+            &mut exists,
+        ),
         _ => {
             *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
             ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
         }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        // SAFETY: pExists is a valid pointer per the function's safety contract.
+        unsafe { *pExists = exists };
+        if exists {
+            stats.record_decoded(1);
+        }
     }
+    result
 }
 
-fn zarrsArrayRetrieveSubsetImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+fn zarrsArrayRetrieveChunksImpl<T: ReadableStorageTraits + ?Sized + 'static>(
     array: &Array<T>,
-    array_subset: &ArraySubset,
-    subset_bytes_length: usize,
-    subset_bytes: *mut u8,
+    chunks: &ArraySubset,
+    bytes_length: usize,
+    bytes_out: *mut u8,
 ) -> ZarrsResult {
-    match array.retrieve_array_subset::<ArrayBytes>(array_subset) {
+    match array.retrieve_chunks::<ArrayBytes>(chunks) {
         Ok(bytes) => {
             let Ok(bytes) = bytes.into_fixed() else {
                 *LAST_ERROR.lock().unwrap() =
                     "variable size data types are not supported".to_string();
                 return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
             };
-            if bytes.len() != subset_bytes_length {
+            if bytes.len() != bytes_length {
                 *LAST_ERROR.lock().unwrap() = format!(
-                    "subset_bytes_length {subset_bytes_length} does not match decoded subset size {}",
+                    "bytes_length {bytes_length} does not match decoded chunks size {}",
                     bytes.len()
                 );
                 ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH
             } else {
-                unsafe { std::ptr::copy(bytes.as_ptr(), subset_bytes, subset_bytes_length) };
+                unsafe { std::ptr::copy(bytes.as_ptr(), bytes_out, bytes_length) };
                 ZarrsResult::ZARRS_SUCCESS
             }
         }
@@ -117,56 +524,1820 @@ fn zarrsArrayRetrieveSubsetImpl<T: ReadableStorageTraits + ?Sized + 'static>(
     }
 }
 
-/// Retrieve a subset from an array.
+/// Retrieve a rectangular region of chunks from an array.
 ///
-/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
-/// `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+/// `pChunksStart` and `pChunksShape` are pointers to arrays of length `dimensionality` holding the start and shape of the chunk range.
+/// `pBytes` is a pointer to an array of bytes of length `bytesCount` that is filled with the decoded data of the array subset covered by the chunks in C order.
+/// `bytesCount` must match the size of the array subset returned by `zarrsArrayGetChunksSubset()` (its number of elements multiplied by the data type size).
+///
+/// The chunks are retrieved and decoded in parallel.
 ///
 /// # Errors
-/// Returns an error if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk range extends beyond the chunk grid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the decoded size of the chunks.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the chunks cannot be retrieved or decoded.
 ///
 /// # Safety
-/// `array` must be a valid `ZarrsArray` handle.
-/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunksStart` and `pChunksShape`.
+/// `pBytes` must point to an array of length `bytesCount`.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn zarrsArrayRetrieveSubset(
+pub unsafe extern "C" fn zarrsArrayRetrieveChunks(
     array: ZarrsArray,
     dimensionality: usize,
-    pSubsetStart: *const u64,
-    pSubsetShape: *const u64,
-    subsetBytesCount: usize,
-    pSubsetBytes: *mut u8,
+    pChunksStart: *const u64,
+    pChunksShape: *const u64,
+    bytesCount: usize,
+    pBytes: *mut u8,
 ) -> ZarrsResult {
-    // Validation
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
-    let array = unsafe { &**array };
-    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
-    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
-    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
-    let array_subset = ArraySubset::from(
-        std::iter::zip(subset_start, subset_shape).map(|(&start, &shape)| start..start + shape),
-    );
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pChunksStart and pChunksShape point to arrays of length dimensionality per the function's safety contract.
+    let chunks_start = unsafe { std::slice::from_raw_parts(pChunksStart, dimensionality) };
+    let chunks_shape = unsafe { std::slice::from_raw_parts(pChunksShape, dimensionality) };
+    let chunks = match validated_chunks(array, chunks_start, chunks_shape) {
+        Ok(chunks) => chunks,
+        Err(result) => return result,
+    };
 
-    // Get the subset bytes
-    match array {
+    // Get the chunks bytes
+    let result = match array {
         ZarrsArrayEnum::R(array) => {
-            zarrsArrayRetrieveSubsetImpl(array, &array_subset, subsetBytesCount, pSubsetBytes)
+            zarrsArrayRetrieveChunksImpl(array, &chunks, bytesCount, pBytes)
         }
         ZarrsArrayEnum::RL(array) => {
-            zarrsArrayRetrieveSubsetImpl(array, &array_subset, subsetBytesCount, pSubsetBytes)
+            zarrsArrayRetrieveChunksImpl(array, &chunks, bytesCount, pBytes)
         }
         ZarrsArrayEnum::RW(array) => {
-            zarrsArrayRetrieveSubsetImpl(array, &array_subset, subsetBytesCount, pSubsetBytes)
+            zarrsArrayRetrieveChunksImpl(array, &chunks, bytesCount, pBytes)
         }
         ZarrsArrayEnum::RWL(array) => {
-            zarrsArrayRetrieveSubsetImpl(array, &array_subset, subsetBytesCount, pSubsetBytes)
+            zarrsArrayRetrieveChunksImpl(array, &chunks, bytesCount, pBytes)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(chunks.num_elements());
+    }
+    result
+}
+
+/// A chunk retrieved by `zarrsArrayRetrieveChunksBatch`: its indices, output buffer, and result.
+type BatchChunk<'a> = (&'a [u64], &'a mut [u8], &'a mut ZarrsResult);
+
+fn zarrsArrayRetrieveChunksBatchImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    batch: &mut [BatchChunk],
+) {
+    // Chunks are retrieved on the rayon thread pool shared with the codecs, so their concurrency is balanced rather than oversubscribed
+    batch
+        .par_iter_mut()
+        .for_each(|(chunk_indices, chunk_bytes, result)| {
+            **result = zarrsArrayRetrieveChunkImpl(
+                array,
+                chunk_indices,
+                chunk_bytes,
+                &CodecOptions::default(),
+            );
+        });
+}
+
+/// Retrieve a batch of chunks from an array into separate buffers.
+///
+/// `pChunkIndicesFlat` is a pointer to an array of length `numChunks * dimensionality` holding the indices of each chunk contiguously.
+/// `pChunkBuffers` is a pointer to an array of `numChunks` output buffers, and `pChunkBufferLens` holds their lengths.
+/// The length of each buffer must match the size of its chunk as returned by `zarrsArrayGetChunkSize()`.
+/// `pPerChunkResults` is a pointer to an array of length `numChunks` that is set to the result of retrieving each chunk, as if by `zarrsArrayRetrieveChunk`.
+///
+/// The chunks are retrieved in parallel, and a chunk that fails to be retrieved does not prevent the others from being retrieved.
+/// If more than one chunk fails, the last error describes one of the failures.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality, in which case no chunks are retrieved.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_PARTIAL_FAILURE` if any chunk could not be retrieved, check `pPerChunkResults` for the result of each chunk.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pChunkIndicesFlat` must point to an array of length `numChunks * dimensionality`.
+/// `pChunkBuffers`, `pChunkBufferLens`, and `pPerChunkResults` must point to arrays of length `numChunks`.
+/// Each element of `pChunkBuffers` must point to an array of the corresponding length in `pChunkBufferLens`, and the buffers must not overlap.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveChunksBatch(
+    array: ZarrsArray,
+    dimensionality: usize,
+    numChunks: usize,
+    pChunkIndicesFlat: *const u64,
+    pChunkBuffers: *const *mut u8,
+    pChunkBufferLens: *const usize,
+    pPerChunkResults: *mut ZarrsResult,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: the pointers point to arrays of the documented lengths per the function's safety contract.
+    let chunk_indices_flat =
+        unsafe { std::slice::from_raw_parts(pChunkIndicesFlat, numChunks * dimensionality) };
+    let chunk_buffers = unsafe { std::slice::from_raw_parts(pChunkBuffers, numChunks) };
+    let chunk_buffer_lens = unsafe { std::slice::from_raw_parts(pChunkBufferLens, numChunks) };
+    let per_chunk_results = unsafe { std::slice::from_raw_parts_mut(pPerChunkResults, numChunks) };
+
+    let mut batch: Vec<BatchChunk> = std::iter::zip(chunk_buffers, chunk_buffer_lens)
+        .zip(per_chunk_results.iter_mut())
+        .enumerate()
+        .map(|(i, ((&chunk_buffer, &chunk_buffer_len), result))| {
+            let chunk_indices = &chunk_indices_flat[i * dimensionality..(i + 1) * dimensionality];
+            // SAFETY: each buffer points to an array of its length and does not overlap the others per the function's safety contract.
+            let chunk_bytes =
+                unsafe { std::slice::from_raw_parts_mut(chunk_buffer, chunk_buffer_len) };
+            (chunk_indices, chunk_bytes, result)
+        })
+        .collect();
+
+    // Retrieve the chunks
+    match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayRetrieveChunksBatchImpl(array, &mut batch),
+        ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveChunksBatchImpl(array, &mut batch),
+        ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveChunksBatchImpl(array, &mut batch),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveChunksBatchImpl(array, &mut batch),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    }
+
+    let chunks_read = batch
+        .iter()
+        .filter(|(_, _, result)| matches!(result, ZarrsResult::ZARRS_SUCCESS))
+        .count() as u64;
+    stats.record_decoded(chunks_read);
+    if chunks_read == batch.len() as u64 {
+        ZarrsResult::ZARRS_SUCCESS
+    } else {
+        ZarrsResult::ZARRS_ERROR_PARTIAL_FAILURE
+    }
+}
+
+fn zarrsArrayChunkExistsImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunk_indices: &[u64],
+    exists: *mut bool,
+) -> ZarrsResult {
+    let chunk_key = array.chunk_key(chunk_indices);
+    match array.storage().size_key(&chunk_key) {
+        Ok(size) => {
+            // SAFETY: exists is a valid pointer per the safety contract of zarrsArrayChunkExists.
+            unsafe { *exists = size.is_some() };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE
         }
+    }
+}
+
+/// Check if a chunk exists in the store.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// The chunk is not retrieved or decoded, only the existence of its key is checked.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk indices are invalid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+/// `pExists` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayChunkExists(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pExists: *mut bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    if let Err(result) = validate_chunk_indices(array, chunk_indices) {
+        return result;
+    }
+
+    match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayChunkExistsImpl(array, chunk_indices, pExists),
+        ZarrsArrayEnum::RL(array) => zarrsArrayChunkExistsImpl(array, chunk_indices, pExists),
+        ZarrsArrayEnum::RW(array) => zarrsArrayChunkExistsImpl(array, chunk_indices, pExists),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayChunkExistsImpl(array, chunk_indices, pExists),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    }
+}
+
+fn zarrsArrayGetStoredChunkSizeImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunk_indices: &[u64],
+    size_bytes: *mut u64,
+    exists: *mut bool,
+) -> ZarrsResult {
+    let chunk_key = array.chunk_key(chunk_indices);
+    match array.storage().size_key(&chunk_key) {
+        Ok(size) => {
+            // SAFETY: size_bytes and exists are valid pointers per the safety contract of zarrsArrayGetStoredChunkSize.
+            unsafe {
+                *size_bytes = size.unwrap_or(0);
+                *exists = size.is_some();
+            }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE
+        }
+    }
+}
+
+/// Get the stored (encoded) size of a chunk in bytes.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// The size is queried from the store without retrieving the chunk.
+/// If the chunk does not exist, `pExists` is set to `false` and `pSizeBytes` is set to zero.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk indices are invalid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+/// `pSizeBytes` and `pExists` must be valid pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetStoredChunkSize(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pSizeBytes: *mut u64,
+    pExists: *mut bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    if let Err(result) = validate_chunk_indices(array, chunk_indices) {
+        return result;
+    }
+
+    match array {
+        ZarrsArrayEnum::R(array) => {
+            zarrsArrayGetStoredChunkSizeImpl(array, chunk_indices, pSizeBytes, pExists)
+        }
+        ZarrsArrayEnum::RL(array) => {
+            zarrsArrayGetStoredChunkSizeImpl(array, chunk_indices, pSizeBytes, pExists)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayGetStoredChunkSizeImpl(array, chunk_indices, pSizeBytes, pExists)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayGetStoredChunkSizeImpl(array, chunk_indices, pSizeBytes, pExists)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    }
+}
+
+/// Get the encoded size of a chunk in bytes.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// The size is queried from the store without retrieving or decoding the chunk.
+/// `pBytes` is set to zero if the chunk does not exist.
+///
+/// # Errors
+/// Returns any error of `zarrsArrayGetStoredChunkSize()`.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+/// `pBytes` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetEncodedChunkSizeBytes(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    pBytes: *mut u64,
+) -> ZarrsResult {
+    let mut exists = false;
+    // SAFETY: the caller upholds the safety contract of zarrsArrayGetStoredChunkSize.
+    unsafe {
+        zarrsArrayGetStoredChunkSize(array, dimensionality, pChunkIndices, pBytes, &mut exists)
+    }
+}
+
+fn set_bitmap_bit(bitmap: &mut [u8], index: usize) {
+    bitmap[index / 8] |= 1 << (index % 8);
+}
+
+fn zarrsArrayGetChunkExistsBitmapProbeImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunks: &ArraySubset,
+    bitmap: &mut [u8],
+) -> ZarrsResult {
+    let storage = array.storage();
+    for (i, chunk_indices) in chunks.indices().into_iter().enumerate() {
+        match storage.size_key(&array.chunk_key(&chunk_indices)) {
+            Ok(Some(_)) => set_bitmap_bit(bitmap, i),
+            Ok(None) => {}
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err.to_string();
+                return ZarrsResult::ZARRS_ERROR_STORAGE;
+            }
+        }
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+fn zarrsArrayGetChunkExistsBitmapListImpl<T: ReadableListableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunks: &ArraySubset,
+    bitmap: &mut [u8],
+) -> ZarrsResult {
+    let keys: HashSet<_> = match list_array_keys(array) {
+        Ok(keys) => keys.into_iter().collect(),
+        Err(result) => return result,
+    };
+    for (i, chunk_indices) in chunks.indices().into_iter().enumerate() {
+        if keys.contains(&array.chunk_key(&chunk_indices)) {
+            set_bitmap_bit(bitmap, i);
+        }
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get a bitmap indicating which chunks in a region of the chunk grid exist in the store.
+///
+/// `pChunksStart` and `pChunksShape` are pointers to arrays of length `dimensionality` holding the start and shape of the region in the chunk grid.
+/// `pBitmap` is a pointer to a caller-allocated buffer of at least `ceil(n / 8)` bytes, where `n` is the number of chunks in the region.
+///
+/// Chunks are enumerated in C order (last dimension fastest) over the region.
+/// The chunk with C order index `i` corresponds to bit `i % 8` (least significant bit first) of byte `i / 8` of `pBitmap`.
+/// A set bit indicates that the chunk exists in the store.
+/// Unused trailing bits of the last byte are cleared.
+///
+/// The keys under the array are listed once if the storage has list capability, otherwise the key of each chunk is queried individually.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the region is not within the chunk grid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pChunksStart` and `pChunksShape` must point to arrays of length `dimensionality`.
+/// `pBitmap` must point to a buffer of at least `ceil(n / 8)` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetChunkExistsBitmap(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunksStart: *const u64,
+    pChunksShape: *const u64,
+    pBitmap: *mut u8,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunksStart and pChunksShape point to arrays of length dimensionality per the function's safety contract.
+    let chunks_start = unsafe { std::slice::from_raw_parts(pChunksStart, dimensionality) };
+    let chunks_shape = unsafe { std::slice::from_raw_parts(pChunksShape, dimensionality) };
+    let chunk_grid_shape = array_fn!(array, chunk_grid_shape);
+    if chunk_grid_shape.len() != dimensionality
+        || std::iter::zip(chunks_start, chunks_shape)
+            .zip(chunk_grid_shape)
+            .any(|((&start, &shape), &size)| start.saturating_add(shape) > size)
+    {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "chunk region with start {chunks_start:?} and shape {chunks_shape:?} is invalid for a chunk grid with shape {chunk_grid_shape:?}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+    }
+    let chunks = ArraySubset::from(
+        std::iter::zip(chunks_start, chunks_shape).map(|(&start, &shape)| start..start + shape),
+    );
+    let num_chunks = usize::try_from(chunks.num_elements()).unwrap();
+    // SAFETY: pBitmap points to a buffer of at least ceil(n / 8) bytes per the function's safety contract.
+    let bitmap = unsafe { std::slice::from_raw_parts_mut(pBitmap, num_chunks.div_ceil(8)) };
+    bitmap.fill(0);
+
+    match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayGetChunkExistsBitmapProbeImpl(array, &chunks, bitmap),
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayGetChunkExistsBitmapProbeImpl(array, &chunks, bitmap)
+        }
+        ZarrsArrayEnum::RL(array) => zarrsArrayGetChunkExistsBitmapListImpl(array, &chunks, bitmap),
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayGetChunkExistsBitmapListImpl(array, &chunks, bitmap)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    }
+}
+
+fn zarrsArrayRetrieveSubsetImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    array_subset: &ArraySubset,
+    subset_bytes: &mut [u8],
+    codec_options: &CodecOptions,
+) -> ZarrsResult {
+    let mut subset_view = match output_view(
+        array.data_type(),
+        array_subset.shape(),
+        subset_bytes,
+        "subset",
+    ) {
+        Ok(subset_view) => subset_view,
+        Err(result) => return result,
+    };
+    // Each chunk is decoded directly into its position in the output buffer
+    match array.retrieve_array_subset_into(array_subset, &mut subset_view, codec_options) {
+        Ok(()) => ZarrsResult::ZARRS_SUCCESS,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        }
+    }
+}
+
+/// Retrieve a subset from an array.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
+/// `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubset(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    subsetBytesCount: usize,
+    pSubsetBytes: *mut u8,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveSubsetOpt, and null codec options are valid.
+    unsafe {
+        zarrsArrayRetrieveSubsetOpt(
+            array,
+            dimensionality,
+            pSubsetStart,
+            pSubsetShape,
+            subsetBytesCount,
+            pSubsetBytes,
+            std::ptr::null_mut(),
+        )
+    }
+}
+
+/// Retrieve a subset from an array into a buffer allocated by zarrs.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// `ppBytes` is set to a buffer holding the decoded subset and `pBytesLen` is set to its length in bytes.
+/// This avoids querying the subset size with `zarrsArrayGetSubsetSize()` and allocating the output buffer in advance.
+///
+/// The buffer must be freed with `zarrsFreeBytes`, never with `free()`.
+/// Use `zarrsArrayRetrieveSubset` to decode into a buffer owned by the caller.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the subset cannot be retrieved.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// `ppBytes` must be a valid pointer to a `uint8_t*` and `pBytesLen` must be a valid pointer to a `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetAlloc(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    ppBytes: *mut *mut u8,
+    pBytesLen: *mut usize,
+) -> ZarrsResult {
+    let mut subset_size = 0;
+    // SAFETY: the caller upholds the safety contract of zarrsArrayGetSubsetSize.
+    let result =
+        unsafe { zarrsArrayGetSubsetSize(array, dimensionality, pSubsetShape, &mut subset_size) };
+    if !matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        return result;
+    }
+    let mut subset_bytes = vec![0u8; subset_size];
+    // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveSubset, and subset_bytes has length subset_size.
+    let result = unsafe {
+        zarrsArrayRetrieveSubset(
+            array,
+            dimensionality,
+            pSubsetStart,
+            pSubsetShape,
+            subset_size,
+            subset_bytes.as_mut_ptr(),
+        )
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        // SAFETY: ppBytes and pBytesLen are valid pointers per the function's safety contract.
+        unsafe {
+            *pBytesLen = subset_size;
+            *ppBytes = buffer_into_raw(subset_bytes);
+        }
+    }
+    result
+}
+
+/// Retrieve a subset from an array with codec options.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
+/// `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+/// If `codecOptions` is null, the default codec options are used.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetOpt(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    subsetBytesCount: usize,
+    pSubsetBytes: *mut u8,
+    codecOptions: ZarrsCodecOptions,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    // SAFETY: pSubsetBytes points to an array of length subsetBytesCount per the function's safety contract.
+    let subset_bytes = unsafe { std::slice::from_raw_parts_mut(pSubsetBytes, subsetBytesCount) };
+    let default_codec_options = CodecOptions::default();
+    // SAFETY: the caller guarantees codecOptions is null or a valid ZarrsCodecOptions handle.
+    let codec_options = unsafe { codec_options_or_default(codecOptions, &default_codec_options) };
+
+    // Get the subset bytes
+    let result = match array {
+        ZarrsArrayEnum::R(array) => {
+            zarrsArrayRetrieveSubsetImpl(array, &array_subset, subset_bytes, codec_options)
+        }
+        ZarrsArrayEnum::RL(array) => {
+            zarrsArrayRetrieveSubsetImpl(array, &array_subset, subset_bytes, codec_options)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayRetrieveSubsetImpl(array, &array_subset, subset_bytes, codec_options)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayRetrieveSubsetImpl(array, &array_subset, subset_bytes, codec_options)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(num_chunks_in_subset(array, &array_subset));
+    }
+    result
+}
+
+/// Retrieve a subset from an array with a concurrency limit.
+///
+/// `maxConcurrency` caps both the number of chunks decoded in parallel and the parallelism of the codecs for this call only.
+/// If `maxConcurrency` is zero, the default codec options are used, as in `zarrsArrayRetrieveSubset`.
+/// This is a shorthand for `zarrsArrayRetrieveSubsetOpt` with codec options created by `zarrsCreateCodecOptions` and `zarrsCodecOptionsSetMaxConcurrency`.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+///
+/// # Errors
+/// Returns an error if the array does not have read capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetParallel(
+    array: ZarrsArray,
+    maxConcurrency: usize,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    subsetBytesCount: usize,
+    pSubsetBytes: *mut u8,
+) -> ZarrsResult {
+    let mut codec_options = ZarrsCodecOptions_T(CodecOptions::default());
+    let codecOptions: ZarrsCodecOptions = if maxConcurrency == 0 {
+        std::ptr::null_mut()
+    } else {
+        codec_options.set_concurrent_target(maxConcurrency);
+        &mut codec_options
+    };
+    // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveSubsetOpt, and codecOptions is null or valid for the duration of the call.
+    unsafe {
+        zarrsArrayRetrieveSubsetOpt(
+            array,
+            dimensionality,
+            pSubsetStart,
+            pSubsetShape,
+            subsetBytesCount,
+            pSubsetBytes,
+            codecOptions,
+        )
+    }
+}
+
+fn zarrsArrayRetrieveSubsetIntoRegionImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    array_subset: &ArraySubset,
+    dst_shape: &[u64],
+    dst_region: ArraySubset,
+    dst_bytes: &mut [u8],
+) -> ZarrsResult {
+    let mut region_view =
+        match output_region_view(array.data_type(), dst_shape, dst_region, dst_bytes, "dst") {
+            Ok(region_view) => region_view,
+            Err(result) => return result,
+        };
+    // Each chunk is decoded directly into its position in the destination buffer
+    match array.retrieve_array_subset_into(array_subset, &mut region_view, &CodecOptions::default())
+    {
+        Ok(()) => ZarrsResult::ZARRS_SUCCESS,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        }
+    }
+}
+
+/// Retrieve a subset from an array into a region of a larger destination buffer.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// `pDstBytes` is a pointer to an array of bytes of length `dstBytesCount` holding elements with shape `pDstShape` in C order.
+/// The subset is decoded directly into the region of the destination starting at `pDstOffset` with shape `pSubsetShape`, without an intermediate buffer.
+/// Elements of the destination outside of the region are not modified.
+///
+/// This can be used to assemble a mosaic from several subset reads into a single preallocated buffer.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape, or the destination region extends past `pDstShape`.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `dstBytesCount` does not match the size of the destination.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart`, `pSubsetShape`, `pDstShape`, and `pDstOffset`.
+/// `pDstBytes` must point to an array of length `dstBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetIntoRegion(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pDstShape: *const u64,
+    pDstOffset: *const u64,
+    dstBytesCount: usize,
+    pDstBytes: *mut u8,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pSubsetStart, pSubsetShape, pDstShape, and pDstOffset point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let dst_shape = unsafe { std::slice::from_raw_parts(pDstShape, dimensionality) };
+    let dst_offset = unsafe { std::slice::from_raw_parts(pDstOffset, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    for (dim, ((&offset, &shape), &size)) in
+        std::iter::zip(std::iter::zip(dst_offset, subset_shape), dst_shape).enumerate()
+    {
+        if offset.checked_add(shape).is_none_or(|end| end > size) {
+            *LAST_ERROR.lock().unwrap() = format!(
+                "the destination region with offset {dst_offset:?} and shape {subset_shape:?} in dimension {dim} is out of bounds of the destination shape {dst_shape:?}"
+            );
+            return ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS;
+        }
+    }
+    if dst_shape
+        .iter()
+        .try_fold(1u64, |num_elements, &size| num_elements.checked_mul(size))
+        .is_none()
+    {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the number of elements in the destination shape {dst_shape:?} overflows");
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+    let dst_region = ArraySubset::from(
+        std::iter::zip(dst_offset, subset_shape).map(|(&offset, &shape)| offset..offset + shape),
+    );
+    // SAFETY: pDstBytes points to an array of length dstBytesCount per the function's safety contract.
+    let dst_bytes = unsafe { std::slice::from_raw_parts_mut(pDstBytes, dstBytesCount) };
+
+    let result = match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayRetrieveSubsetIntoRegionImpl(
+            array,
+            &array_subset,
+            dst_shape,
+            dst_region,
+            dst_bytes,
+        ),
+        ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveSubsetIntoRegionImpl(
+            array,
+            &array_subset,
+            dst_shape,
+            dst_region,
+            dst_bytes,
+        ),
+        ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveSubsetIntoRegionImpl(
+            array,
+            &array_subset,
+            dst_shape,
+            dst_region,
+            dst_bytes,
+        ),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveSubsetIntoRegionImpl(
+            array,
+            &array_subset,
+            dst_shape,
+            dst_region,
+            dst_bytes,
+        ),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(num_chunks_in_subset(array, &array_subset));
+    }
+    result
+}
+
+fn zarrsArrayRetrieveSubsetAsImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    array_subset: &ArraySubset,
+    converter: ElementConverter,
+    bytes: &mut [u8],
+) -> ZarrsResult {
+    match array.retrieve_array_subset::<ArrayBytes>(array_subset) {
+        Ok(subset_bytes) => {
+            let Ok(subset_bytes) = subset_bytes.into_fixed() else {
+                *LAST_ERROR.lock().unwrap() =
+                    "variable size data types are not supported".to_string();
+                return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+            };
+            converter(&subset_bytes, bytes);
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        }
+    }
+}
+
+/// A callback that receives a decoded part of a subset retrieved by `zarrsArrayRetrieveSubsetCallback`.
+///
+/// - `dimensionality` is the dimensionality of the array.
+/// - `pPartStart` and `pPartShape` point to arrays of length `dimensionality` holding the start (in array coordinates) and shape of the part, which is the intersection of a chunk with the subset.
+/// - `pBytes` points to the decoded bytes of the part in C order, with length `bytesLen`.
+/// - `userData` is the user data passed to `zarrsArrayRetrieveSubsetCallback`.
+///
+/// The pointers are only valid during the callback.
+/// Return zero to continue, or non-zero to abort the retrieval.
+pub type ZarrsSubsetChunkCallback = Option<
+    unsafe extern "C" fn(
+        dimensionality: usize,
+        pPartStart: *const u64,
+        pPartShape: *const u64,
+        pBytes: *const u8,
+        bytesLen: usize,
+        userData: *mut c_void,
+    ) -> i32,
+>;
+
+/// A non-null [`ZarrsSubsetChunkCallback`].
+type SubsetChunkCallbackFn =
+    unsafe extern "C" fn(usize, *const u64, *const u64, *const u8, usize, *mut c_void) -> i32;
+
+/// The user data passed to a [`ZarrsSubsetChunkCallback`].
+#[derive(Clone, Copy)]
+struct CallbackUserData(*mut c_void);
+
+// SAFETY: the caller of zarrsArrayRetrieveSubsetCallback guarantees that the user data can be used from any thread if callbacks are concurrent.
+unsafe impl Send for CallbackUserData {}
+// SAFETY: as above.
+unsafe impl Sync for CallbackUserData {}
+
+/// Retrieve the intersection of the chunk at `chunk_indices` with `array_subset` and pass it to `callback`.
+fn zarrsArrayRetrieveSubsetCallbackPart<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    array_subset: &ArraySubset,
+    chunk_indices: &[u64],
+    callback: SubsetChunkCallbackFn,
+    user_data: CallbackUserData,
+) -> ZarrsResult {
+    let part = array
+        .chunk_subset(chunk_indices)
+        .map_err(|err| err.to_string())
+        .and_then(|chunk_subset| {
+            chunk_subset
+                .overlap(array_subset)
+                .map_err(|err| err.to_string())
+        });
+    let part = match part {
+        Ok(part) => part,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err;
+            return ZarrsResult::ZARRS_ERROR_ARRAY;
+        }
+    };
+    let part_bytes = match array.retrieve_array_subset::<ArrayBytes>(&part) {
+        Ok(part_bytes) => part_bytes,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_ARRAY;
+        }
+    };
+    let Ok(part_bytes) = part_bytes.into_fixed() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    // SAFETY: the callback is valid per the safety contract of zarrsArrayRetrieveSubsetCallback, and the pointers are valid for the duration of the call.
+    let abort = unsafe {
+        callback(
+            part.dimensionality(),
+            part.start().as_ptr(),
+            part.shape().as_ptr(),
+            part_bytes.as_ptr(),
+            part_bytes.len(),
+            user_data.0,
+        )
+    };
+    if abort == 0 {
+        ZarrsResult::ZARRS_SUCCESS
+    } else {
+        *LAST_ERROR.lock().unwrap() =
+            "the subset retrieval was aborted by the callback".to_string();
+        ZarrsResult::ZARRS_ERROR_ABORTED
+    }
+}
+
+fn zarrsArrayRetrieveSubsetCallbackImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    array_subset: &ArraySubset,
+    callback: SubsetChunkCallbackFn,
+    user_data: CallbackUserData,
+    concurrent: bool,
+) -> ZarrsResult {
+    let chunks = match array.chunks_in_array_subset(array_subset) {
+        Ok(Some(chunks)) => chunks,
+        Ok(None) => {
+            *LAST_ERROR.lock().unwrap() =
+                "the chunks intersecting the subset cannot be determined".to_string();
+            return ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS;
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+        }
+    };
+
+    // Chunks are retrieved one at a time per thread, so only one chunk per thread is held in memory
+    let part = |chunk_indices: Vec<u64>| match zarrsArrayRetrieveSubsetCallbackPart(
+        array,
+        array_subset,
+        &chunk_indices,
+        callback,
+        user_data,
+    ) {
+        ZarrsResult::ZARRS_SUCCESS => Ok(()),
+        result => Err(result),
+    };
+    let chunk_indices = chunks.indices();
+    let result = if concurrent {
+        // The rayon thread pool is shared with the codecs, so decoding within a chunk does not oversubscribe threads
+        (&chunk_indices).into_par_iter().try_for_each(part)
+    } else {
+        chunk_indices.into_iter().try_for_each(part)
+    };
+    result.err().unwrap_or(ZarrsResult::ZARRS_SUCCESS)
+}
+
+/// Retrieve a subset from an array, passing it to a callback one chunk at a time.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// For each chunk intersecting the subset, the intersection is decoded and passed to `callback` along with `userData` (see `ZarrsSubsetChunkCallback`).
+/// This keeps peak memory usage to about one chunk (per thread) regardless of the size of the subset.
+///
+/// If `concurrent` is false, `callback` is invoked serially on the calling thread with the chunks in C order.
+/// If `concurrent` is true, chunks are decoded in parallel and `callback` may be invoked concurrently from multiple threads in any order, so it and `userData` must be thread safe.
+/// Concurrent callbacks run on the threads of the global rayon thread pool used by the zarrs codecs (which may include the calling thread), not on threads created by the caller.
+/// They must not rely on thread-local state of the calling thread, and must not block waiting for another callback, as it may be queued on the same thread.
+///
+/// If `callback` returns non-zero, no further callbacks are started and `ZarrsResult::ZARRS_ERROR_ABORTED` is returned.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `callback` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if a chunk cannot be retrieved.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ABORTED` if `callback` returned non-zero.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pSubsetStart` and `pSubsetShape` must point to arrays of length `dimensionality`.
+/// If `concurrent` is true, `callback` must be safe to call concurrently from multiple threads with `userData`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetCallback(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    callback: ZarrsSubsetChunkCallback,
+    userData: *mut c_void,
+    concurrent: bool,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    let Some(callback) = callback else {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    };
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    if array_fn!(array, data_type).fixed_size().is_none() {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    }
+    let user_data = CallbackUserData(userData);
+
+    // Retrieve the subset chunk by chunk
+    let result = match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayRetrieveSubsetCallbackImpl(
+            array,
+            &array_subset,
+            callback,
+            user_data,
+            concurrent,
+        ),
+        ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveSubsetCallbackImpl(
+            array,
+            &array_subset,
+            callback,
+            user_data,
+            concurrent,
+        ),
+        ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveSubsetCallbackImpl(
+            array,
+            &array_subset,
+            callback,
+            user_data,
+            concurrent,
+        ),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveSubsetCallbackImpl(
+            array,
+            &array_subset,
+            callback,
+            user_data,
+            concurrent,
+        ),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(num_chunks_in_subset(array, &array_subset));
+    }
+    result
+}
+
+/// Retrieve a subset from an array in C or Fortran order.
+///
+/// This is equivalent to `zarrsArrayRetrieveSubset`, except that `order` sets the memory layout of `pSubsetBytes`.
+/// With `ZarrsOrder::ZARRS_ORDER_F`, the subset is written in Fortran (column-major) order, where the first dimension is contiguous.
+/// The subset size is independent of the order.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `subsetBytesCount` does not match the size of the subset.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// `order` must be a valid `ZarrsOrder`.
+/// `pSubsetBytes` must point to an array of length `subsetBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetOrd(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    order: ZarrsOrder,
+    subsetBytesCount: usize,
+    pSubsetBytes: *mut u8,
+) -> ZarrsResult {
+    if order == ZarrsOrder::ZARRS_ORDER_C {
+        // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveSubset.
+        return unsafe {
+            zarrsArrayRetrieveSubset(
+                array,
+                dimensionality,
+                pSubsetStart,
+                pSubsetShape,
+                subsetBytesCount,
+                pSubsetBytes,
+            )
+        };
+    }
+
+    // Retrieve the subset in C order and transpose it into the output buffer
+    let mut subset_bytes_c = vec![0u8; subsetBytesCount];
+    // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveSubset, and subset_bytes_c has length subsetBytesCount.
+    let result = unsafe {
+        zarrsArrayRetrieveSubset(
+            array,
+            dimensionality,
+            pSubsetStart,
+            pSubsetShape,
+            subsetBytesCount,
+            subset_bytes_c.as_mut_ptr(),
+        )
+    };
+    if !matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        return result;
+    }
+    // SAFETY: array is a valid ZarrsArray handle, as the subset was retrieved.
+    let array = unsafe { &**array };
+    let element_size = array_fn!(array, data_type).fixed_size().unwrap_or_default();
+    // SAFETY: pSubsetShape points to an array of length dimensionality per the function's safety contract.
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let subset_shape: Vec<usize> = subset_shape.iter().map(|&shape| shape as usize).collect();
+    // SAFETY: pSubsetBytes points to an array of length subsetBytesCount per the function's safety contract.
+    let subset_bytes = unsafe { std::slice::from_raw_parts_mut(pSubsetBytes, subsetBytesCount) };
+    transpose_order(
+        &subset_bytes_c,
+        subset_bytes,
+        &subset_shape,
+        element_size,
+        true,
+    );
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Retrieve a subset from an array, converting the elements to another data type.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// `pBytes` is a pointer to an array of bytes of length `bytesCount` that must match the number of elements in the subset multiplied by the size of `outputType`.
+/// Elements are written in the native endianness.
+///
+/// Only lossless widening numeric conversions are supported, for example:
+/// - integers to wider integers of the same signedness, or unsigned integers to wider signed integers,
+/// - integers to floating point data types that represent every value exactly (e.g. `uint16` to `float32`, `int32` to `float64`),
+/// - `float16` and `bfloat16` to `float32` or `float64`, and `float32` to `float64`, and
+/// - `complex64` to `complex128`.
+///
+/// Converting to the data type of the array is equivalent to `zarrsArrayRetrieveSubset`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the elements cannot be losslessly converted to `outputType`.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the size of the converted subset.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `outputType` must be a valid `ZarrsDataType`.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// `pBytes` must point to an array of length `bytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetAs(
+    array: ZarrsArray,
+    outputType: ZarrsDataType,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    bytesCount: usize,
+    pBytes: *mut u8,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+
+    let data_type = array_fn!(array, data_type);
+    let converter = element_converter(&zarrs_data_type(data_type), &outputType);
+    let output_size = match outputType {
+        ZarrsDataType::ZARRS_RAW_BITS => data_type.fixed_size(),
+        _ => outputType.fixed_size(),
+    };
+    let (Some(converter), Some(output_size)) = (converter, output_size) else {
+        *LAST_ERROR.lock().unwrap() =
+            "the array elements cannot be losslessly converted to the output data type".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let expected_length = array_subset.num_elements_usize() * output_size;
+    if bytesCount != expected_length {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "bytes_length {bytesCount} does not match converted subset size {expected_length}"
+        );
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+    // SAFETY: pBytes points to an array of length bytesCount per the function's safety contract.
+    let bytes = unsafe { std::slice::from_raw_parts_mut(pBytes, bytesCount) };
+
+    // Get and convert the subset bytes
+    let result = match array {
+        ZarrsArrayEnum::R(array) => {
+            zarrsArrayRetrieveSubsetAsImpl(array, &array_subset, converter, bytes)
+        }
+        ZarrsArrayEnum::RL(array) => {
+            zarrsArrayRetrieveSubsetAsImpl(array, &array_subset, converter, bytes)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayRetrieveSubsetAsImpl(array, &array_subset, converter, bytes)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayRetrieveSubsetAsImpl(array, &array_subset, converter, bytes)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(num_chunks_in_subset(array, &array_subset));
+    }
+    result
+}
+
+fn zarrsArrayRetrieveSubsetScaledImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    array_subset: &ArraySubset,
+    scale: f64,
+    offset: f64,
+    output_size: usize,
+    bytes: &mut [u8],
+) -> ZarrsResult {
+    let subset_bytes = match array.retrieve_array_subset::<ArrayBytes>(array_subset) {
+        Ok(subset_bytes) => subset_bytes,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_ARRAY;
+        }
+    };
+    let Ok(subset_bytes) = subset_bytes.into_fixed() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let data_type = array.data_type();
+    let fill_value = array.fill_value().as_ne_bytes();
+    for (element, output) in std::iter::zip(
+        subset_bytes.chunks_exact(fill_value.len()),
+        bytes.chunks_exact_mut(output_size),
+    ) {
+        let value = if element == fill_value {
+            f64::NAN
+        } else {
+            element_to_f64(data_type, element).unwrap_or(f64::NAN) * scale + offset
+        };
+        if output_size == size_of::<f32>() {
+            output.copy_from_slice(&(value as f32).to_ne_bytes());
+        } else {
+            output.copy_from_slice(&value.to_ne_bytes());
+        }
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Retrieve a subset from an array, applying a linear scale and offset to the elements.
+///
+/// Each element is converted to `float64` and transformed to `value * scale + offset`, following the `scale_factor` and `add_offset` attributes of the CF conventions.
+/// Elements equal to the array fill value are written as NaN.
+/// `outputType` must be `ZARRS_FLOAT32` or `ZARRS_FLOAT64`, and elements are written in the native endianness.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// `pBytes` is a pointer to an array of bytes of length `bytesCount` that must match the number of elements in the subset multiplied by the size of `outputType`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if `outputType` is not a floating point data type or the array data type is not a real numeric data type.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the size of the converted subset.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `outputType` must be a valid `ZarrsDataType`.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// `pBytes` must point to an array of length `bytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetScaled(
+    array: ZarrsArray,
+    scale: f64,
+    offset: f64,
+    outputType: ZarrsDataType,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    bytesCount: usize,
+    pBytes: *mut u8,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    let output_size = match outputType {
+        ZarrsDataType::ZARRS_FLOAT32 => size_of::<f32>(),
+        ZarrsDataType::ZARRS_FLOAT64 => size_of::<f64>(),
+        _ => {
+            *LAST_ERROR.lock().unwrap() =
+                "scaled elements can only be retrieved as float32 or float64".to_string();
+            return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+        }
+    };
+    let data_type = array_fn!(array, data_type);
+    let fill_value = array_fn!(array, fill_value).as_ne_bytes();
+    if element_to_f64(data_type, fill_value).is_none() {
+        *LAST_ERROR.lock().unwrap() =
+            "scaling is only supported for real numeric data types".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    }
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    let expected_length = array_subset.num_elements_usize() * output_size;
+    if bytesCount != expected_length {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "bytes_length {bytesCount} does not match scaled subset size {expected_length}"
+        );
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+    // SAFETY: pBytes points to an array of length bytesCount per the function's safety contract.
+    let bytes = unsafe { std::slice::from_raw_parts_mut(pBytes, bytesCount) };
+
+    // Get and scale the subset bytes
+    let result = match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayRetrieveSubsetScaledImpl(
+            array,
+            &array_subset,
+            scale,
+            offset,
+            output_size,
+            bytes,
+        ),
+        ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveSubsetScaledImpl(
+            array,
+            &array_subset,
+            scale,
+            offset,
+            output_size,
+            bytes,
+        ),
+        ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveSubsetScaledImpl(
+            array,
+            &array_subset,
+            scale,
+            offset,
+            output_size,
+            bytes,
+        ),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveSubsetScaledImpl(
+            array,
+            &array_subset,
+            scale,
+            offset,
+            output_size,
+            bytes,
+        ),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(num_chunks_in_subset(array, &array_subset));
+    }
+    result
+}
+
+/// Retrieve a subset from an array, applying the `scale_factor` and `add_offset` attributes to the elements.
+///
+/// This is equivalent to `zarrsArrayRetrieveSubsetScaled`, with the scale and offset read from the `scale_factor` and `add_offset` array attributes.
+/// If an attribute is absent, the scale defaults to 1 and the offset defaults to 0.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the `scale_factor` or `add_offset` attribute is not a number.
+/// - Returns the errors of `zarrsArrayRetrieveSubsetScaled`.
+///
+/// # Safety
+/// The safety contract of `zarrsArrayRetrieveSubsetScaled` applies.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetScaledFromAttributes(
+    array: ZarrsArray,
+    outputType: ZarrsDataType,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    bytesCount: usize,
+    pBytes: *mut u8,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array_handle = unsafe { &**array };
+    let attributes = array_fn!(array_handle, attributes);
+    let mut scale_and_offset = [1.0, 0.0];
+    for (name, value) in std::iter::zip(["scale_factor", "add_offset"], &mut scale_and_offset) {
+        if let Some(attribute) = attributes.get(name) {
+            let Some(attribute) = attribute.as_f64() else {
+                *LAST_ERROR.lock().unwrap() =
+                    format!("the {name} attribute {attribute} is not a number");
+                return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+            };
+            *value = attribute;
+        }
+    }
+    let [scale, offset] = scale_and_offset;
+    // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveSubsetScaled.
+    unsafe {
+        zarrsArrayRetrieveSubsetScaled(
+            array,
+            scale,
+            offset,
+            outputType,
+            dimensionality,
+            pSubsetStart,
+            pSubsetShape,
+            bytesCount,
+            pBytes,
+        )
+    }
+}
+
+/// The bytes and offsets of the elements of a variable size subset.
+type VlenSubset = (Vec<u8>, Vec<u64>);
+
+fn zarrsArrayRetrieveSubsetVlenImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    array_subset: &ArraySubset,
+) -> Result<VlenSubset, ZarrsResult> {
+    let subset_bytes = array
+        .retrieve_array_subset::<ArrayBytes>(array_subset)
+        .map_err(|err| {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        })?;
+    let Ok((bytes, offsets)) = subset_bytes.into_variable() else {
+        *LAST_ERROR.lock().unwrap() = "fixed size data types are not supported".to_string();
+        return Err(ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+    };
+    let offsets = offsets.iter().map(|&offset| offset as u64).collect();
+    Ok((bytes.into_owned(), offsets))
+}
+
+/// Retrieve a subset from an array with a variable size data type (e.g. `string`).
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// The elements are returned in C order as a buffer of concatenated bytes in `pBytes` with length `pBytesLen`.
+/// For the `string` data type, each element is UTF-8 encoded without a null terminator.
+/// The offsets of the elements are returned in `pOffsets` with length `pOffsetsLen`, which is the number of elements in the subset plus one.
+/// Element `i` spans the bytes from `pOffsets[i]` (inclusive) to `pOffsets[i + 1]` (exclusive).
+///
+/// The bytes must be freed with `zarrsFreeBytes` and the offsets must be freed with `zarrsFreeOffsets`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a fixed size data type.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the subset cannot be retrieved.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pSubsetStart` and `pSubsetShape` must point to arrays of length `dimensionality`.
+/// `pBytes` and `pOffsets` must be valid pointers to a `uint8_t*` and `uint64_t*` respectively.
+/// `pBytesLen` and `pOffsetsLen` must be valid pointers to a `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetVlen(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pBytes: *mut *mut u8,
+    pBytesLen: *mut usize,
+    pOffsets: *mut *mut u64,
+    pOffsetsLen: *mut usize,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+
+    // Get the subset bytes and offsets
+    let subset = match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayRetrieveSubsetVlenImpl(array, &array_subset),
+        ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveSubsetVlenImpl(array, &array_subset),
+        ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveSubsetVlenImpl(array, &array_subset),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveSubsetVlenImpl(array, &array_subset),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            Err(ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY)
+        }
+    };
+    match subset {
+        Ok((bytes, offsets)) => {
+            stats.record_decoded(num_chunks_in_subset(array, &array_subset));
+            // SAFETY: the output pointers are valid per the function's safety contract.
+            unsafe {
+                *pBytesLen = bytes.len();
+                *pOffsetsLen = offsets.len();
+                *pBytes = buffer_into_raw(bytes);
+                *pOffsets = buffer_into_raw(offsets);
+            }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Retrieve a single element from an array.
+///
+/// `pIndices` is a pointer to an array of length `dimensionality` holding the element indices.
+/// `pElementBytes` is a pointer to an array of bytes of length `elementBytesCount` that must match the size of the data type.
+///
+/// The chunk containing the element is retrieved and decoded, so this is no more efficient than chunk or subset retrieval.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the element indices are out of bounds.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `elementBytesCount` does not match the size of the data type.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pIndices` must point to an array of length `dimensionality`.
+/// `pElementBytes` must point to an array of length `elementBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSingleElement(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pIndices: *const u64,
+    elementBytesCount: usize,
+    pElementBytes: *mut u8,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pIndices points to an array of length dimensionality per the function's safety contract.
+    let element_indices = unsafe { std::slice::from_raw_parts(pIndices, dimensionality) };
+    let array_subset = match element_subset(array, element_indices) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    // SAFETY: pElementBytes points to an array of length elementBytesCount per the function's safety contract.
+    let element_bytes = unsafe { std::slice::from_raw_parts_mut(pElementBytes, elementBytesCount) };
+
+    // Get the element bytes
+    let result = match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayRetrieveSubsetImpl(
+            array,
+            &array_subset,
+            element_bytes,
+            &CodecOptions::default(),
+        ),
+        ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveSubsetImpl(
+            array,
+            &array_subset,
+            element_bytes,
+            &CodecOptions::default(),
+        ),
+        ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveSubsetImpl(
+            array,
+            &array_subset,
+            element_bytes,
+            &CodecOptions::default(),
+        ),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveSubsetImpl(
+            array,
+            &array_subset,
+            element_bytes,
+            &CodecOptions::default(),
+        ),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(1);
+    }
+    result
+}
+
+/// Retrieve a single element from an array as a `double`.
+///
+/// `pIndices` is a pointer to an array of length `dimensionality` holding the element indices.
+/// Boolean, integer, and floating point (including `float16` and `bfloat16`) elements are converted to a `double`.
+/// 64-bit integers with a magnitude above 2^53 lose precision.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type cannot be converted to a `double`.
+/// - Returns any error of `zarrsArrayGetSingleElement()`.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pIndices` must point to an array of length `dimensionality`.
+/// `pValue` must be a valid pointer to a `double`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSingleElementFloat64(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pIndices: *const u64,
+    pValue: *mut f64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let data_type = array_fn!(unsafe { &**array }, data_type);
+    let Some(data_type_size) = data_type.fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let mut element_bytes = vec![0u8; data_type_size];
+    // SAFETY: the caller upholds the safety contract of zarrsArrayGetSingleElement, and element_bytes has length data_type_size.
+    let result = unsafe {
+        zarrsArrayGetSingleElement(
+            array,
+            dimensionality,
+            pIndices,
+            element_bytes.len(),
+            element_bytes.as_mut_ptr(),
+        )
+    };
+    if !matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        return result;
+    }
+    let Some(value) = element_to_f64(data_type, &element_bytes) else {
+        *LAST_ERROR.lock().unwrap() = "the array data type cannot be converted to f64".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    // SAFETY: pValue is a valid pointer per the function's safety contract.
+    unsafe { *pValue = value };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+fn zarrsArrayRetrieveSubsetStridedImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    array_subset: &ArraySubset,
+    output_strides: &[i64],
+    element_size: usize,
+    output_bytes: *mut u8,
+) -> ZarrsResult {
+    match array.retrieve_array_subset::<ArrayBytes>(array_subset) {
+        Ok(bytes) => {
+            let Ok(bytes) = bytes.into_fixed() else {
+                *LAST_ERROR.lock().unwrap() =
+                    "variable size data types are not supported".to_string();
+                return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+            };
+            let mut elements = bytes.chunks_exact(element_size);
+            for_each_strided_offset(array_subset.shape(), output_strides, |offset| {
+                let element = elements.next().unwrap();
+                // SAFETY: the caller guarantees that every strided element of output_bytes is valid for writes.
+                unsafe {
+                    std::ptr::copy(element.as_ptr(), output_bytes.offset(offset), element_size);
+                }
+            });
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        }
+    }
+}
+
+/// Retrieve a subset from an array into a strided buffer.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// `pDstStridesBytes` is a pointer to an array of length `dimensionality` holding the byte stride of each dimension of the destination buffer.
+/// Strides may be negative, in which case the corresponding axis is reversed in the destination buffer.
+/// `pDstBase` is a pointer to the destination buffer of length `dstBufferLen`, which starts at the element with the lowest address.
+/// For example, the first element of the subset (all indices zero) is at `pDstBase` if all strides are positive, and the last row of a 2D buffer with a negative row stride holds the first row of the subset.
+/// Elements are written in the native endianness with the size of the data type.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if any element addressed by the strides is outside the destination buffer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pSubsetStart`, `pSubsetShape`, and `pDstStridesBytes` must point to arrays of length `dimensionality`.
+/// `pDstBase` must point to an array of length `dstBufferLen`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetStrided(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pDstStridesBytes: *const i64,
+    pDstBase: *mut u8,
+    dstBufferLen: usize,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pSubsetStart, pSubsetShape, and pDstStridesBytes point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let output_strides = unsafe { std::slice::from_raw_parts(pDstStridesBytes, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    let Some(data_type_size) = array_fn!(array, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let first_offset =
+        match strided_first_offset(subset_shape, output_strides, data_type_size, dstBufferLen) {
+            Ok(Some(first_offset)) => first_offset,
+            Ok(None) => return ZarrsResult::ZARRS_SUCCESS, // the subset is empty
+            Err(result) => return result,
+        };
+    // SAFETY: first_offset is within the destination buffer of length dstBufferLen.
+    let pOutputBytes = unsafe { pDstBase.add(first_offset) };
+
+    // Get the subset bytes
+    let result = match array {
+        ZarrsArrayEnum::R(array) => zarrsArrayRetrieveSubsetStridedImpl(
+            array,
+            &array_subset,
+            output_strides,
+            data_type_size,
+            pOutputBytes,
+        ),
+        ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveSubsetStridedImpl(
+            array,
+            &array_subset,
+            output_strides,
+            data_type_size,
+            pOutputBytes,
+        ),
+        ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveSubsetStridedImpl(
+            array,
+            &array_subset,
+            output_strides,
+            data_type_size,
+            pOutputBytes,
+        ),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveSubsetStridedImpl(
+            array,
+            &array_subset,
+            output_strides,
+            data_type_size,
+            pOutputBytes,
+        ),
         _ => {
             *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
             ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
         }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(num_chunks_in_subset(array, &array_subset));
     }
+    result
 }
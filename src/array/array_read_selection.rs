@@ -0,0 +1,338 @@
+use std::collections::BTreeMap;
+
+use zarrs::{
+    array::{Array, ArrayBytes, ArraySubset},
+    storage::ReadableStorageTraits,
+};
+
+use crate::{LAST_ERROR, ZarrsResult};
+
+use super::{
+    ZarrsArray, ZarrsArray_T, ZarrsArrayEnum, array_fn, array_read::zarrsArrayRetrieveSubset,
+};
+
+/// The selected indices along one dimension grouped by chunk.
+///
+/// Each group holds the chunk index along the dimension and the output position and array index of each selected index in that chunk.
+type DimensionSelection = Vec<(u64, Vec<(usize, u64)>)>;
+
+/// Call `f` with each multi-index of an array with `shape`, in C order.
+///
+/// Stops at the first error returned by `f`.
+fn try_for_each_index(
+    shape: &[usize],
+    mut f: impl FnMut(&[usize]) -> Result<(), ZarrsResult>,
+) -> Result<(), ZarrsResult> {
+    if shape.contains(&0) {
+        return Ok(());
+    }
+    let mut indices = vec![0; shape.len()];
+    loop {
+        f(&indices)?;
+        let mut dim = shape.len();
+        loop {
+            if dim == 0 {
+                return Ok(());
+            }
+            dim -= 1;
+            indices[dim] += 1;
+            if indices[dim] < shape[dim] {
+                break;
+            }
+            indices[dim] = 0;
+        }
+    }
+}
+
+/// Group the selected `indices` along dimension `dim` by the chunk that contains them.
+///
+/// Sets the last error on failure.
+fn group_indices_by_chunk<T: ?Sized>(
+    array: &Array<T>,
+    dim: usize,
+    indices: &[u64],
+) -> Result<DimensionSelection, ZarrsResult> {
+    let shape = array.shape();
+    let mut element_indices = vec![0; shape.len()];
+    let mut groups: BTreeMap<u64, Vec<(usize, u64)>> = BTreeMap::new();
+    for (position, &index) in indices.iter().enumerate() {
+        element_indices[dim] = index;
+        let chunk_indices = if index < shape[dim] {
+            array
+                .chunk_grid()
+                .chunk_indices(&element_indices)
+                .ok()
+                .flatten()
+        } else {
+            None
+        };
+        let Some(chunk_indices) = chunk_indices else {
+            *LAST_ERROR.lock().unwrap() = format!(
+                "index {index} in dimension {dim} is out of bounds of the array shape {shape:?}"
+            );
+            return Err(ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+        };
+        groups
+            .entry(chunk_indices[dim])
+            .or_default()
+            .push((position, index));
+    }
+    Ok(groups.into_iter().collect())
+}
+
+/// Retrieve the outer product of the selected `index_lists` into `bytes` in C order.
+///
+/// Only the chunks containing selected elements are retrieved, and each is retrieved once.
+/// Returns the number of chunks retrieved.
+/// Sets the last error on failure.
+fn retrieve_orthogonal<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    index_lists: &[Vec<u64>],
+    bytes: &mut [u8],
+) -> Result<u64, ZarrsResult> {
+    let Some(element_size) = array.data_type().fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return Err(ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+    };
+    let output_shape: Vec<usize> = index_lists.iter().map(Vec::len).collect();
+    let expected_length = output_shape.iter().product::<usize>() * element_size;
+    if bytes.len() != expected_length {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "bytes_length {} does not match expected length {expected_length}",
+            bytes.len()
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+    }
+    let selections = index_lists
+        .iter()
+        .enumerate()
+        .map(|(dim, indices)| group_indices_by_chunk(array, dim, indices))
+        .collect::<Result<Vec<_>, _>>()?;
+    let output_strides = c_order_strides(&output_shape);
+
+    let num_groups: Vec<usize> = selections.iter().map(Vec::len).collect();
+    let mut num_chunks = 0;
+    try_for_each_index(&num_groups, |group_indices| {
+        let groups: Vec<&[(usize, u64)]> = std::iter::zip(&selections, group_indices)
+            .map(|(selection, &group)| selection[group].1.as_slice())
+            .collect();
+
+        // Retrieve the bounding box of the selected elements in the chunk
+        let part = ArraySubset::from(groups.iter().map(|group| {
+            let min = group.iter().map(|&(_, index)| index).min().unwrap_or(0);
+            let max = group.iter().map(|&(_, index)| index).max().unwrap_or(0);
+            min..max + 1
+        }));
+        let part_bytes = array
+            .retrieve_array_subset::<ArrayBytes>(&part)
+            .map_err(|err| {
+                *LAST_ERROR.lock().unwrap() = err.to_string();
+                ZarrsResult::ZARRS_ERROR_ARRAY
+            })?
+            .into_fixed()
+            .map_err(|_| {
+                *LAST_ERROR.lock().unwrap() =
+                    "variable size data types are not supported".to_string();
+                ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE
+            })?;
+        let part_shape: Vec<usize> = part.shape().iter().map(|&size| size as usize).collect();
+        let part_strides = c_order_strides(&part_shape);
+
+        // Scatter the selected elements into the output
+        let group_lengths: Vec<usize> = groups.iter().map(|group| group.len()).collect();
+        try_for_each_index(&group_lengths, |element| {
+            let mut part_offset = 0;
+            let mut output_offset = 0;
+            for (dim, &i) in element.iter().enumerate() {
+                let (position, index) = groups[dim][i];
+                part_offset += (index - part.start()[dim]) as usize * part_strides[dim];
+                output_offset += position * output_strides[dim];
+            }
+            bytes[output_offset * element_size..(output_offset + 1) * element_size]
+                .copy_from_slice(
+                    &part_bytes[part_offset * element_size..(part_offset + 1) * element_size],
+                );
+            Ok(())
+        })?;
+        num_chunks += 1;
+        Ok(())
+    })?;
+    Ok(num_chunks)
+}
+
+/// The C order strides (in elements) of an array with `shape`.
+fn c_order_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for dim in (0..shape.len().saturating_sub(1)).rev() {
+        strides[dim] = strides[dim + 1] * shape[dim + 1];
+    }
+    strides
+}
+
+/// Retrieve the outer product of the selected `index_lists` from an array handle.
+///
+/// Sets the last error on failure.
+fn retrieve_orthogonal_handle(
+    array: &ZarrsArray_T,
+    index_lists: &[Vec<u64>],
+    bytes: &mut [u8],
+) -> ZarrsResult {
+    let ZarrsArray_T(array, stats) = array;
+    let num_chunks = match array {
+        ZarrsArrayEnum::R(array) => retrieve_orthogonal(array, index_lists, bytes),
+        ZarrsArrayEnum::RL(array) => retrieve_orthogonal(array, index_lists, bytes),
+        ZarrsArrayEnum::RW(array) => retrieve_orthogonal(array, index_lists, bytes),
+        ZarrsArrayEnum::RWL(array) => retrieve_orthogonal(array, index_lists, bytes),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            Err(ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY)
+        }
+    };
+    match num_chunks {
+        Ok(num_chunks) => {
+            stats.record_decoded(num_chunks);
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Retrieve a subset from an array with a step along each dimension.
+///
+/// `pStart`, `pShapeOut`, and `pStep` are pointers to arrays of length `dimensionality`.
+/// Along each dimension, `pShapeOut[i]` elements are selected starting at `pStart[i]` and separated by `pStep[i]`, e.g. a step of 4 selects every 4th element.
+/// `pBytes` is a pointer to an array of bytes of length `bytesCount` that must match the size of the selected elements (the product of `pShapeOut` multiplied by the data type size).
+///
+/// Only chunks that contain selected elements are retrieved, so steps larger than the chunk shape skip whole chunks.
+/// If every step is 1, this is equivalent to `zarrsArrayRetrieveSubset`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if a step is zero or a selected element is out of bounds.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the size of the selected elements.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pStart`, `pShapeOut`, and `pStep`.
+/// `pBytes` must point to an array of length `bytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetStep(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pStart: *const u64,
+    pShapeOut: *const u64,
+    pStep: *const u64,
+    bytesCount: usize,
+    pBytes: *mut u8,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array_handle = unsafe { &*array };
+    let array_dimensionality = array_fn!(&array_handle.0, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pStart, pShapeOut, and pStep point to arrays of length dimensionality per the function's safety contract.
+    let start = unsafe { std::slice::from_raw_parts(pStart, dimensionality) };
+    let shape_out = unsafe { std::slice::from_raw_parts(pShapeOut, dimensionality) };
+    let step = unsafe { std::slice::from_raw_parts(pStep, dimensionality) };
+
+    if let Some(dim) = step.iter().position(|&step| step == 0) {
+        *LAST_ERROR.lock().unwrap() = format!("the step in dimension {dim} must be non-zero");
+        return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+    }
+    if step.iter().all(|&step| step == 1) {
+        // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveSubset.
+        return unsafe {
+            zarrsArrayRetrieveSubset(array, dimensionality, pStart, pShapeOut, bytesCount, pBytes)
+        };
+    }
+
+    let mut index_lists = Vec::with_capacity(dimensionality);
+    for (dim, ((&start, &shape), &step)) in
+        std::iter::zip(std::iter::zip(start, shape_out), step).enumerate()
+    {
+        let indices: Option<Vec<u64>> = (0..shape)
+            .map(|i| {
+                i.checked_mul(step)
+                    .and_then(|offset| start.checked_add(offset))
+            })
+            .collect();
+        let Some(indices) = indices else {
+            *LAST_ERROR.lock().unwrap() =
+                format!("the selection in dimension {dim} is out of bounds");
+            return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+        };
+        index_lists.push(indices);
+    }
+    // SAFETY: pBytes points to an array of length bytesCount per the function's safety contract.
+    let bytes = unsafe { std::slice::from_raw_parts_mut(pBytes, bytesCount) };
+    retrieve_orthogonal_handle(array_handle, &index_lists, bytes)
+}
+
+/// Retrieve elements from an array by orthogonal (outer) indexing.
+///
+/// `pIndexLists` is a pointer to an array of length `dimensionality` holding a pointer to the list of selected indices along each dimension, and `pIndexListLens` holds the length of each list.
+/// The selected elements are the outer product of the index lists, written to `pBytes` in C order, i.e. `pBytes` holds an array with shape `pIndexListLens`.
+/// Index lists do not need to be sorted and may contain duplicates, and the output follows the order of the index lists.
+/// `pBytes` is a pointer to an array of bytes of length `bytesCount` that must match the size of the selected elements.
+///
+/// Requested indices are grouped by chunk, so each chunk containing selected elements is retrieved exactly once and other chunks are not retrieved.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if an index is out of bounds, and the last error names the dimension.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the size of the selected elements.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pIndexLists` and `pIndexListLens`.
+/// Each `pIndexLists[i]` must point to an array of length `pIndexListLens[i]`.
+/// `pBytes` must point to an array of length `bytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveOrthogonal(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pIndexLists: *const *const u64,
+    pIndexListLens: *const usize,
+    bytesCount: usize,
+    pBytes: *mut u8,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &*array };
+    let array_dimensionality = array_fn!(&array.0, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pIndexLists and pIndexListLens point to arrays of length dimensionality per the function's safety contract.
+    let index_list_pointers = unsafe { std::slice::from_raw_parts(pIndexLists, dimensionality) };
+    let index_list_lens = unsafe { std::slice::from_raw_parts(pIndexListLens, dimensionality) };
+    let index_lists: Vec<Vec<u64>> = std::iter::zip(index_list_pointers, index_list_lens)
+        .map(|(&indices, &len)| {
+            if len == 0 {
+                Vec::new()
+            } else {
+                // SAFETY: each index list points to an array of its length per the function's safety contract.
+                unsafe { std::slice::from_raw_parts(indices, len) }.to_vec()
+            }
+        })
+        .collect();
+    // SAFETY: pBytes points to an array of length bytesCount per the function's safety contract.
+    let bytes = unsafe { std::slice::from_raw_parts_mut(pBytes, bytesCount) };
+    retrieve_orthogonal_handle(array, &index_lists, bytes)
+}
@@ -1,19 +1,101 @@
 use zarrs::{
-    array::{Array, ArrayBytes, ArraySubset},
-    storage::ReadableWritableStorageTraits,
+    array::{Array, ArrayBytes, ArraySubset, CodecOptions},
+    storage::{ReadableWritableStorageTraits, WritableStorageTraits},
 };
 
-use crate::{LAST_ERROR, ZarrsResult};
+use crate::{
+    LAST_ERROR, ZarrsResult,
+    codec_options::{ZarrsCodecOptions, codec_options_or_default},
+    stats::num_chunks_in_subset,
+};
 
-use super::{ZarrsArray, ZarrsArrayEnum};
+use super::{
+    ZarrsArray, ZarrsArray_T, ZarrsArrayEnum, ZarrsOrder, array_fn, data_type::f64_to_element,
+    element_subset, for_each_strided_offset, strided_first_offset, transpose_order,
+    validated_array_subset,
+};
 
 fn zarrsArrayStoreSubsetImpl<T: ReadableWritableStorageTraits + ?Sized + 'static>(
     array: &Array<T>,
     array_subset: &ArraySubset,
     subset_bytes: &[u8],
+    codec_options: &CodecOptions,
 ) -> ZarrsResult {
     let array_bytes: ArrayBytes<'static> = ArrayBytes::new_flen(subset_bytes.to_vec());
-    if let Err(err) = array.store_array_subset(array_subset, array_bytes) {
+    if let Err(err) = array.store_array_subset_opt(array_subset, array_bytes, codec_options) {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        ZarrsResult::ZARRS_ERROR_ARRAY
+    } else {
+        ZarrsResult::ZARRS_SUCCESS
+    }
+}
+
+/// Store a chunk-aligned array subset without reading from the store.
+///
+/// The subset must start on a chunk boundary and end on a chunk boundary or the array shape along every dimension.
+/// The elements of edge chunks outside of the array shape are set to the fill value.
+fn zarrsArrayStoreAlignedSubsetImpl<T: WritableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    array_subset: &ArraySubset,
+    subset_bytes: &[u8],
+    codec_options: &CodecOptions,
+) -> ZarrsResult {
+    if array_subset.num_elements() == 0 {
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+    let chunks_subset = array
+        .chunks_in_array_subset(array_subset)
+        .ok()
+        .flatten()
+        .and_then(|chunks| Some((array.chunks_subset(&chunks).ok()?, chunks)));
+    let is_aligned = chunks_subset.as_ref().is_some_and(|(chunks_subset, _)| {
+        chunks_subset.start() == array_subset.start()
+            && std::iter::zip(chunks_subset.end_exc(), array_subset.end_exc())
+                .zip(array.shape())
+                .all(|((chunks_end, subset_end), &size)| subset_end == chunks_end.min(size))
+    });
+    let Some((chunks_subset, chunks)) = chunks_subset.filter(|_| is_aligned) else {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "storage does not have read/write capability, so the subset {array_subset:?} must be aligned to chunk boundaries"
+        );
+        return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+    };
+    let Some(data_type_size) = array.data_type().fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let expected_length = array_subset.num_elements_usize() * data_type_size;
+    if subset_bytes.len() != expected_length {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "subset_bytes_length {} does not match subset size {expected_length}",
+            subset_bytes.len()
+        );
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+
+    // Pad edge chunks with the fill value
+    let chunks_bytes = if chunks_subset == *array_subset {
+        subset_bytes.to_vec()
+    } else {
+        let mut chunks_bytes = array
+            .fill_value()
+            .as_ne_bytes()
+            .repeat(chunks_subset.num_elements_usize());
+        let mut strides = vec![data_type_size as i64; chunks_subset.dimensionality()];
+        for dim in (0..strides.len().saturating_sub(1)).rev() {
+            strides[dim] = strides[dim + 1] * chunks_subset.shape()[dim + 1] as i64;
+        }
+        let mut elements = subset_bytes.chunks_exact(data_type_size);
+        for_each_strided_offset(array_subset.shape(), &strides, |offset| {
+            if let Some(element) = elements.next() {
+                let offset = offset as usize;
+                chunks_bytes[offset..offset + data_type_size].copy_from_slice(element);
+            }
+        });
+        chunks_bytes
+    };
+    let array_bytes: ArrayBytes<'static> = ArrayBytes::new_flen(chunks_bytes);
+    if let Err(err) = array.store_chunks_opt(&chunks, array_bytes, codec_options) {
         *LAST_ERROR.lock().unwrap() = err.to_string();
         ZarrsResult::ZARRS_ERROR_ARRAY
     } else {
@@ -26,8 +108,13 @@ fn zarrsArrayStoreSubsetImpl<T: ReadableWritableStorageTraits + ?Sized + 'static
 /// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
 /// `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
 ///
+/// Storing a subset that partially covers a chunk requires reading the chunk, so arrays with only write capability are limited to chunk-aligned subsets.
+/// A subset is chunk-aligned if it starts on a chunk boundary and ends on a chunk boundary or the array shape along every dimension.
+/// The elements of edge chunks outside of the array shape are then set to the fill value.
+///
 /// # Errors
-/// Returns an error if the array does not have read/write capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability, or only has write capability and the subset is not chunk-aligned.
 ///
 /// # Safety
 /// `array`  must be a valid `ZarrsArray` handle.
@@ -40,29 +127,400 @@ pub unsafe extern "C" fn zarrsArrayStoreSubset(
     pSubsetShape: *const u64,
     subsetBytesCount: usize,
     pSubsetBytes: *const u8,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of zarrsArrayStoreSubsetOpt, and null codec options are valid.
+    unsafe {
+        zarrsArrayStoreSubsetOpt(
+            array,
+            dimensionality,
+            pSubsetStart,
+            pSubsetShape,
+            subsetBytesCount,
+            pSubsetBytes,
+            std::ptr::null_mut(),
+        )
+    }
+}
+
+/// Store an array subset with codec options.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
+/// `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+///
+/// Storing a subset that partially covers a chunk requires reading the chunk, so arrays with only write capability are limited to chunk-aligned subsets.
+/// A subset is chunk-aligned if it starts on a chunk boundary and ends on a chunk boundary or the array shape along every dimension.
+/// The elements of edge chunks outside of the array shape are then set to the fill value.
+/// If `codecOptions` is null, the default codec options are used.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability, or only has write capability and the subset is not chunk-aligned.
+///
+/// # Safety
+/// `array`  must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayStoreSubsetOpt(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    subsetBytesCount: usize,
+    pSubsetBytes: *const u8,
+    codecOptions: ZarrsCodecOptions,
 ) -> ZarrsResult {
     // Validation
     if array.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
-    let array = unsafe { &**array };
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    // SAFETY: pSubsetBytes points to an array of length subsetBytesCount per the function's safety contract.
+    let subset_bytes = unsafe { std::slice::from_raw_parts(pSubsetBytes, subsetBytesCount) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+
+    let default_codec_options = CodecOptions::default();
+    // SAFETY: the caller guarantees codecOptions is null or a valid ZarrsCodecOptions handle.
+    let codec_options = unsafe { codec_options_or_default(codecOptions, &default_codec_options) };
+
+    // Store the subset bytes
+    let result = match array {
+        ZarrsArrayEnum::W(array) => {
+            zarrsArrayStoreAlignedSubsetImpl(array, &array_subset, subset_bytes, codec_options)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayStoreSubsetImpl(array, &array_subset, subset_bytes, codec_options)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayStoreSubsetImpl(array, &array_subset, subset_bytes, codec_options)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have write capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_encoded(num_chunks_in_subset(array, &array_subset));
+    }
+    result
+}
+
+/// Store an array subset in C or Fortran order.
+///
+/// This is equivalent to `zarrsArrayStoreSubset`, except that `order` sets the memory layout of `pSubsetBytes`.
+/// With `ZarrsOrder::ZARRS_ORDER_F`, the subset is read in Fortran (column-major) order, where the first dimension is contiguous.
+/// The subset size is independent of the order.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+/// - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the size of the subset overflows a `size_t`.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `subsetBytesCount` does not match the size of the subset.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read/write capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// `order` must be a valid `ZarrsOrder`.
+/// `pSubsetBytes` must point to an array of length `subsetBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayStoreSubsetOrd(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    order: ZarrsOrder,
+    subsetBytesCount: usize,
+    pSubsetBytes: *const u8,
+) -> ZarrsResult {
+    if order == ZarrsOrder::ZARRS_ORDER_C {
+        // SAFETY: the caller upholds the safety contract of zarrsArrayStoreSubset.
+        return unsafe {
+            zarrsArrayStoreSubset(
+                array,
+                dimensionality,
+                pSubsetStart,
+                pSubsetShape,
+                subsetBytesCount,
+                pSubsetBytes,
+            )
+        };
+    }
+
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array_ref = unsafe { &**array };
     // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
     let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
     let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    if let Err(result) = validated_array_subset(array_ref, subset_start, subset_shape) {
+        return result;
+    }
+    let Some(element_size) = array_fn!(array_ref, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let subset_shape_usize: Option<Vec<usize>> = subset_shape
+        .iter()
+        .map(|&shape| usize::try_from(shape).ok())
+        .collect();
+    let Some((subset_shape, expected_length)) = subset_shape_usize.and_then(|subset_shape| {
+        let expected_length = subset_shape
+            .iter()
+            .try_fold(element_size, |length, &shape| length.checked_mul(shape))?;
+        Some((subset_shape, expected_length))
+    }) else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the size of a subset with shape {subset_shape:?} overflows");
+        return ZarrsResult::ZARRS_ERROR_OVERFLOW;
+    };
+    if subsetBytesCount != expected_length {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "subset_bytes_length {subsetBytesCount} does not match subset size {expected_length}"
+        );
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+
+    // Transpose the subset to C order and store it
     // SAFETY: pSubsetBytes points to an array of length subsetBytesCount per the function's safety contract.
     let subset_bytes = unsafe { std::slice::from_raw_parts(pSubsetBytes, subsetBytesCount) };
-    let array_subset = ArraySubset::from(
-        std::iter::zip(subset_start, subset_shape).map(|(&start, &shape)| start..start + shape),
+    let mut subset_bytes_c = vec![0u8; subsetBytesCount];
+    transpose_order(
+        subset_bytes,
+        &mut subset_bytes_c,
+        &subset_shape,
+        element_size,
+        false,
     );
+    // SAFETY: the caller upholds the safety contract of zarrsArrayStoreSubset, and subset_bytes_c has length subsetBytesCount.
+    unsafe {
+        zarrsArrayStoreSubset(
+            array,
+            dimensionality,
+            pSubsetStart,
+            pSubsetShape,
+            subsetBytesCount,
+            subset_bytes_c.as_ptr(),
+        )
+    }
+}
+
+/// Store a single element in an array.
+///
+/// `pIndices` is a pointer to an array of length `dimensionality` holding the element indices.
+/// `pElementBytes` is a pointer to an array of bytes of length `elementBytesCount` that must match the size of the data type.
+///
+/// The chunk containing the element is retrieved, updated, and stored, so this is no more efficient than chunk or subset storage.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the element indices are out of bounds.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `elementBytesCount` does not match the size of the data type.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read/write capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pIndices` must point to an array of length `dimensionality`.
+/// `pElementBytes` must point to an array of length `elementBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArraySetSingleElement(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pIndices: *const u64,
+    elementBytesCount: usize,
+    pElementBytes: *const u8,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pIndices points to an array of length dimensionality per the function's safety contract.
+    let element_indices = unsafe { std::slice::from_raw_parts(pIndices, dimensionality) };
+    // SAFETY: pElementBytes points to an array of length elementBytesCount per the function's safety contract.
+    let element_bytes = unsafe { std::slice::from_raw_parts(pElementBytes, elementBytesCount) };
+    let array_subset = match element_subset(array, element_indices) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    let Some(data_type_size) = array_fn!(array, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    if elementBytesCount != data_type_size {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "element_bytes_length {elementBytesCount} does not match the data type size {data_type_size}"
+        );
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+
+    // Store the element bytes
+    let result = match array {
+        ZarrsArrayEnum::RW(array) => zarrsArrayStoreSubsetImpl(
+            array,
+            &array_subset,
+            element_bytes,
+            &CodecOptions::default(),
+        ),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayStoreSubsetImpl(
+            array,
+            &array_subset,
+            element_bytes,
+            &CodecOptions::default(),
+        ),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read/write capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_encoded(1);
+    }
+    result
+}
+
+/// Store a single element in an array from a `double`.
+///
+/// `pIndices` is a pointer to an array of length `dimensionality` holding the element indices.
+/// `value` is converted to the data type of the array, which may be boolean, integer, or floating point (including `float16` and `bfloat16`).
+/// Conversions to integer data types truncate towards zero and saturate at the bounds of the data type.
+/// Conversions to floating point data types round to the nearest representable value.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if a `double` cannot be converted to the data type.
+/// - Returns any error of `zarrsArraySetSingleElement()`.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pIndices` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArraySetSingleElementFloat64(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pIndices: *const u64,
+    value: f64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let data_type = array_fn!(unsafe { &**array }, data_type);
+    let Some(element_bytes) = f64_to_element(data_type, value) else {
+        *LAST_ERROR.lock().unwrap() = "f64 cannot be converted to the array data type".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    // SAFETY: the caller upholds the safety contract of zarrsArraySetSingleElement, and element_bytes is valid for its length.
+    unsafe {
+        zarrsArraySetSingleElement(
+            array,
+            dimensionality,
+            pIndices,
+            element_bytes.len(),
+            element_bytes.as_ptr(),
+        )
+    }
+}
+
+/// Store an array subset from a strided buffer.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+/// `pSrcStridesBytes` is a pointer to an array of length `dimensionality` holding the byte stride of each dimension of the source buffer.
+/// Strides may be negative, in which case the corresponding axis is reversed in the source buffer.
+/// `pSrcBase` is a pointer to the source buffer of length `srcBufferLen`, which starts at the element with the lowest address.
+/// The layout of the source buffer matches the destination buffer of `zarrsArrayRetrieveSubsetStrided()` with the same strides.
+/// Elements are read in the native endianness with the size of the data type.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if any element addressed by the strides is outside the source buffer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read/write capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `pSubsetStart`, `pSubsetShape`, and `pSrcStridesBytes` must point to arrays of length `dimensionality`.
+/// `pSrcBase` must point to an array of length `srcBufferLen`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayStoreSubsetStrided(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    pSrcStridesBytes: *const i64,
+    pSrcBase: *const u8,
+    srcBufferLen: usize,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pSubsetStart, pSubsetShape, and pSrcStridesBytes point to arrays of length dimensionality per the function's safety contract.
+    let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
+    let input_strides = unsafe { std::slice::from_raw_parts(pSrcStridesBytes, dimensionality) };
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    let Some(data_type_size) = array_fn!(array, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let first_offset =
+        match strided_first_offset(subset_shape, input_strides, data_type_size, srcBufferLen) {
+            Ok(Some(first_offset)) => first_offset,
+            Ok(None) => return ZarrsResult::ZARRS_SUCCESS, // the subset is empty
+            Err(result) => return result,
+        };
+    // SAFETY: first_offset is within the source buffer of length srcBufferLen.
+    let pInputBytes = unsafe { pSrcBase.add(first_offset) };
+
+    // Gather the strided input into a contiguous buffer
+    let mut subset_bytes = Vec::with_capacity(array_subset.num_elements_usize() * data_type_size);
+    for_each_strided_offset(subset_shape, input_strides, |offset| {
+        // SAFETY: strided_first_offset checked that every strided element is within the source buffer.
+        let element =
+            unsafe { std::slice::from_raw_parts(pInputBytes.offset(offset), data_type_size) };
+        subset_bytes.extend_from_slice(element);
+    });
 
     // Store the subset bytes
-    match array {
-        ZarrsArrayEnum::RW(array) => zarrsArrayStoreSubsetImpl(array, &array_subset, subset_bytes),
-        ZarrsArrayEnum::RWL(array) => zarrsArrayStoreSubsetImpl(array, &array_subset, subset_bytes),
+    let result = match array {
+        ZarrsArrayEnum::RW(array) => zarrsArrayStoreSubsetImpl(
+            array,
+            &array_subset,
+            &subset_bytes,
+            &CodecOptions::default(),
+        ),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayStoreSubsetImpl(
+            array,
+            &array_subset,
+            &subset_bytes,
+            &CodecOptions::default(),
+        ),
         _ => {
             *LAST_ERROR.lock().unwrap() = "storage does not have read/write capability".to_string();
             ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
         }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_encoded(num_chunks_in_subset(array, &array_subset));
     }
+    result
 }
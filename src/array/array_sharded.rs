@@ -1,26 +1,113 @@
+use std::sync::{Arc, Mutex};
+
 use zarrs::{
     array::{
         Array, ArrayBytes, ArrayShardedExt, ArrayShardedReadableExt, ArrayShardedReadableExtCache,
         ArraySubset, CodecOptions, chunk_shape_to_array_shape,
     },
-    storage::ReadableStorageTraits,
+    storage::{ReadableStorageTraits, ReadableWritableStorageTraits},
+};
+
+use crate::{
+    LAST_ERROR, ZarrsResult, buffer_into_raw,
+    codec_options::{ZarrsCodecOptions, codec_options_or_default},
+    lru::LruCache,
+    stats::num_chunks_in_subset,
 };
 
-use crate::{LAST_ERROR, ZarrsResult};
+use super::{
+    ZarrsArray, ZarrsArray_T, ZarrsArrayEnum, array_fn, for_each_strided_offset,
+    validate_chunk_indices, validated_array_subset,
+};
+
+/// A shard index cache holding at most `capacity` shard indexes.
+///
+/// An [`ArrayShardedReadableExtCache`] cannot evict individual shard indexes, so each shard has its own cache.
+struct BoundedShardIndexCache {
+    capacity: usize,
+    /// The cache of each shard, keyed by the shard indices.
+    caches: LruCache<Vec<u64>, Arc<ArrayShardedReadableExtCache>>,
+    /// The number of shard indexes that were loaded into evicted caches.
+    evicted: u64,
+}
 
-use super::{ZarrsArray, ZarrsArrayEnum, array_fn};
+impl BoundedShardIndexCache {
+    /// Evict the least recently used shard indexes until there are at most `capacity`.
+    fn evict(&mut self) {
+        while self.caches.len() > self.capacity {
+            if let Some((_, cache)) = self.caches.pop_lru() {
+                self.evicted += cache.len() as u64;
+            }
+        }
+    }
+}
+
+enum ShardIndexCache {
+    Unbounded(Arc<ArrayShardedReadableExtCache>),
+    Bounded(Mutex<BoundedShardIndexCache>),
+}
 
 #[doc(hidden)]
-pub struct ZarrsShardIndexCache_T(pub ArrayShardedReadableExtCache);
+pub struct ZarrsShardIndexCache_T(ShardIndexCache);
+
+impl ZarrsShardIndexCache_T {
+    /// Get the cache holding the index of the shard at `shard_indices`, marking it as most recently used.
+    fn shard_cache<T: ReadableStorageTraits + ?Sized + 'static>(
+        &self,
+        array: &Array<T>,
+        shard_indices: &[u64],
+    ) -> Arc<ArrayShardedReadableExtCache> {
+        match &self.0 {
+            ShardIndexCache::Unbounded(cache) => cache.clone(),
+            ShardIndexCache::Bounded(cache) => {
+                let mut cache = cache.lock().unwrap();
+                let shard_indices = shard_indices.to_vec();
+                if let Some(shard_cache) = cache.caches.get(&shard_indices) {
+                    return shard_cache.clone();
+                }
+                let shard_cache = Arc::new(ArrayShardedReadableExtCache::new(array));
+                cache.caches.insert(shard_indices, shard_cache.clone());
+                cache.evict();
+                shard_cache
+            }
+        }
+    }
 
-impl std::ops::Deref for ZarrsShardIndexCache_T {
-    type Target = ArrayShardedReadableExtCache;
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    /// The total number of shard indexes loaded into the cache, including those since evicted.
+    fn num_loaded(&self) -> u64 {
+        match &self.0 {
+            ShardIndexCache::Unbounded(cache) => cache.len() as u64,
+            ShardIndexCache::Bounded(cache) => {
+                let cache = cache.lock().unwrap();
+                cache.evicted
+                    + cache
+                        .caches
+                        .values()
+                        .map(|cache| cache.len() as u64)
+                        .sum::<u64>()
+            }
+        }
+    }
+
+    /// Drop the cached index of the shard at `shard_indices`, so that it is reloaded when the shard is next read.
+    ///
+    /// An unbounded cache cannot drop individual shard indexes, so it is cleared entirely.
+    fn invalidate(&self, shard_indices: &[u64]) {
+        match &self.0 {
+            ShardIndexCache::Unbounded(cache) => cache.clear(),
+            ShardIndexCache::Bounded(cache) => {
+                let mut cache = cache.lock().unwrap();
+                if let Some(shard_cache) = cache.caches.remove(&shard_indices.to_vec()) {
+                    cache.evicted += shard_cache.len() as u64;
+                }
+            }
+        }
     }
 }
 
-/// An opaque handle to a zarrs [`ArrayShardedReadableExtCache`].
+/// An opaque handle to a shard index cache.
+///
+/// The cache is either unbounded (a zarrs [`ArrayShardedReadableExtCache`]) or holds a bounded number of shard indexes.
 pub type ZarrsShardIndexCache = *mut ZarrsShardIndexCache_T;
 
 /// Get the shape of the inner chunk grid of a sharded array.
@@ -59,6 +146,70 @@ pub unsafe extern "C" fn zarrsArrayGetSubChunkGridShape(
     ZarrsResult::ZARRS_SUCCESS
 }
 
+/// Get the shape of the inner chunk grid within a shard of a sharded array.
+///
+/// `pShardIndices` is a pointer to an array of length `dimensionality` holding the shard indices.
+/// `pInnerGridShape` is set to the number of inner chunks along each dimension of the shard that intersect the array.
+/// For shards within the array this is the shard shape divided by the inner chunk shape.
+/// For shards on the array boundary, inner chunks entirely outside of the array are excluded, so the shape may be smaller.
+/// Inner chunks that are partially outside of the array are included.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pShardIndices` are outside of the chunk grid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array is not sharded.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pShardIndices` and `pInnerGridShape`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubChunkGridShapeForShard(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pShardIndices: *const u64,
+    pInnerGridShape: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pShardIndices points to an array of length dimensionality per the function's safety contract.
+    let shard_indices = unsafe { std::slice::from_raw_parts(pShardIndices, dimensionality) };
+    if let Err(result) = validate_chunk_indices(array, shard_indices) {
+        return result;
+    }
+    let Some(subchunk_shape) = array_fn!(array, subchunk_shape) else {
+        *LAST_ERROR.lock().unwrap() = "array is not sharded".to_string();
+        return ZarrsResult::ZARRS_ERROR_ARRAY;
+    };
+    let shard_subset = match array_fn!(array, chunk_subset_bounded, shard_indices) {
+        Ok(shard_subset) => shard_subset,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+        }
+    };
+
+    // SAFETY: pInnerGridShape points to an array of length dimensionality per the function's safety contract.
+    let pInnerGridShape =
+        unsafe { std::slice::from_raw_parts_mut(pInnerGridShape, dimensionality) };
+    for ((inner_grid_size, &shard_size), subchunk_size) in
+        std::iter::zip(pInnerGridShape, shard_subset.shape()).zip(subchunk_shape.iter())
+    {
+        *inner_grid_size = shard_size.div_ceil(subchunk_size.get());
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
 /// Get the inner chunk shape for a sharded array.
 ///
 /// `pIsSharded` is set to true if the array is sharded, otherwise false.
@@ -100,7 +251,117 @@ pub unsafe extern "C" fn zarrsArrayGetSubChunkShape(
     ZarrsResult::ZARRS_SUCCESS
 }
 
-/// Create a handle to a new shard index cache.
+/// Get the number of elements in the inner chunk of a sharded array at `subchunk_indices`.
+///
+/// Sets the last error on failure.
+fn subchunk_num_elements(
+    array: &ZarrsArrayEnum,
+    subchunk_indices: &[u64],
+) -> Result<u64, ZarrsResult> {
+    let Some(subchunk_shape) = array_fn!(array, subchunk_shape) else {
+        *LAST_ERROR.lock().unwrap() = "array is not sharded".to_string();
+        return Err(ZarrsResult::ZARRS_ERROR_ARRAY);
+    };
+    let subchunk_grid_shape = array_fn!(array, subchunk_grid_shape);
+    if subchunk_grid_shape.len() != subchunk_indices.len()
+        || std::iter::zip(subchunk_indices, &subchunk_grid_shape)
+            .any(|(&index, &size)| index >= size)
+    {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "inner chunk indices {subchunk_indices:?} are invalid for an inner chunk grid with shape {subchunk_grid_shape:?}"
+        );
+        return Err(ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+    }
+    Ok(subchunk_shape.iter().map(|d| d.get()).product())
+}
+
+/// Get the size of an inner chunk of a sharded array in bytes.
+///
+/// `pSubChunkIndices` is a pointer to an array of length `dimensionality` holding the inner chunk indices.
+/// Inner chunks on the array boundary have the full inner chunk shape, consistent with `zarrsArrayRetrieveSubChunk`.
+/// Use this to allocate the buffer passed to `zarrsArrayRetrieveSubChunk`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array is not sharded.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pSubChunkIndices` are outside of the inner chunk grid or `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pSubChunkIndices` must point to an array of length `dimensionality`.
+/// `pSubChunkSize` must be a valid pointer to a `usize`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubChunkSize(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubChunkIndices: *const u64,
+    pSubChunkSize: *mut usize,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pSubChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let subchunk_indices = unsafe { std::slice::from_raw_parts(pSubChunkIndices, dimensionality) };
+
+    let num_elements = match subchunk_num_elements(array, subchunk_indices) {
+        Ok(num_elements) => num_elements,
+        Err(result) => return result,
+    };
+    let Some(data_type_size) = array_fn!(array, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    // SAFETY: pSubChunkSize is a valid pointer per the function's safety contract.
+    unsafe { *pSubChunkSize = usize::try_from(num_elements).unwrap() * data_type_size };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the number of elements in an inner chunk of a sharded array.
+///
+/// `pSubChunkIndices` is a pointer to an array of length `dimensionality` holding the inner chunk indices.
+/// Inner chunks on the array boundary have the full inner chunk shape, consistent with `zarrsArrayRetrieveSubChunk`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array is not sharded.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pSubChunkIndices` are outside of the inner chunk grid or `dimensionality` does not match the array dimensionality.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pSubChunkIndices` must point to an array of length `dimensionality`.
+/// `pNumElements` must be a valid pointer to a `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetSubChunkElements(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pSubChunkIndices: *const u64,
+    pNumElements: *mut u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pSubChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let subchunk_indices = unsafe { std::slice::from_raw_parts(pSubChunkIndices, dimensionality) };
+
+    match subchunk_num_elements(array, subchunk_indices) {
+        Ok(num_elements) => {
+            // SAFETY: pNumElements is a valid pointer per the function's safety contract.
+            unsafe { *pNumElements = num_elements };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Create a handle to a new unbounded shard index cache.
+///
+/// The cache holds the index of every shard that is read through it, so it can consume unbounded memory for large sharded arrays.
+/// Use `zarrsCreateShardIndexCacheWithCapacity` to limit the number of cached shard indexes.
 ///
 /// # Errors
 /// Returns an error if the array does not have read capability.
@@ -119,37 +380,147 @@ pub unsafe extern "C" fn zarrsCreateShardIndexCache(
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
     let array = unsafe { &**array };
 
-    match array {
-        ZarrsArrayEnum::R(array) => {
-            // SAFETY: pShardIndexCache is a valid pointer per the function's safety contract.
-            unsafe {
-                *pShardIndexCache = Box::into_raw(Box::new(ZarrsShardIndexCache_T(
-                    ArrayShardedReadableExtCache::new(array),
-                )));
-            }
-        }
-        ZarrsArrayEnum::RW(array) => {
-            // SAFETY: pShardIndexCache is a valid pointer per the function's safety contract.
-            unsafe {
-                *pShardIndexCache = Box::into_raw(Box::new(ZarrsShardIndexCache_T(
-                    ArrayShardedReadableExtCache::new(array),
-                )));
-            }
-        }
-        ZarrsArrayEnum::RWL(array) => {
-            // SAFETY: pShardIndexCache is a valid pointer per the function's safety contract.
-            unsafe {
-                *pShardIndexCache = Box::into_raw(Box::new(ZarrsShardIndexCache_T(
-                    ArrayShardedReadableExtCache::new(array),
-                )));
-            }
-        }
+    let cache = match array {
+        ZarrsArrayEnum::R(array) => ArrayShardedReadableExtCache::new(array),
+        ZarrsArrayEnum::RW(array) => ArrayShardedReadableExtCache::new(array),
+        ZarrsArrayEnum::RWL(array) => ArrayShardedReadableExtCache::new(array),
         _ => {
             *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
             return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
         }
+    };
+    // SAFETY: pShardIndexCache is a valid pointer per the function's safety contract.
+    unsafe {
+        *pShardIndexCache = Box::into_raw(Box::new(ZarrsShardIndexCache_T(
+            ShardIndexCache::Unbounded(Arc::new(cache)),
+        )));
+    }
+
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Create a handle to a new shard index cache holding at most `maxEntries` shard indexes.
+///
+/// When a shard index is read and the cache is full, the least recently used shard index is evicted.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `maxEntries` is zero.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pShardIndexCache` must be a valid pointer to a `ZarrsShardIndexCache` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCreateShardIndexCacheWithCapacity(
+    array: ZarrsArray,
+    maxEntries: usize,
+    pShardIndexCache: *mut ZarrsShardIndexCache,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+
+    if maxEntries == 0 {
+        *LAST_ERROR.lock().unwrap() =
+            "the capacity of a shard index cache must be at least one".to_string();
+        return ZarrsResult::ZARRS_ERROR_ARRAY;
+    }
+    if !matches!(
+        array,
+        ZarrsArrayEnum::R(_) | ZarrsArrayEnum::RW(_) | ZarrsArrayEnum::RWL(_)
+    ) {
+        *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+        return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+    }
+
+    let cache = BoundedShardIndexCache {
+        capacity: maxEntries,
+        caches: LruCache::default(),
+        evicted: 0,
+    };
+    // SAFETY: pShardIndexCache is a valid pointer per the function's safety contract.
+    unsafe {
+        *pShardIndexCache = Box::into_raw(Box::new(ZarrsShardIndexCache_T(
+            ShardIndexCache::Bounded(Mutex::new(cache)),
+        )));
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the maximum number of shard indexes held by a shard index cache.
+///
+/// `pCapacity` is set to zero if the cache is unbounded.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `cache` is a null pointer.
+///
+/// # Safety
+/// If not null, `cache` must be a valid `ZarrsShardIndexCache` handle.
+/// `pCapacity` must be a valid pointer to a `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsShardIndexCacheGetCapacity(
+    cache: ZarrsShardIndexCache,
+    pCapacity: *mut usize,
+) -> ZarrsResult {
+    if cache.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsShardIndexCache handle.
+    let cache = unsafe { &*cache };
+
+    let capacity = match &cache.0 {
+        ShardIndexCache::Unbounded(_) => 0,
+        ShardIndexCache::Bounded(cache) => cache.lock().unwrap().capacity,
+    };
+    // SAFETY: pCapacity is a valid pointer per the function's safety contract.
+    unsafe { *pCapacity = capacity };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Set the maximum number of shard indexes held by a shard index cache.
+///
+/// If the cache holds more than `newCapacity` shard indexes, the least recently used are evicted.
+/// An unbounded cache becomes bounded, and the shard indexes it holds are discarded.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `cache` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `newCapacity` is zero.
+///
+/// # Safety
+/// If not null, `cache` must be a valid `ZarrsShardIndexCache` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsShardIndexCacheSetCapacity(
+    cache: ZarrsShardIndexCache,
+    newCapacity: usize,
+) -> ZarrsResult {
+    if cache.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
+    // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsShardIndexCache handle.
+    let cache = unsafe { &mut *cache };
 
+    if newCapacity == 0 {
+        *LAST_ERROR.lock().unwrap() =
+            "the capacity of a shard index cache must be at least one".to_string();
+        return ZarrsResult::ZARRS_ERROR_ARRAY;
+    }
+    match &mut cache.0 {
+        ShardIndexCache::Unbounded(_) => {
+            cache.0 = ShardIndexCache::Bounded(Mutex::new(BoundedShardIndexCache {
+                capacity: newCapacity,
+                caches: LruCache::default(),
+                evicted: 0,
+            }));
+        }
+        ShardIndexCache::Bounded(cache) => {
+            let mut cache = cache.lock().unwrap();
+            cache.capacity = newCapacity;
+            cache.evict();
+        }
+    }
     ZarrsResult::ZARRS_SUCCESS
 }
 
@@ -173,15 +544,41 @@ pub unsafe extern "C" fn zarrsDestroyShardIndexCache(
     }
 }
 
+/// Get the indices of the shard containing the inner chunk at `subchunk_indices`.
+///
+/// Returns [`None`] if the shard cannot be determined.
+fn subchunk_shard_indices<T: ?Sized>(
+    array: &Array<T>,
+    subchunk_indices: &[u64],
+) -> Option<Vec<u64>> {
+    let Some(subchunk_shape) = array.subchunk_shape() else {
+        return Some(subchunk_indices.to_vec());
+    };
+    let element_indices: Vec<u64> = std::iter::zip(subchunk_indices, subchunk_shape.iter())
+        .map(|(&index, size)| index * size.get())
+        .collect();
+    array
+        .chunk_grid()
+        .chunk_indices(&element_indices)
+        .ok()
+        .flatten()
+}
+
 fn zarrsArrayRetrieveSubChunkImpl<T: ReadableStorageTraits + ?Sized + 'static>(
     array: &Array<T>,
-    cache: &ArrayShardedReadableExtCache,
+    cache: &ZarrsShardIndexCache_T,
     chunk_indices: &[u64],
     chunk_bytes_length: usize,
     chunk_bytes: *mut u8,
+    codec_options: &CodecOptions,
 ) -> ZarrsResult {
-    match array.retrieve_subchunk_opt::<ArrayBytes>(cache, chunk_indices, &CodecOptions::default())
-    {
+    let Some(shard_indices) = subchunk_shard_indices(array, chunk_indices) else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the shard containing inner chunk {chunk_indices:?} cannot be determined");
+        return ZarrsResult::ZARRS_ERROR_ARRAY;
+    };
+    let cache = cache.shard_cache(array, &shard_indices);
+    match array.retrieve_subchunk_opt::<ArrayBytes>(&cache, chunk_indices, codec_options) {
         Ok(bytes) => {
             let Ok(bytes) = bytes.into_fixed() else {
                 *LAST_ERROR.lock().unwrap() =
@@ -209,7 +606,7 @@ fn zarrsArrayRetrieveSubChunkImpl<T: ReadableStorageTraits + ?Sized + 'static>(
 /// Retrieve an inner chunk from a sharded array (or outer chunk for an unsharded array).
 ///
 /// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
-/// `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
+/// `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetSubChunkSize()`.
 ///
 /// # Errors
 /// Returns an error if the array does not have read capability.
@@ -225,25 +622,69 @@ pub unsafe extern "C" fn zarrsArrayRetrieveSubChunk(
     pChunkIndices: *const u64,
     chunkBytesCount: usize,
     pChunkBytes: *mut u8,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveSubChunkOpt, and null codec options are valid.
+    unsafe {
+        zarrsArrayRetrieveSubChunkOpt(
+            array,
+            cache,
+            dimensionality,
+            pChunkIndices,
+            chunkBytesCount,
+            pChunkBytes,
+            std::ptr::null_mut(),
+        )
+    }
+}
+
+/// Retrieve an inner chunk from a sharded array (or outer chunk for an unsharded array) with codec options.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetSubChunkSize()`.
+/// If `codecOptions` is null, the default codec options are used.
+///
+/// # Errors
+/// Returns an error if the array does not have read capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubChunkOpt(
+    array: ZarrsArray,
+    cache: ZarrsShardIndexCache,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    chunkBytesCount: usize,
+    pChunkBytes: *mut u8,
+    codecOptions: ZarrsCodecOptions,
 ) -> ZarrsResult {
     if array.is_null() || cache.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
-    let array = unsafe { &**array };
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
     // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsShardIndexCache handle.
-    let cache = unsafe { &**cache };
+    let cache = unsafe { &*cache };
     // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
     let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
 
+    let default_codec_options = CodecOptions::default();
+    // SAFETY: the caller guarantees codecOptions is null or a valid ZarrsCodecOptions handle.
+    let codec_options = unsafe { codec_options_or_default(codecOptions, &default_codec_options) };
+
+    let num_loaded = cache.num_loaded();
+
     // Get the chunk bytes
-    match array {
+    let result = match array {
         ZarrsArrayEnum::R(array) => zarrsArrayRetrieveSubChunkImpl(
             array,
             cache,
             chunk_indices,
             chunkBytesCount,
             pChunkBytes,
+            codec_options,
         ),
         ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveSubChunkImpl(
             array,
@@ -251,6 +692,7 @@ pub unsafe extern "C" fn zarrsArrayRetrieveSubChunk(
             chunk_indices,
             chunkBytesCount,
             pChunkBytes,
+            codec_options,
         ),
         ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveSubChunkImpl(
             array,
@@ -258,6 +700,7 @@ pub unsafe extern "C" fn zarrsArrayRetrieveSubChunk(
             chunk_indices,
             chunkBytesCount,
             pChunkBytes,
+            codec_options,
         ),
         ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveSubChunkImpl(
             array,
@@ -265,45 +708,230 @@ pub unsafe extern "C" fn zarrsArrayRetrieveSubChunk(
             chunk_indices,
             chunkBytesCount,
             pChunkBytes,
+            codec_options,
         ),
         _ => {
             *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
             ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
         }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_decoded(1);
+        let hit = cache.num_loaded() == num_loaded;
+        stats.record_cache(u64::from(hit), u64::from(!hit));
     }
+    result
 }
 
-fn zarrsArrayRetrieveSubsetShardedImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+/// The indices of the inner chunks stored within the shard at `shard_indices`, in C order.
+///
+/// An inner chunk is stored if its shard index entry has a byte range, so only the shard index is read (through `cache`).
+///
+/// Sets the last error on failure.
+pub(crate) fn stored_subchunk_indices<T: ReadableStorageTraits + ?Sized + 'static>(
     array: &Array<T>,
     cache: &ArrayShardedReadableExtCache,
+    shard_indices: &[u64],
+) -> Result<Vec<Vec<u64>>, ZarrsResult> {
+    let Some(subchunk_shape) = array.subchunk_shape() else {
+        *LAST_ERROR.lock().unwrap() = "array is not sharded".to_string();
+        return Err(ZarrsResult::ZARRS_ERROR_ARRAY);
+    };
+    let shard_subset = array.chunk_subset_bounded(shard_indices).map_err(|err| {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        ZarrsResult::ZARRS_ERROR_INVALID_INDICES
+    })?;
+    let subchunks = ArraySubset::from(
+        std::iter::zip(shard_subset.start(), shard_subset.shape())
+            .zip(subchunk_shape.iter())
+            .map(|((&start, &shape), subchunk_size)| {
+                start / subchunk_size.get()..(start + shape).div_ceil(subchunk_size.get())
+            }),
+    );
+
+    let mut stored_subchunk_indices = Vec::new();
+    for subchunk_indices in subchunks.indices() {
+        match array.subchunk_byte_range(cache, &subchunk_indices) {
+            Ok(Some(_)) => stored_subchunk_indices.push(subchunk_indices),
+            Ok(None) => {}
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err.to_string();
+                return Err(ZarrsResult::ZARRS_ERROR_ARRAY);
+            }
+        }
+    }
+    Ok(stored_subchunk_indices)
+}
+
+fn zarrsArrayGetStoredInnerChunkIndicesForShardImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    cache: &ZarrsShardIndexCache_T,
+    shard_indices: &[u64],
+) -> Result<Vec<u64>, ZarrsResult> {
+    let cache = cache.shard_cache(array, shard_indices);
+    Ok(stored_subchunk_indices(array, &cache, shard_indices)?.concat())
+}
+
+/// Get the inner chunks stored within a shard of a sharded array.
+///
+/// `pShardIndices` is a pointer to an array of length `dimensionality` holding the shard indices.
+/// `pCount` is set to the number of stored inner chunks in the shard, which is 0 if the shard is not stored.
+/// `ppInnerChunkIndices` is set to a buffer of `pCount * dimensionality` inner chunk indices, with one row of `dimensionality` indices per inner chunk.
+/// The inner chunk indices are in the inner chunk grid of the array (as for `zarrsArrayRetrieveSubChunk`), not relative to the shard, and are in C order.
+/// The buffer must be freed with `zarrsFreeBytes`.
+///
+/// The stored inner chunks are determined from the shard index alone, which is read through `cache`: an inner chunk is stored if its entry in the shard index has a byte offset and size.
+/// The encoded inner chunks are not retrieved, so a shard whose index is already cached is not read from storage again.
+/// Inner chunks that were entirely the fill value when the shard was written are not stored.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array`, `cache`, `pCount`, or `ppInnerChunkIndices` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pShardIndices` are outside of the chunk grid or `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array is not sharded or the shard cannot be read.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `cache` must be a valid `ZarrsShardIndexCache` handle.
+/// `pShardIndices` must point to an array of length `dimensionality`.
+/// If not null, `pCount` must be a valid pointer to a `size_t` and `ppInnerChunkIndices` must be a valid pointer to a `uint64_t*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetStoredInnerChunkIndicesForShard(
+    array: ZarrsArray,
+    cache: ZarrsShardIndexCache,
+    dimensionality: usize,
+    pShardIndices: *const u64,
+    pCount: *mut usize,
+    ppInnerChunkIndices: *mut *mut u64,
+) -> ZarrsResult {
+    if array.is_null() || cache.is_null() || pCount.is_null() || ppInnerChunkIndices.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsShardIndexCache handle.
+    let cache = unsafe { &*cache };
+    // SAFETY: pShardIndices points to an array of length dimensionality per the function's safety contract.
+    let shard_indices = unsafe { std::slice::from_raw_parts(pShardIndices, dimensionality) };
+    if let Err(result) = validate_chunk_indices(array, shard_indices) {
+        return result;
+    }
+
+    let stored_subchunk_indices = match array {
+        ZarrsArrayEnum::R(array) => {
+            zarrsArrayGetStoredInnerChunkIndicesForShardImpl(array, cache, shard_indices)
+        }
+        ZarrsArrayEnum::RL(array) => {
+            zarrsArrayGetStoredInnerChunkIndicesForShardImpl(array, cache, shard_indices)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayGetStoredInnerChunkIndicesForShardImpl(array, cache, shard_indices)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayGetStoredInnerChunkIndicesForShardImpl(array, cache, shard_indices)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            Err(ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY)
+        }
+    };
+    match stored_subchunk_indices {
+        Ok(stored_subchunk_indices) => {
+            // SAFETY: pCount and ppInnerChunkIndices are not null, and are valid pointers per the function's safety contract.
+            unsafe {
+                *pCount = stored_subchunk_indices
+                    .len()
+                    .checked_div(dimensionality)
+                    .unwrap_or_default();
+                *ppInnerChunkIndices = buffer_into_raw(stored_subchunk_indices);
+            }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Retrieve a subset of an array with a fixed size data type one shard at a time, using the cache of each shard.
+///
+/// The intersection of the subset with each shard is copied into its position in `subset_bytes`.
+fn retrieve_subset_by_shard<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    cache: &ZarrsShardIndexCache_T,
+    array_subset: &ArraySubset,
+    data_type_size: usize,
+    subset_bytes: &mut [u8],
+    codec_options: &CodecOptions,
+) -> Result<(), String> {
+    let shards = array
+        .chunks_in_array_subset(array_subset)
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("the shards intersecting {array_subset:?} cannot be determined"))?;
+
+    // Byte strides of the subset
+    let mut subset_strides = vec![data_type_size as i64; array_subset.dimensionality()];
+    for dim in (0..subset_strides.len().saturating_sub(1)).rev() {
+        subset_strides[dim] = subset_strides[dim + 1] * array_subset.shape()[dim + 1] as i64;
+    }
+
+    for shard_indices in shards.indices() {
+        let shard_subset = array
+            .chunk_subset(&shard_indices)
+            .map_err(|err| err.to_string())?;
+        let overlap = shard_subset
+            .overlap(array_subset)
+            .map_err(|err| err.to_string())?;
+        let shard_cache = cache.shard_cache(array, &shard_indices);
+        let bytes = array
+            .retrieve_array_subset_sharded_opt::<ArrayBytes>(&shard_cache, &overlap, codec_options)
+            .map_err(|err| err.to_string())?
+            .into_fixed()
+            .map_err(|_| "variable size data types are not supported".to_string())?;
+
+        let start_offset: i64 = std::iter::zip(overlap.start(), array_subset.start())
+            .zip(&subset_strides)
+            .map(|((&start, &subset_start), &stride)| (start - subset_start) as i64 * stride)
+            .sum();
+        let mut elements = bytes.chunks_exact(data_type_size);
+        for_each_strided_offset(overlap.shape(), &subset_strides, |offset| {
+            if let Some(element) = elements.next() {
+                let offset = (start_offset + offset as i64) as usize;
+                subset_bytes[offset..offset + data_type_size].copy_from_slice(element);
+            }
+        });
+    }
+    Ok(())
+}
+
+fn zarrsArrayRetrieveSubsetShardedImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    cache: &ZarrsShardIndexCache_T,
     array_subset: &ArraySubset,
-    subset_bytes_length: usize,
-    subset_bytes: *mut u8,
+    subset_bytes: &mut [u8],
+    codec_options: &CodecOptions,
 ) -> ZarrsResult {
-    match array.retrieve_array_subset_sharded_opt::<ArrayBytes>(
+    let Some(data_type_size) = array.data_type().fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let expected_length = array_subset.num_elements_usize() * data_type_size;
+    if expected_length != subset_bytes.len() {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "subset_bytes_length {} does not match decoded subset size {expected_length}",
+            subset_bytes.len()
+        );
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+    // Only the intersection with one shard is held in memory at a time, rather than the entire subset
+    match retrieve_subset_by_shard(
+        array,
         cache,
         array_subset,
-        &CodecOptions::default(),
+        data_type_size,
+        subset_bytes,
+        codec_options,
     ) {
-        Ok(bytes) => {
-            let Ok(bytes) = bytes.into_fixed() else {
-                *LAST_ERROR.lock().unwrap() =
-                    "variable size data types are not supported".to_string();
-                return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
-            };
-            if bytes.len() != subset_bytes_length {
-                *LAST_ERROR.lock().unwrap() = format!(
-                    "subset_bytes_length {subset_bytes_length} does not match decoded subset size {}",
-                    bytes.len()
-                );
-                ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH
-            } else {
-                unsafe { std::ptr::copy(bytes.as_ptr(), subset_bytes, subset_bytes_length) };
-                ZarrsResult::ZARRS_SUCCESS
-            }
-        }
+        Ok(()) => ZarrsResult::ZARRS_SUCCESS,
         Err(err) => {
-            *LAST_ERROR.lock().unwrap() = err.to_string();
+            *LAST_ERROR.lock().unwrap() = err;
             ZarrsResult::ZARRS_ERROR_ARRAY
         }
     }
@@ -317,7 +945,8 @@ fn zarrsArrayRetrieveSubsetShardedImpl<T: ReadableStorageTraits + ?Sized + 'stat
 /// `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
 ///
 /// # Errors
-/// Returns an error if the array does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
 ///
 /// # Safety
 /// `array` must be a valid `ZarrsArray` handle.
@@ -331,55 +960,253 @@ pub unsafe extern "C" fn zarrsArrayRetrieveSubsetSharded(
     pSubsetShape: *const u64,
     subsetBytesCount: usize,
     pSubsetBytes: *mut u8,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of zarrsArrayRetrieveSubsetShardedOpt, and null codec options are valid.
+    unsafe {
+        zarrsArrayRetrieveSubsetShardedOpt(
+            array,
+            cache,
+            dimensionality,
+            pSubsetStart,
+            pSubsetShape,
+            subsetBytesCount,
+            pSubsetBytes,
+            std::ptr::null_mut(),
+        )
+    }
+}
+
+/// Retrieve a subset from an array (with a shard index cache) with codec options.
+///
+/// `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
+/// `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+/// If `codecOptions` is null, the default codec options are used.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+///
+/// # Safety
+/// `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayRetrieveSubsetShardedOpt(
+    array: ZarrsArray,
+    cache: ZarrsShardIndexCache,
+    dimensionality: usize,
+    pSubsetStart: *const u64,
+    pSubsetShape: *const u64,
+    subsetBytesCount: usize,
+    pSubsetBytes: *mut u8,
+    codecOptions: ZarrsCodecOptions,
 ) -> ZarrsResult {
     // Validation
     if array.is_null() || cache.is_null() {
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
-    let array = unsafe { &**array };
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
     // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsShardIndexCache handle.
-    let cache = unsafe { &**cache };
+    let cache = unsafe { &*cache };
     // SAFETY: pSubsetStart and pSubsetShape point to arrays of length dimensionality per the function's safety contract.
     let subset_start = unsafe { std::slice::from_raw_parts(pSubsetStart, dimensionality) };
     let subset_shape = unsafe { std::slice::from_raw_parts(pSubsetShape, dimensionality) };
-    let array_subset = ArraySubset::from(
-        std::iter::zip(subset_start, subset_shape).map(|(&start, &shape)| start..start + shape),
-    );
+    let array_subset = match validated_array_subset(array, subset_start, subset_shape) {
+        Ok(array_subset) => array_subset,
+        Err(result) => return result,
+    };
+    // SAFETY: pSubsetBytes points to an array of length subsetBytesCount per the function's safety contract.
+    let subset_bytes = unsafe { std::slice::from_raw_parts_mut(pSubsetBytes, subsetBytesCount) };
+
+    let default_codec_options = CodecOptions::default();
+    // SAFETY: the caller guarantees codecOptions is null or a valid ZarrsCodecOptions handle.
+    let codec_options = unsafe { codec_options_or_default(codecOptions, &default_codec_options) };
+
+    let num_loaded = cache.num_loaded();
 
     // Get the subset bytes
-    match array {
+    let result = match array {
         ZarrsArrayEnum::R(array) => zarrsArrayRetrieveSubsetShardedImpl(
             array,
             cache,
             &array_subset,
-            subsetBytesCount,
-            pSubsetBytes,
+            subset_bytes,
+            codec_options,
         ),
         ZarrsArrayEnum::RL(array) => zarrsArrayRetrieveSubsetShardedImpl(
             array,
             cache,
             &array_subset,
-            subsetBytesCount,
-            pSubsetBytes,
+            subset_bytes,
+            codec_options,
         ),
         ZarrsArrayEnum::RW(array) => zarrsArrayRetrieveSubsetShardedImpl(
             array,
             cache,
             &array_subset,
-            subsetBytesCount,
-            pSubsetBytes,
+            subset_bytes,
+            codec_options,
         ),
         ZarrsArrayEnum::RWL(array) => zarrsArrayRetrieveSubsetShardedImpl(
             array,
             cache,
             &array_subset,
-            subsetBytesCount,
-            pSubsetBytes,
+            subset_bytes,
+            codec_options,
         ),
         _ => {
             *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
             ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
         }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        let num_chunks = num_chunks_in_subset(array, &array_subset);
+        let misses = cache.num_loaded().saturating_sub(num_loaded);
+        stats.record_decoded(num_chunks);
+        stats.record_cache(num_chunks.saturating_sub(misses), misses);
+    }
+    result
+}
+
+fn zarrsArrayStoreInnerChunkSubsetImpl<T: ReadableWritableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    cache: &ZarrsShardIndexCache_T,
+    shard_indices: &[u64],
+    shard_subset: &ArraySubset,
+    subset_bytes: &[u8],
+) -> ZarrsResult {
+    let array_bytes: ArrayBytes<'static> = ArrayBytes::new_flen(subset_bytes.to_vec());
+    let result = array.store_chunk_subset(shard_indices, shard_subset, array_bytes);
+    // The shard index changes when the shard is rewritten
+    cache.invalidate(shard_indices);
+    if let Err(err) = result {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        ZarrsResult::ZARRS_ERROR_ARRAY
+    } else {
+        ZarrsResult::ZARRS_SUCCESS
+    }
+}
+
+/// Store a subset of a shard of a sharded array (or a subset of a chunk of an unsharded array).
+///
+/// `pOuterChunkIndices` is a pointer to an array of length `dimensionality` holding the shard indices.
+/// `pInnerSubsetStart` and `pInnerSubsetShape` are pointers to arrays of length `dimensionality` holding the start and shape of the subset relative to the start of the shard.
+/// `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` holding the elements of the subset in C order.
+///
+/// The shard is retrieved, the inner chunks intersecting the subset are updated, and the shard is written back.
+/// Inner chunks of the shard that do not intersect the subset are preserved.
+/// This is the write counterpart of `zarrsArrayRetrieveSubsetSharded`.
+/// The index of the shard is dropped from `cache` (and an unbounded cache is cleared), so subsequent reads through `cache` observe the stored subset.
+///
+/// Storing a subset is not atomic.
+/// Concurrent calls storing subsets of the same shard (through any handle or process) may overwrite each other's inner chunks, so they must be serialised by the caller.
+/// Calls storing subsets of different shards can run concurrently.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `cache` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pOuterChunkIndices` are outside of the chunk grid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the shard.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `subsetBytesCount` does not match the size of the subset.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read/write capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the subset cannot be stored.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `cache` must be a valid `ZarrsShardIndexCache` handle.
+/// `dimensionality` must match the length of the arrays pointed to by `pOuterChunkIndices`, `pInnerSubsetStart`, and `pInnerSubsetShape`.
+/// `pSubsetBytes` must point to an array of length `subsetBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayStoreInnerChunkSubset(
+    array: ZarrsArray,
+    cache: ZarrsShardIndexCache,
+    dimensionality: usize,
+    pOuterChunkIndices: *const u64,
+    pInnerSubsetStart: *const u64,
+    pInnerSubsetShape: *const u64,
+    subsetBytesCount: usize,
+    pSubsetBytes: *const u8,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() || cache.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: cache is not null, and the caller guarantees it is a valid ZarrsShardIndexCache handle.
+    let cache = unsafe { &*cache };
+    let array_dimensionality = array_fn!(array, dimensionality);
+    if dimensionality != array_dimensionality {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "dimensionality {dimensionality} does not match the array dimensionality {array_dimensionality}"
+        );
+        return ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY;
+    }
+    // SAFETY: pOuterChunkIndices, pInnerSubsetStart, and pInnerSubsetShape point to arrays of length dimensionality per the function's safety contract.
+    let shard_indices = unsafe { std::slice::from_raw_parts(pOuterChunkIndices, dimensionality) };
+    let subset_start = unsafe { std::slice::from_raw_parts(pInnerSubsetStart, dimensionality) };
+    let subset_shape = unsafe { std::slice::from_raw_parts(pInnerSubsetShape, dimensionality) };
+    // SAFETY: pSubsetBytes points to an array of length subsetBytesCount per the function's safety contract.
+    let subset_bytes = unsafe { std::slice::from_raw_parts(pSubsetBytes, subsetBytesCount) };
+    if let Err(result) = validate_chunk_indices(array, shard_indices) {
+        return result;
+    }
+    let shard_shape = match array_fn!(array, chunk_subset, shard_indices) {
+        Ok(shard_subset) => shard_subset.shape().to_vec(),
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+        }
+    };
+    for (dim, ((&start, &shape), &size)) in
+        std::iter::zip(std::iter::zip(subset_start, subset_shape), &shard_shape).enumerate()
+    {
+        if start.checked_add(shape).is_none_or(|end| end > size) {
+            *LAST_ERROR.lock().unwrap() = format!(
+                "the subset with start {subset_start:?} and shape {subset_shape:?} in dimension {dim} is out of bounds of the shard shape {shard_shape:?}"
+            );
+            return ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS;
+        }
+    }
+    let shard_subset = ArraySubset::from(
+        std::iter::zip(subset_start, subset_shape).map(|(&start, &shape)| start..start + shape),
+    );
+    let Some(data_type_size) = array_fn!(array, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let expected_length = shard_subset.num_elements_usize() * data_type_size;
+    if expected_length != subsetBytesCount {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "subset_bytes_length {subsetBytesCount} does not match expected length {expected_length}"
+        );
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+
+    // Store the subset bytes
+    let result = match array {
+        ZarrsArrayEnum::RW(array) => zarrsArrayStoreInnerChunkSubsetImpl(
+            array,
+            cache,
+            shard_indices,
+            &shard_subset,
+            subset_bytes,
+        ),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayStoreInnerChunkSubsetImpl(
+            array,
+            cache,
+            shard_indices,
+            &shard_subset,
+            subset_bytes,
+        ),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read/write capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_encoded(1);
     }
+    result
 }
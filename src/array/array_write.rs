@@ -1,11 +1,31 @@
-use zarrs::{array::Array, array::ArrayBytes, storage::WritableStorageTraits};
+use std::borrow::Cow;
+
+use zarrs::{
+    array::{Array, ArrayBytes, ArrayMetadata, ArrayMetadataOptions, ArraySubset, CodecOptions},
+    config::{MetadataConvertVersion, MetadataEraseVersion},
+    metadata::v2::ArrayMetadataV2,
+    storage::WritableStorageTraits,
+};
 
 use crate::{
     LAST_ERROR, ZarrsResult,
-    array::{ZarrsArray, ZarrsArrayEnum},
+    array::{ZarrsArray, ZarrsArray_T, ZarrsArrayEnum},
+};
+
+use super::{
+    array_fn, array_metadata_v2::array_metadata_to_v2, validate_chunk_indices, validated_chunks,
 };
 
-use super::array_fn;
+/// The Zarr version of the metadata written by `zarrsArrayStoreMetadataOpt`.
+#[repr(i32)]
+pub enum ZarrsMetadataVersion {
+    /// Write the metadata in its current Zarr version.
+    ZARRS_METADATA_KEEP_CURRENT = 0,
+    /// Write Zarr V3 metadata, converting Zarr V2 metadata if necessary.
+    ZARRS_METADATA_FORCE_V3 = 1,
+    /// Write Zarr V2 metadata, converting Zarr V3 metadata if it can be expressed in Zarr V2.
+    ZARRS_METADATA_FORCE_V2 = 2,
+}
 
 fn zarrsArrayStoreMetadataImpl<T: WritableStorageTraits + ?Sized + 'static>(
     array: &Array<T>,
@@ -44,6 +64,119 @@ pub unsafe extern "C" fn zarrsArrayStoreMetadata(array: ZarrsArray) -> ZarrsResu
     }
 }
 
+/// Create an array with the same storage and path as `array` with its metadata converted to Zarr V2.
+///
+/// Returns a description of the first feature that cannot be expressed in Zarr V2 on failure.
+fn array_as_v2<T: WritableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+) -> Result<Array<T>, String> {
+    let mut metadata = array_metadata_to_v2(&array.metadata())?;
+    metadata["attributes"] = array.attributes().clone().into();
+    let metadata: ArrayMetadataV2 =
+        serde_json::from_value(metadata).map_err(|err| err.to_string())?;
+    Array::new_with_metadata(
+        array.storage(),
+        array.path().as_str(),
+        ArrayMetadata::V2(metadata),
+    )
+    .map_err(|err| err.to_string())
+}
+
+fn zarrsArrayStoreMetadataOptImpl<T: WritableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    target: ZarrsMetadataVersion,
+    erase_other_version: bool,
+) -> ZarrsResult {
+    let is_v2 = matches!(array.metadata(), ArrayMetadata::V2(_));
+    let stored = match target {
+        ZarrsMetadataVersion::ZARRS_METADATA_KEEP_CURRENT => array.store_metadata().map(|()| is_v2),
+        ZarrsMetadataVersion::ZARRS_METADATA_FORCE_V3 => array
+            .store_metadata_opt(
+                &ArrayMetadataOptions::default()
+                    .with_metadata_convert_version(MetadataConvertVersion::V3),
+            )
+            .map(|()| false),
+        ZarrsMetadataVersion::ZARRS_METADATA_FORCE_V2 if is_v2 => {
+            array.store_metadata().map(|()| true)
+        }
+        ZarrsMetadataVersion::ZARRS_METADATA_FORCE_V2 => match array_as_v2(array) {
+            Ok(array_v2) => array_v2.store_metadata().map(|()| true),
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err;
+                return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+            }
+        },
+    };
+    let stored_v2 = match stored {
+        Ok(stored_v2) => stored_v2,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE;
+        }
+    };
+
+    if erase_other_version {
+        let other_version = if stored_v2 {
+            MetadataEraseVersion::V3
+        } else {
+            MetadataEraseVersion::V2
+        };
+        if let Err(err) = array.erase_metadata_opt(other_version) {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE;
+        }
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Store array metadata with a target Zarr version.
+///
+/// `target` selects the Zarr version of the stored metadata:
+/// - `ZARRS_METADATA_KEEP_CURRENT` stores the metadata in its current version, equivalent to `zarrsArrayStoreMetadata`.
+/// - `ZARRS_METADATA_FORCE_V3` stores Zarr V3 metadata (`zarr.json`), converting Zarr V2 metadata.
+/// - `ZARRS_METADATA_FORCE_V2` stores Zarr V2 metadata (`.zarray` and `.zattrs`), converting Zarr V3 metadata as in `zarrsArrayGetMetadataV2String`.
+///
+/// The array handle itself is not converted, so later calls to `zarrsArrayStoreMetadata` store the metadata in its current version.
+/// If `eraseOtherVersion` is true, the metadata of the other Zarr version is erased after storing (e.g. `.zarray` and `.zattrs` are erased after storing `zarr.json`).
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the metadata cannot be expressed in Zarr V2 (e.g. sharding or an extension data type).
+///   The last error names the feature that prevents the conversion.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the metadata could not be stored or erased.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `target` must be a valid `ZarrsMetadataVersion`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayStoreMetadataOpt(
+    array: ZarrsArray,
+    target: ZarrsMetadataVersion,
+    eraseOtherVersion: bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    match array {
+        ZarrsArrayEnum::W(array) => {
+            zarrsArrayStoreMetadataOptImpl(array, target, eraseOtherVersion)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayStoreMetadataOptImpl(array, target, eraseOtherVersion)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayStoreMetadataOptImpl(array, target, eraseOtherVersion)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have write capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    }
+}
+
 fn zarrsArrayStoreChunkImpl<T: WritableStorageTraits + ?Sized + 'static>(
     array: &Array<T>,
     chunk_indices: &[u64],
@@ -82,7 +215,7 @@ pub unsafe extern "C" fn zarrsArrayStoreChunk(
         return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
     // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
-    let array = unsafe { &**array };
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
     // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
     let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
     // SAFETY: pChunkBytes points to an array of length chunkBytesCount per the function's safety contract.
@@ -110,7 +243,7 @@ pub unsafe extern "C" fn zarrsArrayStoreChunk(
     }
 
     // Store the chunk bytes
-    match array {
+    let result = match array {
         ZarrsArrayEnum::W(array) => zarrsArrayStoreChunkImpl(array, chunk_indices, chunk_bytes),
         ZarrsArrayEnum::RW(array) => zarrsArrayStoreChunkImpl(array, chunk_indices, chunk_bytes),
         ZarrsArrayEnum::RWL(array) => zarrsArrayStoreChunkImpl(array, chunk_indices, chunk_bytes),
@@ -118,5 +251,286 @@ pub unsafe extern "C" fn zarrsArrayStoreChunk(
             *LAST_ERROR.lock().unwrap() = "storage does not have write capability".to_string();
             ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
         }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_encoded(1);
+    }
+    result
+}
+
+fn zarrsArrayStoreChunksImpl<T: WritableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunks: &ArraySubset,
+    bytes: &[u8],
+) -> ZarrsResult {
+    let array_bytes: ArrayBytes<'static> = ArrayBytes::new_flen(bytes.to_vec());
+    if let Err(err) = array.store_chunks(chunks, array_bytes) {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        ZarrsResult::ZARRS_ERROR_ARRAY
+    } else {
+        ZarrsResult::ZARRS_SUCCESS
+    }
+}
+
+/// Store a rectangular region of chunks.
+///
+/// `pChunksStart` and `pChunksShape` are pointers to arrays of length `dimensionality` holding the start and shape of the chunk range.
+/// `pBytes` is a pointer to an array of bytes of length `bytesCount` holding the data of the array subset covered by the chunks in C order.
+/// `bytesCount` must match the size of the array subset returned by `zarrsArrayGetChunksSubset()` (its number of elements multiplied by the data type size), as for `zarrsArrayRetrieveChunks`.
+/// Chunks on the array boundary are stored in full, so the array subset includes the elements of those chunks outside of the array shape.
+///
+/// The chunks are split, encoded, and stored in parallel.
+/// Existing chunks in the range are replaced.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk range extends beyond the chunk grid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+/// - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the size of the chunks.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the chunks cannot be encoded or stored.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunksStart` and `pChunksShape`.
+/// `pBytes` must point to an array of length `bytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayStoreChunks(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunksStart: *const u64,
+    pChunksShape: *const u64,
+    bytesCount: usize,
+    pBytes: *const u8,
+) -> ZarrsResult {
+    // Validation
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let ZarrsArray_T(array, stats) = unsafe { &*array };
+    // SAFETY: pChunksStart and pChunksShape point to arrays of length dimensionality per the function's safety contract.
+    let chunks_start = unsafe { std::slice::from_raw_parts(pChunksStart, dimensionality) };
+    let chunks_shape = unsafe { std::slice::from_raw_parts(pChunksShape, dimensionality) };
+    // SAFETY: pBytes points to an array of length bytesCount per the function's safety contract.
+    let bytes = unsafe { std::slice::from_raw_parts(pBytes, bytesCount) };
+    let chunks = match validated_chunks(array, chunks_start, chunks_shape) {
+        Ok(chunks) => chunks,
+        Err(result) => return result,
+    };
+    let array_subset = match array_fn!(array, chunks_subset, &chunks) {
+        Ok(array_subset) => array_subset,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_INVALID_INDICES;
+        }
+    };
+    let Some(data_type_size) = array_fn!(array, data_type).fixed_size() else {
+        *LAST_ERROR.lock().unwrap() = "variable size data types are not supported".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    let expected_length = array_subset.num_elements_usize() * data_type_size;
+    if bytesCount != expected_length {
+        *LAST_ERROR.lock().unwrap() =
+            format!("bytes_length {bytesCount} does not match expected length {expected_length}");
+        return ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH;
+    }
+
+    // Store the chunks bytes
+    let result = match array {
+        ZarrsArrayEnum::W(array) => zarrsArrayStoreChunksImpl(array, &chunks, bytes),
+        ZarrsArrayEnum::RW(array) => zarrsArrayStoreChunksImpl(array, &chunks, bytes),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayStoreChunksImpl(array, &chunks, bytes),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have write capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    };
+    if matches!(result, ZarrsResult::ZARRS_SUCCESS) {
+        stats.record_encoded(chunks.num_elements());
+    }
+    result
+}
+
+/// Check that `encoded_bytes` decode with the codec pipeline of the array to a chunk at `chunk_indices`.
+///
+/// Sets the last error on failure.
+fn validate_encoded_chunk<T: ?Sized + 'static>(
+    array: &Array<T>,
+    chunk_indices: &[u64],
+    encoded_bytes: &[u8],
+) -> Result<(), ZarrsResult> {
+    let chunk_representation = array
+        .chunk_array_representation(chunk_indices)
+        .map_err(|err| {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        })?;
+    let decoded = array
+        .codecs()
+        .decode(
+            Cow::Borrowed(encoded_bytes),
+            &chunk_representation,
+            &CodecOptions::default(),
+        )
+        .map_err(|err| {
+            *LAST_ERROR.lock().unwrap() =
+                format!("encoded chunk does not match the codecs of the array: {err}");
+            ZarrsResult::ZARRS_ERROR_ARRAY
+        })?;
+    if let (Some(data_type_size), Ok(decoded)) =
+        (array.data_type().fixed_size(), decoded.into_fixed())
+    {
+        let expected_length = chunk_representation.num_elements_usize() * data_type_size;
+        if decoded.len() != expected_length {
+            *LAST_ERROR.lock().unwrap() = format!(
+                "encoded chunk decodes to {} bytes, but the chunk has {expected_length} bytes",
+                decoded.len()
+            );
+            return Err(ZarrsResult::ZARRS_ERROR_ARRAY);
+        }
+    }
+    Ok(())
+}
+
+fn zarrsArrayStoreEncodedChunkImpl<T: WritableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunk_indices: &[u64],
+    encoded_bytes: &[u8],
+    validate: bool,
+) -> ZarrsResult {
+    if validate && let Err(result) = validate_encoded_chunk(array, chunk_indices, encoded_bytes) {
+        return result;
+    }
+    let chunk_key = array.chunk_key(chunk_indices);
+    match array
+        .storage()
+        .set(&chunk_key, encoded_bytes.to_vec().into())
+    {
+        Ok(()) => ZarrsResult::ZARRS_SUCCESS,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE
+        }
+    }
+}
+
+/// Store an encoded chunk.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+/// `pEncodedBytes` is a pointer to an array of bytes of length `encodedBytesCount` holding the encoded chunk.
+///
+/// The encoded bytes are written directly to the store key of the chunk without passing through the codec pipeline of the array.
+/// This is intended for importing chunks that have already been encoded (e.g. compressed) elsewhere.
+/// If `validate` is true, the bytes are decoded once before they are written to check that they are encoded according to the codec pipeline of the array and decode to the chunk shape.
+/// Otherwise, the caller is responsible for ensuring that the bytes are correctly encoded, and incorrectly encoded chunks will fail to decode when they are retrieved.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are outside of the chunk grid or `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `validate` is true and the encoded chunk does not decode to the chunk shape.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the encoded chunk could not be written to the store.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+/// `pEncodedBytes` must point to an array of length `encodedBytesCount`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayStoreEncodedChunk(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+    encodedBytesCount: usize,
+    pEncodedBytes: *const u8,
+    validate: bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+    // SAFETY: pEncodedBytes points to an array of length encodedBytesCount per the function's safety contract.
+    let encoded_bytes = unsafe { std::slice::from_raw_parts(pEncodedBytes, encodedBytesCount) };
+
+    if let Err(result) = validate_chunk_indices(array, chunk_indices) {
+        return result;
+    }
+
+    match array {
+        ZarrsArrayEnum::W(array) => {
+            zarrsArrayStoreEncodedChunkImpl(array, chunk_indices, encoded_bytes, validate)
+        }
+        ZarrsArrayEnum::RW(array) => {
+            zarrsArrayStoreEncodedChunkImpl(array, chunk_indices, encoded_bytes, validate)
+        }
+        ZarrsArrayEnum::RWL(array) => {
+            zarrsArrayStoreEncodedChunkImpl(array, chunk_indices, encoded_bytes, validate)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have write capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    }
+}
+
+fn zarrsArrayEraseChunkImpl<T: WritableStorageTraits + ?Sized + 'static>(
+    array: &Array<T>,
+    chunk_indices: &[u64],
+) -> ZarrsResult {
+    match array.erase_chunk(chunk_indices) {
+        Ok(()) => ZarrsResult::ZARRS_SUCCESS,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE
+        }
+    }
+}
+
+/// Erase a chunk.
+///
+/// `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+///
+/// The store key of the chunk is erased, so the chunk is subsequently retrieved as the fill value.
+/// Erasing a chunk that is not stored succeeds.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are outside of the chunk grid or `dimensionality` does not match the array dimensionality.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the chunk could not be erased from the store.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pChunkIndices` must point to an array of length `dimensionality`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayEraseChunk(
+    array: ZarrsArray,
+    dimensionality: usize,
+    pChunkIndices: *const u64,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    // SAFETY: pChunkIndices points to an array of length dimensionality per the function's safety contract.
+    let chunk_indices = unsafe { std::slice::from_raw_parts(pChunkIndices, dimensionality) };
+
+    if let Err(result) = validate_chunk_indices(array, chunk_indices) {
+        return result;
+    }
+
+    match array {
+        ZarrsArrayEnum::W(array) => zarrsArrayEraseChunkImpl(array, chunk_indices),
+        ZarrsArrayEnum::RW(array) => zarrsArrayEraseChunkImpl(array, chunk_indices),
+        ZarrsArrayEnum::RWL(array) => zarrsArrayEraseChunkImpl(array, chunk_indices),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have write capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
     }
 }
@@ -0,0 +1,7 @@
+/// A zarrs chunk grid type.
+#[repr(i32)]
+pub enum ZarrsChunkGridType {
+    ZARRS_CHUNK_GRID_REGULAR = 0,
+    ZARRS_CHUNK_GRID_RECTANGULAR = 1,
+    ZARRS_CHUNK_GRID_OTHER = 2,
+}
@@ -1,3 +1,9 @@
+use zarrs::array::{DataType, data_type as dt};
+
+use crate::{LAST_ERROR, ZarrsResult};
+
+use super::{ZarrsArray, array_fn};
+
 /// A zarrs data type.
 #[repr(i32)]
 pub enum ZarrsDataType {
@@ -18,4 +24,436 @@ pub enum ZarrsDataType {
     ZARRS_COMPLEX128 = 13,
     ZARRS_RAW_BITS = 14,
     ZARRS_BFLOAT16 = 15,
+    ZARRS_STRING = 16,
+}
+
+/// Get the [`ZarrsDataType`] corresponding to a data type.
+///
+/// Returns [`ZarrsDataType::ZARRS_UNDEFINED`] if the data type has no corresponding [`ZarrsDataType`].
+pub(crate) fn zarrs_data_type(data_type: &DataType) -> ZarrsDataType {
+    if data_type.is::<dt::BoolDataType>() {
+        ZarrsDataType::ZARRS_BOOL
+    } else if data_type.is::<dt::Int8DataType>() {
+        ZarrsDataType::ZARRS_INT8
+    } else if data_type.is::<dt::Int16DataType>() {
+        ZarrsDataType::ZARRS_INT16
+    } else if data_type.is::<dt::Int32DataType>() {
+        ZarrsDataType::ZARRS_INT32
+    } else if data_type.is::<dt::Int64DataType>() {
+        ZarrsDataType::ZARRS_INT64
+    } else if data_type.is::<dt::UInt8DataType>() {
+        ZarrsDataType::ZARRS_UINT8
+    } else if data_type.is::<dt::UInt16DataType>() {
+        ZarrsDataType::ZARRS_UINT16
+    } else if data_type.is::<dt::UInt32DataType>() {
+        ZarrsDataType::ZARRS_UINT32
+    } else if data_type.is::<dt::UInt64DataType>() {
+        ZarrsDataType::ZARRS_UINT64
+    } else if data_type.is::<dt::Float16DataType>() {
+        ZarrsDataType::ZARRS_FLOAT16
+    } else if data_type.is::<dt::Float32DataType>() {
+        ZarrsDataType::ZARRS_FLOAT32
+    } else if data_type.is::<dt::Float64DataType>() {
+        ZarrsDataType::ZARRS_FLOAT64
+    } else if data_type.is::<dt::BFloat16DataType>() {
+        ZarrsDataType::ZARRS_BFLOAT16
+    } else if data_type.is::<dt::Complex64DataType>() {
+        ZarrsDataType::ZARRS_COMPLEX64
+    } else if data_type.is::<dt::Complex128DataType>() {
+        ZarrsDataType::ZARRS_COMPLEX128
+    } else if data_type.is::<dt::RawBitsDataType>() {
+        ZarrsDataType::ZARRS_RAW_BITS
+    } else if data_type.is::<dt::StringDataType>() {
+        ZarrsDataType::ZARRS_STRING
+    } else {
+        ZarrsDataType::ZARRS_UNDEFINED
+    }
+}
+
+/// Convert the native endian bytes of a numeric element to a `f64`.
+///
+/// Data types that widen losslessly to `f64` (including half precision floats) are converted with [`element_converter`].
+/// 64-bit integers are converted with a possible loss of precision.
+/// Returns [`None`] if the data type is not a supported numeric data type.
+pub(crate) fn element_to_f64(data_type: &DataType, bytes: &[u8]) -> Option<f64> {
+    if let Some(convert) =
+        element_converter(&zarrs_data_type(data_type), &ZarrsDataType::ZARRS_FLOAT64)
+    {
+        let mut value = [0u8; size_of::<f64>()];
+        convert(bytes, &mut value);
+        return Some(f64::from_ne_bytes(value));
+    }
+    let value = if data_type.is::<dt::BoolDataType>() {
+        f64::from(*bytes.first()? != 0)
+    } else if data_type.is::<dt::Int64DataType>() {
+        i64::from_ne_bytes(bytes.try_into().ok()?) as f64
+    } else if data_type.is::<dt::UInt64DataType>() {
+        u64::from_ne_bytes(bytes.try_into().ok()?) as f64
+    } else {
+        return None;
+    };
+    Some(value)
+}
+
+/// Convert a `f64` to the native endian bytes of a numeric element.
+///
+/// Integer conversions saturate and truncate towards zero, and floating point conversions round to the nearest representable value.
+/// Returns [`None`] if the data type is not a supported numeric data type.
+pub(crate) fn f64_to_element(data_type: &DataType, value: f64) -> Option<Vec<u8>> {
+    macro_rules! to_ne {
+        ($t:ty) => {
+            (value as $t).to_ne_bytes().to_vec()
+        };
+    }
+    let bytes = if data_type.is::<dt::BoolDataType>() {
+        vec![u8::from(value != 0.0)]
+    } else if data_type.is::<dt::Int8DataType>() {
+        to_ne!(i8)
+    } else if data_type.is::<dt::Int16DataType>() {
+        to_ne!(i16)
+    } else if data_type.is::<dt::Int32DataType>() {
+        to_ne!(i32)
+    } else if data_type.is::<dt::Int64DataType>() {
+        to_ne!(i64)
+    } else if data_type.is::<dt::UInt8DataType>() {
+        to_ne!(u8)
+    } else if data_type.is::<dt::UInt16DataType>() {
+        to_ne!(u16)
+    } else if data_type.is::<dt::UInt32DataType>() {
+        to_ne!(u32)
+    } else if data_type.is::<dt::UInt64DataType>() {
+        to_ne!(u64)
+    } else if data_type.is::<dt::Float16DataType>() {
+        half::f16::from_f64(value).to_ne_bytes().to_vec()
+    } else if data_type.is::<dt::BFloat16DataType>() {
+        half::bf16::from_f64(value).to_ne_bytes().to_vec()
+    } else if data_type.is::<dt::Float32DataType>() {
+        to_ne!(f32)
+    } else if data_type.is::<dt::Float64DataType>() {
+        to_ne!(f64)
+    } else {
+        return None;
+    };
+    Some(bytes)
+}
+
+/// Convert a float to a JSON value.
+///
+/// Non-finite values are represented as the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+fn float_to_json(value: f64) -> serde_json::Value {
+    if value.is_nan() {
+        "NaN".into()
+    } else if value == f64::INFINITY {
+        "Infinity".into()
+    } else if value == f64::NEG_INFINITY {
+        "-Infinity".into()
+    } else {
+        value.into()
+    }
+}
+
+/// Convert the native endian bytes of an element to a JSON value.
+///
+/// Complex elements are represented as a `[real, imaginary]` pair.
+/// Returns [`None`] if the data type is not supported.
+pub(crate) fn element_to_json(data_type: &DataType, bytes: &[u8]) -> Option<serde_json::Value> {
+    macro_rules! from_ne {
+        ($t:ty) => {
+            serde_json::Value::from(<$t>::from_ne_bytes(bytes.try_into().ok()?))
+        };
+    }
+    macro_rules! float_from_ne {
+        ($t:ty, $bytes:expr) => {
+            float_to_json(f64::from(<$t>::from_ne_bytes($bytes.try_into().ok()?)))
+        };
+    }
+    let value = if data_type.is::<dt::BoolDataType>() {
+        serde_json::Value::Bool(*bytes.first()? != 0)
+    } else if data_type.is::<dt::Int8DataType>() {
+        from_ne!(i8)
+    } else if data_type.is::<dt::Int16DataType>() {
+        from_ne!(i16)
+    } else if data_type.is::<dt::Int32DataType>() {
+        from_ne!(i32)
+    } else if data_type.is::<dt::Int64DataType>() {
+        from_ne!(i64)
+    } else if data_type.is::<dt::UInt8DataType>() {
+        from_ne!(u8)
+    } else if data_type.is::<dt::UInt16DataType>() {
+        from_ne!(u16)
+    } else if data_type.is::<dt::UInt32DataType>() {
+        from_ne!(u32)
+    } else if data_type.is::<dt::UInt64DataType>() {
+        from_ne!(u64)
+    } else if data_type.is::<dt::Float32DataType>() {
+        float_from_ne!(f32, bytes)
+    } else if data_type.is::<dt::Float64DataType>() {
+        float_from_ne!(f64, bytes)
+    } else if data_type.is::<dt::Complex64DataType>() {
+        let (re, im) = bytes.split_at_checked(4)?;
+        serde_json::json!([float_from_ne!(f32, re), float_from_ne!(f32, im)])
+    } else if data_type.is::<dt::Complex128DataType>() {
+        let (re, im) = bytes.split_at_checked(8)?;
+        serde_json::json!([float_from_ne!(f64, re), float_from_ne!(f64, im)])
+    } else {
+        return None;
+    };
+    Some(value)
+}
+
+/// The category and size of a [`ZarrsDataType`].
+struct DataTypeProperties {
+    is_float: bool,
+    is_complex: bool,
+    is_integer: bool,
+    is_signed: bool,
+    /// The size of an element in bytes, or zero if it is not determined by the [`ZarrsDataType`] alone.
+    fixed_size: usize,
+}
+
+impl ZarrsDataType {
+    /// Returns the properties of the data type, or [`None`] if it is [`ZarrsDataType::ZARRS_UNDEFINED`].
+    fn properties(&self) -> Option<DataTypeProperties> {
+        let (is_float, is_complex, is_integer, is_signed, fixed_size) = match self {
+            Self::ZARRS_UNDEFINED => return None,
+            Self::ZARRS_BOOL => (false, false, false, false, 1),
+            Self::ZARRS_INT8 => (false, false, true, true, 1),
+            Self::ZARRS_INT16 => (false, false, true, true, 2),
+            Self::ZARRS_INT32 => (false, false, true, true, 4),
+            Self::ZARRS_INT64 => (false, false, true, true, 8),
+            Self::ZARRS_UINT8 => (false, false, true, false, 1),
+            Self::ZARRS_UINT16 => (false, false, true, false, 2),
+            Self::ZARRS_UINT32 => (false, false, true, false, 4),
+            Self::ZARRS_UINT64 => (false, false, true, false, 8),
+            Self::ZARRS_FLOAT16 | Self::ZARRS_BFLOAT16 => (true, false, false, true, 2),
+            Self::ZARRS_FLOAT32 => (true, false, false, true, 4),
+            Self::ZARRS_FLOAT64 => (true, false, false, true, 8),
+            Self::ZARRS_COMPLEX64 => (false, true, false, true, 8),
+            Self::ZARRS_COMPLEX128 => (false, true, false, true, 16),
+            Self::ZARRS_RAW_BITS | Self::ZARRS_STRING => (false, false, false, false, 0),
+        };
+        Some(DataTypeProperties {
+            is_float,
+            is_complex,
+            is_integer,
+            is_signed,
+            fixed_size,
+        })
+    }
+
+    /// Returns the size of an element in bytes, or [`None`] if it is not determined by the data type alone.
+    pub(crate) fn fixed_size(&self) -> Option<usize> {
+        self.properties()
+            .map(|properties| properties.fixed_size)
+            .filter(|&fixed_size| fixed_size != 0)
+    }
+}
+
+/// Get the properties of a data type.
+///
+/// - `pIsFloat` is set to true for real floating point data types (`float16`, `bfloat16`, `float32`, `float64`).
+/// - `pIsComplex` is set to true for complex data types.
+/// - `pIsInteger` is set to true for signed and unsigned integer data types.
+/// - `pIsSigned` is set to true for signed integer, floating point, and complex data types.
+/// - `pFixedSize` is set to the size of an element in bytes, or zero for `ZARRS_RAW_BITS` as its size depends on the array metadata and `ZARRS_STRING` as it has a variable size.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if `dataType` is `ZARRS_UNDEFINED`.
+///
+/// # Safety
+/// `dataType` must be a valid `ZarrsDataType`.
+/// `pIsFloat`, `pIsComplex`, `pIsInteger`, and `pIsSigned` must be valid pointers to a `bool`.
+/// `pFixedSize` must be a valid pointer to a `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsDataTypeGetProperties(
+    dataType: ZarrsDataType,
+    pIsFloat: *mut bool,
+    pIsComplex: *mut bool,
+    pIsInteger: *mut bool,
+    pIsSigned: *mut bool,
+    pFixedSize: *mut usize,
+) -> ZarrsResult {
+    let Some(properties) = dataType.properties() else {
+        *LAST_ERROR.lock().unwrap() = "the data type is undefined".to_string();
+        return ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE;
+    };
+    // SAFETY: the pointers are valid per the function's safety contract.
+    unsafe {
+        *pIsFloat = properties.is_float;
+        *pIsComplex = properties.is_complex;
+        *pIsInteger = properties.is_integer;
+        *pIsSigned = properties.is_signed;
+        *pFixedSize = properties.fixed_size;
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Set `pValue` to a property of the data type of `array`.
+///
+/// Data types without a corresponding [`ZarrsDataType`] have no properties set.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pValue` must be a valid pointer to a `bool`.
+unsafe fn array_data_type_property(
+    array: ZarrsArray,
+    pValue: *mut bool,
+    property: impl Fn(&DataTypeProperties) -> bool,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let value = zarrs_data_type(array_fn!(array, data_type))
+        .properties()
+        .is_some_and(|properties| property(&properties));
+    // SAFETY: pValue is a valid pointer per the function's safety contract.
+    unsafe { *pValue = value };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Check whether the data type of the array is a complex data type.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pIsComplex` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetDataTypeIsComplex(
+    array: ZarrsArray,
+    pIsComplex: *mut bool,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of array_data_type_property.
+    unsafe { array_data_type_property(array, pIsComplex, |properties| properties.is_complex) }
+}
+
+/// Check whether the data type of the array is a real floating point data type.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pIsFloat` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetDataTypeIsFloat(
+    array: ZarrsArray,
+    pIsFloat: *mut bool,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of array_data_type_property.
+    unsafe { array_data_type_property(array, pIsFloat, |properties| properties.is_float) }
+}
+
+/// Check whether the data type of the array is a signed or unsigned integer data type.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pIsInteger` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetDataTypeIsInteger(
+    array: ZarrsArray,
+    pIsInteger: *mut bool,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of array_data_type_property.
+    unsafe { array_data_type_property(array, pIsInteger, |properties| properties.is_integer) }
+}
+
+/// Check whether the data type of the array is signed.
+///
+/// Signed integer, floating point, and complex data types are signed.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pIsSigned` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetDataTypeIsSigned(
+    array: ZarrsArray,
+    pIsSigned: *mut bool,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of array_data_type_property.
+    unsafe { array_data_type_property(array, pIsSigned, |properties| properties.is_signed) }
+}
+
+/// Converts native endian elements from an input buffer to an output buffer.
+pub(crate) type ElementConverter = fn(&[u8], &mut [u8]);
+
+/// Get a converter of native endian elements from `input_data_type` to `output_data_type`.
+///
+/// Only lossless widening numeric conversions (and conversions to the same data type) are supported.
+/// Returns [`None`] if the conversion is not supported.
+pub(crate) fn element_converter(
+    input_data_type: &ZarrsDataType,
+    output_data_type: &ZarrsDataType,
+) -> Option<ElementConverter> {
+    macro_rules! convert {
+        ($in:ty, $out:ty) => {
+            Some(
+                (|input: &[u8], output: &mut [u8]| {
+                    let input = input.chunks_exact(size_of::<$in>());
+                    let output = output.chunks_exact_mut(size_of::<$out>());
+                    for (input, output) in std::iter::zip(input, output) {
+                        let value = <$in>::from_ne_bytes(input.try_into().unwrap());
+                        output.copy_from_slice(&<$out>::from(value).to_ne_bytes());
+                    }
+                }) as ElementConverter,
+            )
+        };
+    }
+    use ZarrsDataType as Z;
+    match (input_data_type, output_data_type) {
+        (Z::ZARRS_UNDEFINED, _) | (_, Z::ZARRS_UNDEFINED) => None,
+        _ if std::mem::discriminant(input_data_type)
+            == std::mem::discriminant(output_data_type) =>
+        {
+            Some(
+                (|input: &[u8], output: &mut [u8]| output.copy_from_slice(input))
+                    as ElementConverter,
+            )
+        }
+        (Z::ZARRS_INT8, Z::ZARRS_INT16) => convert!(i8, i16),
+        (Z::ZARRS_INT8, Z::ZARRS_INT32) => convert!(i8, i32),
+        (Z::ZARRS_INT8, Z::ZARRS_INT64) => convert!(i8, i64),
+        (Z::ZARRS_INT8, Z::ZARRS_FLOAT32) => convert!(i8, f32),
+        (Z::ZARRS_INT8, Z::ZARRS_FLOAT64) => convert!(i8, f64),
+        (Z::ZARRS_INT16, Z::ZARRS_INT32) => convert!(i16, i32),
+        (Z::ZARRS_INT16, Z::ZARRS_INT64) => convert!(i16, i64),
+        (Z::ZARRS_INT16, Z::ZARRS_FLOAT32) => convert!(i16, f32),
+        (Z::ZARRS_INT16, Z::ZARRS_FLOAT64) => convert!(i16, f64),
+        (Z::ZARRS_INT32, Z::ZARRS_INT64) => convert!(i32, i64),
+        (Z::ZARRS_INT32, Z::ZARRS_FLOAT64) => convert!(i32, f64),
+        (Z::ZARRS_UINT8, Z::ZARRS_UINT16) => convert!(u8, u16),
+        (Z::ZARRS_UINT8, Z::ZARRS_UINT32) => convert!(u8, u32),
+        (Z::ZARRS_UINT8, Z::ZARRS_UINT64) => convert!(u8, u64),
+        (Z::ZARRS_UINT8, Z::ZARRS_INT16) => convert!(u8, i16),
+        (Z::ZARRS_UINT8, Z::ZARRS_INT32) => convert!(u8, i32),
+        (Z::ZARRS_UINT8, Z::ZARRS_INT64) => convert!(u8, i64),
+        (Z::ZARRS_UINT8, Z::ZARRS_FLOAT32) => convert!(u8, f32),
+        (Z::ZARRS_UINT8, Z::ZARRS_FLOAT64) => convert!(u8, f64),
+        (Z::ZARRS_UINT16, Z::ZARRS_UINT32) => convert!(u16, u32),
+        (Z::ZARRS_UINT16, Z::ZARRS_UINT64) => convert!(u16, u64),
+        (Z::ZARRS_UINT16, Z::ZARRS_INT32) => convert!(u16, i32),
+        (Z::ZARRS_UINT16, Z::ZARRS_INT64) => convert!(u16, i64),
+        (Z::ZARRS_UINT16, Z::ZARRS_FLOAT32) => convert!(u16, f32),
+        (Z::ZARRS_UINT16, Z::ZARRS_FLOAT64) => convert!(u16, f64),
+        (Z::ZARRS_UINT32, Z::ZARRS_UINT64) => convert!(u32, u64),
+        (Z::ZARRS_UINT32, Z::ZARRS_INT64) => convert!(u32, i64),
+        (Z::ZARRS_UINT32, Z::ZARRS_FLOAT64) => convert!(u32, f64),
+        (Z::ZARRS_FLOAT16, Z::ZARRS_FLOAT32) => convert!(half::f16, f32),
+        (Z::ZARRS_FLOAT16, Z::ZARRS_FLOAT64) => convert!(half::f16, f64),
+        (Z::ZARRS_BFLOAT16, Z::ZARRS_FLOAT32) => convert!(half::bf16, f32),
+        (Z::ZARRS_BFLOAT16, Z::ZARRS_FLOAT64) => convert!(half::bf16, f64),
+        (Z::ZARRS_FLOAT32, Z::ZARRS_FLOAT64) => convert!(f32, f64),
+        // The real and imaginary components are converted independently
+        (Z::ZARRS_COMPLEX64, Z::ZARRS_COMPLEX128) => convert!(f32, f64),
+        _ => None,
+    }
 }
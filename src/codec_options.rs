@@ -0,0 +1,187 @@
+use zarrs::array::CodecOptions;
+
+use crate::ZarrsResult;
+
+#[doc(hidden)]
+pub struct ZarrsCodecOptions_T(pub CodecOptions);
+
+impl std::ops::Deref for ZarrsCodecOptions_T {
+    type Target = CodecOptions;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for ZarrsCodecOptions_T {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// An opaque handle to zarrs [`CodecOptions`].
+pub type ZarrsCodecOptions = *mut ZarrsCodecOptions_T;
+
+/// Get the codec options referenced by a `ZarrsCodecOptions` handle, or the default codec options if it is null.
+///
+/// # Safety
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle that outlives the returned reference.
+pub(crate) unsafe fn codec_options_or_default<'a>(
+    codecOptions: ZarrsCodecOptions,
+    default: &'a CodecOptions,
+) -> &'a CodecOptions {
+    if codecOptions.is_null() {
+        default
+    } else {
+        // SAFETY: codecOptions is not null, and the caller guarantees it is a valid ZarrsCodecOptions handle.
+        unsafe { &**codecOptions }
+    }
+}
+
+/// Create a handle to new default codec options.
+///
+/// `pCodecOptions` is a pointer to a handle in which the created `ZarrsCodecOptions` is returned.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `pCodecOptions` is a null pointer.
+///
+/// # Safety
+/// If not null, `pCodecOptions` must be a valid pointer to a `ZarrsCodecOptions` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCreateCodecOptions(
+    pCodecOptions: *mut ZarrsCodecOptions,
+) -> ZarrsResult {
+    if pCodecOptions.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: pCodecOptions is not null, and the caller guarantees it is a valid pointer.
+    unsafe {
+        *pCodecOptions = Box::into_raw(Box::new(ZarrsCodecOptions_T(CodecOptions::default())));
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Destroy codec options.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+///
+/// # Safety
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsDestroyCodecOptions(codecOptions: ZarrsCodecOptions) -> ZarrsResult {
+    if codecOptions.is_null() {
+        ZarrsResult::ZARRS_ERROR_NULL_PTR
+    } else {
+        // SAFETY: codecOptions is not null, and the caller guarantees it is a valid ZarrsCodecOptions handle.
+        unsafe { codecOptions.to_owned().drop_in_place() };
+        ZarrsResult::ZARRS_SUCCESS
+    }
+}
+
+/// Get the maximum concurrency (concurrent target) of codec options.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+///
+/// # Safety
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+/// `pMaxConcurrency` must be a valid pointer to a `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCodecOptionsGetMaxConcurrency(
+    codecOptions: ZarrsCodecOptions,
+    pMaxConcurrency: *mut usize,
+) -> ZarrsResult {
+    if codecOptions.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: codecOptions is not null, and the caller guarantees it is a valid ZarrsCodecOptions handle.
+    let codecOptions = unsafe { &**codecOptions };
+    // SAFETY: pMaxConcurrency is a valid pointer per the function's safety contract.
+    unsafe { *pMaxConcurrency = codecOptions.concurrent_target() };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Set the maximum concurrency (concurrent target) of codec options.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+///
+/// # Safety
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCodecOptionsSetMaxConcurrency(
+    codecOptions: ZarrsCodecOptions,
+    maxConcurrency: usize,
+) -> ZarrsResult {
+    if codecOptions.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: codecOptions is not null, and the caller guarantees it is a valid ZarrsCodecOptions handle.
+    let codecOptions = unsafe { &mut **codecOptions };
+    codecOptions.set_concurrent_target(maxConcurrency);
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Set the concurrency limit of codec options.
+///
+/// This is an alias of `zarrsCodecOptionsSetMaxConcurrency()`, both set the concurrent target of the codec options.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+///
+/// # Safety
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCodecOptionsSetConcurrencyLimit(
+    codecOptions: ZarrsCodecOptions,
+    limit: usize,
+) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of zarrsCodecOptionsSetMaxConcurrency.
+    unsafe { zarrsCodecOptionsSetMaxConcurrency(codecOptions, limit) }
+}
+
+/// Get whether codec options store empty chunks.
+///
+/// If false (the default), chunks composed entirely of the fill value are erased rather than stored.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+///
+/// # Safety
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+/// `pStoreEmptyChunks` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCodecOptionsGetStoreEmptyChunks(
+    codecOptions: ZarrsCodecOptions,
+    pStoreEmptyChunks: *mut bool,
+) -> ZarrsResult {
+    if codecOptions.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: codecOptions is not null, and the caller guarantees it is a valid ZarrsCodecOptions handle.
+    let codecOptions = unsafe { &**codecOptions };
+    // SAFETY: pStoreEmptyChunks is a valid pointer per the function's safety contract.
+    unsafe { *pStoreEmptyChunks = codecOptions.store_empty_chunks() };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Set whether codec options store empty chunks.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+///
+/// # Safety
+/// If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCodecOptionsSetStoreEmptyChunks(
+    codecOptions: ZarrsCodecOptions,
+    storeEmptyChunks: bool,
+) -> ZarrsResult {
+    if codecOptions.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: codecOptions is not null, and the caller guarantees it is a valid ZarrsCodecOptions handle.
+    let codecOptions = unsafe { &mut **codecOptions };
+    codecOptions.set_store_empty_chunks(storeEmptyChunks);
+    ZarrsResult::ZARRS_SUCCESS
+}
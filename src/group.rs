@@ -1,12 +1,18 @@
+pub mod group_consolidated;
 pub mod group_write;
 
 use std::ffi::{CString, c_char};
 
 use ffi_support::FfiStr;
-use zarrs::group::{Group, GroupMetadata};
+use zarrs::{
+    group::{Group, GroupMetadata},
+    storage::{ListableStorageTraits, ReadableStorageTraits, StorageError, StoreKey, StorePrefix},
+};
 
 use crate::{
     LAST_ERROR, ZarrsResult,
+    array::array_list::node_prefix,
+    metadata_zarr_format,
     storage::{ZarrsStorage, ZarrsStorageEnum},
 };
 
@@ -22,7 +28,6 @@ pub enum ZarrsGroupEnum {
     #[allow(dead_code)]
     RL(Group<dyn zarrs::storage::ReadableListableStorageTraits>),
     RW(Group<dyn zarrs::storage::ReadableWritableStorageTraits>),
-    #[allow(dead_code)]
     RWL(Group<dyn zarrs::storage::ReadableWritableListableStorageTraits>),
 }
 
@@ -90,23 +95,30 @@ pub unsafe extern "C" fn zarrsOpenGroupRW(
     // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
     let storage = unsafe { &**storage };
 
-    if let ZarrsStorageEnum::RW(storage) = storage {
-        match Group::open(storage.clone(), path.into()) {
-            Ok(group) => {
-                // SAFETY: pGroup is a valid pointer per the function's safety contract.
-                unsafe {
-                    *pGroup = Box::into_raw(Box::new(ZarrsGroup_T(ZarrsGroupEnum::RW(group))));
-                }
-                ZarrsResult::ZARRS_SUCCESS
-            }
-            Err(err) => {
-                *LAST_ERROR.lock().unwrap() = err.to_string();
-                ZarrsResult::ZARRS_ERROR_GROUP
+    let group = match storage {
+        ZarrsStorageEnum::RW(storage) => {
+            Group::open(storage.clone(), path.into()).map(ZarrsGroupEnum::RW)
+        }
+        ZarrsStorageEnum::RWL(storage) => {
+            Group::open(storage.clone(), path.into()).map(ZarrsGroupEnum::RWL)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not support read and write".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    };
+    match group {
+        Ok(group) => {
+            // SAFETY: pGroup is a valid pointer per the function's safety contract.
+            unsafe {
+                *pGroup = Box::into_raw(Box::new(ZarrsGroup_T(group)));
             }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_GROUP
         }
-    } else {
-        *LAST_ERROR.lock().unwrap() = "storage does not support read and write".to_string();
-        ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
     }
 }
 
@@ -140,23 +152,31 @@ pub unsafe extern "C" fn zarrsCreateGroupRW(
         }
     };
 
-    if let ZarrsStorageEnum::RW(storage) = storage {
-        match Group::new_with_metadata(storage.clone(), path.into(), metadata) {
-            Ok(group) => {
-                // SAFETY: pGroup is a valid pointer per the function's safety contract.
-                unsafe {
-                    *pGroup = Box::into_raw(Box::new(ZarrsGroup_T(ZarrsGroupEnum::RW(group))));
-                }
-                ZarrsResult::ZARRS_SUCCESS
-            }
-            Err(err) => {
-                *LAST_ERROR.lock().unwrap() = err.to_string();
-                ZarrsResult::ZARRS_ERROR_GROUP
+    let group = match storage {
+        ZarrsStorageEnum::RW(storage) => {
+            Group::new_with_metadata(storage.clone(), path.into(), metadata).map(ZarrsGroupEnum::RW)
+        }
+        ZarrsStorageEnum::RWL(storage) => {
+            Group::new_with_metadata(storage.clone(), path.into(), metadata)
+                .map(ZarrsGroupEnum::RWL)
+        }
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not support read and write".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    };
+    match group {
+        Ok(group) => {
+            // SAFETY: pGroup is a valid pointer per the function's safety contract.
+            unsafe {
+                *pGroup = Box::into_raw(Box::new(ZarrsGroup_T(group)));
             }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_GROUP
         }
-    } else {
-        *LAST_ERROR.lock().unwrap() = "storage does not support read and write".to_string();
-        ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
     }
 }
 
@@ -178,6 +198,100 @@ pub unsafe extern "C" fn zarrsDestroyGroup(group: ZarrsGroup) -> ZarrsResult {
     }
 }
 
+/// Returns the Zarr format (2 or 3) of the group metadata.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+/// `pVersion` must be a valid pointer to a `uint32_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupGetZarrFormat(
+    group: ZarrsGroup,
+    pVersion: *mut u32,
+) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+    let version = match group_fn!(group, metadata) {
+        GroupMetadata::V3(_) => 3,
+        GroupMetadata::V2(_) => 2,
+    };
+    // SAFETY: pVersion is a valid pointer per the function's safety contract.
+    unsafe { *pVersion = version };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Returns the `"zarr_format"` of the group metadata.
+///
+/// Unlike `zarrsGroupGetZarrFormat`, this reads the raw value of the `"zarr_format"` field of the serialized metadata rather than deriving it from the metadata variant.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the metadata does not have a `"zarr_format"` that is a positive integer.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+/// `pVersion` must be a valid pointer to a `uint32_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupGetMetadataVersion(
+    group: ZarrsGroup,
+    pVersion: *mut u32,
+) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+    match metadata_zarr_format(serde_json::to_value(group_fn!(group, metadata))) {
+        Ok(version) => {
+            // SAFETY: pVersion is a valid pointer per the function's safety contract.
+            unsafe { *pVersion = version };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Get the store key of the group metadata.
+///
+/// This is `zarr.json` for Zarr V3 metadata and `.zgroup` for Zarr V2 metadata under the group path, e.g. `my/group/zarr.json` for a group at `/my/group` and `zarr.json` for the root group.
+/// Zarr V2 attributes are stored separately in `.zattrs` under the same path.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` or `pKey` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if the group path is not a valid store prefix.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle and `pKey` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupGetMetadataStorageKey(
+    group: ZarrsGroup,
+    pKey: *mut *mut c_char,
+) -> ZarrsResult {
+    if group.is_null() || pKey.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+    let prefix = match node_prefix(group_fn!(group, path).as_str()) {
+        Ok(prefix) => prefix,
+        Err(result) => return result,
+    };
+    let name = match group_fn!(group, metadata) {
+        GroupMetadata::V3(_) => "zarr.json",
+        GroupMetadata::V2(_) => ".zgroup",
+    };
+    let key = format!("{}{name}", prefix.as_str());
+    // SAFETY: pKey is not null, and the caller guarantees it is a valid pointer.
+    unsafe { *pKey = CString::new(key).unwrap().into_raw() };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
 /// Get the group attributes as a JSON string.
 ///
 /// The string must be freed with `zarrsFreeString`.
@@ -215,6 +329,156 @@ pub unsafe extern "C" fn zarrsGroupGetAttributes(
     ZarrsResult::ZARRS_ERROR_INVALID_METADATA
 }
 
+/// Check whether the group has a top-level attribute.
+///
+/// `pExists` is set to true if the attributes contain `key`, otherwise false.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+/// `pExists` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupAttributeExists(
+    group: ZarrsGroup,
+    key: FfiStr,
+    pExists: *mut bool,
+) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+
+    let exists = group_fn!(group, attributes).contains_key(key.as_str());
+    // SAFETY: pExists is a valid pointer per the function's safety contract.
+    unsafe { *pExists = exists };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the number of top-level group attributes.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+/// `pCount` must be a valid pointer to a `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupGetAttributeCount(
+    group: ZarrsGroup,
+    pCount: *mut usize,
+) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+
+    let count = group_fn!(group, attributes).len();
+    // SAFETY: pCount is a valid pointer per the function's safety contract.
+    unsafe { *pCount = count };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Top-level group metadata fields defined by the Zarr V2 and V3 specifications.
+///
+/// These are not reported as extensions.
+const GROUP_CORE_METADATA_FIELDS: &[&str] = &[
+    "zarr_format",
+    "node_type",
+    "attributes",
+    "consolidated_metadata",
+];
+
+/// Get the value of the group metadata extension `extension_name`, if present.
+fn group_extension(group: &ZarrsGroupEnum, extension_name: &str) -> Option<serde_json::Value> {
+    if GROUP_CORE_METADATA_FIELDS.contains(&extension_name) {
+        return None;
+    }
+    match serde_json::to_value(group_fn!(group, metadata)) {
+        Ok(serde_json::Value::Object(mut metadata)) => metadata.remove(extension_name),
+        _ => None,
+    }
+}
+
+/// Check whether the group metadata has an extension.
+///
+/// Extensions are additional top-level fields in the group metadata.
+/// `pHas` is set to true if the group metadata has a field named `extensionName` that is not a core metadata field, otherwise false.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+/// `pHas` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupHasExtension(
+    group: ZarrsGroup,
+    extensionName: FfiStr,
+    pHas: *mut bool,
+) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+
+    let has = group_extension(group, extensionName.as_str()).is_some();
+    // SAFETY: pHas is a valid pointer per the function's safety contract.
+    unsafe { *pHas = has };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the metadata of a group extension as a JSON string.
+///
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the group metadata does not have the extension `extensionName`.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+/// `pExtensionJson` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupGetExtensionString(
+    group: ZarrsGroup,
+    extensionName: FfiStr,
+    pretty: bool,
+    pExtensionJson: *mut *mut c_char,
+) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+    let extension_name = extensionName.as_str();
+
+    let Some(extension) = group_extension(group, extension_name) else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("group metadata does not have the extension {extension_name:?}");
+        return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+    };
+    let extension_str = if pretty {
+        serde_json::to_string_pretty(&extension)
+    } else {
+        serde_json::to_string(&extension)
+    };
+    if let Ok(extension_str) = extension_str
+        && let Ok(cstring) = CString::new(extension_str)
+    {
+        // SAFETY: pExtensionJson is a valid pointer per the function's safety contract.
+        unsafe { *pExtensionJson = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() = "error converting extension to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
+
 /// Set the group attributes from a JSON string.
 ///
 /// # Errors
@@ -249,3 +513,214 @@ pub unsafe extern "C" fn zarrsGroupSetAttributes(
 
     ZarrsResult::ZARRS_SUCCESS
 }
+
+/// Node metadata keys, which mark a prefix as a node.
+const NODE_METADATA_KEYS: [&str; 3] = ["zarr.json", ".zarray", ".zgroup"];
+
+/// Returns true if any prefix directly under `prefix` is a node.
+///
+/// The check stops as soon as a child node is found.
+fn has_child_node<T: ListableStorageTraits + ?Sized>(
+    storage: &T,
+    prefix: &StorePrefix,
+) -> Result<bool, StorageError> {
+    for child_prefix in storage.list_dir(prefix)?.prefixes() {
+        let is_node = storage.list_dir(child_prefix)?.keys().iter().any(|key| {
+            let name = key.as_str().rsplit('/').next().unwrap_or_default();
+            NODE_METADATA_KEYS.contains(&name)
+        });
+        if is_node {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn zarrsGroupIsEmptyImpl<T: ListableStorageTraits + ?Sized + 'static>(
+    group: &Group<T>,
+    empty: *mut bool,
+) -> ZarrsResult {
+    let prefix = match node_prefix(group.path().as_str()) {
+        Ok(prefix) => prefix,
+        Err(result) => return result,
+    };
+    match has_child_node(&*group.storage(), &prefix) {
+        Ok(has_child) => {
+            // SAFETY: empty is a valid pointer per the safety contract of zarrsGroupIsEmpty.
+            unsafe { *empty = !has_child };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE
+        }
+    }
+}
+
+/// Check if a group has no child arrays or groups.
+///
+/// The check stops as soon as a child node is found, and the metadata of child nodes is not read.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the group does not have list capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be listed.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+/// `pEmpty` must be a valid pointer to a `bool`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupIsEmpty(group: ZarrsGroup, pEmpty: *mut bool) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+    match group {
+        ZarrsGroupEnum::L(group) => zarrsGroupIsEmptyImpl(group, pEmpty),
+        ZarrsGroupEnum::RL(group) => zarrsGroupIsEmptyImpl(group, pEmpty),
+        ZarrsGroupEnum::RWL(group) => zarrsGroupIsEmptyImpl(group, pEmpty),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have list capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    }
+}
+
+/// The type of a node in a Zarr hierarchy.
+#[repr(i32)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ZarrsNodeType {
+    ZARRS_NODE_TYPE_ARRAY = 0,
+    ZARRS_NODE_TYPE_GROUP = 1,
+    ZARRS_NODE_TYPE_UNKNOWN = 2,
+}
+
+/// Read the metadata key `name` of the node at `prefix`.
+///
+/// Sets the last error on failure.
+fn read_node_metadata_key<T: ReadableStorageTraits + ?Sized>(
+    storage: &T,
+    prefix: &StorePrefix,
+    name: &str,
+) -> Result<Option<Vec<u8>>, ZarrsResult> {
+    let key = match StoreKey::new(format!("{}{name}", prefix.as_str())) {
+        Ok(key) => key,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return Err(ZarrsResult::ZARRS_ERROR_NODE_PATH);
+        }
+    };
+    match storage.get(&key) {
+        Ok(bytes) => Ok(bytes.map(|bytes| bytes.to_vec())),
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            Err(ZarrsResult::ZARRS_ERROR_STORAGE)
+        }
+    }
+}
+
+/// Determine the type of the node at `prefix` from its metadata.
+///
+/// Sets the last error on failure.
+fn node_type<T: ReadableStorageTraits + ?Sized>(
+    storage: &T,
+    prefix: &StorePrefix,
+) -> Result<ZarrsNodeType, ZarrsResult> {
+    if let Some(bytes) = read_node_metadata_key(storage, prefix, "zarr.json")? {
+        return match serde_json::from_slice::<serde_json::Value>(&bytes) {
+            Ok(serde_json::Value::Object(metadata)) if metadata.contains_key("data_type") => {
+                Ok(ZarrsNodeType::ZARRS_NODE_TYPE_ARRAY)
+            }
+            Ok(serde_json::Value::Object(_)) => Ok(ZarrsNodeType::ZARRS_NODE_TYPE_GROUP),
+            Ok(_) => {
+                *LAST_ERROR.lock().unwrap() =
+                    format!("{}zarr.json is not a JSON object", prefix.as_str());
+                Err(ZarrsResult::ZARRS_ERROR_INVALID_METADATA)
+            }
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err.to_string();
+                Err(ZarrsResult::ZARRS_ERROR_INVALID_METADATA)
+            }
+        };
+    }
+    if read_node_metadata_key(storage, prefix, ".zarray")?.is_some() {
+        Ok(ZarrsNodeType::ZARRS_NODE_TYPE_ARRAY)
+    } else if read_node_metadata_key(storage, prefix, ".zgroup")?.is_some() {
+        Ok(ZarrsNodeType::ZARRS_NODE_TYPE_GROUP)
+    } else {
+        Ok(ZarrsNodeType::ZARRS_NODE_TYPE_UNKNOWN)
+    }
+}
+
+fn zarrsGroupChildNodeTypeImpl<T: ReadableStorageTraits + ?Sized + 'static>(
+    group: &Group<T>,
+    child_path: &str,
+    pNodeType: *mut ZarrsNodeType,
+) -> ZarrsResult {
+    let child_path = child_path.trim_matches('/');
+    if child_path.is_empty() {
+        *LAST_ERROR.lock().unwrap() = "the child path must not be empty".to_string();
+        return ZarrsResult::ZARRS_ERROR_NODE_PATH;
+    }
+    let prefix = match node_prefix(group.path().as_str()) {
+        Ok(prefix) => prefix,
+        Err(result) => return result,
+    };
+    let prefix = match StorePrefix::new(format!("{}{child_path}/", prefix.as_str())) {
+        Ok(prefix) => prefix,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_NODE_PATH;
+        }
+    };
+    match node_type(&*group.storage(), &prefix) {
+        Ok(node_type) => {
+            // SAFETY: pNodeType is a valid pointer per the safety contract of zarrsGroupChildNodeType.
+            unsafe { *pNodeType = node_type };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Get the type of a child node of a group without opening it.
+///
+/// `childPath` is the path of the child relative to the group (e.g. `array` or `subgroup/array`).
+/// The type is determined from the child's `zarr.json`: the child is an array if it has a `data_type` field, otherwise it is a group.
+/// Zarr V2 children are identified by the presence of `.zarray` or `.zgroup`.
+/// `pNodeType` is set to `ZARRS_NODE_TYPE_UNKNOWN` if the child has no node metadata.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the group does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_NODE_PATH` if `childPath` is empty or invalid.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the child's `zarr.json` is not a JSON object.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the child metadata could not be read.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+/// `pNodeType` must be a valid pointer to a `ZarrsNodeType`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupChildNodeType(
+    group: ZarrsGroup,
+    childPath: FfiStr,
+    pNodeType: *mut ZarrsNodeType,
+) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+    let child_path = childPath.as_str();
+    match group {
+        ZarrsGroupEnum::R(group) => zarrsGroupChildNodeTypeImpl(group, child_path, pNodeType),
+        ZarrsGroupEnum::RL(group) => zarrsGroupChildNodeTypeImpl(group, child_path, pNodeType),
+        ZarrsGroupEnum::RW(group) => zarrsGroupChildNodeTypeImpl(group, child_path, pNodeType),
+        ZarrsGroupEnum::RWL(group) => zarrsGroupChildNodeTypeImpl(group, child_path, pNodeType),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    ffi::{CString, c_char},
+    sync::Arc,
+};
+
+use ffi_support::FfiStr;
+use zarrs::{group::Group, metadata::v3::ConsolidatedMetadata, storage::ReadableStorageTraits};
+
+use crate::{
+    LAST_ERROR, ZarrsResult,
+    storage::{ZarrsStorage, ZarrsStorageEnum},
+};
+
+/// Write consolidated metadata for the hierarchy under a group.
+///
+/// The metadata of every node under `rootPath` is gathered and stored inline in the `consolidated_metadata` field of the root group `zarr.json`.
+/// This follows the convention established by `zarr-python` for Zarr V3, which is not part of the Zarr V3 specification.
+/// The root group must be a Zarr V3 group.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read, write, and list capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_GROUP` if there is no group at `rootPath`.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the hierarchy could not be traversed or the metadata could not be stored.
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayConsolidateMetadata(
+    storage: ZarrsStorage,
+    rootPath: FfiStr,
+) -> ZarrsResult {
+    if storage.is_null() {
+        *LAST_ERROR.lock().unwrap() = "storage is null".to_string();
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &**storage };
+    let ZarrsStorageEnum::RWL(storage) = storage else {
+        *LAST_ERROR.lock().unwrap() =
+            "storage does not have read, write, and list capability".to_string();
+        return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+    };
+
+    let mut group = match Group::open(storage.clone(), rootPath.into()) {
+        Ok(group) => group,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_GROUP;
+        }
+    };
+    let metadata = match group.consolidate_metadata() {
+        Ok(metadata) => metadata,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE;
+        }
+    };
+    group.set_consolidated_metadata(Some(ConsolidatedMetadata {
+        metadata,
+        ..Default::default()
+    }));
+    match group.store_metadata() {
+        Ok(()) => ZarrsResult::ZARRS_SUCCESS,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE
+        }
+    }
+}
+
+/// Read the consolidated metadata of a group as a JSON string.
+///
+/// Sets the last error on failure.
+fn consolidated_metadata_string<T: ReadableStorageTraits + ?Sized + 'static>(
+    storage: Arc<T>,
+    root_path: &str,
+) -> Result<String, ZarrsResult> {
+    let group = match Group::open(storage, root_path) {
+        Ok(group) => group,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return Err(ZarrsResult::ZARRS_ERROR_GROUP);
+        }
+    };
+    let Some(consolidated_metadata) = group.consolidated_metadata() else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the group at {root_path} does not have consolidated metadata");
+        return Err(ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+    };
+    serde_json::to_string(consolidated_metadata).map_err(|err| {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+    })
+}
+
+/// Get the consolidated metadata of a group as a JSON string.
+///
+/// Returns the `consolidated_metadata` field of the group at `rootPath` as written by `zarrsArrayConsolidateMetadata`.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_GROUP` if there is no group at `rootPath`.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the group does not have consolidated metadata.
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle.
+/// `pMetadataJson` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetConsolidatedMetadataString(
+    storage: ZarrsStorage,
+    rootPath: FfiStr,
+    pMetadataJson: *mut *mut c_char,
+) -> ZarrsResult {
+    if storage.is_null() {
+        *LAST_ERROR.lock().unwrap() = "storage is null".to_string();
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &**storage };
+    let root_path: &str = rootPath.into();
+    let metadata_str = match storage {
+        ZarrsStorageEnum::R(storage) => consolidated_metadata_string(storage.clone(), root_path),
+        ZarrsStorageEnum::RL(storage) => consolidated_metadata_string(storage.clone(), root_path),
+        ZarrsStorageEnum::RW(storage) => consolidated_metadata_string(storage.clone(), root_path),
+        ZarrsStorageEnum::RWL(storage) => consolidated_metadata_string(storage.clone(), root_path),
+        _ => {
+            *LAST_ERROR.lock().unwrap() = "storage does not have read capability".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    };
+    match metadata_str.map(CString::new) {
+        Ok(Ok(cstring)) => {
+            // SAFETY: pMetadataJson is a valid pointer per the function's safety contract.
+            unsafe { *pMetadataJson = cstring.into_raw() };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Ok(Err(err)) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+        }
+        Err(result) => result,
+    }
+}
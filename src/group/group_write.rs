@@ -1,8 +1,18 @@
-use zarrs::{group::Group, storage::WritableStorageTraits};
+use std::sync::Arc;
+
+use ffi_support::FfiStr;
+use zarrs::{
+    array::{Array, ArrayBytes, ArraySubset},
+    group::Group,
+    storage::{
+        ReadableStorageTraits, ReadableWritableListableStorageTraits, WritableStorageTraits,
+    },
+};
 
 use crate::{
     LAST_ERROR, ZarrsResult,
     group::{ZarrsGroup, ZarrsGroupEnum},
+    storage::{ZarrsStorage, ZarrsStorageEnum},
 };
 
 // use super::group_fn;
@@ -43,3 +53,236 @@ pub unsafe extern "C" fn zarrsGroupStoreMetadata(group: ZarrsGroup) -> ZarrsResu
         }
     }
 }
+
+/// The descendant arrays and groups of a group.
+type Descendants<T> = (Vec<Array<T>>, Vec<Group<T>>);
+
+/// Recursively collect the descendant arrays and groups of a group.
+fn descendants<T: ReadableWritableListableStorageTraits + ?Sized + 'static>(
+    group: &Group<T>,
+) -> Result<Descendants<T>, String> {
+    let mut arrays = group.child_arrays().map_err(|err| err.to_string())?;
+    let mut groups = Vec::new();
+    for child_group in group.child_groups().map_err(|err| err.to_string())? {
+        let (child_arrays, child_groups) = descendants(&child_group)?;
+        arrays.extend(child_arrays);
+        groups.extend(child_groups);
+        groups.push(child_group);
+    }
+    Ok((arrays, groups))
+}
+
+/// Store the metadata of a group and all of its descendant arrays and groups.
+///
+/// Descendants are discovered by listing the storage of the group.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the group does not have read, write, and list capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the descendants could not be listed or the metadata of any node could not be stored.
+///   The last error lists the paths of the nodes that failed, other nodes are still stored.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupStoreMetadataAll(group: ZarrsGroup) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+    let ZarrsGroupEnum::RWL(group) = group else {
+        *LAST_ERROR.lock().unwrap() =
+            "storage does not have read, write, and list capability".to_string();
+        return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+    };
+
+    let (arrays, groups) = match descendants(group) {
+        Ok(descendants) => descendants,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err;
+            return ZarrsResult::ZARRS_ERROR_STORAGE;
+        }
+    };
+    let mut failed_paths = Vec::new();
+    if group.store_metadata().is_err() {
+        failed_paths.push(group.path().as_str().to_string());
+    }
+    for group in &groups {
+        if group.store_metadata().is_err() {
+            failed_paths.push(group.path().as_str().to_string());
+        }
+    }
+    for array in &arrays {
+        if array.store_metadata().is_err() {
+            failed_paths.push(array.path().as_str().to_string());
+        }
+    }
+    if failed_paths.is_empty() {
+        ZarrsResult::ZARRS_SUCCESS
+    } else {
+        *LAST_ERROR.lock().unwrap() = format!(
+            "failed to store metadata for paths: {}",
+            failed_paths.join(", ")
+        );
+        ZarrsResult::ZARRS_ERROR_STORAGE
+    }
+}
+
+/// Map a node path under `source_root` to the equivalent path under `target_root`.
+fn rebase_path(path: &str, source_root: &str, target_root: &str) -> String {
+    let relative_path = path
+        .strip_prefix(source_root)
+        .unwrap_or(path)
+        .trim_start_matches('/');
+    match (target_root.trim_end_matches('/'), relative_path) {
+        (target_root, "") if target_root.is_empty() => "/".to_string(),
+        (target_root, "") => target_root.to_string(),
+        (target_root, relative_path) => format!("{target_root}/{relative_path}"),
+    }
+}
+
+/// Copy an array with its metadata and chunks to `target_path` in `target_storage`.
+fn copy_array<T, U>(
+    array: &Array<T>,
+    target_storage: &Arc<U>,
+    target_path: &str,
+) -> Result<(), String>
+where
+    T: ReadableStorageTraits + ?Sized + 'static,
+    U: WritableStorageTraits + ?Sized + 'static,
+{
+    let target_array = Array::new_with_metadata(
+        target_storage.clone(),
+        target_path,
+        array.metadata().clone(),
+    )
+    .map_err(|err| err.to_string())?;
+    target_array
+        .store_metadata()
+        .map_err(|err| err.to_string())?;
+    let chunks = ArraySubset::new_with_shape(array.chunk_grid_shape().to_vec());
+    for chunk_indices in chunks.indices().into_iter() {
+        if let Some(chunk_bytes) = array
+            .retrieve_chunk_if_exists::<ArrayBytes>(&chunk_indices)
+            .map_err(|err| err.to_string())?
+        {
+            target_array
+                .store_chunk(&chunk_indices, chunk_bytes)
+                .map_err(|err| err.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// Copy a group with its metadata to `target_path` in `target_storage`.
+fn copy_group<T, U>(
+    group: &Group<T>,
+    target_storage: &Arc<U>,
+    target_path: &str,
+) -> Result<(), String>
+where
+    T: ?Sized + 'static,
+    U: WritableStorageTraits + ?Sized + 'static,
+{
+    Group::new_with_metadata(
+        target_storage.clone(),
+        target_path,
+        group.metadata().clone(),
+    )
+    .map_err(|err| err.to_string())?
+    .store_metadata()
+    .map_err(|err| err.to_string())
+}
+
+/// Copy a group hierarchy to `target_root` in `target_storage`.
+fn copy_hierarchy<T, U>(
+    group: &Group<T>,
+    target_storage: &Arc<U>,
+    target_root: &str,
+) -> Result<(), String>
+where
+    T: ReadableWritableListableStorageTraits + ?Sized + 'static,
+    U: WritableStorageTraits + ?Sized + 'static,
+{
+    let source_root = group.path().as_str();
+    let (arrays, groups) = descendants(group)?;
+    copy_group(
+        group,
+        target_storage,
+        &rebase_path(source_root, source_root, target_root),
+    )
+    .map_err(|err| format!("{source_root}: {err}"))?;
+    for group in &groups {
+        let path = group.path().as_str();
+        copy_group(
+            group,
+            target_storage,
+            &rebase_path(path, source_root, target_root),
+        )
+        .map_err(|err| format!("{path}: {err}"))?;
+    }
+    for array in &arrays {
+        let path = array.path().as_str();
+        copy_array(
+            array,
+            target_storage,
+            &rebase_path(path, source_root, target_root),
+        )
+        .map_err(|err| format!("{path}: {err}"))?;
+    }
+    Ok(())
+}
+
+/// Deep copy a group hierarchy to a path in a storage.
+///
+/// The metadata of the group and all of its descendant arrays and groups is copied, along with all stored chunks.
+/// The target may be the same storage as the source, at a different path.
+/// The hierarchy is collected before anything is written, so the target path may be inside the source hierarchy.
+/// Chunks are decoded and re-encoded with the codecs of the copied array metadata.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `sourceGroup` or `targetStorage` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `sourceGroup` does not have read, write, and list capability, or `targetStorage` does not have write capability.
+/// - Returns `ZarrsResult::ZARRS_ERROR_GROUP` if any node could not be copied.
+///
+/// # Safety
+/// If not null, `sourceGroup` must be a valid `ZarrsGroup` handle.
+/// If not null, `targetStorage` must be a valid `ZarrsStorage` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupCopyTo(
+    sourceGroup: ZarrsGroup,
+    targetStorage: ZarrsStorage,
+    targetPath: FfiStr,
+) -> ZarrsResult {
+    if sourceGroup.is_null() || targetStorage.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: sourceGroup and targetStorage are not null, and the caller guarantees they are valid handles.
+    let group = unsafe { &**sourceGroup };
+    let target_storage = unsafe { &**targetStorage };
+    let target_path: &str = targetPath.into();
+    let ZarrsGroupEnum::RWL(group) = group else {
+        *LAST_ERROR.lock().unwrap() =
+            "storage does not have read, write, and list capability".to_string();
+        return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+    };
+
+    let result = match target_storage {
+        ZarrsStorageEnum::W(storage) => copy_hierarchy(group, storage, target_path),
+        ZarrsStorageEnum::RW(storage) => copy_hierarchy(group, storage, target_path),
+        ZarrsStorageEnum::RWL(storage) => copy_hierarchy(group, storage, target_path),
+        _ => {
+            *LAST_ERROR.lock().unwrap() =
+                "target storage does not have write capability".to_string();
+            return ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY;
+        }
+    };
+    match result {
+        Ok(()) => ZarrsResult::ZARRS_SUCCESS,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err;
+            ZarrsResult::ZARRS_ERROR_GROUP
+        }
+    }
+}
@@ -3,7 +3,9 @@
 #![allow(non_camel_case_types)]
 
 use std::{
-    ffi::{CString, c_char},
+    any::Any,
+    collections::HashMap,
+    ffi::{CString, c_char, c_void},
     sync::Mutex,
 };
 
@@ -12,19 +14,31 @@ use once_cell::sync::Lazy;
 extern crate zarrs;
 
 mod array;
+mod codec_options;
 mod group;
+mod lru;
+mod ome_zarr;
+mod stats;
 mod storage;
 mod version;
 
 pub use array::{
-    array_read::*, array_read_write::*, array_sharded::*, array_write::*, data_type::*, *,
+    array_additional_fields::*, array_async::*, array_chunk_cache::*, array_info::*, array_json::*,
+    array_list::*, array_metadata_v2::*, array_read::*, array_read_selection::*,
+    array_read_write::*, array_sharded::*, array_write::*, chunk_grid::*, data_type::*, *,
+};
+pub use codec_options::*;
+pub use group::{group_consolidated::*, group_write::*, *};
+pub use ome_zarr::*;
+pub use stats::*;
+pub use storage::{
+    storage_http::*, storage_performance_metrics::*, storage_url::*, storage_zip::*, *,
 };
-pub use group::{group_write::*, *};
-pub use storage::*;
 pub use version::*;
 
 #[non_exhaustive]
 #[repr(i32)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum ZarrsResult {
     ZARRS_SUCCESS = 0,
     ZARRS_ERROR_NULL_PTR = -1,
@@ -41,10 +55,62 @@ pub enum ZarrsResult {
     ZARRS_ERROR_UNSUPPORTED_DATA_TYPE = -12,
     ZARRS_ERROR_GROUP = -13,
     ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY = -14,
+    ZARRS_ERROR_OVERFLOW = -15,
+    ZARRS_ERROR_NOT_FOUND = -16,
+    ZARRS_ERROR_PARTIAL_FAILURE = -17,
+    ZARRS_ERROR_ABORTED = -18,
+    ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS = -19,
+    ZARRS_ERROR_UNKNOWN_POINTER = -20,
 }
 
 static LAST_ERROR: Lazy<Mutex<String>> = Lazy::new(|| Mutex::new("".to_string()));
 
+/// Buffers allocated by zarrs and owned by the caller, keyed by their data pointer.
+static ALLOCATIONS: Lazy<Mutex<HashMap<usize, Box<dyn Any + Send>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Transfer ownership of `buffer` to the caller.
+///
+/// The returned pointer is never null and must be released with `zarrsFreeBytes`.
+pub(crate) fn buffer_into_raw<T: Send + 'static>(mut buffer: Vec<T>) -> *mut T {
+    if buffer.capacity() == 0 {
+        // Guarantee a unique pointer for empty buffers
+        buffer.reserve(1);
+    }
+    let ptr = buffer.as_mut_ptr();
+    ALLOCATIONS
+        .lock()
+        .unwrap()
+        .insert(ptr as usize, Box::new(buffer));
+    ptr
+}
+
+/// Get the `"zarr_format"` of serialized array or group metadata.
+///
+/// Sets the last error and returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if it is absent or not a positive integer.
+pub(crate) fn metadata_zarr_format(
+    metadata: Result<serde_json::Value, serde_json::Error>,
+) -> Result<u32, ZarrsResult> {
+    let metadata = metadata.map_err(|err| {
+        *LAST_ERROR.lock().unwrap() = err.to_string();
+        ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+    })?;
+    match metadata
+        .get("zarr_format")
+        .and_then(serde_json::Value::as_u64)
+    {
+        Some(zarr_format) if zarr_format > 0 => u32::try_from(zarr_format).map_err(|_| {
+            *LAST_ERROR.lock().unwrap() = format!("zarr_format {zarr_format} is out of range");
+            ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+        }),
+        _ => {
+            *LAST_ERROR.lock().unwrap() =
+                "metadata does not have a zarr_format that is a positive integer".to_string();
+            Err(ZarrsResult::ZARRS_ERROR_INVALID_METADATA)
+        }
+    }
+}
+
 /// Get the last error string.
 ///
 /// The string must be freed with `zarrsFreeString`.
@@ -68,3 +134,75 @@ pub unsafe extern "C" fn zarrsFreeString(string: *mut c_char) -> ZarrsResult {
     }
     ZarrsResult::ZARRS_SUCCESS
 }
+
+/// Free an array of strings created by zarrs.
+///
+/// `count` must be the number of strings in the array, as returned by the function that created it.
+///
+/// # Safety
+/// `strings` must be a valid array of `count` strings created by zarrs.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsFreeStringArray(
+    strings: *mut *mut c_char,
+    count: usize,
+) -> ZarrsResult {
+    if strings.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: strings is an array of count strings created by zarrs per the function's safety contract.
+    let strings = unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(strings, count)) };
+    for string in strings {
+        if !string.is_null() {
+            // SAFETY: each non-null string was created by zarrs with CString::into_raw.
+            unsafe {
+                let _ = CString::from_raw(string);
+            }
+        }
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Free a buffer created by zarrs.
+///
+/// Buffers returned by zarrs (e.g. by `zarrsArrayRetrieveSubsetAlloc` or `zarrsArrayGetStoredInnerChunkIndicesForShard`) are recorded in a process-wide registry keyed by their pointer, which holds their length and element type.
+/// This is why no length is passed: `pBytes` is looked up in the registry rather than dereferenced, so a pointer that zarrs did not return (including a pointer offset into a zarrs buffer) or that was already freed is detected rather than corrupting memory.
+/// Buffers can be freed from any thread.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `pBytes` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_POINTER` if `pBytes` is not a buffer created by zarrs or it has already been freed.
+///
+/// # Safety
+/// The buffer must not be accessed after it is freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsFreeBytes(pBytes: *mut c_void) -> ZarrsResult {
+    if pBytes.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    if ALLOCATIONS
+        .lock()
+        .unwrap()
+        .remove(&(pBytes as usize))
+        .is_some()
+    {
+        ZarrsResult::ZARRS_SUCCESS
+    } else {
+        *LAST_ERROR.lock().unwrap() =
+            "pBytes is not a buffer created by zarrs or has already been freed".to_string();
+        ZarrsResult::ZARRS_ERROR_UNKNOWN_POINTER
+    }
+}
+
+/// Free an offsets buffer created by zarrs (e.g. by `zarrsArrayRetrieveSubsetVlen`).
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `pOffsets` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_POINTER` if `pOffsets` is not a buffer created by zarrs or it has already been freed.
+///
+/// # Safety
+/// The buffer must not be accessed after it is freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsFreeOffsets(pOffsets: *mut u64) -> ZarrsResult {
+    // SAFETY: the caller upholds the safety contract of zarrsFreeBytes.
+    unsafe { zarrsFreeBytes(pOffsets.cast()) }
+}
@@ -0,0 +1,75 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+};
+
+/// A least recently used cache indexed by a hash map.
+///
+/// Entries are ordered by the tick of their last use, so lookups, insertions, and evictions are `O(log n)` rather than a scan of the cache.
+/// The cache has no capacity of its own; the owner evicts entries with [`LruCache::pop_lru`] according to its own limit (e.g. a number of entries or a total size).
+pub(crate) struct LruCache<K, V> {
+    entries: HashMap<K, (u64, V)>,
+    /// The keys of the entries ordered from least to most recently used.
+    order: BTreeMap<u64, K>,
+    tick: u64,
+}
+
+impl<K, V> Default for LruCache<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// The number of entries in the cache.
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Get the entry at `key`, marking it as most recently used.
+    pub(crate) fn get(&mut self, key: &K) -> Option<&V> {
+        self.tick += 1;
+        let (last_used, value) = self.entries.get_mut(key)?;
+        self.order.remove(last_used);
+        self.order.insert(self.tick, key.clone());
+        *last_used = self.tick;
+        Some(value)
+    }
+
+    /// Returns true if the cache has an entry at `key`, without marking it as used.
+    pub(crate) fn contains(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Insert `value` at `key` as the most recently used entry, returning the value it replaced.
+    pub(crate) fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        let (last_used, previous) = self.entries.insert(key, (self.tick, value))?;
+        self.order.remove(&last_used);
+        Some(previous)
+    }
+
+    /// Remove the entry at `key`.
+    pub(crate) fn remove(&mut self, key: &K) -> Option<V> {
+        let (last_used, value) = self.entries.remove(key)?;
+        self.order.remove(&last_used);
+        Some(value)
+    }
+
+    /// Remove the least recently used entry.
+    pub(crate) fn pop_lru(&mut self) -> Option<(K, V)> {
+        let (_, key) = self.order.pop_first()?;
+        let (_, value) = self.entries.remove(&key)?;
+        Some((key, value))
+    }
+
+    /// Iterate over the values in the cache in an arbitrary order.
+    pub(crate) fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.values().map(|(_, value)| value)
+    }
+}
@@ -0,0 +1,372 @@
+use std::ffi::{CString, c_char};
+
+use ffi_support::FfiStr;
+use serde_json::{Map, Value};
+
+use crate::{
+    LAST_ERROR, ZarrsResult,
+    array::{ZarrsArray, array_fn},
+    group::{ZarrsGroup, group_fn},
+};
+
+/// Get the OME-Zarr attributes of a node.
+///
+/// OME-Zarr 0.5 nests its attributes under an `ome` key, whereas earlier versions store them at the top level.
+fn ome_zarr_attributes(attributes: &Map<String, Value>) -> &Map<String, Value> {
+    match attributes.get("ome") {
+        Some(Value::Object(ome)) => ome,
+        _ => attributes,
+    }
+}
+
+/// Get the OME-Zarr `multiscales` array of a node.
+///
+/// Sets the last error if it is absent.
+fn ome_zarr_multiscales(attributes: &Map<String, Value>) -> Result<&Vec<Value>, ZarrsResult> {
+    if let Some(Value::Array(multiscales)) = ome_zarr_attributes(attributes).get("multiscales") {
+        Ok(multiscales)
+    } else {
+        *LAST_ERROR.lock().unwrap() =
+            "the attributes do not contain OME-Zarr multiscales".to_string();
+        Err(ZarrsResult::ZARRS_ERROR_INVALID_METADATA)
+    }
+}
+
+/// Get a field of the first OME-Zarr `multiscales` entry of a node.
+///
+/// Sets the last error if it is absent.
+fn ome_zarr_multiscales_field<'a>(
+    attributes: &'a Map<String, Value>,
+    field: &str,
+) -> Result<&'a Value, ZarrsResult> {
+    let multiscales = ome_zarr_multiscales(attributes)?;
+    if let Some(value) = multiscales
+        .first()
+        .and_then(|multiscale| multiscale.get(field))
+    {
+        Ok(value)
+    } else {
+        *LAST_ERROR.lock().unwrap() =
+            format!("the OME-Zarr multiscales do not contain a {field} field");
+        Err(ZarrsResult::ZARRS_ERROR_INVALID_METADATA)
+    }
+}
+
+/// Validate OME-Zarr coordinate transformations against the number of axes.
+fn validate_coordinate_transformations(
+    transformations: &Value,
+    num_axes: usize,
+    context: &str,
+) -> Result<(), String> {
+    let Value::Array(transformations) = transformations else {
+        return Err(format!(
+            "{context} coordinateTransformations must be an array"
+        ));
+    };
+    for (i, transformation) in transformations.iter().enumerate() {
+        let kind = transformation.get("type").and_then(Value::as_str);
+        let values = match kind {
+            Some("scale") => transformation.get("scale"),
+            Some("translation") => transformation.get("translation"),
+            Some("identity") => continue,
+            _ => {
+                return Err(format!(
+                    "{context} coordinateTransformations[{i}] must have a type of scale, translation, or identity"
+                ));
+            }
+        };
+        let kind = kind.unwrap_or_default();
+        match values {
+            Some(Value::Array(values)) if values.iter().all(Value::is_number) => {
+                if values.len() != num_axes {
+                    return Err(format!(
+                        "{context} coordinateTransformations[{i}] {kind} has {} values, expected {num_axes} to match the axes",
+                        values.len()
+                    ));
+                }
+            }
+            // A transformation may reference a binary file instead of listing values inline
+            None if transformation.get("path").is_some() => {}
+            _ => {
+                return Err(format!(
+                    "{context} coordinateTransformations[{i}] {kind} must be an array of numbers"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validate OME-Zarr multiscales metadata.
+///
+/// If `dimensionality` is provided, the number of axes of each multiscale must match it.
+fn validate_multiscales(multiscales: &Value, dimensionality: Option<usize>) -> Result<(), String> {
+    let Value::Array(multiscales) = multiscales else {
+        return Err("multiscales must be an array".to_string());
+    };
+    if multiscales.is_empty() {
+        return Err("multiscales must not be empty".to_string());
+    }
+    for (i, multiscale) in multiscales.iter().enumerate() {
+        let context = format!("multiscales[{i}]");
+        let Some(Value::Array(axes)) = multiscale.get("axes") else {
+            return Err(format!("{context} must have an axes array"));
+        };
+        for (j, axis) in axes.iter().enumerate() {
+            if !axis.get("name").is_some_and(Value::is_string) {
+                return Err(format!("{context} axes[{j}] must have a string name"));
+            }
+            if axis.get("type").is_some_and(|kind| !kind.is_string()) {
+                return Err(format!("{context} axes[{j}] type must be a string"));
+            }
+        }
+        if let Some(dimensionality) = dimensionality
+            && axes.len() != dimensionality
+        {
+            return Err(format!(
+                "{context} has {} axes, expected {dimensionality} to match the array dimensionality",
+                axes.len()
+            ));
+        }
+
+        let Some(Value::Array(datasets)) = multiscale.get("datasets") else {
+            return Err(format!("{context} must have a datasets array"));
+        };
+        if datasets.is_empty() {
+            return Err(format!("{context} datasets must not be empty"));
+        }
+        for (j, dataset) in datasets.iter().enumerate() {
+            let context = format!("{context} datasets[{j}]");
+            if !dataset.get("path").is_some_and(Value::is_string) {
+                return Err(format!("{context} must have a string path"));
+            }
+            let Some(transformations) = dataset.get("coordinateTransformations") else {
+                return Err(format!("{context} must have coordinateTransformations"));
+            };
+            validate_coordinate_transformations(transformations, axes.len(), &context)?;
+        }
+        if let Some(transformations) = multiscale.get("coordinateTransformations") {
+            validate_coordinate_transformations(transformations, axes.len(), &context)?;
+        }
+    }
+    Ok(())
+}
+
+/// Parse and validate OME-Zarr multiscales metadata, then set it in `attributes`.
+///
+/// The multiscales are set under the `ome` key if present (OME-Zarr 0.5), otherwise at the top level.
+/// Sets the last error on failure.
+fn set_ome_zarr_multiscales(
+    attributes: &mut Map<String, Value>,
+    multiscales_json: &str,
+    dimensionality: Option<usize>,
+) -> ZarrsResult {
+    let multiscales = match serde_json::from_str::<Value>(multiscales_json) {
+        Ok(multiscales) => multiscales,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = format!("error parsing multiscales: {err}");
+            return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+        }
+    };
+    if let Err(err) = validate_multiscales(&multiscales, dimensionality) {
+        *LAST_ERROR.lock().unwrap() = format!("invalid OME-Zarr multiscales: {err}");
+        return ZarrsResult::ZARRS_ERROR_INVALID_METADATA;
+    }
+    if let Some(Value::Object(ome)) = attributes.get_mut("ome") {
+        ome.insert("multiscales".to_string(), multiscales);
+    } else {
+        attributes.insert("multiscales".to_string(), multiscales);
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Write a JSON value to `pString` as a JSON string.
+///
+/// # Safety
+/// `pString` must be a valid pointer to a `char*`.
+unsafe fn json_to_c_string(value: &Value, pretty: bool, pString: *mut *mut c_char) -> ZarrsResult {
+    let value_str = if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    };
+    if let Ok(value_str) = value_str
+        && let Ok(cstring) = CString::new(value_str)
+    {
+        // SAFETY: pString is a valid pointer per the function's safety contract.
+        unsafe { *pString = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() = "error converting OME-Zarr metadata to a json string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
+
+/// Get the OME-Zarr version of an array.
+///
+/// The version is read from `ome.version` (OME-Zarr 0.5) or the `version` of the first `multiscales` entry (OME-Zarr 0.4 and earlier).
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the attributes do not contain OME-Zarr metadata with a version.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pVersion` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetOMEZarrVersion(
+    array: ZarrsArray,
+    pVersion: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    let attributes = array_fn!(array, attributes);
+
+    let version = match ome_zarr_attributes(attributes).get("version") {
+        Some(version) => version,
+        None => match ome_zarr_multiscales_field(attributes, "version") {
+            Ok(version) => version,
+            Err(result) => return result,
+        },
+    };
+    if let Value::String(version) = version
+        && let Ok(cstring) = CString::new(version.as_str())
+    {
+        // SAFETY: pVersion is a valid pointer per the function's safety contract.
+        unsafe { *pVersion = cstring.into_raw() };
+        return ZarrsResult::ZARRS_SUCCESS;
+    }
+
+    *LAST_ERROR.lock().unwrap() = "the OME-Zarr version is not a string".to_string();
+    ZarrsResult::ZARRS_ERROR_INVALID_METADATA
+}
+
+/// Get the OME-Zarr axes of an array as a JSON string.
+///
+/// The axes are read from the first `multiscales` entry.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the attributes do not contain OME-Zarr multiscales with axes.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+/// `pAxesJson` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetOMEZarrAxesString(
+    array: ZarrsArray,
+    pretty: bool,
+    pAxesJson: *mut *mut c_char,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &**array };
+    match ome_zarr_multiscales_field(array_fn!(array, attributes), "axes") {
+        // SAFETY: pAxesJson is a valid pointer per the function's safety contract.
+        Ok(axes) => unsafe { json_to_c_string(axes, pretty, pAxesJson) },
+        Err(result) => result,
+    }
+}
+
+/// Get the OME-Zarr multiscales of a group as a JSON string.
+///
+/// The multiscales are read from `ome.multiscales` (OME-Zarr 0.5) or `multiscales` (OME-Zarr 0.4 and earlier).
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the attributes do not contain OME-Zarr multiscales.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+/// `pMultiscalesJson` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupGetOMEZarrMultiscalesString(
+    group: ZarrsGroup,
+    pretty: bool,
+    pMultiscalesJson: *mut *mut c_char,
+) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &**group };
+    let attributes = group_fn!(group, attributes);
+    match ome_zarr_multiscales(attributes) {
+        Ok(multiscales) => {
+            let multiscales = Value::Array(multiscales.clone());
+            // SAFETY: pMultiscalesJson is a valid pointer per the function's safety contract.
+            unsafe { json_to_c_string(&multiscales, pretty, pMultiscalesJson) }
+        }
+        Err(result) => result,
+    }
+}
+
+/// Set the OME-Zarr multiscales of an array.
+///
+/// `multiscalesJson` is a JSON array of OME-Zarr multiscales.
+/// It is validated against the OME-Zarr multiscales schema before being set as the `multiscales` attribute, under `ome` if present.
+/// The number of axes of each multiscale must match the dimensionality of the array.
+///
+/// The attributes are only updated in memory, use `zarrsArrayStoreMetadata` to write them.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if `multiscalesJson` is not valid OME-Zarr multiscales metadata.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArraySetOMEZarrMetadata(
+    array: ZarrsArray,
+    multiscalesJson: FfiStr,
+) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &mut **array };
+    let dimensionality = array_fn!(array, dimensionality);
+    set_ome_zarr_multiscales(
+        array_fn!(array, attributes_mut),
+        multiscalesJson.into(),
+        Some(dimensionality),
+    )
+}
+
+/// Set the OME-Zarr multiscales of a group.
+///
+/// `multiscalesJson` is a JSON array of OME-Zarr multiscales.
+/// It is validated against the OME-Zarr multiscales schema before being set as the `multiscales` attribute, under `ome` if present.
+///
+/// The attributes are only updated in memory, use `zarrsGroupStoreMetadata` to write them.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if `multiscalesJson` is not valid OME-Zarr multiscales metadata.
+///
+/// # Safety
+/// If not null, `group` must be a valid `ZarrsGroup` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsGroupSetOMEZarrMetadata(
+    group: ZarrsGroup,
+    multiscalesJson: FfiStr,
+) -> ZarrsResult {
+    if group.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: group is not null, and the caller guarantees it is a valid ZarrsGroup handle.
+    let group = unsafe { &mut **group };
+    set_ome_zarr_multiscales(
+        group_fn!(group, attributes_mut),
+        multiscalesJson.into(),
+        None,
+    )
+}
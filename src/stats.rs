@@ -0,0 +1,207 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+use crate::{
+    ZarrsResult,
+    array::{ZarrsArray, ZarrsArrayEnum, array_fn},
+    storage::{
+        ZarrsStorage, ZarrsStorage_T, ZarrsStorageEnum,
+        storage_performance_metrics::{StorageMetrics, performance_metrics_storage},
+    },
+};
+use zarrs::array::ArraySubset;
+
+/// Cumulative I/O statistics of an array or storage handle.
+///
+/// `bytesRead` and `bytesWritten` count the bytes read from and written to the store, including metadata.
+/// They depend on the codecs and on partial decoding, and a read served from a chunk or shard index cache reads no bytes.
+///
+/// `chunksDecoded` and `chunksEncoded` count the chunks intersecting each retrieved and stored region.
+/// Chunks served from a decoded chunk cache are not counted.
+///
+/// `cacheHits` and `cacheMisses` count chunk cache lookups by the chunk cache functions.
+/// Shard index cache hits and misses are inferred from the number of shard indexes loaded into the cache during a call.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct ZarrsStorageStats {
+    pub bytesRead: u64,
+    pub bytesWritten: u64,
+    pub chunksDecoded: u64,
+    pub chunksEncoded: u64,
+    pub cacheHits: u64,
+    pub cacheMisses: u64,
+}
+
+/// Counters backing [`ZarrsStorageStats`].
+pub(crate) struct StatsCounters {
+    /// The requests made to the store.
+    io: StorageMetrics,
+    /// The bytes read and written when the counters were last reset.
+    bytes_read_reset: AtomicU64,
+    bytes_written_reset: AtomicU64,
+    chunks_decoded: AtomicU64,
+    chunks_encoded: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+}
+
+impl StatsCounters {
+    pub(crate) fn new(io: StorageMetrics) -> Self {
+        Self {
+            io,
+            bytes_read_reset: AtomicU64::default(),
+            bytes_written_reset: AtomicU64::default(),
+            chunks_decoded: AtomicU64::default(),
+            chunks_encoded: AtomicU64::default(),
+            cache_hits: AtomicU64::default(),
+            cache_misses: AtomicU64::default(),
+        }
+    }
+
+    fn snapshot(&self) -> ZarrsStorageStats {
+        let io = (self.io)();
+        ZarrsStorageStats {
+            bytesRead: io
+                .bytesRead
+                .saturating_sub(self.bytes_read_reset.load(Ordering::Relaxed)),
+            bytesWritten: io
+                .bytesWritten
+                .saturating_sub(self.bytes_written_reset.load(Ordering::Relaxed)),
+            chunksDecoded: self.chunks_decoded.load(Ordering::Relaxed),
+            chunksEncoded: self.chunks_encoded.load(Ordering::Relaxed),
+            cacheHits: self.cache_hits.load(Ordering::Relaxed),
+            cacheMisses: self.cache_misses.load(Ordering::Relaxed),
+        }
+    }
+
+    fn reset(&self) {
+        let io = (self.io)();
+        self.bytes_read_reset.store(io.bytesRead, Ordering::Relaxed);
+        self.bytes_written_reset
+            .store(io.bytesWritten, Ordering::Relaxed);
+        self.chunks_decoded.store(0, Ordering::Relaxed);
+        self.chunks_encoded.store(0, Ordering::Relaxed);
+        self.cache_hits.store(0, Ordering::Relaxed);
+        self.cache_misses.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Records statistics of an array handle and the storage handle it was created from.
+pub(crate) struct StatsRecorder {
+    array: StatsCounters,
+    storage: Arc<StatsCounters>,
+}
+
+impl StatsRecorder {
+    /// Wrap the store of `storage` for an array handle, so that the bytes read and written by the array are counted separately.
+    pub(crate) fn new(storage: &ZarrsStorage_T) -> (ZarrsStorageEnum, Self) {
+        let (storage_enum, io) = performance_metrics_storage(&storage.0);
+        let stats = Self {
+            array: StatsCounters::new(io),
+            storage: storage.1.clone(),
+        };
+        (storage_enum, stats)
+    }
+
+    fn for_each(&self, f: impl Fn(&StatsCounters)) {
+        f(&self.array);
+        f(&self.storage);
+    }
+
+    pub(crate) fn record_decoded(&self, chunks: u64) {
+        self.for_each(|counters| {
+            counters.chunks_decoded.fetch_add(chunks, Ordering::Relaxed);
+        });
+    }
+
+    pub(crate) fn record_encoded(&self, chunks: u64) {
+        self.for_each(|counters| {
+            counters.chunks_encoded.fetch_add(chunks, Ordering::Relaxed);
+        });
+    }
+
+    pub(crate) fn record_cache(&self, hits: u64, misses: u64) {
+        self.for_each(|counters| {
+            counters.cache_hits.fetch_add(hits, Ordering::Relaxed);
+            counters.cache_misses.fetch_add(misses, Ordering::Relaxed);
+        });
+    }
+}
+
+/// Returns the number of chunks intersecting `array_subset`, or zero if it cannot be determined.
+pub(crate) fn num_chunks_in_subset(array: &ZarrsArrayEnum, array_subset: &ArraySubset) -> u64 {
+    match array_fn!(array, chunks_in_array_subset, array_subset) {
+        Ok(Some(chunks)) => chunks.num_elements(),
+        _ => 0,
+    }
+}
+
+/// Get the cumulative I/O statistics of an array handle.
+///
+/// Statistics are accumulated since the array handle was created or last reset with `zarrsArrayResetStorageStats()`.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pStats` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle and `pStats` must be a valid pointer to a `ZarrsStorageStats`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayGetStorageStats(
+    array: ZarrsArray,
+    pStats: *mut ZarrsStorageStats,
+) -> ZarrsResult {
+    if array.is_null() || pStats.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &*array };
+    // SAFETY: pStats is not null, and the caller guarantees it is a valid pointer.
+    unsafe { *pStats = array.1.array.snapshot() };
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Reset the cumulative I/O statistics of an array handle to zero.
+///
+/// The statistics of the storage handle are not reset.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+///
+/// # Safety
+/// If not null, `array` must be a valid `ZarrsArray` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsArrayResetStorageStats(array: ZarrsArray) -> ZarrsResult {
+    if array.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: array is not null, and the caller guarantees it is a valid ZarrsArray handle.
+    let array = unsafe { &*array };
+    array.1.array.reset();
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the cumulative I/O statistics of a storage handle.
+///
+/// Statistics are accumulated over all requests made through the storage handle, including those of the array handles created from it.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` or `pStats` is a null pointer.
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle and `pStats` must be a valid pointer to a `ZarrsStorageStats`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsStorageGetStats(
+    storage: ZarrsStorage,
+    pStats: *mut ZarrsStorageStats,
+) -> ZarrsResult {
+    if storage.is_null() || pStats.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &*storage };
+    // SAFETY: pStats is not null, and the caller guarantees it is a valid pointer.
+    unsafe { *pStats = storage.1.snapshot() };
+    ZarrsResult::ZARRS_SUCCESS
+}
@@ -1,7 +1,14 @@
+pub mod storage_http;
+pub mod storage_performance_metrics;
+pub mod storage_url;
+pub mod storage_zip;
+
 use ffi_support::FfiStr;
 use std::sync::Arc;
 
-use crate::{LAST_ERROR, ZarrsResult};
+use crate::{LAST_ERROR, ZarrsResult, stats::StatsCounters};
+
+use storage_performance_metrics::{StorageMetrics, performance_metrics_storage};
 
 #[doc(hidden)]
 #[allow(clippy::upper_case_acronyms)]
@@ -14,8 +21,66 @@ pub enum ZarrsStorageEnum {
     RWL(Arc<dyn zarrs::storage::ReadableWritableListableStorageTraits>),
 }
 
+/// Storage capability flags.
+///
+/// The flags are combined with bitwise or into a `uint32_t` bitfield.
+#[repr(u32)]
+pub enum ZarrsStorageCapabilityFlags {
+    ZARRS_CAP_READ = 1,
+    ZARRS_CAP_WRITE = 2,
+    ZARRS_CAP_LIST = 4,
+}
+
+impl ZarrsStorageEnum {
+    /// Return the capabilities of the storage as a [`ZarrsStorageCapabilityFlags`] bitfield.
+    pub(crate) fn capability_flags(&self) -> u32 {
+        const READ: u32 = ZarrsStorageCapabilityFlags::ZARRS_CAP_READ as u32;
+        const WRITE: u32 = ZarrsStorageCapabilityFlags::ZARRS_CAP_WRITE as u32;
+        const LIST: u32 = ZarrsStorageCapabilityFlags::ZARRS_CAP_LIST as u32;
+        match self {
+            Self::R(_) => READ,
+            Self::W(_) => WRITE,
+            Self::L(_) => LIST,
+            Self::RL(_) => READ | LIST,
+            Self::RW(_) => READ | WRITE,
+            Self::RWL(_) => READ | WRITE | LIST,
+        }
+    }
+}
+
 #[doc(hidden)]
-pub struct ZarrsStorage_T(pub ZarrsStorageEnum);
+pub struct ZarrsStorage_T(
+    pub ZarrsStorageEnum,
+    pub(crate) Arc<StatsCounters>,
+    /// The URL of the storage, if it has one.
+    pub(crate) Option<String>,
+    /// Called when the storage handle is destroyed, e.g. to write staged data to its destination.
+    pub(crate) Option<StorageFinalizer>,
+    /// The request counts of the storage, if it was created with `zarrsCreateStoragePerformanceMetrics`.
+    pub(crate) Option<StorageMetrics>,
+);
+
+impl ZarrsStorage_T {
+    /// Create a storage handle that counts the bytes read from and written to `storage`.
+    pub(crate) fn new(
+        storage: ZarrsStorageEnum,
+        url: Option<String>,
+        finalizer: Option<StorageFinalizer>,
+        metrics: Option<StorageMetrics>,
+    ) -> Self {
+        let (storage, io) = performance_metrics_storage(&storage);
+        Self(
+            storage,
+            Arc::new(StatsCounters::new(io)),
+            url,
+            finalizer,
+            metrics,
+        )
+    }
+}
+
+/// A function that finalizes a storage when its handle is destroyed.
+pub(crate) type StorageFinalizer = Box<dyn FnOnce() -> Result<(), String> + Send>;
 
 impl std::ops::Deref for ZarrsStorage_T {
     type Target = ZarrsStorageEnum;
@@ -27,7 +92,7 @@ impl std::ops::Deref for ZarrsStorage_T {
 /// An opaque handle to a zarr store or storage transformer.
 pub type ZarrsStorage = *mut ZarrsStorage_T;
 
-/// Create a storage handle to a filesystem store.
+/// Create a storage handle to a filesystem store (read/write/list capability).
 ///
 /// `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
 ///
@@ -41,11 +106,17 @@ pub unsafe extern "C" fn zarrsCreateStorageFilesystem(
     let path = std::path::Path::new(path.as_str());
     match zarrs::filesystem::FilesystemStore::new(path) {
         Ok(store) => {
+            let url = std::path::absolute(path)
+                .ok()
+                .map(|path| format!("file://{}", path.to_string_lossy()));
             // SAFETY: pStorage is a valid pointer per the function's safety contract.
             unsafe {
-                *pStorage = Box::into_raw(Box::new(ZarrsStorage_T(ZarrsStorageEnum::RW(
-                    Arc::new(store),
-                ))));
+                *pStorage = Box::into_raw(Box::new(ZarrsStorage_T::new(
+                    ZarrsStorageEnum::RWL(Arc::new(store)),
+                    url,
+                    None,
+                    None,
+                )));
             }
             ZarrsResult::ZARRS_SUCCESS
         }
@@ -56,10 +127,33 @@ pub unsafe extern "C" fn zarrsCreateStorageFilesystem(
     }
 }
 
+/// Create a storage handle to an in-memory store (read/write/list capability).
+///
+/// `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+///
+/// # Safety
+/// `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCreateStorageMemory(pStorage: *mut ZarrsStorage) -> ZarrsResult {
+    let store = zarrs::storage::store::MemoryStore::new();
+    // SAFETY: pStorage is a valid pointer per the function's safety contract.
+    unsafe {
+        *pStorage = Box::into_raw(Box::new(ZarrsStorage_T::new(
+            ZarrsStorageEnum::RWL(Arc::new(store)),
+            None,
+            None,
+            None,
+        )));
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
 /// Destroy storage.
 ///
 /// # Errors
-/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the storage could not be finalized (e.g. writing a ZIP archive created with `zarrsStorageCreateZipRW` failed).
+///   The storage handle is destroyed regardless.
 ///
 /// # Safety
 /// If not null, `storage` must be a valid storage device created with a `zarrsStorage` function.
@@ -68,8 +162,41 @@ pub unsafe extern "C" fn zarrsDestroyStorage(storage: ZarrsStorage) -> ZarrsResu
     if storage.is_null() {
         ZarrsResult::ZARRS_ERROR_NULL_PTR
     } else {
+        // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+        let finalizer = unsafe { (*storage).3.take() };
         // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
         unsafe { storage.to_owned().drop_in_place() };
-        ZarrsResult::ZARRS_SUCCESS
+        match finalizer.map_or(Ok(()), |finalizer| finalizer()) {
+            Ok(()) => ZarrsResult::ZARRS_SUCCESS,
+            Err(err) => {
+                *LAST_ERROR.lock().unwrap() = err;
+                ZarrsResult::ZARRS_ERROR_STORAGE
+            }
+        }
+    }
+}
+
+/// Get the capabilities of the storage.
+///
+/// `pFlags` is set to a bitfield of `ZarrsStorageCapabilityFlags`.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle.
+/// `pFlags` must be a valid pointer to a `uint32_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsStorageGetCapabilityFlags(
+    storage: ZarrsStorage,
+    pFlags: *mut u32,
+) -> ZarrsResult {
+    if storage.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
     }
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &**storage };
+    // SAFETY: pFlags is a valid pointer per the function's safety contract.
+    unsafe { *pFlags = storage.capability_flags() };
+    ZarrsResult::ZARRS_SUCCESS
 }
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use ffi_support::FfiStr;
+
+use crate::{LAST_ERROR, ZarrsResult};
+
+use super::{ZarrsStorage, ZarrsStorage_T, ZarrsStorageEnum};
+
+/// Blocks on async storage operations with a dedicated tokio runtime.
+#[cfg(feature = "http")]
+struct TokioBlockOn(tokio::runtime::Runtime);
+
+#[cfg(feature = "http")]
+impl zarrs::storage::storage_adapter::async_to_sync::AsyncToSyncBlockOn for TokioBlockOn {
+    fn block_on<F: core::future::Future>(&self, future: F) -> F::Output {
+        self.0.block_on(future)
+    }
+}
+
+/// Return true if `url` is a `http://` or `https://` URL with a host.
+fn is_http_url(url: &str) -> bool {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    matches!(scheme.to_ascii_lowercase().as_str(), "http" | "https") && !host.is_empty()
+}
+
+/// Create a read-only HTTP store at `base_url`.
+///
+/// A `timeout` or `max_retries` of [`None`] uses the default of the HTTP client.
+/// Sets the last error on failure.
+#[cfg(feature = "http")]
+pub(crate) fn http_storage(
+    base_url: &str,
+    timeout: Option<std::time::Duration>,
+    max_retries: Option<usize>,
+) -> Result<ZarrsStorageEnum, ZarrsResult> {
+    use object_store::{ClientOptions, RetryConfig, http::HttpBuilder};
+    use zarrs::storage::storage_adapter::async_to_sync::AsyncToSyncStorageAdapter;
+
+    if !is_http_url(base_url) {
+        *LAST_ERROR.lock().unwrap() = format!("{base_url} is not a valid http or https URL");
+        return Err(ZarrsResult::ZARRS_ERROR_STORE_PREFIX);
+    }
+
+    let mut client_options = ClientOptions::new().with_allow_http(true);
+    if let Some(timeout) = timeout {
+        client_options = client_options.with_timeout(timeout);
+    }
+    let mut retry_config = RetryConfig::default();
+    if let Some(max_retries) = max_retries {
+        retry_config.max_retries = max_retries;
+    }
+    let store = match HttpBuilder::new()
+        .with_url(base_url)
+        .with_client_options(client_options)
+        .with_retry(retry_config)
+        .build()
+    {
+        Ok(store) => store,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return Err(ZarrsResult::ZARRS_ERROR_STORE_PREFIX);
+        }
+    };
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            return Err(ZarrsResult::ZARRS_ERROR_STORAGE);
+        }
+    };
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(store));
+    let store = AsyncToSyncStorageAdapter::new(store, TokioBlockOn(runtime));
+    Ok(ZarrsStorageEnum::R(Arc::new(store)))
+}
+
+/// Create a read-only HTTP store at `base_url`.
+///
+/// Always fails, as zarrs_ffi was built without the `http` feature.
+#[cfg(not(feature = "http"))]
+pub(crate) fn http_storage(
+    base_url: &str,
+    _timeout: Option<std::time::Duration>,
+    _max_retries: Option<usize>,
+) -> Result<ZarrsStorageEnum, ZarrsResult> {
+    if !is_http_url(base_url) {
+        *LAST_ERROR.lock().unwrap() = format!("{base_url} is not a valid http or https URL");
+        return Err(ZarrsResult::ZARRS_ERROR_STORE_PREFIX);
+    }
+    *LAST_ERROR.lock().unwrap() =
+        "HTTP storage requires zarrs_ffi to be built with the http feature".to_string();
+    Err(ZarrsResult::ZARRS_ERROR_STORAGE)
+}
+
+/// Return a handle to `storage` with the URL `base_url`.
+///
+/// # Safety
+/// `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+unsafe fn http_storage_into_handle(
+    storage: Result<ZarrsStorageEnum, ZarrsResult>,
+    base_url: &str,
+    pStorage: *mut ZarrsStorage,
+) -> ZarrsResult {
+    match storage {
+        Ok(storage) => {
+            // SAFETY: pStorage is a valid pointer per the function's safety contract.
+            unsafe {
+                *pStorage = Box::into_raw(Box::new(ZarrsStorage_T::new(
+                    storage,
+                    Some(base_url.to_string()),
+                    None,
+                    None,
+                )));
+            }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Create a storage handle to a HTTP store (read capability).
+///
+/// `baseUrl` is the `http://` or `https://` URL of the root of the store.
+/// `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+///
+/// HTTP storage requires zarrs_ffi to be built with the `http` feature.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if `baseUrl` is not a valid `http://` or `https://` URL.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the storage could not be created or the `http` feature is not enabled.
+///
+/// # Safety
+/// `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsStorageCreateHTTP(
+    baseUrl: FfiStr,
+    pStorage: *mut ZarrsStorage,
+) -> ZarrsResult {
+    let base_url = baseUrl.as_str();
+    let storage = http_storage(base_url, None, None);
+    // SAFETY: pStorage is a valid pointer per the function's safety contract.
+    unsafe { http_storage_into_handle(storage, base_url, pStorage) }
+}
+
+/// Create a storage handle to a HTTP store (read capability) with connection options.
+///
+/// `baseUrl` is the `http://` or `https://` URL of the root of the store.
+/// `timeoutSeconds` is the timeout of each request, and `maxRetries` is the maximum number of times a failed request is retried.
+/// A `timeoutSeconds` of zero uses the default timeout.
+/// `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+///
+/// HTTP storage requires zarrs_ffi to be built with the `http` feature.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if `baseUrl` is not a valid `http://` or `https://` URL.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the storage could not be created or the `http` feature is not enabled.
+///
+/// # Safety
+/// `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsStorageCreateHTTPWithOptions(
+    baseUrl: FfiStr,
+    timeoutSeconds: u32,
+    maxRetries: u32,
+    pStorage: *mut ZarrsStorage,
+) -> ZarrsResult {
+    let base_url = baseUrl.as_str();
+    let timeout =
+        (timeoutSeconds > 0).then(|| std::time::Duration::from_secs(u64::from(timeoutSeconds)));
+    let storage = http_storage(base_url, timeout, Some(maxRetries as usize));
+    // SAFETY: pStorage is a valid pointer per the function's safety contract.
+    unsafe { http_storage_into_handle(storage, base_url, pStorage) }
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use zarrs::storage::storage_adapter::performance_metrics::PerformanceMetricsStorageAdapter;
+
+use crate::{LAST_ERROR, ZarrsResult};
+
+use super::{ZarrsStorage, ZarrsStorage_T, ZarrsStorageEnum};
+
+/// The requests made to a storage created with `zarrsCreateStoragePerformanceMetrics`.
+///
+/// These count the requests that reach the underlying store.
+/// A read that is served from a chunk or shard index cache is not counted.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct ZarrsStoragePerformanceMetrics {
+    /// The number of read requests (gets, partial gets, and size queries).
+    pub reads: u64,
+    /// The number of bytes read.
+    pub bytesRead: u64,
+    /// The number of write requests.
+    pub writes: u64,
+    /// The number of bytes written.
+    pub bytesWritten: u64,
+}
+
+/// Get the current metrics of a performance metrics storage.
+pub(crate) type StorageMetrics = Arc<dyn Fn() -> ZarrsStoragePerformanceMetrics + Send + Sync>;
+
+fn storage_metrics<TStorage: ?Sized + Send + Sync + 'static>(
+    storage: &Arc<PerformanceMetricsStorageAdapter<TStorage>>,
+) -> StorageMetrics {
+    let storage = storage.clone();
+    Arc::new(move || ZarrsStoragePerformanceMetrics {
+        reads: storage.reads() as u64,
+        bytesRead: storage.bytes_read() as u64,
+        writes: storage.writes() as u64,
+        bytesWritten: storage.bytes_written() as u64,
+    })
+}
+
+/// Wrap `storage` in a storage adapter that counts the requests made to it.
+pub(crate) fn performance_metrics_storage(
+    storage: &ZarrsStorageEnum,
+) -> (ZarrsStorageEnum, StorageMetrics) {
+    match storage {
+        ZarrsStorageEnum::R(storage) => {
+            let storage = Arc::new(PerformanceMetricsStorageAdapter::new(storage.clone()));
+            (
+                ZarrsStorageEnum::R(storage.clone()),
+                storage_metrics(&storage),
+            )
+        }
+        ZarrsStorageEnum::W(storage) => {
+            let storage = Arc::new(PerformanceMetricsStorageAdapter::new(storage.clone()));
+            (
+                ZarrsStorageEnum::W(storage.clone()),
+                storage_metrics(&storage),
+            )
+        }
+        ZarrsStorageEnum::L(storage) => {
+            let storage = Arc::new(PerformanceMetricsStorageAdapter::new(storage.clone()));
+            (
+                ZarrsStorageEnum::L(storage.clone()),
+                storage_metrics(&storage),
+            )
+        }
+        ZarrsStorageEnum::RL(storage) => {
+            let storage = Arc::new(PerformanceMetricsStorageAdapter::new(storage.clone()));
+            (
+                ZarrsStorageEnum::RL(storage.clone()),
+                storage_metrics(&storage),
+            )
+        }
+        ZarrsStorageEnum::RW(storage) => {
+            let storage = Arc::new(PerformanceMetricsStorageAdapter::new(storage.clone()));
+            (
+                ZarrsStorageEnum::RW(storage.clone()),
+                storage_metrics(&storage),
+            )
+        }
+        ZarrsStorageEnum::RWL(storage) => {
+            let storage = Arc::new(PerformanceMetricsStorageAdapter::new(storage.clone()));
+            (
+                ZarrsStorageEnum::RWL(storage.clone()),
+                storage_metrics(&storage),
+            )
+        }
+    }
+}
+
+/// Create a storage handle that counts the requests made to another storage.
+///
+/// The created storage has the same capabilities and URL as `storage`.
+/// `storage` remains valid and must still be destroyed with `zarrsDestroyStorage`; requests made through it directly are not counted.
+/// The counts are retrieved with `zarrsStorageGetPerformanceMetrics`.
+///
+/// `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+///
+/// # Errors
+/// Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle.
+/// `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsCreateStoragePerformanceMetrics(
+    storage: ZarrsStorage,
+    pStorage: *mut ZarrsStorage,
+) -> ZarrsResult {
+    if storage.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &*storage };
+    let (storage_enum, metrics) = performance_metrics_storage(&storage.0);
+    // SAFETY: pStorage is a valid pointer per the function's safety contract.
+    unsafe {
+        *pStorage = Box::into_raw(Box::new(ZarrsStorage_T::new(
+            storage_enum,
+            storage.2.clone(),
+            None,
+            Some(metrics),
+        )));
+    }
+    ZarrsResult::ZARRS_SUCCESS
+}
+
+/// Get the requests made to a storage created with `zarrsCreateStoragePerformanceMetrics`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` or `pMetrics` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_NOT_FOUND` if `storage` was not created with `zarrsCreateStoragePerformanceMetrics`.
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle and `pMetrics` must be a valid pointer to a `ZarrsStoragePerformanceMetrics`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsStorageGetPerformanceMetrics(
+    storage: ZarrsStorage,
+    pMetrics: *mut ZarrsStoragePerformanceMetrics,
+) -> ZarrsResult {
+    if storage.is_null() || pMetrics.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &*storage };
+    let Some(metrics) = &storage.4 else {
+        *LAST_ERROR.lock().unwrap() =
+            "storage was not created with zarrsCreateStoragePerformanceMetrics".to_string();
+        return ZarrsResult::ZARRS_ERROR_NOT_FOUND;
+    };
+    // SAFETY: pMetrics is not null, and the caller guarantees it is a valid pointer.
+    unsafe { *pMetrics = metrics() };
+    ZarrsResult::ZARRS_SUCCESS
+}
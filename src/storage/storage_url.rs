@@ -0,0 +1,150 @@
+use std::{
+    ffi::{CString, c_char},
+    sync::Arc,
+};
+
+use ffi_support::FfiStr;
+
+use crate::{LAST_ERROR, ZarrsResult};
+
+use super::{ZarrsStorage, ZarrsStorage_T, ZarrsStorageEnum, storage_http::http_storage};
+
+/// The URL schemes supported by `zarrsStorageCreateFromURL` in this build.
+const SUPPORTED_SCHEMES: &str = if cfg!(feature = "http") {
+    "file, http, https"
+} else {
+    "file"
+};
+
+/// Set the last error to `err` and return `ZarrsResult::ZARRS_ERROR_STORAGE`.
+fn storage_error(err: String) -> ZarrsResult {
+    *LAST_ERROR.lock().unwrap() = err;
+    ZarrsResult::ZARRS_ERROR_STORAGE
+}
+
+/// Create a filesystem store from the location of a `file://` URL.
+///
+/// Sets the last error on failure.
+fn storage_from_file_url(location: &str) -> Result<(ZarrsStorageEnum, String), ZarrsResult> {
+    let path = location.strip_prefix("localhost").unwrap_or(location);
+    if !path.starts_with('/') {
+        return Err(storage_error(format!(
+            "file URLs must have an empty or localhost host and an absolute path, got {location}"
+        )));
+    }
+    match zarrs::filesystem::FilesystemStore::new(path) {
+        Ok(store) => Ok((
+            ZarrsStorageEnum::RWL(Arc::new(store)),
+            format!("file://{path}"),
+        )),
+        Err(err) => Err(storage_error(err.to_string())),
+    }
+}
+
+/// Create a storage backend from a URL.
+///
+/// Returns the storage and its URL.
+/// Sets the last error on failure.
+fn storage_from_url(url: &str) -> Result<(ZarrsStorageEnum, String), ZarrsResult> {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return Err(storage_error(format!("{url} is not a URL")));
+    };
+    let scheme = scheme.to_ascii_lowercase();
+    let (location, query) = rest.split_once('?').unwrap_or((rest, ""));
+    if !query.is_empty() {
+        return Err(storage_error(format!(
+            "URL query parameters are not supported by the {scheme} storage backend"
+        )));
+    }
+    match scheme.as_str() {
+        "file" => storage_from_file_url(location),
+        "http" | "https" if cfg!(feature = "http") => {
+            http_storage(url, None, None).map(|storage| (storage, url.to_string()))
+        }
+        "http" | "https" => Err(storage_error(format!(
+            "the {scheme} URL scheme requires zarrs_ffi to be built with the http feature, supported schemes are: {SUPPORTED_SCHEMES}"
+        ))),
+        _ => Err(storage_error(format!(
+            "unsupported URL scheme {scheme}, supported schemes are: {SUPPORTED_SCHEMES}"
+        ))),
+    }
+}
+
+/// Create a storage handle from a URL.
+///
+/// The storage backend is chosen based on the URL scheme:
+/// - `file://` creates a filesystem store (read/write/list capability). The URL must have an empty or `localhost` host and an absolute path.
+/// - `http://` and `https://` create a HTTP store (read capability), equivalent to `zarrsStorageCreateHTTP`. These require the `http` feature.
+///
+/// Object storage schemes (e.g. `s3://`, `gs://`, `az://`) are not supported.
+/// None of the supported backends accept configuration through URL query parameters, so URLs with a query are rejected rather than silently ignoring it.
+/// `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the URL scheme is unsupported, the URL is invalid, or the storage could not be created.
+///   The last error lists the URL schemes supported by this build.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if a `http://` or `https://` URL is malformed.
+///
+/// # Safety
+/// `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsStorageCreateFromURL(
+    url: FfiStr,
+    pStorage: *mut ZarrsStorage,
+) -> ZarrsResult {
+    match storage_from_url(url.as_str()) {
+        Ok((storage, url)) => {
+            // SAFETY: pStorage is a valid pointer per the function's safety contract.
+            unsafe {
+                *pStorage = Box::into_raw(Box::new(ZarrsStorage_T::new(
+                    storage,
+                    Some(url),
+                    None,
+                    None,
+                )));
+            }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Get the URL of a storage handle.
+///
+/// This is the reverse of `zarrsStorageCreateFromURL`.
+/// Filesystem storage created with `zarrsCreateStorageFilesystem` has a `file://` URL with an absolute path.
+/// The string must be freed with `zarrsFreeString`.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+/// - Returns `ZarrsResult::ZARRS_ERROR_NOT_FOUND` if the storage does not have a URL (e.g. in-memory storage).
+///
+/// # Safety
+/// If not null, `storage` must be a valid `ZarrsStorage` handle.
+/// `pUrl` must be a valid pointer to a `char*`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsStorageGetURL(
+    storage: ZarrsStorage,
+    pUrl: *mut *mut c_char,
+) -> ZarrsResult {
+    if storage.is_null() {
+        return ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    }
+    // SAFETY: storage is not null, and the caller guarantees it is a valid ZarrsStorage handle.
+    let storage = unsafe { &*storage };
+    let Some(url) = &storage.2 else {
+        *LAST_ERROR.lock().unwrap() = "storage does not have a URL".to_string();
+        return ZarrsResult::ZARRS_ERROR_NOT_FOUND;
+    };
+    match CString::new(url.as_str()) {
+        Ok(cstring) => {
+            // SAFETY: pUrl is a valid pointer per the function's safety contract.
+            unsafe { *pUrl = cstring.into_raw() };
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(err) => {
+            *LAST_ERROR.lock().unwrap() = err.to_string();
+            ZarrsResult::ZARRS_ERROR_STORAGE
+        }
+    }
+}
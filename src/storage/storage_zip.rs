@@ -0,0 +1,272 @@
+#[cfg(feature = "zip")]
+use std::path::Path;
+use std::sync::Arc;
+
+use ffi_support::FfiStr;
+
+use crate::{LAST_ERROR, ZarrsResult};
+
+use super::{StorageFinalizer, ZarrsStorage, ZarrsStorage_T, ZarrsStorageEnum};
+
+/// Set the last error to `err` and return `ZarrsResult::ZARRS_ERROR_STORE_PREFIX`.
+#[cfg(feature = "zip")]
+fn store_prefix_error(err: impl ToString) -> ZarrsResult {
+    *LAST_ERROR.lock().unwrap() = err.to_string();
+    ZarrsResult::ZARRS_ERROR_STORE_PREFIX
+}
+
+/// Check that the directory that will contain the ZIP archive at `path` exists.
+///
+/// Sets the last error on failure.
+#[cfg(feature = "zip")]
+fn check_zip_parent(path: &Path) -> Result<(), ZarrsResult> {
+    match path.parent() {
+        Some(parent) if parent.as_os_str().is_empty() || parent.is_dir() => Ok(()),
+        _ => Err(store_prefix_error(format!(
+            "the parent directory of {} does not exist",
+            path.display()
+        ))),
+    }
+}
+
+/// Read every file in the ZIP archive at `path` into `store`.
+#[cfg(feature = "zip")]
+fn read_zip(path: &Path, store: &zarrs::storage::store::MemoryStore) -> Result<(), String> {
+    use std::io::Read;
+    use zarrs::storage::{StoreKey, WritableStorageTraits};
+
+    let file = std::fs::File::open(path).map_err(|err| err.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|err| err.to_string())?;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(|err| err.to_string())?;
+        if entry.is_dir() {
+            continue;
+        }
+        let key = StoreKey::new(entry.name()).map_err(|err| err.to_string())?;
+        let mut bytes = Vec::new();
+        entry
+            .read_to_end(&mut bytes)
+            .map_err(|err| err.to_string())?;
+        store
+            .set(&key, bytes.into())
+            .map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
+
+/// Write every value in `store` to a new ZIP archive at `path`.
+///
+/// Values are stored without compression, as chunks are typically already compressed.
+#[cfg(feature = "zip")]
+fn write_zip(path: &Path, store: &zarrs::storage::store::MemoryStore) -> Result<(), String> {
+    use std::io::Write;
+    use zarrs::storage::{ListableStorageTraits, ReadableStorageTraits};
+
+    let file = std::fs::File::create(path).map_err(|err| err.to_string())?;
+    let mut writer = zip::ZipWriter::new(file);
+    let options =
+        zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    for key in store.list().map_err(|err| err.to_string())? {
+        if let Some(bytes) = store.get(&key).map_err(|err| err.to_string())? {
+            writer
+                .start_file(key.as_str(), options)
+                .map_err(|err| err.to_string())?;
+            writer.write_all(&bytes).map_err(|err| err.to_string())?;
+        }
+    }
+    writer.finish().map_err(|err| err.to_string())?;
+    Ok(())
+}
+
+/// Open the ZIP archive at `path` as read-only storage.
+///
+/// Sets the last error on failure.
+#[cfg(feature = "zip")]
+fn zip_storage_r(path: &Path) -> Result<(ZarrsStorageEnum, Option<StorageFinalizer>), ZarrsResult> {
+    use zarrs::{filesystem::FilesystemStore, storage::StoreKey};
+
+    if !path.is_file() {
+        return Err(store_prefix_error(format!(
+            "{} is not an existing file",
+            path.display()
+        )));
+    }
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let file_name = path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy())
+        .unwrap_or_default();
+    let store = FilesystemStore::new(parent).map_err(store_prefix_error)?;
+    let key = StoreKey::new(file_name).map_err(store_prefix_error)?;
+    let store =
+        zarrs_zip::ZipStorageAdapter::new(Arc::new(store), key).map_err(store_prefix_error)?;
+    Ok((ZarrsStorageEnum::RL(Arc::new(store)), None))
+}
+
+/// Create storage that is written to a ZIP archive at `path` when it is finalized.
+///
+/// If `existing` is true and the archive exists, its contents are loaded into the storage.
+/// Sets the last error on failure.
+#[cfg(feature = "zip")]
+fn zip_storage_staged(
+    path: &Path,
+    existing: bool,
+) -> Result<(Arc<zarrs::storage::store::MemoryStore>, StorageFinalizer), ZarrsResult> {
+    check_zip_parent(path)?;
+    let store = Arc::new(zarrs::storage::store::MemoryStore::new());
+    if existing && path.exists() {
+        read_zip(path, &store).map_err(store_prefix_error)?;
+    }
+    let path = path.to_path_buf();
+    let finalizer_store = store.clone();
+    let finalizer: StorageFinalizer = Box::new(move || {
+        write_zip(&path, &finalizer_store)
+            .map_err(|err| format!("error writing ZIP archive {}: {err}", path.display()))
+    });
+    Ok((store, finalizer))
+}
+
+/// Open the ZIP archive at `path` as read/write storage.
+///
+/// Sets the last error on failure.
+#[cfg(feature = "zip")]
+fn zip_storage_rw(
+    path: &Path,
+) -> Result<(ZarrsStorageEnum, Option<StorageFinalizer>), ZarrsResult> {
+    let (store, finalizer) = zip_storage_staged(path, true)?;
+    Ok((ZarrsStorageEnum::RWL(store), Some(finalizer)))
+}
+
+/// Create a new ZIP archive at `path` as write-only storage.
+///
+/// Sets the last error on failure.
+#[cfg(feature = "zip")]
+fn zip_storage_w(path: &Path) -> Result<(ZarrsStorageEnum, Option<StorageFinalizer>), ZarrsResult> {
+    let (store, finalizer) = zip_storage_staged(path, false)?;
+    Ok((ZarrsStorageEnum::W(store), Some(finalizer)))
+}
+
+/// ZIP storage is unavailable without the `zip` feature.
+///
+/// Sets the last error.
+#[cfg(not(feature = "zip"))]
+fn zip_storage_unavailable() -> Result<(ZarrsStorageEnum, Option<StorageFinalizer>), ZarrsResult> {
+    *LAST_ERROR.lock().unwrap() =
+        "ZIP storage requires zarrs_ffi to be built with the zip feature".to_string();
+    Err(ZarrsResult::ZARRS_ERROR_STORAGE)
+}
+
+/// Return a handle to a ZIP storage.
+///
+/// # Safety
+/// `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+unsafe fn zip_storage_into_handle(
+    storage: Result<(ZarrsStorageEnum, Option<StorageFinalizer>), ZarrsResult>,
+    pStorage: *mut ZarrsStorage,
+) -> ZarrsResult {
+    match storage {
+        Ok((storage, finalizer)) => {
+            // SAFETY: pStorage is a valid pointer per the function's safety contract.
+            unsafe {
+                *pStorage = Box::into_raw(Box::new(ZarrsStorage_T::new(
+                    storage, None, finalizer, None,
+                )));
+            }
+            ZarrsResult::ZARRS_SUCCESS
+        }
+        Err(result) => result,
+    }
+}
+
+/// Create a storage handle to a ZIP archive (read/list capability).
+///
+/// `path` is the path to an existing ZIP archive (e.g. `data.zarr.zip`) containing a Zarr hierarchy at its root.
+/// `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+///
+/// ZIP storage requires zarrs_ffi to be built with the `zip` feature.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if `path` does not exist or is not a valid ZIP archive.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the `zip` feature is not enabled.
+///
+/// # Safety
+/// `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsStorageCreateZipR(
+    path: FfiStr,
+    pStorage: *mut ZarrsStorage,
+) -> ZarrsResult {
+    #[cfg(feature = "zip")]
+    let storage = zip_storage_r(Path::new(path.as_str()));
+    #[cfg(not(feature = "zip"))]
+    let storage = {
+        let _ = path;
+        zip_storage_unavailable()
+    };
+    // SAFETY: pStorage is a valid pointer per the function's safety contract.
+    unsafe { zip_storage_into_handle(storage, pStorage) }
+}
+
+/// Create a storage handle to a new or existing ZIP archive (read/write/list capability).
+///
+/// If the archive at `path` exists, its contents are loaded into memory.
+/// Writes are staged in memory, and the archive at `path` is (re)written when the storage handle is destroyed with `zarrsDestroyStorage`.
+/// Array and group handles created from the storage should store their data before the storage handle is destroyed.
+/// `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+///
+/// ZIP storage requires zarrs_ffi to be built with the `zip` feature.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if `path` exists but is not a valid ZIP archive, or its parent directory does not exist.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the `zip` feature is not enabled.
+///
+/// # Safety
+/// `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsStorageCreateZipRW(
+    path: FfiStr,
+    pStorage: *mut ZarrsStorage,
+) -> ZarrsResult {
+    #[cfg(feature = "zip")]
+    let storage = zip_storage_rw(Path::new(path.as_str()));
+    #[cfg(not(feature = "zip"))]
+    let storage = {
+        let _ = path;
+        zip_storage_unavailable()
+    };
+    // SAFETY: pStorage is a valid pointer per the function's safety contract.
+    unsafe { zip_storage_into_handle(storage, pStorage) }
+}
+
+/// Create a storage handle to a new ZIP archive (write capability).
+///
+/// Writes are staged in memory, and the archive at `path` is created (or overwritten) when the storage handle is destroyed with `zarrsDestroyStorage`.
+/// Array and group handles created from the storage should store their data before the storage handle is destroyed.
+/// `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+///
+/// ZIP storage requires zarrs_ffi to be built with the `zip` feature.
+///
+/// # Errors
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if the parent directory of `path` does not exist.
+/// - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the `zip` feature is not enabled.
+///
+/// # Safety
+/// `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zarrsStorageCreateZipW(
+    path: FfiStr,
+    pStorage: *mut ZarrsStorage,
+) -> ZarrsResult {
+    #[cfg(feature = "zip")]
+    let storage = zip_storage_w(Path::new(path.as_str()));
+    #[cfg(not(feature = "zip"))]
+    let storage = {
+        let _ = path;
+        zip_storage_unavailable()
+    };
+    // SAFETY: pStorage is a valid pointer per the function's safety contract.
+    unsafe { zip_storage_into_handle(storage, pStorage) }
+}
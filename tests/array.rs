@@ -62,3 +62,545 @@ fn ffi_array_write_c_read_c() {
         .success()
         .stdout("nan nan nan nan nan -1 -2 nan nan -3 -4 nan nan nan nan nan\n");
 }
+
+#[test]
+fn ffi_array_chunk_grid() {
+    let tmp_path = tempfile::tempdir().unwrap();
+    // SAFETY: This test runs in a single-threaded context
+    unsafe {
+        std::env::set_var(
+            "INLINE_C_RS_TMP_PATH_CHUNK_GRID",
+            tmp_path.path().to_string_lossy().to_string(),
+        );
+    }
+
+    assert_cxx_str(include_str!("array_chunk_grid.cpp")).success();
+}
+
+#[test]
+fn ffi_array_chunk_exists() {
+    let tmp_path = tempfile::tempdir().unwrap();
+    // SAFETY: This test runs in a single-threaded context
+    unsafe {
+        std::env::set_var(
+            "INLINE_C_RS_TMP_PATH_CHUNK_EXISTS",
+            tmp_path.path().to_string_lossy().to_string(),
+        );
+    }
+
+    assert_cxx_str(include_str!("array_chunk_exists.cpp")).success();
+}
+
+#[test]
+fn ffi_array_chunk_exists_bitmap() {
+    let tmp_path = tempfile::tempdir().unwrap();
+    // SAFETY: This test runs in a single-threaded context
+    unsafe {
+        std::env::set_var(
+            "INLINE_C_RS_TMP_PATH_CHUNK_EXISTS_BITMAP",
+            tmp_path.path().to_string_lossy().to_string(),
+        );
+    }
+
+    assert_cxx_str(include_str!("array_chunk_exists_bitmap.cpp")).success();
+}
+
+#[test]
+fn ffi_array_stored_chunk_size() {
+    let tmp_path = tempfile::tempdir().unwrap();
+    // SAFETY: This test runs in a single-threaded context
+    unsafe {
+        std::env::set_var(
+            "INLINE_C_RS_TMP_PATH_STORED_CHUNK_SIZE",
+            tmp_path.path().to_string_lossy().to_string(),
+        );
+    }
+
+    assert_cxx_str(include_str!("array_stored_chunk_size.cpp")).success();
+}
+
+#[test]
+fn ffi_array_stored_chunk_count() {
+    assert_cxx_str(include_str!("array_stored_chunk_count.cpp")).success();
+}
+
+#[test]
+fn ffi_is_empty() {
+    assert_cxx_str(include_str!("is_empty.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_subset() {
+    assert_cxx_str(include_str!("array_retrieve_subset.cpp")).success();
+}
+
+#[test]
+fn ffi_array_stride() {
+    assert_cxx_str(include_str!("array_stride.cpp")).success();
+}
+
+#[test]
+fn ffi_data_type_properties() {
+    assert_cxx_str(include_str!("data_type_properties.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_subset_as() {
+    assert_cxx_str(include_str!("array_retrieve_subset_as.cpp")).success();
+}
+
+#[test]
+fn ffi_array_update_shape() {
+    assert_cxx_str(include_str!("array_update_shape.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_subset_vlen() {
+    use std::sync::Arc;
+    use zarrs::array::{FillValue, data_type};
+    use zarrs::filesystem::FilesystemStore;
+
+    let tmp_path = tempfile::tempdir().unwrap();
+    // SAFETY: This test runs in a single-threaded context
+    unsafe {
+        std::env::set_var(
+            "INLINE_C_RS_TMP_PATH_RETRIEVE_SUBSET_VLEN",
+            tmp_path.path().to_string_lossy().to_string(),
+        );
+    }
+
+    let store = Arc::new(FilesystemStore::new(tmp_path.path()).unwrap());
+
+    // Create a string array with one stored chunk
+    let array = zarrs::array::ArrayBuilder::new(
+        vec![2, 4],
+        vec![2, 2],
+        data_type::string(),
+        FillValue::from(""),
+    )
+    .build(store.clone(), "/array")
+    .unwrap();
+    array.store_metadata().unwrap();
+    array
+        .store_chunk(&[0, 0], vec!["a", "bb", "", "dddd"])
+        .unwrap();
+
+    // Create a fixed size array
+    let array_fixed = zarrs::array::ArrayBuilder::new(
+        vec![2, 4],
+        vec![2, 2],
+        data_type::uint8(),
+        FillValue::from(0u8),
+    )
+    .build(store, "/array_fixed")
+    .unwrap();
+    array_fixed.store_metadata().unwrap();
+
+    assert_cxx_str(include_str!("array_retrieve_subset_vlen.cpp"))
+        .success()
+        .stdout("[bb] [] [dddd] []\n");
+}
+
+#[test]
+fn ffi_array_retrieve_alloc() {
+    assert_cxx_str(include_str!("array_retrieve_alloc.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_subset_strided() {
+    assert_cxx_str(include_str!("array_retrieve_subset_strided.cpp")).success();
+}
+
+#[test]
+fn ffi_array_order() {
+    assert_cxx_str(include_str!("array_order.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_chunks_batch() {
+    assert_cxx_str(include_str!("array_retrieve_chunks_batch.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_subset_callback() {
+    assert_cxx_str(include_str!("array_retrieve_subset_callback.cpp")).success();
+}
+
+#[test]
+fn ffi_group_child_node_type() {
+    assert_cxx_str(include_str!("group_child_node_type.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_subset_async() {
+    assert_cxx_str(include_str!("array_retrieve_subset_async.cpp")).success();
+}
+
+#[test]
+fn ffi_array_fill_value() {
+    assert_cxx_str(include_str!("array_fill_value.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_subset_parallel() {
+    assert_cxx_str(include_str!("array_retrieve_subset_parallel.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_subset_step() {
+    assert_cxx_str(include_str!("array_retrieve_subset_step.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_chunk_subset_into_buffer() {
+    assert_cxx_str(include_str!("array_retrieve_chunk_subset_into_buffer.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_orthogonal() {
+    assert_cxx_str(include_str!("array_retrieve_orthogonal.cpp")).success();
+}
+
+#[test]
+fn ffi_array_subset_bounds() {
+    assert_cxx_str(include_str!("array_subset_bounds.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_subset_scaled() {
+    assert_cxx_str(include_str!("array_retrieve_subset_scaled.cpp")).success();
+}
+
+#[test]
+fn ffi_array_chunk_cache() {
+    assert_cxx_str(include_str!("array_chunk_cache.cpp")).success();
+}
+
+#[test]
+fn ffi_array_chunk_representation() {
+    assert_cxx_str(include_str!("array_chunk_representation.cpp")).success();
+}
+
+#[test]
+fn ffi_array_codecs() {
+    assert_cxx_str(include_str!("array_codecs.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_subset_into_region() {
+    assert_cxx_str(include_str!("array_retrieve_subset_into_region.cpp")).success();
+}
+
+#[test]
+fn ffi_array_store_inner_chunk_subset() {
+    assert_cxx_str(include_str!("array_store_inner_chunk_subset.cpp")).success();
+}
+
+#[test]
+fn ffi_array_store_chunks() {
+    assert_cxx_str(include_str!("array_store_chunks.cpp")).success();
+}
+
+#[test]
+fn ffi_array_store_subset_write_only() {
+    assert_cxx_str(include_str!("array_store_subset_write_only.cpp")).success();
+}
+
+#[test]
+fn ffi_array_erase_chunk() {
+    assert_cxx_str(include_str!("array_erase_chunk.cpp")).success();
+}
+
+#[test]
+fn ffi_array_stored_inner_chunks() {
+    assert_cxx_str(include_str!("array_stored_inner_chunks.cpp")).success();
+}
+
+#[test]
+fn ffi_codec_options() {
+    assert_cxx_str(include_str!("codec_options.cpp")).success();
+}
+
+#[test]
+fn ffi_zarr_format() {
+    let tmp_path = tempfile::tempdir().unwrap();
+    // SAFETY: This test runs in a single-threaded context
+    unsafe {
+        std::env::set_var(
+            "INLINE_C_RS_TMP_PATH_ZARR_FORMAT",
+            tmp_path.path().to_string_lossy().to_string(),
+        );
+    }
+
+    assert_cxx_str(include_str!("zarr_format.cpp")).success();
+}
+
+#[test]
+fn ffi_storage_stats() {
+    assert_cxx_str(include_str!("storage_stats.cpp")).success();
+}
+
+#[test]
+fn ffi_array_single_element() {
+    assert_cxx_str(include_str!("array_single_element.cpp")).success();
+}
+
+#[test]
+fn ffi_array_chunk_subset() {
+    assert_cxx_str(include_str!("array_chunk_subset.cpp")).success();
+}
+
+#[test]
+fn ffi_array_json() {
+    assert_cxx_str(include_str!("array_json.cpp")).success();
+}
+
+#[test]
+fn ffi_consolidated_metadata() {
+    assert_cxx_str(include_str!("consolidated_metadata.cpp")).success();
+}
+
+#[test]
+fn ffi_array_attribute() {
+    assert_cxx_str(include_str!("array_attribute.cpp")).success();
+}
+
+#[test]
+fn ffi_attribute_count() {
+    assert_cxx_str(include_str!("attribute_count.cpp")).success();
+}
+
+#[test]
+fn ffi_group_hierarchy() {
+    assert_cxx_str(include_str!("group_hierarchy.cpp")).success();
+}
+
+#[test]
+fn ffi_ome_zarr() {
+    assert_cxx_str(include_str!("ome_zarr.cpp")).success();
+}
+
+#[test]
+fn ffi_array_metadata_v2() {
+    assert_cxx_str(include_str!("array_metadata_v2.cpp")).success();
+}
+
+#[test]
+fn ffi_array_additional_fields() {
+    assert_cxx_str(include_str!("array_additional_fields.cpp")).success();
+}
+
+#[test]
+fn ffi_extensions() {
+    assert_cxx_str(include_str!("extensions.cpp")).success();
+}
+
+#[test]
+fn ffi_array_encoded_chunk() {
+    assert_cxx_str(include_str!("array_encoded_chunk.cpp")).success();
+}
+
+#[test]
+fn ffi_array_info() {
+    assert_cxx_str(include_str!("array_info.cpp")).success();
+}
+
+#[test]
+fn ffi_shard_index_cache() {
+    assert_cxx_str(include_str!("shard_index_cache.cpp")).success();
+}
+
+#[test]
+fn ffi_array_retrieve_chunks() {
+    assert_cxx_str(include_str!("array_retrieve_chunks.cpp")).success();
+}
+
+#[test]
+fn ffi_array_subchunk_size() {
+    assert_cxx_str(include_str!("array_subchunk_size.cpp")).success();
+}
+
+#[test]
+fn ffi_array_v2() {
+    let tmp_path = tempfile::tempdir().unwrap();
+    // SAFETY: This test runs in a single-threaded context
+    unsafe {
+        std::env::set_var(
+            "INLINE_C_RS_TMP_PATH_ARRAY_V2",
+            tmp_path.path().to_string_lossy().to_string(),
+        );
+    }
+
+    // Write a Zarr V2 fixture with elements 0..16, omitting the chunk at [1, 1]
+    let array_path = tmp_path.path().join("array");
+    std::fs::create_dir(&array_path).unwrap();
+    std::fs::write(
+        array_path.join(".zarray"),
+        r#"{
+    "zarr_format": 2,
+    "shape": [4, 4],
+    "chunks": [2, 2],
+    "dtype": "<u2",
+    "compressor": null,
+    "fill_value": 0,
+    "order": "C",
+    "filters": null,
+    "dimension_separator": "."
+}"#,
+    )
+    .unwrap();
+    for (i, j) in [(0, 0), (0, 1), (1, 0)] {
+        let chunk: Vec<u8> = [(0, 0), (0, 1), (1, 0), (1, 1)]
+            .into_iter()
+            .flat_map(|(y, x)| (((2 * i + y) * 4 + 2 * j + x) as u16).to_le_bytes())
+            .collect();
+        std::fs::write(array_path.join(format!("{i}.{j}")), chunk).unwrap();
+    }
+
+    let invalid_path = tmp_path.path().join("invalid");
+    std::fs::create_dir(&invalid_path).unwrap();
+    std::fs::write(invalid_path.join(".zarray"), r#"{"zarr_format": 2}"#).unwrap();
+
+    assert_cxx_str(include_str!("array_v2.cpp")).success();
+}
+
+#[test]
+fn ffi_storage_capability() {
+    assert_cxx_str(include_str!("storage_capability.cpp")).success();
+}
+
+#[test]
+fn ffi_array_dimension_names() {
+    assert_cxx_str(include_str!("array_dimension_names.cpp")).success();
+}
+
+#[test]
+fn ffi_storage_url() {
+    let tmp_path = tempfile::tempdir().unwrap();
+    // SAFETY: This test runs in a single-threaded context
+    unsafe {
+        std::env::set_var(
+            "INLINE_C_RS_TMP_PATH_STORAGE_URL",
+            tmp_path.path().to_string_lossy().to_string(),
+        );
+    }
+
+    assert_cxx_str(include_str!("storage_url.cpp")).success();
+}
+
+/// Serve the files under `root` over HTTP on a local port, returning the base URL.
+///
+/// This is a minimal HTTP/1.1 server supporting `GET` and `HEAD` requests with single byte ranges.
+#[cfg(feature = "http")]
+fn serve_directory(root: std::path::PathBuf) -> String {
+    use std::io::{BufRead, BufReader, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut range = None;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("range")
+                {
+                    range = value.trim().strip_prefix("bytes=").map(str::to_string);
+                }
+            }
+
+            let mut parts = request_line.split_whitespace();
+            let (method, path) = (parts.next().unwrap(), parts.next().unwrap());
+            let Ok(bytes) = std::fs::read(root.join(path.trim_start_matches('/'))) else {
+                write!(
+                    stream,
+                    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                )
+                .unwrap();
+                continue;
+            };
+            let size = bytes.len();
+            let (status, content_range, body) = match range.and_then(|range| {
+                let (start, end) = range.split_once('-')?;
+                let (start, end) = match (start.parse::<usize>(), end.parse::<usize>()) {
+                    (Ok(start), Ok(end)) => (start, end.min(size - 1)),
+                    (Ok(start), Err(_)) => (start, size - 1),
+                    (Err(_), Ok(suffix)) => (size - suffix.min(size), size - 1),
+                    _ => return None,
+                };
+                Some((start, end))
+            }) {
+                Some((start, end)) => (
+                    "206 Partial Content",
+                    format!("Content-Range: bytes {start}-{end}/{size}\r\n"),
+                    &bytes[start..=end],
+                ),
+                None => ("200 OK", String::new(), &bytes[..]),
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\n{content_range}Last-Modified: Thu, 01 Jan 2026 00:00:00 GMT\r\nETag: \"{size}\"\r\nConnection: close\r\n\r\n",
+                body.len()
+            )
+            .unwrap();
+            if method == "GET" {
+                stream.write_all(body).unwrap();
+            }
+        }
+    });
+    url
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn ffi_storage_http() {
+    use std::sync::Arc;
+    use zarrs::array::{FillValue, data_type};
+    use zarrs::filesystem::FilesystemStore;
+
+    let tmp_path = tempfile::tempdir().unwrap();
+    let store = Arc::new(FilesystemStore::new(tmp_path.path()).unwrap());
+    let array = zarrs::array::ArrayBuilder::new(
+        vec![8, 8],
+        vec![4, 4],
+        data_type::float32(),
+        FillValue::from(-1.0f32),
+    )
+    .build(store, "/array")
+    .unwrap();
+    array.store_metadata().unwrap();
+    array
+        .store_chunk(&[0, 0], (0..16).map(|f| f as f32).collect::<Vec<_>>())
+        .unwrap();
+
+    let url = serve_directory(tmp_path.path().to_path_buf());
+    // SAFETY: This test runs in a single-threaded context
+    unsafe {
+        std::env::set_var("INLINE_C_RS_TMP_PATH_STORAGE_HTTP_URL", url);
+    }
+
+    assert_cxx_str(include_str!("storage_http.cpp")).success();
+}
+
+#[cfg(feature = "zip")]
+#[test]
+fn ffi_storage_zip() {
+    let tmp_path = tempfile::tempdir().unwrap();
+    // SAFETY: This test runs in a single-threaded context
+    unsafe {
+        std::env::set_var(
+            "INLINE_C_RS_TMP_PATH_STORAGE_ZIP",
+            tmp_path.path().to_string_lossy().to_string(),
+        );
+    }
+
+    assert_cxx_str(include_str!("storage_zip.cpp")).success();
+}
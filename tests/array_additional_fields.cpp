@@ -0,0 +1,66 @@
+#include "zarrs.h"
+
+#include <cstring>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  char *json = nullptr;
+  zarrs_assert(zarrsArrayGetAdditionalFieldsString(array, &json));
+  assert(strcmp(json, "{}") == 0);
+  zarrs_assert(zarrsFreeString(json));
+
+  // Core fields are rejected
+  assert(zarrsArraySetAdditionalField(array, "shape", "[1, 1]", true) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  assert(zarrsArraySetAdditionalField(array, "attributes", "{}", false) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+
+  // Invalid values are rejected
+  assert(zarrsArraySetAdditionalField(array, "provenance", "{", false) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  assert(zarrsArraySetAdditionalField(array, "provenance", "\"tool\"", false) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+
+  zarrs_assert(zarrsArraySetAdditionalField(array, "provenance", R"({"tool": "writer"})", false));
+  zarrs_assert(zarrsArraySetAdditionalField(array, "provenance", R"({"tool": "pipeline"})", false));
+  zarrs_assert(zarrsArrayGetAdditionalFieldsString(array, &json));
+  assert(strstr(json, "\"provenance\"") != nullptr);
+  assert(strstr(json, "\"pipeline\"") != nullptr);
+  assert(strstr(json, "\"writer\"") == nullptr);
+  assert(strstr(json, "\"must_understand\":false") != nullptr);
+  zarrs_assert(zarrsFreeString(json));
+
+  // Persist and reopen
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array", &array));
+  zarrs_assert(zarrsArrayGetAdditionalFieldsString(array, &json));
+  assert(strstr(json, "\"pipeline\"") != nullptr);
+  zarrs_assert(zarrsFreeString(json));
+  zarrs_assert(zarrsArrayGetMetadataString(array, false, &json));
+  assert(strstr(json, "\"provenance\"") != nullptr);
+  zarrs_assert(zarrsFreeString(json));
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
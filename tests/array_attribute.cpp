@@ -0,0 +1,101 @@
+#include "zarrs.h"
+
+#include <cstring>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}],
+    "attributes": {
+        "multiscales": [{"axes": [{"name": "y", "type": "space"}, {"name": "x", "type": "space"}]}],
+        "units": {"spatial": "micrometer"},
+        "a/b": 1
+    }
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  char *value = nullptr;
+
+  // Nested objects
+  zarrs_assert(zarrsArrayGetAttribute(array, "/units/spatial", false, &value));
+  assert(strcmp(value, "\"micrometer\"") == 0);
+  zarrs_assert(zarrsFreeString(value));
+
+  // Array indices
+  zarrs_assert(zarrsArrayGetAttribute(array, "/multiscales/0/axes/1", false, &value));
+  assert(strcmp(value, "{\"name\":\"x\",\"type\":\"space\"}") == 0);
+  zarrs_assert(zarrsFreeString(value));
+
+  // Escaped keys
+  zarrs_assert(zarrsArrayGetAttribute(array, "/a~1b", false, &value));
+  assert(strcmp(value, "1") == 0);
+  zarrs_assert(zarrsFreeString(value));
+
+  // Missing keys
+  assert(zarrsArrayGetAttribute(array, "/units/temporal", false, &value) ==
+         ZarrsResult::ZARRS_ERROR_NOT_FOUND);
+  assert(zarrsArrayGetAttribute(array, "/multiscales/1", false, &value) ==
+         ZarrsResult::ZARRS_ERROR_NOT_FOUND);
+
+  // Merge updates
+  zarrs_assert(zarrsArrayUpdateAttributes(array, R"({"units": {"temporal": "second"}, "a/b": null, "new": [1, 2]})"));
+  zarrs_assert(zarrsArrayGetAttribute(array, "/units/spatial", false, &value));
+  assert(strcmp(value, "\"micrometer\"") == 0);
+  zarrs_assert(zarrsFreeString(value));
+  zarrs_assert(zarrsArrayGetAttribute(array, "/units/temporal", false, &value));
+  assert(strcmp(value, "\"second\"") == 0);
+  zarrs_assert(zarrsFreeString(value));
+  zarrs_assert(zarrsArrayGetAttribute(array, "/multiscales/0/axes/1/name", false, &value));
+  assert(strcmp(value, "\"x\"") == 0);
+  zarrs_assert(zarrsFreeString(value));
+  zarrs_assert(zarrsArrayGetAttribute(array, "/new", false, &value));
+  assert(strcmp(value, "[1,2]") == 0);
+  zarrs_assert(zarrsFreeString(value));
+  assert(zarrsArrayGetAttribute(array, "/a~1b", false, &value) == ZarrsResult::ZARRS_ERROR_NOT_FOUND);
+
+  // Non-object values replace objects
+  zarrs_assert(zarrsArrayUpdateAttributes(array, R"({"units": "micrometer"})"));
+  zarrs_assert(zarrsArrayGetAttribute(array, "/units", false, &value));
+  assert(strcmp(value, "\"micrometer\"") == 0);
+  zarrs_assert(zarrsFreeString(value));
+
+  // Single attributes
+  zarrs_assert(zarrsArraySetAttribute(array, "last_written_chunk", "[0, 1]"));
+  zarrs_assert(zarrsArraySetAttribute(array, "last_written_chunk", "[1, 1]"));
+  zarrs_assert(zarrsArrayGetAttribute(array, "/last_written_chunk", false, &value));
+  assert(strcmp(value, "[1,1]") == 0);
+  zarrs_assert(zarrsFreeString(value));
+  zarrs_assert(zarrsArrayGetAttribute(array, "/units", false, &value));
+  assert(strcmp(value, "\"micrometer\"") == 0);
+  zarrs_assert(zarrsFreeString(value));
+  assert(zarrsArraySetAttribute(array, "last_written_chunk", "[1,") == ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  bool removed = false;
+  zarrs_assert(zarrsArrayRemoveAttribute(array, "last_written_chunk", &removed));
+  assert(removed);
+  zarrs_assert(zarrsArrayRemoveAttribute(array, "last_written_chunk", &removed));
+  assert(!removed);
+  assert(zarrsArrayGetAttribute(array, "/last_written_chunk", false, &value) ==
+         ZarrsResult::ZARRS_ERROR_NOT_FOUND);
+
+  // Invalid updates
+  assert(zarrsArrayUpdateAttributes(array, "[1]") == ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  assert(zarrsArrayUpdateAttributes(array, "{") == ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
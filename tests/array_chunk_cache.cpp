@@ -0,0 +1,217 @@
+#include "zarrs.h"
+
+#include <thread>
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+void store_elements(ZarrsArray array, uint16_t first) {
+  std::vector<uint16_t> elements(8 * 8);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = first + i;
+  }
+  uint64_t start[] = {0, 0};
+  uint64_t shape[] = {8, 8};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, start, shape, elements.size() * sizeof(uint16_t),
+                                     reinterpret_cast<const uint8_t *>(elements.data())));
+}
+
+uint64_t store_reads(ZarrsStorage storage) {
+  ZarrsStoragePerformanceMetrics metrics;
+  zarrs_assert(zarrsStorageGetPerformanceMetrics(storage, &metrics));
+  return metrics.reads;
+}
+
+int main() {
+  // Count the requests that reach the store
+  ZarrsStorage memory_storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&memory_storage));
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStoragePerformanceMetrics(memory_storage, &storage));
+  ZarrsStoragePerformanceMetrics metrics;
+  assert(zarrsStorageGetPerformanceMetrics(memory_storage, &metrics) ==
+         ZarrsResult::ZARRS_ERROR_NOT_FOUND);
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  store_elements(array, 0);
+
+  ZarrsChunkCache cache = nullptr;
+  zarrs_assert(zarrsCreateChunkCacheDecodedLru(array, 1024 * 1024, &cache));
+
+  // A subset intersecting all 4 chunks
+  uint64_t subset_start[] = {2, 1};
+  uint64_t subset_shape[] = {4, 5};
+  std::vector<uint16_t> subset(4 * 5);
+  zarrs_assert(zarrsArrayResetStorageStats(array));
+  uint64_t reads = store_reads(storage);
+  zarrs_assert(zarrsArrayRetrieveSubsetCached(array, cache, 2, subset_start, subset_shape,
+                                              subset.size() * sizeof(uint16_t),
+                                              reinterpret_cast<uint8_t *>(subset.data())));
+  assert(store_reads(storage) > reads);
+  for (size_t i = 0; i < 4; ++i) {
+    for (size_t j = 0; j < 5; ++j) {
+      assert(subset[i * 5 + j] == (2 + i) * 8 + 1 + j);
+    }
+  }
+  ZarrsStorageStats stats;
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.cacheHits == 0 && stats.cacheMisses == 4);
+  assert(stats.chunksDecoded == 4);
+
+  // The second read is served from the cache without any requests to the store
+  zarrs_assert(zarrsArrayResetStorageStats(array));
+  reads = store_reads(storage);
+  std::vector<uint16_t> subset_again(4 * 5);
+  zarrs_assert(zarrsArrayRetrieveSubsetCached(array, cache, 2, subset_start, subset_shape,
+                                              subset_again.size() * sizeof(uint16_t),
+                                              reinterpret_cast<uint8_t *>(subset_again.data())));
+  assert(store_reads(storage) == reads);
+  assert(subset_again == subset);
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.cacheHits == 4 && stats.cacheMisses == 0);
+  assert(stats.chunksDecoded == 0 && stats.bytesRead == 0);
+
+  // The cache is not consulted for storage, so chunks overwritten through another handle are still served from the cache
+  ZarrsArray other_array = nullptr;
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array", &other_array));
+  store_elements(other_array, 1000);
+  zarrs_assert(zarrsArrayRetrieveSubsetCached(array, cache, 2, subset_start, subset_shape,
+                                              subset_again.size() * sizeof(uint16_t),
+                                              reinterpret_cast<uint8_t *>(subset_again.data())));
+  assert(subset_again == subset);
+  std::vector<uint16_t> chunk(4 * 4);
+  uint64_t chunk_indices[] = {1, 1};
+  zarrs_assert(zarrsArrayRetrieveChunkCached(array, cache, 2, chunk_indices,
+                                             chunk.size() * sizeof(uint16_t),
+                                             reinterpret_cast<uint8_t *>(chunk.data())));
+  assert(chunk[0] == 4 * 8 + 4);
+  assert(zarrsArrayRetrieveChunkCached(array, cache, 2, chunk_indices, 1,
+                                       reinterpret_cast<uint8_t *>(chunk.data())) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+
+  // The cache is bound to the array handle it was created for
+  assert(zarrsArrayRetrieveChunkCached(other_array, cache, 2, chunk_indices,
+                                       chunk.size() * sizeof(uint16_t),
+                                       reinterpret_cast<uint8_t *>(chunk.data())) ==
+         ZarrsResult::ZARRS_ERROR_ARRAY);
+  uint64_t hits, misses, current_bytes, entry_count;
+  zarrs_assert(zarrsChunkCacheStats(cache, &hits, &misses, &current_bytes, &entry_count));
+  assert(hits == 10 && misses == 4);
+  assert(current_bytes == 4 * 4 * 4 * sizeof(uint16_t) && entry_count == 4);
+  zarrs_assert(zarrsDestroyChunkCache(cache));
+
+  // The cache can be shared by concurrent reads
+  zarrs_assert(zarrsCreateChunkCacheDecodedLruChunkLimit(array, 2, &cache));
+  std::vector<std::thread> threads;
+  for (uint64_t t = 0; t < 8; ++t) {
+    threads.emplace_back([array, cache, t]() {
+      for (uint64_t i = 0; i < 16; ++i) {
+        uint64_t indices[] = {(t + i) % 2, i % 2};
+        std::vector<uint16_t> thread_chunk(4 * 4);
+        zarrs_assert(zarrsArrayRetrieveChunkCached(array, cache, 2, indices,
+                                                   thread_chunk.size() * sizeof(uint16_t),
+                                                   reinterpret_cast<uint8_t *>(thread_chunk.data())));
+        assert(thread_chunk[0] == 1000 + indices[0] * 4 * 8 + indices[1] * 4);
+      }
+    });
+  }
+  for (auto &thread : threads) {
+    thread.join();
+  }
+  zarrs_assert(zarrsDestroyChunkCache(cache));
+
+  // An encoded chunk cache skips storage on a hit, but decodes every chunk
+  zarrs_assert(zarrsCreateChunkCacheEncodedLru(array, 1024 * 1024, &cache));
+  for (int read = 0; read < 2; ++read) {
+    zarrs_assert(zarrsArrayResetStorageStats(array));
+    reads = store_reads(storage);
+    zarrs_assert(zarrsArrayRetrieveSubsetCached(array, cache, 2, subset_start, subset_shape,
+                                                subset_again.size() * sizeof(uint16_t),
+                                                reinterpret_cast<uint8_t *>(subset_again.data())));
+    assert((store_reads(storage) == reads) == (read == 1));
+    for (size_t i = 0; i < 4; ++i) {
+      for (size_t j = 0; j < 5; ++j) {
+        assert(subset_again[i * 5 + j] == 1000 + (2 + i) * 8 + 1 + j);
+      }
+    }
+    zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+    assert(stats.chunksDecoded == 4);
+    zarrs_assert(zarrsChunkCacheStats(cache, &hits, &misses, &current_bytes, &entry_count));
+    assert(hits == 4 * read && misses == 4);
+    assert(current_bytes == 4 * 4 * 4 * sizeof(uint16_t) && entry_count == 4);
+  }
+
+  // Clearing the cache evicts all chunks, so the next read misses
+  zarrs_assert(zarrsChunkCacheClear(cache));
+  zarrs_assert(zarrsChunkCacheStats(cache, &hits, &misses, &current_bytes, &entry_count));
+  assert(current_bytes == 0 && entry_count == 0);
+  zarrs_assert(zarrsArrayRetrieveChunkCached(array, cache, 2, chunk_indices,
+                                             chunk.size() * sizeof(uint16_t),
+                                             reinterpret_cast<uint8_t *>(chunk.data())));
+  assert(chunk[0] == 1000 + 4 * 8 + 4);
+  zarrs_assert(zarrsChunkCacheStats(cache, &hits, &misses, &current_bytes, &entry_count));
+  assert(hits == 4 && misses == 5 && entry_count == 1);
+  zarrs_assert(zarrsDestroyChunkCache(cache));
+
+  // Chunks that do not exist are cached as empty and decoded to the fill value
+  ZarrsArray empty_array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/empty", array_metadata, &empty_array));
+  zarrs_assert(zarrsCreateChunkCacheEncodedLru(empty_array, 1024 * 1024, &cache));
+  for (int read = 0; read < 2; ++read) {
+    chunk.assign(chunk.size(), 1);
+    zarrs_assert(zarrsArrayRetrieveChunkCached(empty_array, cache, 2, chunk_indices,
+                                               chunk.size() * sizeof(uint16_t),
+                                               reinterpret_cast<uint8_t *>(chunk.data())));
+    for (uint16_t element : chunk) {
+      assert(element == 0);
+    }
+  }
+  zarrs_assert(zarrsChunkCacheStats(cache, &hits, &misses, &current_bytes, &entry_count));
+  assert(hits == 1 && misses == 1);
+  assert(current_bytes == 0 && entry_count == 1);
+  zarrs_assert(zarrsDestroyChunkCache(cache));
+
+  // A chunk larger than the cache capacity is not cached, and does not evict the cached chunks
+  zarrs_assert(zarrsCreateChunkCacheEncodedLru(empty_array, 4 * 4 * sizeof(uint16_t) - 1, &cache));
+  zarrs_assert(zarrsArrayRetrieveChunkCached(empty_array, cache, 2, chunk_indices,
+                                             chunk.size() * sizeof(uint16_t),
+                                             reinterpret_cast<uint8_t *>(chunk.data())));
+  uint64_t stored_indices[] = {0, 0};
+  chunk.assign(chunk.size(), 1);
+  zarrs_assert(zarrsArrayStoreChunk(empty_array, 2, stored_indices, chunk.size() * sizeof(uint16_t),
+                                    reinterpret_cast<const uint8_t *>(chunk.data())));
+  for (int read = 0; read < 2; ++read) {
+    zarrs_assert(zarrsArrayRetrieveChunkCached(empty_array, cache, 2, stored_indices,
+                                               chunk.size() * sizeof(uint16_t),
+                                               reinterpret_cast<uint8_t *>(chunk.data())));
+  }
+  zarrs_assert(zarrsChunkCacheStats(cache, &hits, &misses, &current_bytes, &entry_count));
+  assert(hits == 0 && misses == 3);
+  assert(current_bytes == 0 && entry_count == 1);
+  zarrs_assert(zarrsDestroyChunkCache(cache));
+  zarrs_assert(zarrsDestroyArray(empty_array));
+
+  assert(zarrsCreateChunkCacheDecodedLruChunkLimit(array, 0, &cache) ==
+         ZarrsResult::ZARRS_ERROR_ARRAY);
+  assert(zarrsDestroyChunkCache(nullptr) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsChunkCacheClear(nullptr) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  zarrs_assert(zarrsDestroyArray(other_array));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+  zarrs_assert(zarrsDestroyStorage(memory_storage));
+}
@@ -0,0 +1,78 @@
+#include "zarrs.h"
+
+#include <algorithm>
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  const char *tmp_path = getenv("TMP_PATH_CHUNK_EXISTS");
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageFilesystem(tmp_path, &storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+
+  // Store the chunks in the first row of the chunk grid only
+  std::vector<uint8_t> chunk_bytes(16, 1);
+  for (uint64_t x = 0; x < 2; ++x) {
+    uint64_t chunk_indices[] = {0, x};
+    zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, chunk_bytes.size(), chunk_bytes.data()));
+  }
+
+  for (uint64_t y = 0; y < 2; ++y) {
+    for (uint64_t x = 0; x < 2; ++x) {
+      uint64_t chunk_indices[] = {y, x};
+      bool exists = false;
+      zarrs_assert(zarrsArrayChunkExists(array, 2, chunk_indices, &exists));
+      assert(exists == (y == 0));
+    }
+  }
+
+  // Out of bounds chunk indices
+  uint64_t chunk_indices[] = {2, 0};
+  bool exists = false;
+  assert(zarrsArrayChunkExists(array, 2, chunk_indices, &exists) == ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+
+  // Retrieve chunks only if they exist
+  std::vector<uint8_t> retrieved(16, 7);
+  uint64_t stored_chunk_indices[] = {0, 1};
+  zarrs_assert(zarrsArrayRetrieveChunkIfExists(array, 2, stored_chunk_indices, retrieved.size(),
+                                               retrieved.data(), &exists));
+  assert(exists);
+  assert(retrieved == chunk_bytes);
+  std::fill(retrieved.begin(), retrieved.end(), 7);
+  uint64_t missing_chunk_indices[] = {1, 1};
+  zarrs_assert(zarrsArrayRetrieveChunkIfExists(array, 2, missing_chunk_indices, retrieved.size(),
+                                               retrieved.data(), &exists));
+  assert(!exists);
+  assert(retrieved == std::vector<uint8_t>(16, 7));
+  assert(zarrsArrayRetrieveChunkIfExists(array, 2, stored_chunk_indices, retrieved.size() - 1,
+                                         retrieved.data(), &exists) == ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+
+  // Read-only arrays
+  ZarrsArray array_r = nullptr;
+  zarrs_assert(zarrsOpenArrayR(storage, "/array", &array_r));
+  zarrs_assert(zarrsArrayRetrieveChunkIfExists(array_r, 2, stored_chunk_indices, retrieved.size(),
+                                               retrieved.data(), &exists));
+  assert(exists);
+  assert(retrieved == chunk_bytes);
+  zarrs_assert(zarrsDestroyArray(array_r));
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,69 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [16, 16],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+void test_chunk_exists_bitmap(ZarrsStorage storage) {
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+
+  // Store the chunks on the diagonal of the 4x4 chunk grid and chunk [0, 3]
+  std::vector<uint8_t> chunk_bytes(16, 1);
+  uint64_t stored_chunks[][2] = {{0, 0}, {1, 1}, {2, 2}, {3, 3}, {0, 3}};
+  for (auto &chunk_indices : stored_chunks) {
+    zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, chunk_bytes.size(), chunk_bytes.data()));
+  }
+
+  // Entire chunk grid, bit i = chunk (i / 4, i % 4)
+  uint64_t chunks_start[] = {0, 0};
+  uint64_t chunks_shape[] = {4, 4};
+  uint8_t bitmap[2] = {0xFF, 0xFF};
+  zarrs_assert(zarrsArrayGetChunkExistsBitmap(array, 2, chunks_start, chunks_shape, bitmap));
+  assert(bitmap[0] == 0b00101001); // chunks 0, 3, 5
+  assert(bitmap[1] == 0b10000100); // chunks 10, 15
+
+  // Region [1..3, 1..4], bit i = chunk (1 + i / 3, 1 + i % 3)
+  uint64_t region_start[] = {1, 1};
+  uint64_t region_shape[] = {2, 3};
+  uint8_t region_bitmap = 0xFF;
+  zarrs_assert(zarrsArrayGetChunkExistsBitmap(array, 2, region_start, region_shape, &region_bitmap));
+  assert(region_bitmap == 0b00010001); // chunks [1, 1] and [2, 2]
+
+  // Region out of bounds
+  uint64_t invalid_shape[] = {4, 5};
+  assert(zarrsArrayGetChunkExistsBitmap(array, 2, chunks_start, invalid_shape, bitmap) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+
+  zarrs_assert(zarrsDestroyArray(array));
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+
+  // Memory store
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  test_chunk_exists_bitmap(storage);
+  zarrs_assert(zarrsDestroyStorage(storage));
+
+  // Filesystem store
+  const char *tmp_path = getenv("TMP_PATH_CHUNK_EXISTS_BITMAP");
+  zarrs_assert(zarrsCreateStorageFilesystem(tmp_path, &storage));
+  test_chunk_exists_bitmap(storage);
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,232 @@
+#include "zarrs.h"
+
+#include <cstring>
+#include <string>
+
+const char *regular_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+const char *rectangular_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "rectangular",
+        "configuration": {"chunk_shape": [[1, 3, 4], 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+const char *edge_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [10, 8],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+const char *huge_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4294967296, 4294967297],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [65536, 65536]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  const char *tmp_path = getenv("TMP_PATH_CHUNK_GRID");
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageFilesystem(tmp_path, &storage));
+
+  // Regular chunk grid
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/regular", regular_array_metadata, &array));
+  ZarrsChunkGridType chunk_grid_type;
+  zarrs_assert(zarrsArrayGetChunkGridType(array, &chunk_grid_type));
+  assert(chunk_grid_type == ZarrsChunkGridType::ZARRS_CHUNK_GRID_REGULAR);
+  char *chunk_grid_json = nullptr;
+  zarrs_assert(zarrsArrayGetChunkGridString(array, &chunk_grid_json));
+  assert(std::string(chunk_grid_json).find("\"regular\"") != std::string::npos);
+  zarrs_assert(zarrsFreeString(chunk_grid_json));
+  uint64_t element_indices[] = {5, 2};
+  uint64_t element_chunk_indices[2];
+  uint64_t within_chunk_offset[2];
+  zarrs_assert(zarrsArrayGetChunkIndicesForElement(array, 2, element_indices, element_chunk_indices,
+                                                   within_chunk_offset));
+  assert(element_chunk_indices[0] == 1 && element_chunk_indices[1] == 0);
+  assert(within_chunk_offset[0] == 1 && within_chunk_offset[1] == 2);
+  uint64_t chunk_count[2];
+  zarrs_assert(zarrsArrayGetChunkCount(array, 2, chunk_count));
+  assert(chunk_count[0] == 2 && chunk_count[1] == 2);
+  uint64_t total_chunk_count = 0;
+  zarrs_assert(zarrsArrayGetChunkCountProduct(array, &total_chunk_count));
+  assert(total_chunk_count == (8 / 4) * (8 / 4));
+  uint64_t num_chunks = 0;
+  zarrs_assert(zarrsArrayGetNumChunks(array, &num_chunks));
+  assert(num_chunks == total_chunk_count);
+  uint64_t num_elements = 0;
+  zarrs_assert(zarrsArrayGetNumElements(array, &num_elements));
+  assert(num_elements == 8 * 8);
+  uint64_t regular_chunk_shape[2];
+  zarrs_assert(zarrsArrayGetRegularChunkShape(array, 2, regular_chunk_shape));
+  assert(regular_chunk_shape[0] == 4 && regular_chunk_shape[1] == 4);
+  assert(zarrsArrayGetRegularChunkShape(array, 3, regular_chunk_shape) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  uint64_t edge_chunk_shape[2];
+  bool has_edge_chunks = true;
+  zarrs_assert(zarrsArrayGetChunkSizeForBoundaryChunks(array, 2, regular_chunk_shape, edge_chunk_shape,
+                                                       &has_edge_chunks));
+  assert(regular_chunk_shape[0] == 4 && regular_chunk_shape[1] == 4);
+  assert(edge_chunk_shape[0] == 4 && edge_chunk_shape[1] == 4);
+  assert(!has_edge_chunks);
+  assert(zarrsArrayGetChunkSizeForBoundaryChunks(array, 1, regular_chunk_shape, edge_chunk_shape,
+                                                 &has_edge_chunks) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  size_t max_chunk_size = 0;
+  zarrs_assert(zarrsArrayGetMaxChunkSizeBytes(array, &max_chunk_size));
+  assert(max_chunk_size == 4 * 4);
+  uint64_t out_of_bounds_element_indices[] = {8, 0};
+  assert(zarrsArrayGetChunkIndicesForElement(array, 2, out_of_bounds_element_indices,
+                                             element_chunk_indices, within_chunk_offset) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Regular chunk grid with edge chunks
+  zarrs_assert(zarrsCreateArrayRW(storage, "/edge", edge_array_metadata, &array));
+  zarrs_assert(zarrsArrayGetChunkSizeForBoundaryChunks(array, 2, regular_chunk_shape, edge_chunk_shape,
+                                                       &has_edge_chunks));
+  assert(regular_chunk_shape[0] == 4 && regular_chunk_shape[1] == 4);
+  assert(edge_chunk_shape[0] == 2 && edge_chunk_shape[1] == 4);
+  assert(has_edge_chunks);
+  zarrs_assert(zarrsArrayGetNumChunks(array, &num_chunks));
+  assert(num_chunks == 3 * 2);
+  zarrs_assert(zarrsArrayGetNumElements(array, &num_elements));
+  assert(num_elements == 10 * 8);
+  zarrs_assert(zarrsArrayGetMaxChunkSizeBytes(array, &max_chunk_size));
+  assert(max_chunk_size == 4 * 4 * sizeof(uint16_t));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Rectangular chunk grid
+  zarrs_assert(zarrsCreateArrayRW(storage, "/rectangular", rectangular_array_metadata, &array));
+  zarrs_assert(zarrsArrayGetChunkGridType(array, &chunk_grid_type));
+  assert(chunk_grid_type == ZarrsChunkGridType::ZARRS_CHUNK_GRID_RECTANGULAR);
+  zarrs_assert(zarrsArrayGetChunkGridString(array, &chunk_grid_json));
+  assert(std::string(chunk_grid_json).find("\"rectangular\"") != std::string::npos);
+  zarrs_assert(zarrsFreeString(chunk_grid_json));
+  uint64_t chunk_grid_shape[2];
+  zarrs_assert(zarrsArrayGetChunkGridShape(array, 2, chunk_grid_shape));
+  assert(chunk_grid_shape[0] == 3);
+  assert(chunk_grid_shape[1] == 2);
+  uint64_t first_chunk_indices[] = {9, 9};
+  zarrs_assert(zarrsArrayGetFirstChunkIndices(array, 2, first_chunk_indices));
+  assert(first_chunk_indices[0] == 0 && first_chunk_indices[1] == 0);
+  uint64_t last_chunk_indices[2];
+  zarrs_assert(zarrsArrayGetLastChunkIndices(array, 2, last_chunk_indices));
+  assert(last_chunk_indices[0] == 2 && last_chunk_indices[1] == 1);
+  zarrs_assert(zarrsArrayGetChunkCount(array, 2, chunk_count));
+  assert(chunk_count[0] == chunk_grid_shape[0] && chunk_count[1] == chunk_grid_shape[1]);
+  zarrs_assert(zarrsArrayGetChunkCountProduct(array, &total_chunk_count));
+  assert(total_chunk_count == 3 * 2);
+  zarrs_assert(zarrsArrayGetNumChunks(array, &num_chunks));
+  assert(num_chunks == 3 * 2);
+  assert(zarrsArrayGetRegularChunkShape(array, 2, regular_chunk_shape) ==
+         ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE);
+  assert(zarrsArrayGetChunkSizeForBoundaryChunks(array, 2, regular_chunk_shape, edge_chunk_shape,
+                                                 &has_edge_chunks) ==
+         ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE);
+  zarrs_assert(zarrsArrayGetMaxChunkSizeBytes(array, &max_chunk_size));
+  assert(max_chunk_size == 4 * 4);
+  assert(zarrsArrayGetChunkCount(array, 1, chunk_count) == ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  assert(zarrsArrayGetLastChunkIndices(array, 3, last_chunk_indices) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  uint64_t chunk_indices[] = {1, 0};
+  uint64_t chunk_shape[2];
+  zarrs_assert(zarrsArrayGetChunkShape(array, 2, chunk_indices, chunk_shape));
+  assert(chunk_shape[0] == 3);
+  assert(chunk_shape[1] == 4);
+  zarrs_assert(zarrsArrayGetChunkIndicesForElement(array, 2, element_indices, element_chunk_indices,
+                                                   within_chunk_offset));
+  assert(element_chunk_indices[0] == 2 && element_chunk_indices[1] == 0);
+  assert(within_chunk_offset[0] == 1 && within_chunk_offset[1] == 2);
+
+  // Rows 2..5 intersect the chunk rows [1, 4) and [4, 8), and columns 3..5 intersect both chunk columns
+  uint64_t subset_start[] = {2, 3};
+  uint64_t subset_shape[] = {3, 2};
+  size_t subset_chunk_count = 0;
+  zarrs_assert(zarrsArrayGetSubsetChunkCount(array, 2, subset_start, subset_shape, &subset_chunk_count));
+  assert(subset_chunk_count == 4);
+  uint64_t *subset_chunk_indices = nullptr;
+  zarrs_assert(zarrsArrayGetSubsetChunkIndices(array, 2, subset_start, subset_shape, &subset_chunk_count,
+                                               &subset_chunk_indices));
+  assert(subset_chunk_count == 4);
+  const uint64_t expected_subset_chunk_indices[] = {1, 0, 1, 1, 2, 0, 2, 1};
+  assert(memcmp(subset_chunk_indices, expected_subset_chunk_indices, sizeof(expected_subset_chunk_indices)) == 0);
+  zarrs_assert(zarrsFreeBytes(subset_chunk_indices));
+
+  // A subset within a single chunk
+  subset_start[0] = 0;
+  subset_shape[0] = 1;
+  subset_shape[1] = 1;
+  zarrs_assert(zarrsArrayGetSubsetChunkIndices(array, 2, subset_start, subset_shape, &subset_chunk_count,
+                                               &subset_chunk_indices));
+  assert(subset_chunk_count == 1);
+  assert(subset_chunk_indices[0] == 0 && subset_chunk_indices[1] == 0);
+  zarrs_assert(zarrsFreeBytes(subset_chunk_indices));
+
+  subset_start[0] = 6;
+  subset_shape[0] = 3;
+  assert(zarrsArrayGetSubsetChunkCount(array, 2, subset_start, subset_shape, &subset_chunk_count) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayGetSubsetChunkIndices(array, 2, subset_start, subset_shape, &subset_chunk_count,
+                                         &subset_chunk_indices) == ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayGetSubsetChunkCount(array, 1, subset_start, subset_shape, &subset_chunk_count) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  assert(zarrsArrayGetSubsetChunkIndices(nullptr, 2, subset_start, subset_shape, &subset_chunk_count,
+                                         &subset_chunk_indices) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // The number of elements overflows a uint64_t, but the number of chunks does not
+  zarrs_assert(zarrsCreateArrayRW(storage, "/huge", huge_array_metadata, &array));
+  assert(zarrsArrayGetNumElements(array, &num_elements) == ZarrsResult::ZARRS_ERROR_OVERFLOW);
+  zarrs_assert(zarrsArrayGetNumChunks(array, &num_chunks));
+  assert(num_chunks == 65536ull * 65537ull);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  assert(zarrsArrayGetNumElements(nullptr, &num_elements) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsArrayGetNumChunks(nullptr, &num_chunks) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,99 @@
+#include "zarrs.h"
+
+#include <cstring>
+#include <string>
+
+const char *array_metadata_c = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 6],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 3]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+const char *array_metadata_f = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 6],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 3]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [
+        {"name": "transpose", "configuration": {"order": [1, 0]}},
+        {"name": "bytes", "configuration": {"endian": "big"}}
+    ]
+}
+)"""";
+
+const char *array_metadata_permuted = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [2, 4, 6],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2, 3]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [
+        {"name": "transpose", "configuration": {"order": [1, 2, 0]}},
+        {"name": "bytes"}
+    ]
+}
+)"""";
+
+void check_chunk_representation(ZarrsArray array, const char *order, const char *endian) {
+  char *json = nullptr;
+  zarrs_assert(zarrsArrayGetChunkRepresentationString(array, false, &json));
+  std::string representation = json;
+  zarrs_assert(zarrsFreeString(json));
+  assert(representation.find(std::string("\"order\":") + order) != std::string::npos);
+  assert(representation.find(std::string("\"endian\":") + endian) != std::string::npos);
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+
+  // No transpose codec
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/c", array_metadata_c, &array));
+  check_chunk_representation(array, "\"C\"", "\"little\"");
+  char *layout = nullptr;
+  zarrs_assert(zarrsArrayGetDataLayout(array, &layout));
+  assert(strcmp(layout, "C") == 0);
+  zarrs_assert(zarrsFreeString(layout));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // A transpose codec reversing the dimensions
+  zarrs_assert(zarrsCreateArrayRW(storage, "/f", array_metadata_f, &array));
+  check_chunk_representation(array, "\"F\"", "\"big\"");
+  zarrs_assert(zarrsArrayGetDataLayout(array, &layout));
+  assert(strcmp(layout, "F") == 0);
+  zarrs_assert(zarrsFreeString(layout));
+  assert(zarrsArrayGetDataLayout(array, nullptr) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // A transpose codec that is neither C nor F order
+  zarrs_assert(zarrsCreateArrayRW(storage, "/permuted", array_metadata_permuted, &array));
+  check_chunk_representation(array, "[1,2,0]", "null");
+  assert(zarrsArrayGetDataLayout(array, &layout) == ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
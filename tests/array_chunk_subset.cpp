@@ -0,0 +1,182 @@
+#include "zarrs.h"
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [10, 7],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  uint64_t start[2];
+  uint64_t shape[2];
+
+  // Interior chunk
+  uint64_t interior_chunk_indices[] = {0, 0};
+  zarrs_assert(zarrsArrayGetChunkSubset(array, 2, interior_chunk_indices, true, start, shape));
+  assert(start[0] == 0 && start[1] == 0);
+  assert(shape[0] == 4 && shape[1] == 4);
+
+  // Edge chunk overhanging the array bounds
+  uint64_t edge_chunk_indices[] = {2, 1};
+  zarrs_assert(zarrsArrayGetChunkSubset(array, 2, edge_chunk_indices, false, start, shape));
+  assert(start[0] == 8 && start[1] == 4);
+  assert(shape[0] == 4 && shape[1] == 4);
+  zarrs_assert(zarrsArrayGetChunkSubset(array, 2, edge_chunk_indices, true, start, shape));
+  assert(start[0] == 8 && start[1] == 4);
+  assert(shape[0] == 2 && shape[1] == 3);
+
+  // Chunk outside the chunk grid and incompatible dimensionality
+  uint64_t out_of_grid_chunk_indices[] = {3, 0};
+  assert(zarrsArrayGetChunkSubset(array, 2, out_of_grid_chunk_indices, false, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayGetChunkSubset(array, 1, interior_chunk_indices, false, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  // Chunk indices one past the edge of the chunk grid are rejected rather than clipped to an empty subset
+  uint64_t past_edge_chunk_indices[] = {2, 2};
+  assert(zarrsArrayGetChunkSubset(array, 2, past_edge_chunk_indices, true, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayGetChunkSubset(array, 1, interior_chunk_indices, true, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  // Origin and shape
+  zarrs_assert(zarrsArrayGetChunkOriginAndShape(array, 2, edge_chunk_indices, start, shape));
+  assert(start[0] == 8 && start[1] == 4);
+  assert(shape[0] == 4 && shape[1] == 4);
+  zarrs_assert(zarrsArrayGetChunkBoundsAsSubset(array, 2, interior_chunk_indices, start, shape));
+  assert(start[0] == 0 && start[1] == 0);
+  assert(shape[0] == 4 && shape[1] == 4);
+  uint64_t invalid_chunk_indices[] = {3, 0};
+  assert(zarrsArrayGetChunkOriginAndShape(array, 2, invalid_chunk_indices, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  zarrs_assert(zarrsArrayGetChunkSubsetFromIndices(array, 2, edge_chunk_indices, start, shape));
+  assert(start[0] == 8 && start[1] == 4);
+  assert(shape[0] == 4 && shape[1] == 4);
+  assert(zarrsArrayGetChunkSubsetFromIndices(array, 2, invalid_chunk_indices, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+
+  // Intersection of a chunk with a subset
+  uint64_t intersect_subset_start[] = {3, 5};
+  uint64_t intersect_subset_shape[] = {6, 2};
+  uint64_t middle_chunk_indices[] = {1, 1};
+  zarrs_assert(zarrsArrayGetChunkIntersectionWithSubset(array, 2, middle_chunk_indices,
+                                                        intersect_subset_start,
+                                                        intersect_subset_shape, start, shape));
+  assert(start[0] == 4 && start[1] == 5);
+  assert(shape[0] == 4 && shape[1] == 2);
+  zarrs_assert(zarrsArrayGetChunkIntersectionWithSubset(array, 2, edge_chunk_indices,
+                                                        intersect_subset_start,
+                                                        intersect_subset_shape, start, shape));
+  assert(start[0] == 8 && start[1] == 5);
+  assert(shape[0] == 1 && shape[1] == 2);
+  // A chunk that only overlaps the subset along one dimension does not intersect it
+  zarrs_assert(zarrsArrayGetChunkIntersectionWithSubset(array, 2, interior_chunk_indices,
+                                                        intersect_subset_start,
+                                                        intersect_subset_shape, start, shape));
+  assert(shape[0] == 0 && shape[1] == 0);
+  assert(zarrsArrayGetChunkIntersectionWithSubset(array, 2, invalid_chunk_indices,
+                                                  intersect_subset_start, intersect_subset_shape,
+                                                  start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayGetChunkIntersectionWithSubset(array, 1, interior_chunk_indices,
+                                                  intersect_subset_start, intersect_subset_shape,
+                                                  start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  // Intersection of a chunk with a subset, which contains a zero if they do not intersect
+  zarrs_assert(zarrsArrayGetChunkIntersectWithSubset(array, 2, edge_chunk_indices, intersect_subset_start,
+                                                     intersect_subset_shape, start, shape));
+  assert(start[0] == 8 && start[1] == 5);
+  assert(shape[0] == 1 && shape[1] == 2);
+  zarrs_assert(zarrsArrayGetChunkIntersectWithSubset(array, 2, interior_chunk_indices, intersect_subset_start,
+                                                     intersect_subset_shape, start, shape));
+  assert(shape[0] == 0 || shape[1] == 0);
+  assert(zarrsArrayGetChunkIntersectWithSubset(array, 2, invalid_chunk_indices, intersect_subset_start,
+                                               intersect_subset_shape, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayGetChunkIntersectWithSubset(array, 1, interior_chunk_indices, intersect_subset_start,
+                                               intersect_subset_shape, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  uint64_t past_end_intersect_subset_shape[] = {8, 2};
+  assert(zarrsArrayGetChunkIntersectWithSubset(array, 2, middle_chunk_indices, intersect_subset_start,
+                                               past_end_intersect_subset_shape, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+
+  // Range of chunks
+  uint64_t chunks_start[] = {1, 0};
+  uint64_t chunks_shape[] = {2, 2};
+  zarrs_assert(zarrsArrayGetChunksSubset(array, 2, chunks_start, chunks_shape, start, shape));
+  assert(start[0] == 4 && start[1] == 0);
+  assert(shape[0] == 8 && shape[1] == 8);
+
+  // Range of chunks outside the chunk grid
+  uint64_t out_of_grid_chunks_start[] = {2, 0};
+  assert(zarrsArrayGetChunksSubset(array, 2, out_of_grid_chunks_start, chunks_shape, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+
+  // Element counts
+  uint64_t num_elements = 0;
+  zarrs_assert(zarrsArrayGetShapeAsProduct(array, &num_elements));
+  assert(num_elements == 10 * 7);
+  zarrs_assert(zarrsArrayGetChunkSizeElements(array, 2, edge_chunk_indices, &num_elements));
+  assert(num_elements == 4 * 4);
+  uint64_t elements_subset_shape[] = {3, 5};
+  zarrs_assert(zarrsArrayGetSubsetSizeElements(array, 2, elements_subset_shape, &num_elements));
+  assert(num_elements == 3 * 5);
+  assert(zarrsArrayGetChunkSizeElements(array, 2, invalid_chunk_indices, &num_elements) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+
+  // Chunks intersecting a subset
+  uint64_t subset_start[] = {3, 2};
+  uint64_t subset_shape[] = {6, 4};
+  uint64_t chunk_count = 0;
+  zarrs_assert(zarrsArrayGetChunksIntersectingBounds(array, 2, subset_start, subset_shape, start, shape, &chunk_count));
+  assert(start[0] == 0 && start[1] == 0);
+  assert(shape[0] == 3 && shape[1] == 2);
+  assert(chunk_count == 6);
+  chunk_count = 0;
+  zarrs_assert(zarrsArrayGetChunksIntersectingBoundsCount(array, 2, subset_start, subset_shape, &chunk_count));
+  assert(chunk_count == 6);
+  assert(zarrsArrayGetChunksIntersectingBoundsCount(array, 1, subset_start, subset_shape, &chunk_count) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  // List of chunks intersecting a subset, in C order
+  size_t chunk_list_count = 0;
+  uint64_t *chunk_indices_list = nullptr;
+  zarrs_assert(zarrsArrayGetChunksInSubsetList(array, 2, subset_start, subset_shape, &chunk_list_count,
+                                               &chunk_indices_list));
+  assert(chunk_list_count == 6);
+  const uint64_t expected_chunk_indices_list[] = {0, 0, 0, 1, 1, 0, 1, 1, 2, 0, 2, 1};
+  for (size_t i = 0; i < chunk_list_count * 2; ++i) {
+    assert(chunk_indices_list[i] == expected_chunk_indices_list[i]);
+  }
+  zarrs_assert(zarrsFreeBytes(chunk_indices_list));
+
+  uint64_t empty_subset_shape[] = {0, 4};
+  zarrs_assert(zarrsArrayGetChunksInSubsetList(array, 2, subset_start, empty_subset_shape, &chunk_list_count,
+                                               &chunk_indices_list));
+  assert(chunk_list_count == 0);
+  zarrs_assert(zarrsFreeBytes(chunk_indices_list));
+  uint64_t past_end_subset_shape[] = {8, 4};
+  assert(zarrsArrayGetChunksInSubsetList(array, 2, subset_start, past_end_subset_shape, &chunk_list_count,
+                                         &chunk_indices_list) == ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayGetChunksInSubsetList(array, 1, subset_start, subset_shape, &chunk_list_count,
+                                         &chunk_indices_list) == ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,88 @@
+#include "zarrs.h"
+
+#include <cstring>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [2, 4, 6],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2, 3]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [
+        {"name": "transpose", "configuration": {"order": [1, 2, 0]}},
+        {"name": "bytes", "configuration": {"endian": "little"}},
+        {"name": "crc32c"}
+    ]
+}
+)"""";
+
+const char *array_metadata_sharded = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "transpose", "configuration": {"order": [1, 0]}}, {"name": "bytes"}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+
+  // A transpose codec followed by other codecs
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  size_t permutation[3] = {};
+  bool has_transpose = false;
+  zarrs_assert(zarrsArrayGetTransposeCodecOrder(array, 3, permutation, &has_transpose));
+  assert(has_transpose);
+  assert(permutation[0] == 1 && permutation[1] == 2 && permutation[2] == 0);
+  assert(zarrsArrayGetTransposeCodecOrder(array, 2, permutation, &has_transpose) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  size_t count = 0;
+  char **names = nullptr;
+  zarrs_assert(zarrsArrayGetCodecNames(array, &count, &names));
+  assert(count == 3);
+  assert(strcmp(names[0], "transpose") == 0);
+  assert(strcmp(names[1], "bytes") == 0);
+  assert(strcmp(names[2], "crc32c") == 0);
+  zarrs_assert(zarrsFreeStringArray(names, count));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // The codecs within a shard are not part of the codec pipeline of the array
+  zarrs_assert(zarrsCreateArrayRW(storage, "/sharded", array_metadata_sharded, &array));
+  has_transpose = true;
+  zarrs_assert(zarrsArrayGetTransposeCodecOrder(array, 2, permutation, &has_transpose));
+  assert(!has_transpose);
+  zarrs_assert(zarrsArrayGetCodecNames(array, &count, &names));
+  assert(count == 1);
+  assert(strcmp(names[0], "sharding_indexed") == 0);
+  zarrs_assert(zarrsFreeStringArray(names, count));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  assert(zarrsArrayGetTransposeCodecOrder(nullptr, 2, permutation, &has_transpose) ==
+         ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsArrayGetCodecNames(nullptr, &count, &names) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,70 @@
+#include "zarrs.h"
+
+#include <cstring>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  char **names = nullptr;
+
+  // No dimension names
+  zarrs_assert(zarrsArrayGetDimensionNames(array, 3, &names));
+  assert(names[0] == nullptr && names[1] == nullptr && names[2] == nullptr);
+  zarrs_assert(zarrsFreeStringArray(names, 3));
+
+  // Set dimension names with an unnamed dimension
+  const char *new_names[] = {"z", nullptr, "x"};
+  assert(zarrsArraySetDimensionNames(array, 2, new_names) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  zarrs_assert(zarrsArraySetDimensionNames(array, 3, new_names));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Round trip through storage
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array", &array));
+  assert(zarrsArrayGetDimensionNames(array, 2, &names) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  zarrs_assert(zarrsArrayGetDimensionNames(array, 3, &names));
+  assert(strcmp(names[0], "z") == 0);
+  assert(names[1] == nullptr);
+  assert(strcmp(names[2], "x") == 0);
+  zarrs_assert(zarrsFreeStringArray(names, 3));
+  size_t dimensionality = 0;
+  zarrs_assert(zarrsArrayGetDimensionalityWithNames(array, &dimensionality, &names));
+  assert(dimensionality == 3);
+  assert(strcmp(names[0], "z") == 0);
+  assert(names[1] == nullptr);
+  assert(strcmp(names[2], "x") == 0);
+  zarrs_assert(zarrsFreeStringArray(names, dimensionality));
+  dimensionality = 0;
+  zarrs_assert(zarrsArrayGetDimensionalityWithNames(array, &dimensionality, nullptr));
+  assert(dimensionality == 3);
+  assert(zarrsArrayGetDimensionalityWithNames(array, nullptr, &names) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  // Remove the dimension names
+  zarrs_assert(zarrsArraySetDimensionNames(array, 3, nullptr));
+  zarrs_assert(zarrsArrayGetDimensionNames(array, 3, &names));
+  assert(names[0] == nullptr && names[1] == nullptr && names[2] == nullptr);
+  zarrs_assert(zarrsFreeStringArray(names, 3));
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,78 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "big"}}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+
+  // Encode a chunk with the big endian bytes codec manually
+  std::vector<uint16_t> elements = {1, 2, 0x0102, 0xFF00};
+  std::vector<uint8_t> encoded;
+  for (uint16_t element : elements) {
+    encoded.push_back(element >> 8);
+    encoded.push_back(element & 0xFF);
+  }
+  uint64_t chunk_indices[] = {1, 0};
+  zarrs_assert(zarrsArrayStoreEncodedChunk(array, 2, chunk_indices, encoded.size(), encoded.data(), false));
+
+  // The encoded chunk is stored as is
+  uint64_t size_bytes = 0;
+  bool exists = false;
+  zarrs_assert(zarrsArrayGetStoredChunkSize(array, 2, chunk_indices, &size_bytes, &exists));
+  assert(exists);
+  assert(size_bytes == encoded.size());
+
+  // Round trip through the codec pipeline
+  std::vector<uint16_t> decoded(4);
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, chunk_indices, decoded.size() * sizeof(uint16_t),
+                                       reinterpret_cast<uint8_t *>(decoded.data())));
+  assert(decoded == elements);
+
+  // Validate the encoded chunk before storing it
+  uint64_t validated_chunk_indices[] = {1, 1};
+  zarrs_assert(zarrsArrayStoreEncodedChunk(array, 2, validated_chunk_indices, encoded.size(), encoded.data(), true));
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, validated_chunk_indices, decoded.size() * sizeof(uint16_t),
+                                       reinterpret_cast<uint8_t *>(decoded.data())));
+  assert(decoded == elements);
+  uint64_t unstored_chunk_indices[] = {0, 0};
+  assert(zarrsArrayStoreEncodedChunk(array, 2, unstored_chunk_indices, encoded.size() - 2, encoded.data(), true) ==
+         ZarrsResult::ZARRS_ERROR_ARRAY);
+  zarrs_assert(zarrsArrayGetStoredChunkSize(array, 2, unstored_chunk_indices, &size_bytes, &exists));
+  assert(!exists);
+
+  // Invalid chunk indices
+  uint64_t invalid_chunk_indices[] = {2, 0};
+  assert(zarrsArrayStoreEncodedChunk(array, 2, invalid_chunk_indices, encoded.size(), encoded.data(), false) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayStoreEncodedChunk(array, 1, chunk_indices, encoded.size(), encoded.data(), false) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Read-only arrays
+  zarrs_assert(zarrsOpenArrayR(storage, "/array", &array));
+  assert(zarrsArrayStoreEncodedChunk(array, 2, chunk_indices, encoded.size(), encoded.data(), false) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
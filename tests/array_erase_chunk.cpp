@@ -0,0 +1,65 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 9,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+
+  std::vector<uint16_t> elements = {1, 2, 3, 4};
+  uint64_t chunk_indices[] = {1, 0};
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, elements.size() * sizeof(uint16_t),
+                                    reinterpret_cast<const uint8_t *>(elements.data())));
+  bool exists = false;
+  zarrs_assert(zarrsArrayChunkExists(array, 2, chunk_indices, &exists));
+  assert(exists);
+
+  // Erase the chunk
+  zarrs_assert(zarrsArrayEraseChunk(array, 2, chunk_indices));
+  zarrs_assert(zarrsArrayChunkExists(array, 2, chunk_indices, &exists));
+  assert(!exists);
+  std::vector<uint16_t> decoded(4);
+  exists = true;
+  zarrs_assert(zarrsArrayRetrieveChunkIfExists(array, 2, chunk_indices, decoded.size() * sizeof(uint16_t),
+                                               reinterpret_cast<uint8_t *>(decoded.data()), &exists));
+  assert(!exists);
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, chunk_indices, decoded.size() * sizeof(uint16_t),
+                                       reinterpret_cast<uint8_t *>(decoded.data())));
+  assert(decoded == std::vector<uint16_t>(4, 9));
+
+  // Erasing a chunk that is not stored succeeds
+  zarrs_assert(zarrsArrayEraseChunk(array, 2, chunk_indices));
+
+  // Invalid chunk indices
+  uint64_t invalid_chunk_indices[] = {2, 0};
+  assert(zarrsArrayEraseChunk(array, 2, invalid_chunk_indices) == ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayEraseChunk(array, 1, chunk_indices) == ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayEraseChunk(nullptr, 2, chunk_indices) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Read-only arrays
+  zarrs_assert(zarrsOpenArrayR(storage, "/array", &array));
+  assert(zarrsArrayEraseChunk(array, 2, chunk_indices) == ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
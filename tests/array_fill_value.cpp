@@ -0,0 +1,108 @@
+#include "zarrs.h"
+
+#include <cstring>
+#include <string>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4],
+    "data_type": "DATA_TYPE",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": FILL_VALUE,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+ZarrsArray create_array(ZarrsStorage storage, const std::string &data_type,
+                        const std::string &fill_value) {
+  std::string metadata = array_metadata;
+  metadata.replace(metadata.find("DATA_TYPE"), 9, data_type);
+  metadata.replace(metadata.find("FILL_VALUE"), 10, fill_value);
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", metadata.c_str(), &array));
+  return array;
+}
+
+void assert_fill_value(ZarrsArray array, const char *expected) {
+  char *fill_value = nullptr;
+  zarrs_assert(zarrsArrayGetFillValueString(array, &fill_value));
+  assert(strcmp(fill_value, expected) == 0);
+  zarrs_assert(zarrsFreeString(fill_value));
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+
+  ZarrsArray array = create_array(storage, "float32", "\"NaN\"");
+  assert_fill_value(array, "\"NaN\"");
+  zarrs_assert(zarrsDestroyArray(array));
+  array = create_array(storage, "bool", "false");
+  assert_fill_value(array, "false");
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Round trip a fill value through the array metadata
+  array = create_array(storage, "uint8", "0");
+  assert_fill_value(array, "0");
+  zarrs_assert(zarrsArraySetFillValueFromString(array, "7"));
+  assert_fill_value(array, "7");
+  uint8_t bytes[4] = {0};
+  zarrs_assert(zarrsArrayFillBuffer(array, sizeof(bytes), bytes));
+  assert(bytes[0] == 7 && bytes[3] == 7);
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  ZarrsArray reopened = nullptr;
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array", &reopened));
+  assert_fill_value(reopened, "7");
+  zarrs_assert(zarrsDestroyArray(reopened));
+
+  // Invalid fill values leave the array unchanged
+  assert(zarrsArraySetFillValueFromString(array, "300") ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  assert(zarrsArraySetFillValueFromString(array, "\"abc\"") ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  assert(zarrsArraySetFillValueFromString(array, "{") ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  assert_fill_value(array, "7");
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Fill a subset buffer with a multi-byte fill value
+  array = create_array(storage, "uint16", "4660");
+  uint64_t subset_shape[] = {3};
+  uint16_t subset[3] = {0};
+  zarrs_assert(zarrsArrayGetFilledSubset(array, 1, subset_shape, sizeof(subset), reinterpret_cast<uint8_t *>(subset)));
+  for (uint16_t element : subset) {
+    assert(element == 4660);
+  }
+  // The subset shape is not bounded by the array shape
+  uint64_t large_subset_shape[] = {8};
+  uint16_t large_subset[8] = {0};
+  zarrs_assert(zarrsArrayGetFilledSubset(array, 1, large_subset_shape, sizeof(large_subset),
+                                         reinterpret_cast<uint8_t *>(large_subset)));
+  assert(large_subset[0] == 4660 && large_subset[7] == 4660);
+  assert(zarrsArrayGetFilledSubset(array, 1, subset_shape, sizeof(subset) - 1,
+                                   reinterpret_cast<uint8_t *>(subset)) == ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  uint64_t two_dimensional_subset_shape[] = {3, 1};
+  assert(zarrsArrayGetFilledSubset(array, 2, two_dimensional_subset_shape, sizeof(subset),
+                                   reinterpret_cast<uint8_t *>(subset)) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  uint64_t overflowing_subset_shape[] = {UINT64_MAX};
+  assert(zarrsArrayGetFilledSubset(array, 1, overflowing_subset_shape, sizeof(subset),
+                                   reinterpret_cast<uint8_t *>(subset)) == ZarrsResult::ZARRS_ERROR_OVERFLOW);
+  assert(zarrsArrayGetFilledSubset(nullptr, 1, subset_shape, sizeof(subset), reinterpret_cast<uint8_t *>(subset)) ==
+         ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  // A buffer that is not a multiple of the data type size ends with a truncated element
+  uint8_t truncated[3] = {0};
+  zarrs_assert(zarrsArrayFillBuffer(array, sizeof(truncated), truncated));
+  uint16_t fill_value = 4660;
+  assert(memcmp(truncated, &fill_value, 2) == 0 && truncated[2] == truncated[0]);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
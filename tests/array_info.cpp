@@ -0,0 +1,75 @@
+#include "zarrs.h"
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [10, 7],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+const char *array_metadata_sharded = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "float32",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0.0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArrayInfo info;
+
+  // Unsharded array
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayGetInfo(array, &info));
+  assert(info.dimensionality == 2);
+  assert(info.shape[0] == 10 && info.shape[1] == 7 && info.shape[2] == 0);
+  assert(info.dataType == ZarrsDataType::ZARRS_UINT16);
+  assert(info.dataTypeSize == 2);
+  assert(info.chunkGridShape[0] == 3 && info.chunkGridShape[1] == 2);
+  assert(info.isRegularChunkGrid);
+  assert(info.chunkShape[0] == 4 && info.chunkShape[1] == 4);
+  assert(!info.isSharded);
+  assert(zarrsArrayGetInfo(array, nullptr) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Sharded array
+  ZarrsArray array_sharded = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array_sharded", array_metadata_sharded, &array_sharded));
+  zarrs_assert(zarrsArrayGetInfo(array_sharded, &info));
+  assert(info.dimensionality == 2);
+  assert(info.dataType == ZarrsDataType::ZARRS_FLOAT32);
+  assert(info.dataTypeSize == 4);
+  assert(info.chunkGridShape[0] == 2 && info.chunkGridShape[1] == 2);
+  assert(info.chunkShape[0] == 4 && info.chunkShape[1] == 4);
+  assert(info.isSharded);
+  zarrs_assert(zarrsDestroyArray(array_sharded));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
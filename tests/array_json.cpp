@@ -0,0 +1,69 @@
+#include "zarrs.h"
+
+#include <cmath>
+#include <cstring>
+
+const char *float_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "float32",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": "NaN",
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+const char *string_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4],
+    "data_type": "string",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": "",
+    "codecs": [{"name": "vlen-utf8"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/float", float_array_metadata, &array));
+  uint64_t chunk_indices[] = {0, 0};
+  float chunk_elements[] = {1.0f, -2.5f, INFINITY, -INFINITY};
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, sizeof(chunk_elements),
+                                    (uint8_t *)chunk_elements));
+
+  char *json = nullptr;
+  zarrs_assert(zarrsArrayGetChunkAsJSON(array, 2, chunk_indices, &json));
+  assert(strcmp(json, "[[1.0,-2.5],[\"Infinity\",\"-Infinity\"]]") == 0);
+  zarrs_assert(zarrsFreeString(json));
+
+  uint64_t subset_start[] = {1, 1};
+  uint64_t subset_shape[] = {1, 2};
+  zarrs_assert(zarrsArrayGetSubsetAsJSON(array, 2, subset_start, subset_shape, &json));
+  assert(strcmp(json, "[[\"-Infinity\",\"NaN\"]]") == 0);
+  zarrs_assert(zarrsFreeString(json));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Variable-length data types are not supported
+  zarrs_assert(zarrsCreateArrayRW(storage, "/string", string_array_metadata, &array));
+  uint64_t string_chunk_indices[] = {0};
+  assert(zarrsArrayGetChunkAsJSON(array, 1, string_chunk_indices, &json) ==
+         ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,123 @@
+#include "zarrs.h"
+
+#include <string>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "v2", "configuration": {"separator": "."}},
+    "fill_value": 0,
+    "codecs": [
+        {"name": "bytes", "configuration": {"endian": "little"}},
+        {"name": "gzip", "configuration": {"level": 5}}
+    ]
+}
+)"""";
+
+const char *sharded_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  char *metadata_json = nullptr;
+
+  // Convertible array
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayGetMetadataV2String(array, false, &metadata_json));
+  std::string metadata(metadata_json);
+  assert(metadata.find("\"zarr_format\":2") != std::string::npos);
+  assert(metadata.find("\"dtype\":\"<u2\"") != std::string::npos);
+  assert(metadata.find("\"chunks\":[4,4]") != std::string::npos);
+  assert(metadata.find("\"id\":\"gzip\"") != std::string::npos);
+  assert(metadata.find("\"dimension_separator\":\".\"") != std::string::npos);
+  zarrs_assert(zarrsFreeString(metadata_json));
+
+  // Chunk key encoding
+  char *encoding_name = nullptr;
+  char separator = 0;
+  zarrs_assert(zarrsArrayGetChunkKeyEncodingString(array, &encoding_name, &separator));
+  assert(std::string(encoding_name) == "v2");
+  assert(separator == '.');
+  zarrs_assert(zarrsFreeString(encoding_name));
+  zarrs_assert(zarrsArrayGetChunkKeyEncodingMetadataString(array, false, &metadata_json));
+  assert(std::string(metadata_json).find("\"separator\":\".\"") != std::string::npos);
+  zarrs_assert(zarrsFreeString(metadata_json));
+  assert(zarrsArrayGetChunkKeyEncodingString(array, nullptr, &separator) ==
+         ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  // Store Zarr V2 metadata alongside Zarr V3 metadata
+  zarrs_assert(zarrsArraySetAttributes(array, R"({"units": "m"})"));
+  zarrs_assert(zarrsArrayStoreMetadataOpt(array, ZarrsMetadataVersion::ZARRS_METADATA_FORCE_V2, false));
+  ZarrsArray array_v2 = nullptr;
+  uint32_t zarr_format = 0;
+  zarrs_assert(zarrsOpenArrayV2R(storage, "/array", &array_v2));
+  zarrs_assert(zarrsArrayGetZarrFormat(array_v2, &zarr_format));
+  assert(zarr_format == 2);
+  zarrs_assert(zarrsArrayGetAttributes(array_v2, false, &metadata_json));
+  assert(std::string(metadata_json) == "{\"units\":\"m\"}");
+  zarrs_assert(zarrsFreeString(metadata_json));
+  zarrs_assert(zarrsDestroyArray(array_v2));
+
+  // Store Zarr V3 metadata and erase the Zarr V2 metadata
+  zarrs_assert(zarrsArrayStoreMetadataOpt(array, ZarrsMetadataVersion::ZARRS_METADATA_FORCE_V3, true));
+  assert(zarrsOpenArrayV2R(storage, "/array", &array_v2) != ZarrsResult::ZARRS_SUCCESS);
+
+  // Store Zarr V2 metadata and erase the Zarr V3 metadata
+  zarrs_assert(zarrsArrayStoreMetadataOpt(array, ZarrsMetadataVersion::ZARRS_METADATA_FORCE_V2, true));
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array", &array_v2));
+  zarrs_assert(zarrsArrayGetZarrFormat(array_v2, &zarr_format));
+  assert(zarr_format == 2);
+
+  // Convert the Zarr V2 array back to Zarr V3
+  zarrs_assert(zarrsArrayStoreMetadataOpt(array_v2, ZarrsMetadataVersion::ZARRS_METADATA_FORCE_V3, true));
+  zarrs_assert(zarrsDestroyArray(array_v2));
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array", &array_v2));
+  zarrs_assert(zarrsArrayGetZarrFormat(array_v2, &zarr_format));
+  assert(zarr_format == 3);
+  zarrs_assert(zarrsDestroyArray(array_v2));
+  assert(zarrsOpenArrayV2R(storage, "/array", &array_v2) != ZarrsResult::ZARRS_SUCCESS);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Sharded array
+  zarrs_assert(zarrsCreateArrayRW(storage, "/sharded", sharded_array_metadata, &array));
+  assert(zarrsArrayGetMetadataV2String(array, false, &metadata_json) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  assert(zarrsArrayStoreMetadataOpt(array, ZarrsMetadataVersion::ZARRS_METADATA_FORCE_V2, false) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  zarrs_assert(zarrsArrayGetChunkKeyEncodingString(array, &encoding_name, &separator));
+  assert(std::string(encoding_name) == "default");
+  assert(separator == '/');
+  zarrs_assert(zarrsFreeString(encoding_name));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
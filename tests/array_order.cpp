@@ -0,0 +1,80 @@
+#include "zarrs.h"
+
+#include <cstring>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4, 5],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  // A non-square 3D subset in Fortran order, where element (i, j, k) has the value 100i + 10j + k
+  const uint64_t ni = 2, nj = 3, nk = 4;
+  uint64_t subset_start[] = {1, 0, 1};
+  uint64_t subset_shape[] = {ni, nj, nk};
+  uint16_t elements_f[ni * nj * nk];
+  for (uint64_t i = 0; i < ni; ++i) {
+    for (uint64_t j = 0; j < nj; ++j) {
+      for (uint64_t k = 0; k < nk; ++k) {
+        elements_f[i + ni * j + ni * nj * k] = 100 * i + 10 * j + k;
+      }
+    }
+  }
+  zarrs_assert(zarrsArrayStoreSubsetOrd(array, 3, subset_start, subset_shape, ZarrsOrder::ZARRS_ORDER_F,
+                                        sizeof(elements_f), reinterpret_cast<const uint8_t *>(elements_f)));
+
+  // Retrieve in C order
+  uint16_t elements_c[ni * nj * nk];
+  zarrs_assert(zarrsArrayRetrieveSubsetOrd(array, 3, subset_start, subset_shape, ZarrsOrder::ZARRS_ORDER_C,
+                                           sizeof(elements_c), reinterpret_cast<uint8_t *>(elements_c)));
+  for (uint64_t i = 0; i < ni; ++i) {
+    for (uint64_t j = 0; j < nj; ++j) {
+      for (uint64_t k = 0; k < nk; ++k) {
+        assert(elements_c[i * nj * nk + j * nk + k] == 100 * i + 10 * j + k);
+      }
+    }
+  }
+
+  // Retrieve in Fortran order
+  uint16_t elements_f_retrieved[ni * nj * nk];
+  zarrs_assert(zarrsArrayRetrieveSubsetOrd(array, 3, subset_start, subset_shape, ZarrsOrder::ZARRS_ORDER_F,
+                                           sizeof(elements_f_retrieved),
+                                           reinterpret_cast<uint8_t *>(elements_f_retrieved)));
+  assert(memcmp(elements_f, elements_f_retrieved, sizeof(elements_f)) == 0);
+
+  // The byte count contract is unchanged
+  assert(zarrsArrayStoreSubsetOrd(array, 3, subset_start, subset_shape, ZarrsOrder::ZARRS_ORDER_F,
+                                  sizeof(elements_f) - 1, reinterpret_cast<const uint8_t *>(elements_f)) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  assert(zarrsArrayRetrieveSubsetOrd(array, 3, subset_start, subset_shape, ZarrsOrder::ZARRS_ORDER_F,
+                                     sizeof(elements_f) - 1, reinterpret_cast<uint8_t *>(elements_f)) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+
+  // The subset is validated before it is transposed
+  assert(zarrsArrayStoreSubsetOrd(array, 2, subset_start, subset_shape, ZarrsOrder::ZARRS_ORDER_F,
+                                  sizeof(elements_f), reinterpret_cast<const uint8_t *>(elements_f)) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  uint64_t out_of_bounds_subset_start[] = {3, 0, 1};
+  assert(zarrsArrayStoreSubsetOrd(array, 3, out_of_bounds_subset_start, subset_shape, ZarrsOrder::ZARRS_ORDER_F,
+                                  sizeof(elements_f), reinterpret_cast<const uint8_t *>(elements_f)) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -35,6 +35,11 @@ int main() {
     std::cout << (i == 0 ? "" : " ") << chunk_elements[i];
   }
   std::cout << std::endl;
+
+  // The buffer length must match the chunk size
+  assert(zarrsArrayRetrieveChunk(array, 2, indices, chunk_size - 1,
+                                 reinterpret_cast<uint8_t *>(chunk_elements.get())) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
   chunk_elements.reset();
 
   // Cleanup
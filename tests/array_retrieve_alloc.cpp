@@ -0,0 +1,73 @@
+#include "zarrs.h"
+
+#include <cstring>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  uint16_t elements[16];
+  for (uint16_t i = 0; i < 16; ++i) {
+    elements[i] = i;
+  }
+  uint64_t start[] = {0, 0};
+  uint64_t shape[] = {4, 4};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, start, shape, sizeof(elements), reinterpret_cast<uint8_t *>(elements)));
+
+  // Retrieve a chunk into a zarrs-allocated buffer
+  uint64_t chunk_indices[] = {1, 0};
+  uint8_t *bytes = nullptr;
+  size_t bytes_len = 0;
+  zarrs_assert(zarrsArrayRetrieveChunkAlloc(array, 2, chunk_indices, &bytes, &bytes_len));
+  assert(bytes_len == 4 * sizeof(uint16_t));
+  const uint16_t expected_chunk[] = {8, 9, 12, 13};
+  assert(memcmp(bytes, expected_chunk, bytes_len) == 0);
+  zarrs_assert(zarrsFreeBytes(bytes));
+
+  // Retrieve a subset into a zarrs-allocated buffer
+  uint64_t subset_start[] = {1, 1};
+  uint64_t subset_shape[] = {2, 3};
+  zarrs_assert(zarrsArrayRetrieveSubsetAlloc(array, 2, subset_start, subset_shape, &bytes, &bytes_len));
+  assert(bytes_len == 6 * sizeof(uint16_t));
+  const uint16_t expected_subset[] = {5, 6, 7, 9, 10, 11};
+  assert(memcmp(bytes, expected_subset, bytes_len) == 0);
+  // Only the pointers returned by zarrs can be freed, and only once
+  assert(zarrsFreeBytes(bytes + 1) == ZarrsResult::ZARRS_ERROR_UNKNOWN_POINTER);
+  assert(zarrsFreeBytes(elements) == ZarrsResult::ZARRS_ERROR_UNKNOWN_POINTER);
+  zarrs_assert(zarrsFreeBytes(bytes));
+  assert(zarrsFreeBytes(bytes) == ZarrsResult::ZARRS_ERROR_UNKNOWN_POINTER);
+  assert(zarrsFreeBytes(nullptr) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  // Empty subsets return a valid buffer
+  uint64_t empty_shape[] = {0, 2};
+  zarrs_assert(zarrsArrayRetrieveSubsetAlloc(array, 2, subset_start, empty_shape, &bytes, &bytes_len));
+  assert(bytes != nullptr);
+  assert(bytes_len == 0);
+  zarrs_assert(zarrsFreeBytes(bytes));
+
+  // Invalid chunk indices
+  uint64_t invalid_chunk_indices[] = {2, 0};
+  assert(zarrsArrayRetrieveChunkAlloc(array, 2, invalid_chunk_indices, &bytes, &bytes_len) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
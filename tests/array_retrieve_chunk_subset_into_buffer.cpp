@@ -0,0 +1,94 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  std::vector<uint8_t> elements(8 * 8);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  uint64_t array_start[] = {0, 0};
+  uint64_t array_shape[] = {8, 8};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, array_start, array_shape, elements.size(),
+                                     elements.data()));
+
+  // Composite a 2x3 region of chunk [0, 1] into a 4x5 image at row 1, column 2
+  uint64_t chunk_indices[] = {0, 1};
+  uint64_t subset_start[] = {1, 1};
+  uint64_t subset_shape[] = {2, 3};
+  std::vector<uint8_t> image(4 * 5, 255);
+  zarrs_assert(zarrsArrayRetrieveChunkSubsetIntoBuffer(array, 2, chunk_indices, subset_start,
+                                                       subset_shape, 5, 1 * 5 + 2, image.size(),
+                                                       image.data()));
+  for (size_t row = 0; row < 4; ++row) {
+    for (size_t col = 0; col < 5; ++col) {
+      uint8_t pixel = image[row * 5 + col];
+      if (row >= 1 && row < 3 && col >= 2) {
+        assert(pixel == elements[(row - 1 + 1) * 8 + 4 + 1 + col - 2]);
+      } else {
+        assert(pixel == 255);
+      }
+    }
+  }
+
+  // A contiguous buffer is equivalent to retrieving the subset
+  std::vector<uint8_t> contiguous(2 * 3);
+  zarrs_assert(zarrsArrayRetrieveChunkSubsetIntoBuffer(array, 2, chunk_indices, subset_start,
+                                                       subset_shape, 3, 0, contiguous.size(),
+                                                       contiguous.data()));
+  uint64_t array_subset_start[] = {1, 5};
+  std::vector<uint8_t> subset(2 * 3);
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 2, array_subset_start, subset_shape, subset.size(),
+                                        subset.data()));
+  assert(contiguous == subset);
+
+  // Sub-regions exceeding the chunk are invalid
+  uint64_t overhanging_start[] = {3, 2};
+  assert(zarrsArrayRetrieveChunkSubsetIntoBuffer(array, 2, chunk_indices, overhanging_start,
+                                                 subset_shape, 5, 0, image.size(),
+                                                 image.data()) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  uint64_t invalid_chunk_indices[] = {2, 0};
+  assert(zarrsArrayRetrieveChunkSubsetIntoBuffer(array, 2, invalid_chunk_indices, subset_start,
+                                                 subset_shape, 5, 0, image.size(),
+                                                 image.data()) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+
+  // The rows must fit in the buffer without overlapping
+  assert(zarrsArrayRetrieveChunkSubsetIntoBuffer(array, 2, chunk_indices, subset_start,
+                                                 subset_shape, 2, 0, image.size(),
+                                                 image.data()) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  assert(zarrsArrayRetrieveChunkSubsetIntoBuffer(array, 2, chunk_indices, subset_start,
+                                                 subset_shape, 5, 18, image.size(),
+                                                 image.data()) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  assert(zarrsArrayRetrieveChunkSubsetIntoBuffer(array, 1, chunk_indices, subset_start,
+                                                 subset_shape, 5, 0, image.size(),
+                                                 image.data()) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
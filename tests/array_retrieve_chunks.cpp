@@ -0,0 +1,66 @@
+#include "zarrs.h"
+
+#include <cstring>
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 6],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 3]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  // Element (i, j) has the value i * 6 + j
+  std::vector<uint8_t> elements(48);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  uint64_t array_start[] = {0, 0};
+  uint64_t array_shape[] = {8, 6};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, array_start, array_shape, elements.size(), elements.data()));
+
+  // The chunks in the last column of the chunk grid
+  uint64_t chunks_start[] = {0, 1};
+  uint64_t chunks_shape[] = {2, 1};
+  uint64_t start[2];
+  uint64_t shape[2];
+  zarrs_assert(zarrsArrayGetChunksSubset(array, 2, chunks_start, chunks_shape, start, shape));
+  std::vector<uint8_t> bytes(shape[0] * shape[1]);
+  zarrs_assert(zarrsArrayRetrieveChunks(array, 2, chunks_start, chunks_shape, bytes.size(), bytes.data()));
+  for (uint64_t i = 0; i < shape[0]; ++i) {
+    for (uint64_t j = 0; j < shape[1]; ++j) {
+      assert(bytes[i * shape[1] + j] == (start[0] + i) * 6 + start[1] + j);
+    }
+  }
+
+  // All chunks
+  uint64_t all_chunks_shape[] = {2, 2};
+  std::vector<uint8_t> all(48);
+  zarrs_assert(zarrsArrayRetrieveChunks(array, 2, array_start, all_chunks_shape, all.size(), all.data()));
+  assert(all == elements);
+
+  // Buffer length mismatch
+  assert(zarrsArrayRetrieveChunks(array, 2, chunks_start, chunks_shape, bytes.size() - 1, bytes.data()) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  char *error = zarrsLastError();
+  assert(strstr(error, "23") != nullptr && strstr(error, "24") != nullptr);
+  zarrs_assert(zarrsFreeString(error));
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
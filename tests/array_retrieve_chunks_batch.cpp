@@ -0,0 +1,74 @@
+#include "zarrs.h"
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 7,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  uint64_t chunk_indices[] = {1, 0};
+  uint16_t chunk_elements[] = {1, 2, 3, 4};
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, sizeof(chunk_elements),
+                                    reinterpret_cast<uint8_t *>(chunk_elements)));
+
+  // Retrieve a stored chunk and a missing chunk
+  uint16_t buffer_a[4] = {0};
+  uint16_t buffer_b[4] = {0};
+  uint16_t buffer_c[4] = {0};
+  {
+    uint64_t batch_indices[] = {1, 0, 0, 1};
+    uint8_t *buffers[] = {reinterpret_cast<uint8_t *>(buffer_a), reinterpret_cast<uint8_t *>(buffer_b)};
+    size_t buffer_lens[] = {sizeof(buffer_a), sizeof(buffer_b)};
+    ZarrsResult results[2];
+    zarrs_assert(zarrsArrayRetrieveChunksBatch(array, 2, 2, batch_indices, buffers, buffer_lens, results));
+    assert(results[0] == ZarrsResult::ZARRS_SUCCESS);
+    assert(results[1] == ZarrsResult::ZARRS_SUCCESS);
+    assert(buffer_a[0] == 1 && buffer_a[1] == 2 && buffer_a[2] == 3 && buffer_a[3] == 4);
+    assert(buffer_b[0] == 7 && buffer_b[1] == 7 && buffer_b[2] == 7 && buffer_b[3] == 7);
+  }
+
+  // One failing chunk does not fail the whole batch
+  {
+    uint64_t batch_indices[] = {0, 0, 9, 9, 1, 0};
+    uint8_t *buffers[] = {reinterpret_cast<uint8_t *>(buffer_a), reinterpret_cast<uint8_t *>(buffer_b),
+                          reinterpret_cast<uint8_t *>(buffer_c)};
+    size_t buffer_lens[] = {sizeof(buffer_a), sizeof(buffer_b), sizeof(buffer_c) - 1};
+    ZarrsResult results[3];
+    assert(zarrsArrayRetrieveChunksBatch(array, 2, 3, batch_indices, buffers, buffer_lens, results) ==
+           ZarrsResult::ZARRS_ERROR_PARTIAL_FAILURE);
+    assert(results[0] == ZarrsResult::ZARRS_SUCCESS);
+    assert(results[1] != ZarrsResult::ZARRS_SUCCESS);
+    assert(results[2] == ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+    assert(buffer_a[0] == 7 && buffer_a[3] == 7);
+  }
+
+  // A dimensionality mismatch is rejected without retrieving any chunks
+  {
+    uint64_t batch_indices[] = {1, 0, 0};
+    uint8_t *buffers[] = {reinterpret_cast<uint8_t *>(buffer_a)};
+    size_t buffer_lens[] = {sizeof(buffer_a)};
+    ZarrsResult results[1] = {ZarrsResult::ZARRS_ERROR_NULL_PTR};
+    assert(zarrsArrayRetrieveChunksBatch(array, 3, 1, batch_indices, buffers, buffer_lens, results) ==
+           ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+    assert(results[0] == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  }
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,90 @@
+#include "zarrs.h"
+
+#include <cstring>
+#include <string>
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [10, 12],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [3, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  std::vector<uint16_t> elements(10 * 12);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  uint64_t array_start[] = {0, 0};
+  uint64_t array_shape[] = {10, 12};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, array_start, array_shape,
+                                     elements.size() * sizeof(uint16_t),
+                                     reinterpret_cast<uint8_t *>(elements.data())));
+
+  // Unsorted rows and columns with duplicates
+  uint64_t rows[] = {9, 0, 4, 4, 1};
+  uint64_t cols[] = {11, 2, 5, 2};
+  const uint64_t *index_lists[] = {rows, cols};
+  size_t index_list_lens[] = {5, 4};
+  std::vector<uint16_t> selected(5 * 4);
+  zarrs_assert(zarrsArrayResetStorageStats(array));
+  zarrs_assert(zarrsArrayRetrieveOrthogonal(array, 2, index_lists, index_list_lens,
+                                            selected.size() * sizeof(uint16_t),
+                                            reinterpret_cast<uint8_t *>(selected.data())));
+  for (size_t i = 0; i < 5; ++i) {
+    for (size_t j = 0; j < 4; ++j) {
+      assert(selected[i * 4 + j] == elements[rows[i] * 12 + cols[j]]);
+    }
+  }
+
+  // Each chunk containing selected elements is retrieved once
+  // Rows are in chunks 0, 1, 3 and columns are in chunks 0, 1, 2
+  ZarrsStorageStats stats;
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.chunksDecoded == 3 * 3);
+
+  // Empty selections
+  size_t empty_index_list_lens[] = {0, 4};
+  zarrs_assert(zarrsArrayRetrieveOrthogonal(array, 2, index_lists, empty_index_list_lens, 0,
+                                            reinterpret_cast<uint8_t *>(selected.data())));
+
+  // Out of bounds indices name the dimension
+  uint64_t out_of_bounds_cols[] = {3, 12};
+  const uint64_t *out_of_bounds_index_lists[] = {rows, out_of_bounds_cols};
+  size_t out_of_bounds_index_list_lens[] = {5, 2};
+  std::vector<uint16_t> out_of_bounds(5 * 2);
+  assert(zarrsArrayRetrieveOrthogonal(array, 2, out_of_bounds_index_lists,
+                                      out_of_bounds_index_list_lens,
+                                      out_of_bounds.size() * sizeof(uint16_t),
+                                      reinterpret_cast<uint8_t *>(out_of_bounds.data())) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  char *last_error = zarrsLastError();
+  assert(std::string(last_error).find("dimension 1") != std::string::npos);
+  zarrs_assert(zarrsFreeString(last_error));
+
+  assert(zarrsArrayRetrieveOrthogonal(array, 2, index_lists, index_list_lens, 1,
+                                      reinterpret_cast<uint8_t *>(selected.data())) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  assert(zarrsArrayRetrieveOrthogonal(array, 1, index_lists, index_list_lens,
+                                      selected.size() * sizeof(uint16_t),
+                                      reinterpret_cast<uint8_t *>(selected.data())) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,110 @@
+#include "zarrs.h"
+
+#include <algorithm>
+#include <vector>
+
+// Chunks do not evenly divide the array shape, so subsets cross partial chunks
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [60, 50, 3],
+    "data_type": "uint32",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [7, 6, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+const char *array_metadata_sharded = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [60, 50, 3],
+    "data_type": "uint32",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [14, 12, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [7, 6, 2],
+            "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+const uint64_t shape[] = {60, 50, 3};
+
+// Element (i, j, k) has the value (i * 50 + j) * 3 + k
+void store_elements(ZarrsArray array) {
+  std::vector<uint32_t> elements(shape[0] * shape[1] * shape[2]);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  uint64_t start[] = {0, 0, 0};
+  zarrs_assert(zarrsArrayStoreSubset(array, 3, start, shape, elements.size() * sizeof(uint32_t),
+                                     reinterpret_cast<const uint8_t *>(elements.data())));
+}
+
+void check_elements(const std::vector<uint32_t> &elements, const uint64_t *start,
+                    const uint64_t *subset_shape) {
+  size_t index = 0;
+  for (uint64_t i = start[0]; i < start[0] + subset_shape[0]; ++i) {
+    for (uint64_t j = start[1]; j < start[1] + subset_shape[1]; ++j) {
+      for (uint64_t k = start[2]; k < start[2] + subset_shape[2]; ++k) {
+        assert(elements[index++] == (i * shape[1] + j) * shape[2] + k);
+      }
+    }
+  }
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  uint64_t start[] = {3, 5, 1};
+  uint64_t subset_shape[] = {50, 41, 2};
+  std::vector<uint32_t> elements(subset_shape[0] * subset_shape[1] * subset_shape[2]);
+  uint8_t *bytes = reinterpret_cast<uint8_t *>(elements.data());
+  const size_t bytes_count = elements.size() * sizeof(uint32_t);
+
+  // Unsharded
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  store_elements(array);
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 3, start, subset_shape, bytes_count, bytes));
+  check_elements(elements, start, subset_shape);
+  assert(zarrsArrayRetrieveSubset(array, 3, start, subset_shape, bytes_count - 1, bytes) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Sharded
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array_sharded", array_metadata_sharded, &array));
+  store_elements(array);
+  ZarrsShardIndexCache cache = nullptr;
+  zarrs_assert(zarrsCreateShardIndexCache(array, &cache));
+  std::fill(elements.begin(), elements.end(), 0);
+  zarrs_assert(zarrsArrayRetrieveSubsetSharded(array, cache, 3, start, subset_shape, bytes_count, bytes));
+  check_elements(elements, start, subset_shape);
+  assert(zarrsArrayRetrieveSubsetSharded(array, cache, 3, start, subset_shape, bytes_count + 1, bytes) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+
+  zarrs_assert(zarrsCreateShardIndexCacheWithCapacity(array, 2, &cache));
+  std::fill(elements.begin(), elements.end(), 0);
+  zarrs_assert(zarrsArrayRetrieveSubsetSharded(array, cache, 3, start, subset_shape, bytes_count, bytes));
+  check_elements(elements, start, subset_shape);
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,89 @@
+#include "zarrs.h"
+
+#include <cstdio>
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [2, 3],
+    "data_type": "%s",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [1, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+ZarrsArray create_array(ZarrsStorage storage, const char *path, const char *data_type) {
+  char metadata[1024];
+  snprintf(metadata, sizeof(metadata), array_metadata, data_type);
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, path, metadata, &array));
+  return array;
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  uint64_t start[] = {0, 0};
+  uint64_t shape[] = {2, 3};
+
+  // float16 to float64
+  ZarrsArray array = create_array(storage, "/float16", "float16");
+  // 1.0, -2.0, 0.5, 65504.0, 0.0, 0.333251953125
+  std::vector<uint16_t> float16_bits = {0x3C00, 0xC000, 0x3800, 0x7BFF, 0x0000, 0x3555};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, start, shape, float16_bits.size() * sizeof(uint16_t),
+                                     reinterpret_cast<const uint8_t *>(float16_bits.data())));
+  std::vector<double> float64_elements(6);
+  zarrs_assert(zarrsArrayRetrieveSubsetAs(array, ZarrsDataType::ZARRS_FLOAT64, 2, start, shape,
+                                          float64_elements.size() * sizeof(double),
+                                          reinterpret_cast<uint8_t *>(float64_elements.data())));
+  assert(float64_elements == std::vector<double>({1.0, -2.0, 0.5, 65504.0, 0.0, 0.333251953125}));
+
+  // The buffer length is validated against the output data type
+  assert(zarrsArrayRetrieveSubsetAs(array, ZarrsDataType::ZARRS_FLOAT64, 2, start, shape,
+                                    float16_bits.size() * sizeof(uint16_t),
+                                    reinterpret_cast<uint8_t *>(float64_elements.data())) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+
+  // Narrowing conversions are not supported
+  assert(zarrsArrayRetrieveSubsetAs(array, ZarrsDataType::ZARRS_INT32, 2, start, shape,
+                                    6 * sizeof(int32_t),
+                                    reinterpret_cast<uint8_t *>(float64_elements.data())) ==
+         ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // uint16 to float32, on a subset crossing chunk boundaries
+  array = create_array(storage, "/uint16", "uint16");
+  std::vector<uint16_t> uint16_elements = {0, 1, 65535, 1234, 40000, 7};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, start, shape, uint16_elements.size() * sizeof(uint16_t),
+                                     reinterpret_cast<const uint8_t *>(uint16_elements.data())));
+  uint64_t subset_start[] = {0, 1};
+  uint64_t subset_shape[] = {2, 2};
+  std::vector<float> float32_elements(4);
+  zarrs_assert(zarrsArrayRetrieveSubsetAs(array, ZarrsDataType::ZARRS_FLOAT32, 2, subset_start,
+                                          subset_shape, float32_elements.size() * sizeof(float),
+                                          reinterpret_cast<uint8_t *>(float32_elements.data())));
+  assert(float32_elements == std::vector<float>({1.0f, 65535.0f, 40000.0f, 7.0f}));
+
+  // Conversion to the same data type
+  std::vector<uint16_t> same_elements(6);
+  zarrs_assert(zarrsArrayRetrieveSubsetAs(array, ZarrsDataType::ZARRS_UINT16, 2, start, shape,
+                                          same_elements.size() * sizeof(uint16_t),
+                                          reinterpret_cast<uint8_t *>(same_elements.data())));
+  assert(same_elements == uint16_elements);
+
+  // Signed integers cannot be represented by unsigned integers
+  assert(zarrsArrayRetrieveSubsetAs(array, ZarrsDataType::ZARRS_INT16, 2, start, shape,
+                                    6 * sizeof(int16_t),
+                                    reinterpret_cast<uint8_t *>(same_elements.data())) ==
+         ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
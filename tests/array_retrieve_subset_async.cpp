@@ -0,0 +1,131 @@
+#include "zarrs.h"
+
+#include <algorithm>
+#include <atomic>
+#include <mutex>
+#include <set>
+#include <thread>
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [16, 16],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+struct Completion {
+  std::thread::id caller;
+  std::atomic<size_t> num_calls{0};
+  std::atomic<size_t> num_errors{0};
+  std::atomic<bool> called_on_caller{false};
+  std::mutex threads_mutex;
+  std::set<std::thread::id> threads;
+};
+
+void on_done(ZarrsResult result, void *userData) {
+  Completion *completion = static_cast<Completion *>(userData);
+  if (std::this_thread::get_id() == completion->caller) {
+    completion->called_on_caller = true;
+  }
+  if (result != ZarrsResult::ZARRS_SUCCESS) {
+    ++completion->num_errors;
+  }
+  {
+    std::lock_guard<std::mutex> lock(completion->threads_mutex);
+    completion->threads.insert(std::this_thread::get_id());
+  }
+  ++completion->num_calls;
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  uint16_t elements[16 * 16];
+  for (uint16_t i = 0; i < 16 * 16; ++i) {
+    elements[i] = i;
+  }
+  uint64_t array_start[] = {0, 0};
+  uint64_t array_shape[] = {16, 16};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, array_start, array_shape, sizeof(elements),
+                                     reinterpret_cast<uint8_t *>(elements)));
+
+  // Issue many more concurrent reads of a 3x5 subset at different offsets than there are worker threads
+  const size_t num_cpus = std::max(std::thread::hardware_concurrency(), 1u);
+  const size_t num_operations = std::max<size_t>(64, 16 * num_cpus);
+  Completion completion;
+  completion.caller = std::this_thread::get_id();
+  std::vector<std::vector<uint16_t>> buffers(num_operations, std::vector<uint16_t>(3 * 5));
+  std::vector<ZarrsOperation> operations(num_operations, nullptr);
+  for (size_t op = 0; op < num_operations; ++op) {
+    uint64_t subset_start[] = {op % 13, (op * 7) % 11};
+    uint64_t subset_shape[] = {3, 5};
+    zarrs_assert(zarrsArrayRetrieveSubsetAsync(
+        array, 2, subset_start, subset_shape, buffers[op].size() * sizeof(uint16_t),
+        reinterpret_cast<uint8_t *>(buffers[op].data()), on_done, &completion, &operations[op]));
+  }
+  for (size_t op = 0; op < num_operations; ++op) {
+    ZarrsResult result = ZarrsResult::ZARRS_ERROR_NULL_PTR;
+    zarrs_assert(zarrsOperationWait(operations[op], &result));
+    zarrs_assert(result);
+    // Waiting again returns the same result
+    zarrs_assert(zarrsOperationWait(operations[op], &result));
+    zarrs_assert(result);
+    zarrs_assert(zarrsOperationDestroy(operations[op]));
+  }
+  assert(completion.num_calls == num_operations);
+  assert(completion.num_errors == 0);
+  assert(!completion.called_on_caller);
+  // The operations are queued on a fixed pool of worker threads rather than each spawning a thread
+  assert(!completion.threads.empty() && completion.threads.size() <= num_cpus);
+  for (size_t op = 0; op < num_operations; ++op) {
+    for (uint64_t i = 0; i < 3; ++i) {
+      for (uint64_t j = 0; j < 5; ++j) {
+        uint64_t row = op % 13 + i;
+        uint64_t col = (op * 7) % 11 + j;
+        assert(buffers[op][i * 5 + j] == elements[row * 16 + col]);
+      }
+    }
+  }
+
+  // Errors are passed to the callback and returned by wait, and the callback is optional
+  uint64_t subset_start[] = {0, 0};
+  uint64_t subset_shape[] = {3, 5};
+  uint16_t buffer[3 * 5];
+  ZarrsOperation operation = nullptr;
+  zarrs_assert(zarrsArrayRetrieveSubsetAsync(array, 2, subset_start, subset_shape, 1,
+                                             reinterpret_cast<uint8_t *>(buffer), on_done,
+                                             &completion, &operation));
+  ZarrsResult result = ZarrsResult::ZARRS_SUCCESS;
+  zarrs_assert(zarrsOperationWait(operation, &result));
+  assert(result != ZarrsResult::ZARRS_SUCCESS);
+  assert(completion.num_calls == num_operations + 1);
+  assert(completion.num_errors == 1);
+  zarrs_assert(zarrsOperationDestroy(operation));
+
+  // Destroying an operation waits for it to complete
+  zarrs_assert(zarrsArrayRetrieveSubsetAsync(array, 2, subset_start, subset_shape, sizeof(buffer),
+                                             reinterpret_cast<uint8_t *>(buffer), nullptr, nullptr,
+                                             &operation));
+  zarrs_assert(zarrsOperationDestroy(operation));
+  assert(buffer[5] == 16);
+
+  assert(zarrsArrayRetrieveSubsetAsync(nullptr, 2, subset_start, subset_shape, sizeof(buffer),
+                                       reinterpret_cast<uint8_t *>(buffer), nullptr, nullptr,
+                                       &operation) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,98 @@
+#include "zarrs.h"
+
+#include <atomic>
+#include <cstring>
+#include <mutex>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [5, 5],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+const uint64_t subset_start[] = {1, 1};
+const uint64_t subset_shape[] = {3, 4};
+
+struct Assembler {
+  std::mutex mutex;
+  uint16_t elements[3 * 4] = {0};
+  std::atomic<size_t> num_calls{0};
+  size_t abort_after = 0;
+};
+
+int32_t assemble(size_t dimensionality, const uint64_t *pPartStart, const uint64_t *pPartShape, const uint8_t *pBytes,
+                 size_t bytesLen, void *userData) {
+  Assembler *assembler = static_cast<Assembler *>(userData);
+  assert(dimensionality == 2);
+  assert(bytesLen == pPartShape[0] * pPartShape[1] * sizeof(uint16_t));
+  const uint16_t *part = reinterpret_cast<const uint16_t *>(pBytes);
+  std::lock_guard<std::mutex> lock(assembler->mutex);
+  for (uint64_t i = 0; i < pPartShape[0]; ++i) {
+    for (uint64_t j = 0; j < pPartShape[1]; ++j) {
+      uint64_t row = pPartStart[0] + i - subset_start[0];
+      uint64_t col = pPartStart[1] + j - subset_start[1];
+      assembler->elements[row * subset_shape[1] + col] = part[i * pPartShape[1] + j];
+    }
+  }
+  size_t num_calls = ++assembler->num_calls;
+  return assembler->abort_after != 0 && num_calls >= assembler->abort_after;
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  uint16_t elements[25];
+  for (uint16_t i = 0; i < 25; ++i) {
+    elements[i] = i;
+  }
+  uint64_t start[] = {0, 0};
+  uint64_t shape[] = {5, 5};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, start, shape, sizeof(elements), reinterpret_cast<uint8_t *>(elements)));
+
+  uint16_t expected[3 * 4];
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 2, subset_start, subset_shape, sizeof(expected),
+                                        reinterpret_cast<uint8_t *>(expected)));
+
+  // The subset intersects 2 x 3 chunks
+  for (bool concurrent : {false, true}) {
+    Assembler assembler;
+    zarrs_assert(zarrsArrayRetrieveSubsetCallback(array, 2, subset_start, subset_shape, assemble, &assembler,
+                                                  concurrent));
+    assert(assembler.num_calls == 6);
+    assert(memcmp(assembler.elements, expected, sizeof(expected)) == 0);
+  }
+
+  // Abort after the first part
+  Assembler assembler;
+  assembler.abort_after = 1;
+  assert(zarrsArrayRetrieveSubsetCallback(array, 2, subset_start, subset_shape, assemble, &assembler, false) ==
+         ZarrsResult::ZARRS_ERROR_ABORTED);
+  assert(assembler.num_calls == 1);
+
+  // Concurrent callbacks already in progress may complete, but no further callbacks are started after an abort
+  Assembler concurrent_assembler;
+  concurrent_assembler.abort_after = 1;
+  assert(zarrsArrayRetrieveSubsetCallback(array, 2, subset_start, subset_shape, assemble, &concurrent_assembler,
+                                          true) == ZarrsResult::ZARRS_ERROR_ABORTED);
+  assert(concurrent_assembler.num_calls >= 1 && concurrent_assembler.num_calls <= 6);
+
+  // A callback is required
+  assert(zarrsArrayRetrieveSubsetCallback(array, 2, subset_start, subset_shape, nullptr, nullptr, false) ==
+         ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
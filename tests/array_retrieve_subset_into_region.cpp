@@ -0,0 +1,85 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [6, 6],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  std::vector<uint16_t> elements(6 * 6);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  uint64_t array_start[] = {0, 0};
+  uint64_t array_shape[] = {6, 6};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, array_start, array_shape, elements.size() * sizeof(uint16_t),
+                                     reinterpret_cast<const uint8_t *>(elements.data())));
+
+  // Assemble two 4x5 subsets side by side in a 5x10 destination
+  uint64_t dst_shape[] = {5, 10};
+  std::vector<uint16_t> dst(5 * 10, 0xFFFF);
+  uint64_t subset_shape[] = {4, 5};
+  uint64_t left_start[] = {0, 0};
+  uint64_t left_offset[] = {0, 0};
+  zarrs_assert(zarrsArrayRetrieveSubsetIntoRegion(array, 2, left_start, subset_shape, dst_shape, left_offset,
+                                                  dst.size() * sizeof(uint16_t),
+                                                  reinterpret_cast<uint8_t *>(dst.data())));
+  uint64_t right_start[] = {2, 1};
+  uint64_t right_offset[] = {0, 5};
+  zarrs_assert(zarrsArrayRetrieveSubsetIntoRegion(array, 2, right_start, subset_shape, dst_shape, right_offset,
+                                                  dst.size() * sizeof(uint16_t),
+                                                  reinterpret_cast<uint8_t *>(dst.data())));
+  for (size_t i = 0; i < 4; ++i) {
+    for (size_t j = 0; j < 5; ++j) {
+      assert(dst[i * 10 + j] == i * 6 + j);
+      assert(dst[i * 10 + 5 + j] == (2 + i) * 6 + 1 + j);
+    }
+  }
+  // Elements outside of the regions are not modified
+  for (size_t j = 0; j < 10; ++j) {
+    assert(dst[4 * 10 + j] == 0xFFFF);
+  }
+
+  // The region must lie within the destination
+  uint64_t past_end_offset[] = {1, 6};
+  assert(zarrsArrayRetrieveSubsetIntoRegion(array, 2, left_start, subset_shape, dst_shape, past_end_offset,
+                                            dst.size() * sizeof(uint16_t),
+                                            reinterpret_cast<uint8_t *>(dst.data())) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  uint64_t overflow_offset[] = {0, UINT64_MAX - 1};
+  assert(zarrsArrayRetrieveSubsetIntoRegion(array, 2, left_start, subset_shape, dst_shape, overflow_offset,
+                                            dst.size() * sizeof(uint16_t),
+                                            reinterpret_cast<uint8_t *>(dst.data())) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  // The subset must lie within the array
+  assert(zarrsArrayRetrieveSubsetIntoRegion(array, 2, right_offset, subset_shape, dst_shape, left_offset,
+                                            dst.size() * sizeof(uint16_t),
+                                            reinterpret_cast<uint8_t *>(dst.data())) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  // The buffer must match the destination shape
+  assert(zarrsArrayRetrieveSubsetIntoRegion(array, 2, left_start, subset_shape, dst_shape, left_offset,
+                                            dst.size() * sizeof(uint16_t) - 1,
+                                            reinterpret_cast<uint8_t *>(dst.data())) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,65 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [16, 16],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  std::vector<uint16_t> elements(16 * 16);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  uint64_t array_start[] = {0, 0};
+  uint64_t array_shape[] = {16, 16};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, array_start, array_shape,
+                                     elements.size() * sizeof(uint16_t),
+                                     reinterpret_cast<uint8_t *>(elements.data())));
+
+  // A subset intersecting 16 chunks, retrieved at concurrency 1, 2, and the default
+  uint64_t subset_start[] = {1, 2};
+  uint64_t subset_shape[] = {13, 11};
+  std::vector<uint16_t> expected(13 * 11);
+  for (uint64_t i = 0; i < 13; ++i) {
+    for (uint64_t j = 0; j < 11; ++j) {
+      expected[i * 11 + j] = elements[(i + 1) * 16 + j + 2];
+    }
+  }
+  for (size_t max_concurrency : {1, 2, 0}) {
+    std::vector<uint16_t> subset(13 * 11);
+    zarrs_assert(zarrsArrayRetrieveSubsetParallel(array, max_concurrency, 2, subset_start,
+                                                  subset_shape, subset.size() * sizeof(uint16_t),
+                                                  reinterpret_cast<uint8_t *>(subset.data())));
+    assert(subset == expected);
+  }
+
+  std::vector<uint16_t> subset(13 * 11);
+  assert(zarrsArrayRetrieveSubsetParallel(array, 1, 2, subset_start, subset_shape, 1,
+                                          reinterpret_cast<uint8_t *>(subset.data())) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  assert(zarrsArrayRetrieveSubsetParallel(nullptr, 1, 2, subset_start, subset_shape,
+                                          subset.size() * sizeof(uint16_t),
+                                          reinterpret_cast<uint8_t *>(subset.data())) ==
+         ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,99 @@
+#include "zarrs.h"
+
+#include <cmath>
+#include <vector>
+
+// Temperatures in kelvin packed as int16 following the CF conventions
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 5],
+    "data_type": "int16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 3]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": -32768,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}],
+    "attributes": {"scale_factor": 0.01, "add_offset": 273.15}
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  std::vector<int16_t> elements(4 * 5);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = static_cast<int16_t>(i * 150) - 1500;
+  }
+  elements[7] = -32768;
+  uint64_t start[] = {0, 0};
+  uint64_t shape[] = {4, 5};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, start, shape, elements.size() * sizeof(int16_t),
+                                     reinterpret_cast<const uint8_t *>(elements.data())));
+
+  const double scale = 0.01;
+  const double offset = 273.15;
+  std::vector<double> scaled(4 * 5);
+  zarrs_assert(zarrsArrayRetrieveSubsetScaled(array, scale, offset, ZarrsDataType::ZARRS_FLOAT64, 2,
+                                              start, shape, scaled.size() * sizeof(double),
+                                              reinterpret_cast<uint8_t *>(scaled.data())));
+  for (size_t i = 0; i < elements.size(); ++i) {
+    if (i == 7) {
+      assert(std::isnan(scaled[i]));
+    } else {
+      assert(scaled[i] == elements[i] * scale + offset);
+    }
+  }
+
+  // The scale and offset attributes, as float32
+  std::vector<float> scaled_f32(4 * 5);
+  zarrs_assert(zarrsArrayRetrieveSubsetScaledFromAttributes(
+      array, ZarrsDataType::ZARRS_FLOAT32, 2, start, shape, scaled_f32.size() * sizeof(float),
+      reinterpret_cast<uint8_t *>(scaled_f32.data())));
+  for (size_t i = 0; i < elements.size(); ++i) {
+    if (i == 7) {
+      assert(std::isnan(scaled_f32[i]));
+    } else {
+      assert(scaled_f32[i] == static_cast<float>(elements[i] * scale + offset));
+    }
+  }
+
+  // A subset crossing chunks
+  uint64_t subset_start[] = {1, 2};
+  uint64_t subset_shape[] = {2, 2};
+  std::vector<double> subset_scaled(2 * 2);
+  zarrs_assert(zarrsArrayRetrieveSubsetScaled(array, 2.0, -1.0, ZarrsDataType::ZARRS_FLOAT64, 2,
+                                              subset_start, subset_shape,
+                                              subset_scaled.size() * sizeof(double),
+                                              reinterpret_cast<uint8_t *>(subset_scaled.data())));
+  assert(std::isnan(subset_scaled[0]));
+  assert(subset_scaled[1] == elements[1 * 5 + 3] * 2.0 - 1.0);
+  assert(subset_scaled[2] == elements[2 * 5 + 2] * 2.0 - 1.0);
+  assert(subset_scaled[3] == elements[2 * 5 + 3] * 2.0 - 1.0);
+
+  // The buffer length is validated against the output data type
+  assert(zarrsArrayRetrieveSubsetScaled(array, scale, offset, ZarrsDataType::ZARRS_FLOAT32, 2,
+                                        start, shape, scaled.size() * sizeof(double),
+                                        reinterpret_cast<uint8_t *>(scaled.data())) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  assert(zarrsArrayRetrieveSubsetScaled(array, scale, offset, ZarrsDataType::ZARRS_INT32, 2, start,
+                                        shape, elements.size() * sizeof(int32_t),
+                                        reinterpret_cast<uint8_t *>(scaled.data())) ==
+         ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+
+  // Invalid scale attributes
+  zarrs_assert(zarrsArraySetAttribute(array, "scale_factor", "\"0.01\""));
+  assert(zarrsArrayRetrieveSubsetScaledFromAttributes(
+             array, ZarrsDataType::ZARRS_FLOAT64, 2, start, shape, scaled.size() * sizeof(double),
+             reinterpret_cast<uint8_t *>(scaled.data())) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
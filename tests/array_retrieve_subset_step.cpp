@@ -0,0 +1,116 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [16, 16],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+std::vector<uint16_t> retrieve_step(ZarrsArray array, uint64_t *start, uint64_t *shape_out,
+                                    uint64_t *step) {
+  std::vector<uint16_t> bytes(shape_out[0] * shape_out[1]);
+  zarrs_assert(zarrsArrayRetrieveSubsetStep(array, 2, start, shape_out, step,
+                                            bytes.size() * sizeof(uint16_t),
+                                            reinterpret_cast<uint8_t *>(bytes.data())));
+  return bytes;
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  std::vector<uint16_t> elements(16 * 16);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  uint64_t array_start[] = {0, 0};
+  uint64_t array_shape[] = {16, 16};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, array_start, array_shape,
+                                     elements.size() * sizeof(uint16_t),
+                                     reinterpret_cast<uint8_t *>(elements.data())));
+
+  // Steps of 1 match zarrsArrayRetrieveSubset
+  uint64_t start[] = {1, 2};
+  uint64_t shape_out[] = {5, 7};
+  uint64_t unit_step[] = {1, 1};
+  std::vector<uint16_t> subset(5 * 7);
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 2, start, shape_out, subset.size() * sizeof(uint16_t),
+                                        reinterpret_cast<uint8_t *>(subset.data())));
+  assert(retrieve_step(array, start, shape_out, unit_step) == subset);
+
+  // Every 4th element, starting at an offset
+  uint64_t shape_out_4[] = {4, 4};
+  uint64_t step_4[] = {4, 4};
+  std::vector<uint16_t> stepped = retrieve_step(array, start, shape_out_4, step_4);
+  for (uint64_t i = 0; i < 4; ++i) {
+    for (uint64_t j = 0; j < 4; ++j) {
+      assert(stepped[i * 4 + j] == elements[(1 + i * 4) * 16 + 2 + j * 4]);
+    }
+  }
+
+  // Different steps per dimension
+  uint64_t mixed_step[] = {3, 1};
+  uint64_t mixed_shape_out[] = {5, 3};
+  stepped = retrieve_step(array, start, mixed_shape_out, mixed_step);
+  for (uint64_t i = 0; i < 5; ++i) {
+    for (uint64_t j = 0; j < 3; ++j) {
+      assert(stepped[i * 3 + j] == elements[(1 + i * 3) * 16 + 2 + j]);
+    }
+  }
+
+  // Steps larger than the chunk shape skip chunks without selected elements
+  zarrs_assert(zarrsArrayResetStorageStats(array));
+  uint64_t origin[] = {0, 0};
+  uint64_t shape_out_8[] = {2, 2};
+  uint64_t step_8[] = {8, 8};
+  stepped = retrieve_step(array, origin, shape_out_8, step_8);
+  assert(stepped == std::vector<uint16_t>({0, 8, 8 * 16, 8 * 16 + 8}));
+  ZarrsStorageStats stats;
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.chunksDecoded == 4);
+  uint64_t step_5[] = {5, 6};
+  uint64_t shape_out_5[] = {4, 3};
+  stepped = retrieve_step(array, start, shape_out_5, step_5);
+  for (uint64_t i = 0; i < 4; ++i) {
+    for (uint64_t j = 0; j < 3; ++j) {
+      assert(stepped[i * 3 + j] == elements[(1 + i * 5) * 16 + 2 + j * 6]);
+    }
+  }
+
+  // Invalid selections
+  uint64_t zero_step[] = {0, 1};
+  assert(zarrsArrayRetrieveSubsetStep(array, 2, start, shape_out_4, zero_step,
+                                      subset.size() * sizeof(uint16_t),
+                                      reinterpret_cast<uint8_t *>(subset.data())) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  uint64_t out_of_bounds_shape_out[] = {5, 4};
+  std::vector<uint16_t> out_of_bounds(5 * 4);
+  assert(zarrsArrayRetrieveSubsetStep(array, 2, start, out_of_bounds_shape_out, step_4,
+                                      out_of_bounds.size() * sizeof(uint16_t),
+                                      reinterpret_cast<uint8_t *>(out_of_bounds.data())) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayRetrieveSubsetStep(array, 2, start, shape_out_4, step_4, 1,
+                                      reinterpret_cast<uint8_t *>(subset.data())) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  assert(zarrsArrayRetrieveSubsetStep(array, 1, start, shape_out_4, step_4,
+                                      subset.size() * sizeof(uint16_t),
+                                      reinterpret_cast<uint8_t *>(subset.data())) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
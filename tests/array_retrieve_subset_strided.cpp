@@ -0,0 +1,124 @@
+#include "zarrs.h"
+
+#include <algorithm>
+#include <cstring>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  uint16_t elements[16];
+  for (uint16_t i = 0; i < 16; ++i) {
+    elements[i] = i;
+  }
+  uint64_t start[] = {0, 0};
+  uint64_t shape[] = {4, 4};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, start, shape, sizeof(elements), reinterpret_cast<uint8_t *>(elements)));
+
+  uint64_t subset_start[] = {1, 1};
+  uint64_t subset_shape[] = {2, 3};
+
+  // Padded rows: each row of 3 elements is followed by 1 element of padding
+  {
+    uint16_t dst[8];
+    std::fill(dst, dst + 8, 0xFFFF);
+    int64_t strides[] = {4 * sizeof(uint16_t), sizeof(uint16_t)};
+    zarrs_assert(zarrsArrayRetrieveSubsetStrided(array, 2, subset_start, subset_shape, strides,
+                                                 reinterpret_cast<uint8_t *>(dst), sizeof(dst)));
+    const uint16_t expected[] = {5, 6, 7, 0xFFFF, 9, 10, 11, 0xFFFF};
+    assert(memcmp(dst, expected, sizeof(dst)) == 0);
+
+    // The padding after the last row is not required
+    zarrs_assert(zarrsArrayRetrieveSubsetStrided(array, 2, subset_start, subset_shape, strides,
+                                                 reinterpret_cast<uint8_t *>(dst), 7 * sizeof(uint16_t)));
+
+    // A buffer that is too short for the strides is rejected
+    assert(zarrsArrayRetrieveSubsetStrided(array, 2, subset_start, subset_shape, strides,
+                                           reinterpret_cast<uint8_t *>(dst), 6 * sizeof(uint16_t)) ==
+           ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  }
+
+  // Flipped rows and columns with negative strides
+  {
+    uint16_t dst[6];
+    int64_t strides[] = {-3 * int64_t(sizeof(uint16_t)), -int64_t(sizeof(uint16_t))};
+    zarrs_assert(zarrsArrayRetrieveSubsetStrided(array, 2, subset_start, subset_shape, strides,
+                                                 reinterpret_cast<uint8_t *>(dst), sizeof(dst)));
+    const uint16_t expected[] = {11, 10, 9, 7, 6, 5};
+    assert(memcmp(dst, expected, sizeof(dst)) == 0);
+
+    // A stride mistake that addresses elements outside the buffer is rejected
+    int64_t bad_strides[] = {-4 * int64_t(sizeof(uint16_t)), -int64_t(sizeof(uint16_t))};
+    assert(zarrsArrayRetrieveSubsetStrided(array, 2, subset_start, subset_shape, bad_strides,
+                                           reinterpret_cast<uint8_t *>(dst), sizeof(dst)) ==
+           ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  }
+
+  // Storing from a strided buffer is the inverse of retrieving into it
+  {
+    uint16_t src[8] = {105, 106, 107, 0xFFFF, 109, 110, 111, 0xFFFF};
+    int64_t strides[] = {4 * sizeof(uint16_t), sizeof(uint16_t)};
+    zarrs_assert(zarrsArrayStoreSubsetStrided(array, 2, subset_start, subset_shape, strides,
+                                              reinterpret_cast<const uint8_t *>(src),
+                                              7 * sizeof(uint16_t)));
+    uint16_t dst[8];
+    std::fill(dst, dst + 8, 0);
+    zarrs_assert(zarrsArrayRetrieveSubsetStrided(array, 2, subset_start, subset_shape, strides,
+                                                 reinterpret_cast<uint8_t *>(dst), sizeof(dst)));
+    const uint16_t expected[] = {105, 106, 107, 0, 109, 110, 111, 0};
+    assert(memcmp(dst, expected, sizeof(dst)) == 0);
+
+    // Elements outside the subset are unchanged
+    zarrs_assert(zarrsArrayRetrieveSubset(array, 2, start, shape, sizeof(elements),
+                                          reinterpret_cast<uint8_t *>(elements)));
+    const uint16_t expected_array[] = {0, 1,   2,   3,   4,  105, 106, 107,
+                                       8, 109, 110, 111, 12, 13,  14,  15};
+    assert(memcmp(elements, expected_array, sizeof(elements)) == 0);
+
+    // Negative strides store flipped rows and columns
+    uint16_t flipped[6] = {211, 210, 209, 207, 206, 205};
+    int64_t flipped_strides[] = {-3 * int64_t(sizeof(uint16_t)), -int64_t(sizeof(uint16_t))};
+    zarrs_assert(zarrsArrayStoreSubsetStrided(array, 2, subset_start, subset_shape,
+                                              flipped_strides,
+                                              reinterpret_cast<const uint8_t *>(flipped),
+                                              sizeof(flipped)));
+    zarrs_assert(zarrsArrayRetrieveSubsetStrided(array, 2, subset_start, subset_shape, strides,
+                                                 reinterpret_cast<uint8_t *>(dst), sizeof(dst)));
+    const uint16_t expected_flipped[] = {205, 206, 207, 0, 209, 210, 211, 0};
+    assert(memcmp(dst, expected_flipped, sizeof(dst)) == 0);
+
+    // A buffer that is too short for the strides is rejected without storing anything
+    assert(zarrsArrayStoreSubsetStrided(array, 2, subset_start, subset_shape, strides,
+                                        reinterpret_cast<const uint8_t *>(src),
+                                        6 * sizeof(uint16_t)) ==
+           ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+    int64_t bad_strides[] = {-4 * int64_t(sizeof(uint16_t)), -int64_t(sizeof(uint16_t))};
+    assert(zarrsArrayStoreSubsetStrided(array, 2, subset_start, subset_shape, bad_strides,
+                                        reinterpret_cast<const uint8_t *>(flipped),
+                                        sizeof(flipped)) == ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+    zarrs_assert(zarrsArrayRetrieveSubsetStrided(array, 2, subset_start, subset_shape, strides,
+                                                 reinterpret_cast<uint8_t *>(dst), sizeof(dst)));
+    assert(memcmp(dst, expected_flipped, sizeof(dst)) == 0);
+  }
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
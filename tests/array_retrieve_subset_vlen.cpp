@@ -0,0 +1,57 @@
+#include "zarrs.h"
+
+#include <iostream>
+#include <string>
+
+int main() {
+  const char *tmp_path = getenv("TMP_PATH_RETRIEVE_SUBSET_VLEN");
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageFilesystem(tmp_path, &storage));
+
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array", &array));
+
+  ZarrsDataType data_type;
+  zarrs_assert(zarrsArrayGetDataType(array, &data_type));
+  assert(data_type == ZarrsDataType::ZARRS_STRING);
+
+  // Retrieve a subset spanning a stored chunk and a missing chunk
+  uint64_t subset_start[] = {0, 1};
+  uint64_t subset_shape[] = {2, 2};
+  uint8_t *bytes = nullptr;
+  size_t bytes_len = 0;
+  uint64_t *offsets = nullptr;
+  size_t offsets_len = 0;
+  zarrs_assert(zarrsArrayRetrieveSubsetVlen(array, 2, subset_start, subset_shape, &bytes, &bytes_len,
+                                            &offsets, &offsets_len));
+  assert(offsets_len == 5);
+  assert(offsets[0] == 0);
+  assert(offsets[offsets_len - 1] == bytes_len);
+  for (size_t i = 0; i + 1 < offsets_len; ++i) {
+    std::string element(reinterpret_cast<const char *>(bytes + offsets[i]), offsets[i + 1] - offsets[i]);
+    std::cout << (i == 0 ? "" : " ") << "[" << element << "]";
+  }
+  std::cout << std::endl;
+  zarrs_assert(zarrsFreeBytes(bytes));
+  zarrs_assert(zarrsFreeOffsets(offsets));
+  assert(zarrsFreeOffsets(offsets) == ZarrsResult::ZARRS_ERROR_UNKNOWN_POINTER);
+
+  // The dimensionality must match the array
+  assert(zarrsArrayRetrieveSubsetVlen(array, 1, subset_start, subset_shape, &bytes, &bytes_len, &offsets,
+                                      &offsets_len) == ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  // Fixed size retrieval is not supported for variable size data types
+  uint8_t fixed_bytes[4];
+  assert(zarrsArrayRetrieveSubset(array, 2, subset_start, subset_shape, sizeof(fixed_bytes), fixed_bytes) ==
+         ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Variable size retrieval is not supported for fixed size data types
+  ZarrsArray array_fixed = nullptr;
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array_fixed", &array_fixed));
+  assert(zarrsArrayRetrieveSubsetVlen(array_fixed, 2, subset_start, subset_shape, &bytes, &bytes_len, &offsets,
+                                      &offsets_len) == ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+  zarrs_assert(zarrsDestroyArray(array_fixed));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,154 @@
+#include "zarrs.h"
+
+#include <cmath>
+#include <cstring>
+#include <string>
+
+std::string array_metadata(const char *data_type, const char *fill_value) {
+  return std::string(R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 6],
+    "data_type": ")"""") +
+         data_type + R""""(",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": )"""" +
+         fill_value + R""""(,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+
+  // Raw element bytes
+  {
+    ZarrsArray array = nullptr;
+    zarrs_assert(zarrsCreateArrayRW(storage, "/int32", array_metadata("int32", "-1").c_str(), &array));
+    uint64_t indices[] = {3, 5};
+    int32_t element = 0;
+    zarrs_assert(zarrsArrayGetSingleElement(array, 2, indices, sizeof(element), reinterpret_cast<uint8_t *>(&element)));
+    assert(element == -1);
+    element = 123456;
+    zarrs_assert(zarrsArraySetSingleElement(array, 2, indices, sizeof(element), reinterpret_cast<uint8_t *>(&element)));
+    element = 0;
+    zarrs_assert(zarrsArrayGetSingleElement(array, 2, indices, sizeof(element), reinterpret_cast<uint8_t *>(&element)));
+    assert(element == 123456);
+
+    // Only the element is changed
+    int32_t chunk[2 * 4];
+    uint64_t chunk_indices[] = {1, 1};
+    zarrs_assert(zarrsArrayRetrieveChunk(array, 2, chunk_indices, sizeof(chunk), reinterpret_cast<uint8_t *>(chunk)));
+    for (size_t i = 0; i < 2 * 4; ++i) {
+      assert(chunk[i] == (i == 2 * 4 - 3 ? 123456 : -1));
+    }
+
+    // The element as a double
+    double value = 0.0;
+    zarrs_assert(zarrsArrayGetSingleElementFloat64(array, 2, indices, &value));
+    assert(value == 123456.0);
+    zarrs_assert(zarrsArraySetSingleElementFloat64(array, 2, indices, -7.9));
+    zarrs_assert(zarrsArrayGetSingleElement(array, 2, indices, sizeof(element), reinterpret_cast<uint8_t *>(&element)));
+    assert(element == -7);
+    zarrs_assert(zarrsArraySetSingleElementFloat64(array, 2, indices, 1e12));
+    zarrs_assert(zarrsArrayGetSingleElementFloat64(array, 2, indices, &value));
+    assert(value == 2147483647.0);
+
+    // Errors
+    uint64_t out_of_bounds_indices[] = {4, 0};
+    assert(zarrsArrayGetSingleElement(array, 2, out_of_bounds_indices, sizeof(element),
+                                      reinterpret_cast<uint8_t *>(&element)) ==
+           ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+    assert(zarrsArraySetSingleElement(array, 2, out_of_bounds_indices, sizeof(element),
+                                      reinterpret_cast<uint8_t *>(&element)) ==
+           ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+    assert(zarrsArrayGetSingleElementFloat64(array, 2, out_of_bounds_indices, &value) ==
+           ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+    assert(zarrsArraySetSingleElementFloat64(array, 2, out_of_bounds_indices, value) ==
+           ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+    assert(zarrsArrayGetSingleElement(array, 1, indices, sizeof(element), reinterpret_cast<uint8_t *>(&element)) ==
+           ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+    assert(zarrsArraySetSingleElementFloat64(array, 1, indices, value) ==
+           ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+    assert(zarrsArrayGetSingleElement(array, 2, indices, 2, reinterpret_cast<uint8_t *>(&element)) ==
+           ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+    int64_t wide_element = 0;
+    assert(zarrsArraySetSingleElement(array, 2, indices, sizeof(wide_element),
+                                      reinterpret_cast<uint8_t *>(&wide_element)) ==
+           ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+    assert(zarrsArrayGetSingleElementFloat64(nullptr, 2, indices, &value) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+    assert(zarrsArraySetSingleElementFloat64(nullptr, 2, indices, value) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+    zarrs_assert(zarrsDestroyArray(array));
+  }
+
+  // Half precision floats are converted to and from a double
+  for (const char *data_type : {"float16", "bfloat16"}) {
+    ZarrsArray array = nullptr;
+    std::string path = std::string("/") + data_type;
+    zarrs_assert(zarrsCreateArrayRW(storage, path.c_str(), array_metadata(data_type, "0.0").c_str(), &array));
+    uint64_t indices[] = {1, 2};
+    double value = 1.0;
+    zarrs_assert(zarrsArrayGetSingleElementFloat64(array, 2, indices, &value));
+    assert(value == 0.0);
+    zarrs_assert(zarrsArraySetSingleElementFloat64(array, 2, indices, -2.5));
+    zarrs_assert(zarrsArrayGetSingleElementFloat64(array, 2, indices, &value));
+    assert(value == -2.5);
+    // Values round to the nearest representable value
+    zarrs_assert(zarrsArraySetSingleElementFloat64(array, 2, indices, 1.0 + 1.0 / 4096));
+    zarrs_assert(zarrsArrayGetSingleElementFloat64(array, 2, indices, &value));
+    assert(value == 1.0);
+    zarrs_assert(zarrsArraySetSingleElementFloat64(array, 2, indices, NAN));
+    zarrs_assert(zarrsArrayGetSingleElementFloat64(array, 2, indices, &value));
+    assert(std::isnan(value));
+    zarrs_assert(zarrsDestroyArray(array));
+  }
+
+  // Booleans and 64-bit integers
+  {
+    ZarrsArray array = nullptr;
+    zarrs_assert(zarrsCreateArrayRW(storage, "/bool", array_metadata("bool", "false").c_str(), &array));
+    uint64_t indices[] = {0, 0};
+    zarrs_assert(zarrsArraySetSingleElementFloat64(array, 2, indices, 0.5));
+    double value = 0.0;
+    zarrs_assert(zarrsArrayGetSingleElementFloat64(array, 2, indices, &value));
+    assert(value == 1.0);
+    zarrs_assert(zarrsDestroyArray(array));
+
+    zarrs_assert(zarrsCreateArrayRW(storage, "/uint64", array_metadata("uint64", "0").c_str(), &array));
+    zarrs_assert(zarrsArraySetSingleElementFloat64(array, 2, indices, -1.0));
+    zarrs_assert(zarrsArrayGetSingleElementFloat64(array, 2, indices, &value));
+    assert(value == 0.0);
+    zarrs_assert(zarrsArraySetSingleElementFloat64(array, 2, indices, 9007199254740992.0));
+    uint64_t element = 0;
+    zarrs_assert(zarrsArrayGetSingleElement(array, 2, indices, sizeof(element), reinterpret_cast<uint8_t *>(&element)));
+    assert(element == 9007199254740992ull);
+    zarrs_assert(zarrsDestroyArray(array));
+  }
+
+  // Data types that cannot be converted to or from a double
+  {
+    ZarrsArray array = nullptr;
+    zarrs_assert(zarrsCreateArrayRW(storage, "/complex64", array_metadata("complex64", "[0.0, 0.0]").c_str(), &array));
+    uint64_t indices[] = {0, 0};
+    double value = 0.0;
+    assert(zarrsArrayGetSingleElementFloat64(array, 2, indices, &value) ==
+           ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+    assert(zarrsArraySetSingleElementFloat64(array, 2, indices, value) ==
+           ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+    float element[2] = {1.0f, -1.0f};
+    zarrs_assert(zarrsArraySetSingleElement(array, 2, indices, sizeof(element), reinterpret_cast<uint8_t *>(element)));
+    memset(element, 0, sizeof(element));
+    zarrs_assert(zarrsArrayGetSingleElement(array, 2, indices, sizeof(element), reinterpret_cast<uint8_t *>(element)));
+    assert(element[0] == 1.0f && element[1] == -1.0f);
+    zarrs_assert(zarrsDestroyArray(array));
+  }
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
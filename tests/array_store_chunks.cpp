@@ -0,0 +1,84 @@
+#include "zarrs.h"
+
+#include <vector>
+
+// The chunks on the array boundary overhang the array shape
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [7, 5],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 3]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  // All chunks cover an 8x6 region, including the elements outside of the array shape
+  uint64_t chunks_start[] = {0, 0};
+  uint64_t chunks_shape[] = {2, 2};
+  uint64_t start[2];
+  uint64_t shape[2];
+  zarrs_assert(zarrsArrayGetChunksSubset(array, 2, chunks_start, chunks_shape, start, shape));
+  assert(shape[0] == 8 && shape[1] == 6);
+  std::vector<uint16_t> elements(8 * 6);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  zarrs_assert(zarrsArrayStoreChunks(array, 2, chunks_start, chunks_shape, elements.size() * sizeof(uint16_t),
+                                     reinterpret_cast<const uint8_t *>(elements.data())));
+
+  // Element (i, j) within the array has the value i * 6 + j
+  uint64_t array_shape[] = {7, 5};
+  std::vector<uint16_t> subset(7 * 5);
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 2, start, array_shape, subset.size() * sizeof(uint16_t),
+                                        reinterpret_cast<uint8_t *>(subset.data())));
+  for (size_t i = 0; i < 7; ++i) {
+    for (size_t j = 0; j < 5; ++j) {
+      assert(subset[i * 5 + j] == i * 6 + j);
+    }
+  }
+
+  // Storing then retrieving the chunks is lossless
+  std::vector<uint16_t> chunks(8 * 6);
+  zarrs_assert(zarrsArrayRetrieveChunks(array, 2, chunks_start, chunks_shape, chunks.size() * sizeof(uint16_t),
+                                        reinterpret_cast<uint8_t *>(chunks.data())));
+  assert(chunks == elements);
+
+  // A single row of chunks
+  uint64_t row_start[] = {1, 0};
+  uint64_t row_shape[] = {1, 2};
+  std::vector<uint16_t> row(4 * 6, 7);
+  zarrs_assert(zarrsArrayStoreChunks(array, 2, row_start, row_shape, row.size() * sizeof(uint16_t),
+                                     reinterpret_cast<const uint8_t *>(row.data())));
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 2, start, array_shape, subset.size() * sizeof(uint16_t),
+                                        reinterpret_cast<uint8_t *>(subset.data())));
+  assert(subset[3 * 5 + 4] == 3 * 6 + 4);
+  assert(subset[4 * 5] == 7 && subset[6 * 5 + 4] == 7);
+
+  // Errors
+  assert(zarrsArrayStoreChunks(array, 2, row_start, row_shape, row.size() * sizeof(uint16_t) - 1,
+                               reinterpret_cast<const uint8_t *>(row.data())) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  uint64_t out_of_grid_shape[] = {2, 2};
+  assert(zarrsArrayStoreChunks(array, 2, row_start, out_of_grid_shape, row.size() * sizeof(uint16_t),
+                               reinterpret_cast<const uint8_t *>(row.data())) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayStoreChunks(array, 1, row_start, row_shape, row.size() * sizeof(uint16_t),
+                               reinterpret_cast<const uint8_t *>(row.data())) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,98 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  std::vector<uint16_t> elements(8 * 8);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  uint64_t start[] = {0, 0};
+  uint64_t shape[] = {8, 8};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, start, shape, elements.size() * sizeof(uint16_t),
+                                     reinterpret_cast<const uint8_t *>(elements.data())));
+
+  // Load the index of the updated shard into the cache
+  ZarrsShardIndexCache cache = nullptr;
+  zarrs_assert(zarrsCreateShardIndexCacheWithCapacity(array, 2, &cache));
+  zarrs_assert(zarrsArrayRetrieveSubsetSharded(array, cache, 2, start, shape, elements.size() * sizeof(uint16_t),
+                                               reinterpret_cast<uint8_t *>(elements.data())));
+
+  // Store a 2x2 subset crossing 4 inner chunks of shard (1, 0)
+  uint64_t shard_indices[] = {1, 0};
+  uint64_t subset_start[] = {1, 1};
+  uint64_t subset_shape[] = {2, 2};
+  const uint16_t subset[] = {1000, 1001, 1002, 1003};
+  zarrs_assert(zarrsArrayStoreInnerChunkSubset(array, cache, 2, shard_indices, subset_start, subset_shape,
+                                               sizeof(subset), reinterpret_cast<const uint8_t *>(subset)));
+
+  // The subset is stored and the rest of the shard is preserved
+  zarrs_assert(zarrsArrayRetrieveSubsetSharded(array, cache, 2, start, shape, elements.size() * sizeof(uint16_t),
+                                               reinterpret_cast<uint8_t *>(elements.data())));
+  for (size_t i = 0; i < 8; ++i) {
+    for (size_t j = 0; j < 8; ++j) {
+      if (i >= 5 && i < 7 && j >= 1 && j < 3) {
+        assert(elements[i * 8 + j] == 1000 + (i - 5) * 2 + (j - 1));
+      } else {
+        assert(elements[i * 8 + j] == i * 8 + j);
+      }
+    }
+  }
+
+  // Errors
+  uint64_t past_end_start[] = {3, 3};
+  assert(zarrsArrayStoreInnerChunkSubset(array, cache, 2, shard_indices, past_end_start, subset_shape,
+                                         sizeof(subset), reinterpret_cast<const uint8_t *>(subset)) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayStoreInnerChunkSubset(array, cache, 2, shard_indices, subset_start, subset_shape,
+                                         sizeof(subset) - 1, reinterpret_cast<const uint8_t *>(subset)) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  uint64_t invalid_shard_indices[] = {2, 0};
+  assert(zarrsArrayStoreInnerChunkSubset(array, cache, 2, invalid_shard_indices, subset_start, subset_shape,
+                                         sizeof(subset), reinterpret_cast<const uint8_t *>(subset)) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayStoreInnerChunkSubset(array, cache, 1, shard_indices, subset_start, subset_shape,
+                                         sizeof(subset), reinterpret_cast<const uint8_t *>(subset)) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+
+  ZarrsArray read_only_array = nullptr;
+  zarrs_assert(zarrsOpenArrayR(storage, "/array", &read_only_array));
+  zarrs_assert(zarrsCreateShardIndexCache(read_only_array, &cache));
+  assert(zarrsArrayStoreInnerChunkSubset(read_only_array, cache, 2, shard_indices, subset_start, subset_shape,
+                                         sizeof(subset), reinterpret_cast<const uint8_t *>(subset)) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+  zarrs_assert(zarrsDestroyArray(read_only_array));
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
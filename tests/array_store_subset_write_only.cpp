@@ -0,0 +1,75 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata_v2 = R""""(
+{
+    "zarr_format": 2,
+    "shape": [5, 4],
+    "chunks": [2, 2],
+    "dtype": "<u2",
+    "compressor": null,
+    "fill_value": 7,
+    "order": "C",
+    "filters": null
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata_v2, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsOpenArrayV2W(storage, "/array", &array));
+
+  // A chunk-aligned subset covering two chunks
+  uint64_t subset_start[] = {0, 0};
+  uint64_t subset_shape[] = {2, 4};
+  std::vector<uint16_t> elements(2 * 4);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, subset_start, subset_shape, elements.size() * sizeof(uint16_t),
+                                     reinterpret_cast<const uint8_t *>(elements.data())));
+
+  // A chunk-aligned subset ending at the array shape within an edge chunk
+  uint64_t edge_subset_start[] = {4, 2};
+  uint64_t edge_subset_shape[] = {1, 2};
+  uint16_t edge_elements[] = {100, 101};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, edge_subset_start, edge_subset_shape, sizeof(edge_elements),
+                                     reinterpret_cast<const uint8_t *>(edge_elements)));
+
+  // Unaligned subsets require read capability
+  uint64_t unaligned_subset_start[] = {1, 0};
+  assert(zarrsArrayStoreSubset(array, 2, unaligned_subset_start, subset_shape, elements.size() * sizeof(uint16_t),
+                               reinterpret_cast<const uint8_t *>(elements.data())) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  uint64_t partial_subset_shape[] = {1, 4};
+  assert(zarrsArrayStoreSubset(array, 2, subset_start, partial_subset_shape, 4 * sizeof(uint16_t),
+                               reinterpret_cast<const uint8_t *>(elements.data())) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  assert(zarrsArrayStoreSubset(array, 2, subset_start, subset_shape, 3,
+                               reinterpret_cast<const uint8_t *>(elements.data())) ==
+         ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Read back the array
+  zarrs_assert(zarrsOpenArrayV2R(storage, "/array", &array));
+  uint64_t array_start[] = {0, 0};
+  uint64_t array_shape[] = {5, 4};
+  uint16_t array_elements[5][4];
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 2, array_start, array_shape, sizeof(array_elements),
+                                        reinterpret_cast<uint8_t *>(array_elements)));
+  for (size_t i = 0; i < 2; ++i) {
+    for (size_t j = 0; j < 4; ++j) {
+      assert(array_elements[i][j] == i * 4 + j);
+    }
+  }
+  assert(array_elements[2][0] == 7 && array_elements[3][3] == 7 && array_elements[4][1] == 7);
+  assert(array_elements[4][2] == 100 && array_elements[4][3] == 101);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,226 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata_v2 = R""""(
+{
+    "zarr_format": 2,
+    "shape": [4, 4],
+    "chunks": [2, 2],
+    "dtype": "|u1",
+    "compressor": null,
+    "fill_value": 0,
+    "order": "C",
+    "filters": null
+}
+)"""";
+
+const char *array_metadata_many_chunks = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [1100, 1],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [1, 1]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+const char *sharded_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+const char *group_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "group"
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata_v2, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+
+  // Metadata keys are not counted
+  uint64_t count = 1;
+  bool is_exact = false;
+  bool empty = false;
+  zarrs_assert(zarrsArrayGetStoredChunkCount(array, &count));
+  assert(count == 0);
+  zarrs_assert(zarrsArrayIsEmpty(array, &empty));
+  assert(empty);
+  zarrs_assert(zarrsArrayGetStoredChunkCountApproximate(array, &count, &is_exact));
+  assert(count == 0 && is_exact);
+  uint64_t total_bytes = 1;
+  zarrs_assert(zarrsArrayGetStoredChunkBytesTotal(array, &total_bytes));
+  assert(total_bytes == 0);
+
+  std::vector<uint8_t> chunk_bytes(4, 1);
+  uint64_t chunk_indices[] = {0, 1};
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, chunk_bytes.size(), chunk_bytes.data()));
+  chunk_indices[0] = 1;
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, chunk_bytes.size(), chunk_bytes.data()));
+
+  zarrs_assert(zarrsArrayGetStoredChunkCount(array, &count));
+  assert(count == 2);
+  zarrs_assert(zarrsArrayIsEmpty(array, &empty));
+  assert(!empty);
+  is_exact = false;
+  zarrs_assert(zarrsArrayGetStoredChunkCountApproximate(array, &count, &is_exact));
+  assert(count == 2 && is_exact);
+  uint64_t listed_total_bytes = 0;
+  zarrs_assert(zarrsArrayGetTotalStoredSizeBytes(array, &listed_total_bytes));
+  zarrs_assert(zarrsArrayGetStoredChunkBytesTotal(array, &total_bytes));
+  assert(total_bytes == 2 * chunk_bytes.size() && total_bytes == listed_total_bytes);
+  is_exact = false;
+  zarrs_assert(zarrsArrayGetStoredChunkBytesFast(array, &total_bytes, &is_exact));
+  assert(total_bytes == 2 * chunk_bytes.size() && is_exact);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Array handles without list capability
+  zarrs_assert(zarrsOpenArrayV2W(storage, "/array", &array));
+  assert(zarrsArrayGetStoredChunkCount(array, &count) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  assert(zarrsArrayGetStoredChunkCountApproximate(array, &count, &is_exact) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  assert(zarrsArrayIsEmpty(array, &empty) == ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  assert(zarrsArrayGetStoredChunkBytesTotal(array, &total_bytes) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  assert(zarrsArrayGetStoredChunkBytesFast(array, &total_bytes, &is_exact) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Each chunk is in its own directory, so the approximate count stops before listing every directory
+  zarrs_assert(zarrsCreateArrayRW(storage, "/many_chunks", array_metadata_many_chunks, &array));
+  std::vector<uint8_t> elements(1100, 1);
+  uint64_t subset_start[] = {0, 0};
+  uint64_t subset_shape[] = {1100, 1};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, subset_start, subset_shape, elements.size(), elements.data()));
+  zarrs_assert(zarrsArrayGetStoredChunkCount(array, &count));
+  assert(count == 1100);
+  is_exact = true;
+  zarrs_assert(zarrsArrayGetStoredChunkCountApproximate(array, &count, &is_exact));
+  assert(!is_exact && count < 1100);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Only the chunk keys of a root array are counted, not unrelated keys or child nodes
+  {
+    ZarrsStorage root_storage = nullptr;
+    zarrs_assert(zarrsCreateStorageMemory(&root_storage));
+    ZarrsArray root = nullptr;
+    zarrs_assert(zarrsCreateArrayRW(root_storage, "/", array_metadata, &root));
+    zarrs_assert(zarrsArrayStoreMetadata(root));
+    ZarrsGroup group = nullptr;
+    zarrs_assert(zarrsCreateGroupRW(root_storage, "/group", group_metadata, &group));
+    zarrs_assert(zarrsGroupStoreMetadata(group));
+    zarrs_assert(zarrsDestroyGroup(group));
+    std::vector<uint8_t> chunk_bytes(4, 1);
+    uint64_t chunk_indices[] = {1, 1};
+    ZarrsArray child = nullptr;
+    zarrs_assert(zarrsCreateArrayRW(root_storage, "/group/child", array_metadata, &child));
+    zarrs_assert(zarrsArrayStoreMetadata(child));
+    zarrs_assert(zarrsArrayStoreChunk(child, 2, chunk_indices, chunk_bytes.size(), chunk_bytes.data()));
+    zarrs_assert(zarrsDestroyArray(child));
+    // The key "other/c/1/1" is not a node, but it is not a chunk key of the root array either
+    zarrs_assert(zarrsCreateArrayRW(root_storage, "/other", array_metadata, &child));
+    zarrs_assert(zarrsArrayStoreChunk(child, 2, chunk_indices, chunk_bytes.size(), chunk_bytes.data()));
+    zarrs_assert(zarrsDestroyArray(child));
+
+    zarrs_assert(zarrsArrayGetStoredChunkCount(root, &count));
+    assert(count == 0);
+    zarrs_assert(zarrsArrayIsEmpty(root, &empty));
+    assert(empty);
+
+    chunk_indices[0] = 0;
+    zarrs_assert(zarrsArrayStoreChunk(root, 2, chunk_indices, chunk_bytes.size(), chunk_bytes.data()));
+    zarrs_assert(zarrsArrayGetStoredChunkCount(root, &count));
+    assert(count == 1);
+    zarrs_assert(zarrsArrayIsEmpty(root, &empty));
+    assert(!empty);
+    zarrs_assert(zarrsDestroyArray(root));
+    zarrs_assert(zarrsDestroyStorage(root_storage));
+  }
+
+  // Stored inner chunks of a sharded array are counted from the shard indexes
+  {
+    zarrs_assert(zarrsCreateArrayRW(storage, "/sharded", sharded_array_metadata, &array));
+    zarrs_assert(zarrsArrayStoreMetadata(array));
+    zarrs_assert(zarrsArrayGetStoredInnerChunkCount(array, &count));
+    assert(count == 0);
+
+    // Shard (0, 1) has only its inner chunk (1, 0) differing from the fill value
+    uint16_t shard[4][4] = {};
+    shard[2][0] = 1;
+    uint64_t shard_indices[] = {0, 1};
+    zarrs_assert(zarrsArrayStoreChunk(array, 2, shard_indices, sizeof(shard), reinterpret_cast<const uint8_t *>(shard)));
+    // Every inner chunk of shard (1, 1) differs from the fill value
+    for (auto &row : shard) {
+      for (auto &element : row) {
+        element = 2;
+      }
+    }
+    shard_indices[0] = 1;
+    zarrs_assert(zarrsArrayStoreChunk(array, 2, shard_indices, sizeof(shard), reinterpret_cast<const uint8_t *>(shard)));
+
+    zarrs_assert(zarrsArrayGetStoredChunkCount(array, &count));
+    assert(count == 2);
+    zarrs_assert(zarrsArrayGetStoredInnerChunkCount(array, &count));
+    assert(count == 1 + 4);
+    zarrs_assert(zarrsDestroyArray(array));
+
+    zarrs_assert(zarrsOpenArrayV2RW(storage, "/array", &array));
+    assert(zarrsArrayGetStoredInnerChunkCount(array, &count) == ZarrsResult::ZARRS_ERROR_ARRAY);
+    zarrs_assert(zarrsDestroyArray(array));
+    zarrs_assert(zarrsOpenArrayV2W(storage, "/array", &array));
+    assert(zarrsArrayGetStoredInnerChunkCount(array, &count) == ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+    zarrs_assert(zarrsDestroyArray(array));
+  }
+
+  assert(zarrsArrayGetStoredInnerChunkCount(nullptr, &count) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsArrayGetStoredChunkCountApproximate(nullptr, &count, &is_exact) ==
+         ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
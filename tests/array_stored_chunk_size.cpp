@@ -0,0 +1,111 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [64, 64],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [32, 32]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [
+        {"name": "bytes"},
+        {"name": "gzip", "configuration": {"level": 5}}
+    ]
+}
+)"""";
+
+const char *array_metadata_float64 = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [3, 5],
+    "data_type": "float64",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0.0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+const char *array_metadata_string = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4],
+    "data_type": "string",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": "",
+    "codecs": [{"name": "vlen-utf8"}]
+}
+)"""";
+
+int main() {
+  const char *tmp_path = getenv("TMP_PATH_STORED_CHUNK_SIZE");
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageFilesystem(tmp_path, &storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+
+  // Store a compressible chunk
+  uint64_t chunk_indices[] = {0, 0};
+  size_t chunk_size;
+  zarrs_assert(zarrsArrayGetChunkSize(array, 2, chunk_indices, &chunk_size));
+  std::vector<uint8_t> chunk_bytes(chunk_size, 1);
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, chunk_bytes.size(), chunk_bytes.data()));
+
+  uint64_t stored_size = 0;
+  bool exists = false;
+  zarrs_assert(zarrsArrayGetStoredChunkSize(array, 2, chunk_indices, &stored_size, &exists));
+  assert(exists);
+  assert(stored_size > 0);
+  assert(stored_size < chunk_size);
+  uint64_t encoded_size = 0;
+  zarrs_assert(zarrsArrayGetEncodedChunkSizeBytes(array, 2, chunk_indices, &encoded_size));
+  assert(encoded_size == stored_size);
+
+  // Missing chunk
+  uint64_t missing_chunk_indices[] = {1, 1};
+  zarrs_assert(zarrsArrayGetStoredChunkSize(array, 2, missing_chunk_indices, &stored_size, &exists));
+  assert(!exists);
+  assert(stored_size == 0);
+  encoded_size = 1;
+  zarrs_assert(zarrsArrayGetEncodedChunkSizeBytes(array, 2, missing_chunk_indices, &encoded_size));
+  assert(encoded_size == 0);
+  uint64_t out_of_bounds_chunk_indices[] = {2, 0};
+  assert(zarrsArrayGetEncodedChunkSizeBytes(array, 2, out_of_bounds_chunk_indices, &encoded_size) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+
+  // The decoded size of the array does not depend on the stored chunks
+  uint64_t decoded_size = 0;
+  zarrs_assert(zarrsArrayGetDecodedSizeBytes(array, &decoded_size));
+  assert(decoded_size == 64 * 64);
+  assert(zarrsArrayGetDecodedSizeBytes(nullptr, &decoded_size) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Multi-byte and variable-length data types
+  zarrs_assert(zarrsCreateArrayRW(storage, "/float64", array_metadata_float64, &array));
+  zarrs_assert(zarrsArrayGetDecodedSizeBytes(array, &decoded_size));
+  assert(decoded_size == 3 * 5 * sizeof(double));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsCreateArrayRW(storage, "/string", array_metadata_string, &array));
+  assert(zarrsArrayGetDecodedSizeBytes(array, &decoded_size) == ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,121 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *sharded_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/sharded", sharded_array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+
+  // Store shard (0, 1) with only its inner chunk (1, 0) differing from the fill value
+  uint16_t shard[4][4] = {};
+  shard[2][0] = 1;
+  shard[3][1] = 2;
+  uint64_t shard_indices[] = {0, 1};
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, shard_indices, sizeof(shard), reinterpret_cast<const uint8_t *>(shard)));
+
+  ZarrsShardIndexCache cache = nullptr;
+  zarrs_assert(zarrsCreateShardIndexCache(array, &cache));
+  size_t count = 0;
+  uint64_t *inner_chunk_indices = nullptr;
+  zarrs_assert(
+      zarrsArrayGetStoredInnerChunkIndicesForShard(array, cache, 2, shard_indices, &count, &inner_chunk_indices));
+  assert(count == 1);
+  assert(inner_chunk_indices[0] == 1 && inner_chunk_indices[1] == 2);
+  zarrs_assert(zarrsFreeBytes(inner_chunk_indices));
+
+  // A shard that is not stored
+  uint64_t empty_shard_indices[] = {1, 1};
+  zarrs_assert(zarrsArrayGetStoredInnerChunkIndicesForShard(array, cache, 2, empty_shard_indices, &count,
+                                                            &inner_chunk_indices));
+  assert(count == 0);
+  zarrs_assert(zarrsFreeBytes(inner_chunk_indices));
+
+  // Errors
+  uint64_t invalid_shard_indices[] = {2, 0};
+  assert(zarrsArrayGetStoredInnerChunkIndicesForShard(array, cache, 2, invalid_shard_indices, &count,
+                                                      &inner_chunk_indices) == ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayGetStoredInnerChunkIndicesForShard(array, cache, 1, shard_indices, &count, &inner_chunk_indices) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayGetStoredInnerChunkIndicesForShard(array, cache, 2, shard_indices, &count, nullptr) ==
+         ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Read-only arrays, where only the shard index is read and a cached shard index is not read again
+  ZarrsStorage metrics_storage = nullptr;
+  zarrs_assert(zarrsCreateStoragePerformanceMetrics(storage, &metrics_storage));
+  zarrs_assert(zarrsOpenArrayR(metrics_storage, "/sharded", &array));
+  zarrs_assert(zarrsCreateShardIndexCache(array, &cache));
+  ZarrsStoragePerformanceMetrics metrics_before, metrics_after;
+  for (int read = 0; read < 2; ++read) {
+    zarrs_assert(zarrsStorageGetPerformanceMetrics(metrics_storage, &metrics_before));
+    zarrs_assert(
+        zarrsArrayGetStoredInnerChunkIndicesForShard(array, cache, 2, shard_indices, &count, &inner_chunk_indices));
+    assert(count == 1);
+    assert(inner_chunk_indices[0] == 1 && inner_chunk_indices[1] == 2);
+    zarrs_assert(zarrsFreeBytes(inner_chunk_indices));
+    zarrs_assert(zarrsStorageGetPerformanceMetrics(metrics_storage, &metrics_after));
+    if (read == 0) {
+      // At most the shard index of 4 inner chunks (16 bytes each) and its checksum
+      uint64_t bytes_read = metrics_after.bytesRead - metrics_before.bytesRead;
+      assert(bytes_read > 0 && bytes_read <= 4 * 16 + 4);
+    } else {
+      assert(metrics_after.reads == metrics_before.reads);
+    }
+  }
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(metrics_storage));
+
+  // Arrays that are not sharded
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsCreateShardIndexCache(array, &cache));
+  assert(zarrsArrayGetStoredInnerChunkIndicesForShard(array, cache, 2, shard_indices, &count, &inner_chunk_indices) ==
+         ZarrsResult::ZARRS_ERROR_ARRAY);
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,115 @@
+#include "zarrs.h"
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [10, 9, 4],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [3, 4, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+const char *rectangular_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "rectangular",
+        "configuration": {"chunk_shape": [[1, 3, 4], 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+const char *string_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4],
+    "data_type": "string",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": "",
+    "codecs": [{"name": "vlen-utf8"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  // Chunk boundaries in a buffer holding the entire array
+  uint64_t chunk_stride[3];
+  zarrs_assert(zarrsArrayGetChunkStride(array, 3, chunk_stride));
+  assert(chunk_stride[0] == 3 * 9 * 4);
+  assert(chunk_stride[1] == 4 * 4);
+  assert(chunk_stride[2] == 2);
+  assert(zarrsArrayGetChunkStride(array, 2, chunk_stride) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  // Element strides within a subset
+  uint64_t subset_shape[] = {5, 3, 2};
+  uint64_t subset_stride[3];
+  zarrs_assert(zarrsArrayGetSubsetStride(array, 3, subset_shape, subset_stride));
+  assert(subset_stride[0] == 6);
+  assert(subset_stride[1] == 2);
+  assert(subset_stride[2] == 1);
+  assert(zarrsArrayGetSubsetStride(array, 2, subset_shape, subset_stride) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  uint64_t huge_subset_shape[] = {2, UINT64_MAX, 2};
+  assert(zarrsArrayGetSubsetStride(array, 3, huge_subset_shape, subset_stride) ==
+         ZarrsResult::ZARRS_ERROR_OVERFLOW);
+
+  // Byte offsets in a buffer holding the entire array
+  uint64_t chunk_indices[] = {1, 2, 1};
+  uint64_t byte_offset = 0;
+  zarrs_assert(zarrsArrayGetChunkByteOffsetInArray(array, 3, chunk_indices, &byte_offset));
+  assert(byte_offset == (3 * 9 * 4 + 8 * 4 + 2) * sizeof(uint16_t));
+  uint64_t element_indices[] = {9, 8, 3};
+  zarrs_assert(zarrsArrayGetSubsetByteOffsetInArray(array, 3, element_indices, &byte_offset));
+  assert(byte_offset == (10 * 9 * 4 - 1) * sizeof(uint16_t));
+  uint64_t invalid_chunk_indices[] = {4, 0, 0};
+  assert(zarrsArrayGetChunkByteOffsetInArray(array, 3, invalid_chunk_indices, &byte_offset) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  uint64_t out_of_bounds_element_indices[] = {10, 0, 0};
+  assert(zarrsArrayGetSubsetByteOffsetInArray(array, 3, out_of_bounds_element_indices,
+                                              &byte_offset) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayGetSubsetByteOffsetInArray(array, 2, element_indices, &byte_offset) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Chunk strides are undefined for irregular chunk grids
+  zarrs_assert(zarrsCreateArrayRW(storage, "/rectangular", rectangular_array_metadata, &array));
+  assert(zarrsArrayGetChunkStride(array, 2, chunk_stride) ==
+         ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE);
+  zarrs_assert(zarrsArrayGetSubsetStride(array, 2, subset_shape, subset_stride));
+  assert(subset_stride[0] == 3 && subset_stride[1] == 1);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Byte offsets are undefined for variable-length data types
+  zarrs_assert(zarrsCreateArrayRW(storage, "/string", string_array_metadata, &array));
+  uint64_t string_chunk_indices[] = {1};
+  assert(zarrsArrayGetChunkByteOffsetInArray(array, 1, string_chunk_indices, &byte_offset) ==
+         ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  assert(zarrsArrayGetChunkStride(nullptr, 2, chunk_stride) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,132 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+const char *array_metadata_sharded = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "float32",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0.0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+// Shards on the boundary of the array are partial
+const char *array_metadata_sharded_partial = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [10, 7],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "bytes"}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+
+  size_t size = 0;
+  uint64_t num_elements = 0;
+
+  // Unsharded array
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  uint64_t indices[] = {0, 0};
+  assert(zarrsArrayGetSubChunkSize(array, 2, indices, &size) == ZarrsResult::ZARRS_ERROR_ARRAY);
+  assert(zarrsArrayGetSubChunkElements(array, 2, indices, &num_elements) ==
+         ZarrsResult::ZARRS_ERROR_ARRAY);
+  uint64_t inner_grid_shape[2];
+  assert(zarrsArrayGetSubChunkGridShapeForShard(array, 2, indices, inner_grid_shape) ==
+         ZarrsResult::ZARRS_ERROR_ARRAY);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Sharded array
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array_sharded", array_metadata_sharded, &array));
+  uint64_t last_indices[] = {3, 3};
+  zarrs_assert(zarrsArrayGetSubChunkSize(array, 2, last_indices, &size));
+  assert(size == 2 * 2 * sizeof(float));
+  zarrs_assert(zarrsArrayGetSubChunkElements(array, 2, last_indices, &num_elements));
+  assert(num_elements == 2 * 2);
+
+  // Invalid indices
+  uint64_t invalid_indices[] = {4, 0};
+  assert(zarrsArrayGetSubChunkSize(array, 2, invalid_indices, &size) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayGetSubChunkElements(array, 2, invalid_indices, &num_elements) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayGetSubChunkSize(array, 1, last_indices, &size) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+
+  // The size matches the buffer expected by zarrsArrayRetrieveSubChunk
+  std::vector<float> shard(4 * 4, 1.0f);
+  uint64_t shard_indices[] = {1, 1};
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, shard_indices, shard.size() * sizeof(float),
+                                    reinterpret_cast<const uint8_t *>(shard.data())));
+  ZarrsShardIndexCache cache = nullptr;
+  zarrs_assert(zarrsCreateShardIndexCache(array, &cache));
+  zarrs_assert(zarrsArrayGetSubChunkSize(array, 2, last_indices, &size));
+  std::vector<uint8_t> subchunk(size);
+  zarrs_assert(zarrsArrayRetrieveSubChunk(array, cache, 2, last_indices, subchunk.size(), subchunk.data()));
+  assert(reinterpret_cast<float *>(subchunk.data())[0] == 1.0f);
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Inner chunk grid shape of each shard
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array_sharded_partial", array_metadata_sharded_partial, &array));
+  uint64_t interior_shard_indices[] = {0, 0};
+  zarrs_assert(zarrsArrayGetSubChunkGridShapeForShard(array, 2, interior_shard_indices, inner_grid_shape));
+  assert(inner_grid_shape[0] == 2 && inner_grid_shape[1] == 2);
+  uint64_t edge_shard_indices[] = {2, 1};
+  zarrs_assert(zarrsArrayGetSubChunkGridShapeForShard(array, 2, edge_shard_indices, inner_grid_shape));
+  assert(inner_grid_shape[0] == 1 && inner_grid_shape[1] == 2);
+  uint64_t invalid_shard_indices[] = {3, 0};
+  assert(zarrsArrayGetSubChunkGridShapeForShard(array, 2, invalid_shard_indices, inner_grid_shape) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayGetSubChunkGridShapeForShard(array, 1, interior_shard_indices, inner_grid_shape) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,171 @@
+#include "zarrs.h"
+
+#include <cstring>
+#include <string>
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 6],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "bytes"}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+        }
+    }]
+}
+)"""";
+
+void assert_last_error_contains(const char *text) {
+  char *last_error = zarrsLastError();
+  assert(std::string(last_error).find(text) != std::string::npos);
+  zarrs_assert(zarrsFreeString(last_error));
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  ZarrsShardIndexCache cache = nullptr;
+  zarrs_assert(zarrsCreateShardIndexCache(array, &cache));
+  std::vector<uint8_t> bytes(8 * 6);
+  uint64_t chunks_start[2];
+  uint64_t chunks_shape[2];
+
+  // Subsets within the array shape
+  uint64_t start[] = {0, 2};
+  uint64_t shape[] = {8, 4};
+  zarrs_assert(zarrsArrayValidateSubset(array, 2, start, shape));
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, start, shape, 8 * 4, bytes.data()));
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 2, start, shape, 8 * 4, bytes.data()));
+  zarrs_assert(zarrsArrayRetrieveSubsetSharded(array, cache, 2, start, shape, 8 * 4, bytes.data()));
+  zarrs_assert(zarrsArrayGetChunksInSubset(array, 2, start, shape, chunks_start, chunks_shape));
+
+  // A subset extending past the array shape in dimension 1
+  uint64_t past_end_start[] = {0, 4};
+  uint64_t past_end_shape[] = {2, 4};
+  assert(zarrsArrayValidateSubset(array, 2, past_end_start, past_end_shape) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert_last_error_contains("dimension 1");
+  assert_last_error_contains("extent 6");
+  assert(zarrsArrayStoreSubset(array, 2, past_end_start, past_end_shape, 2 * 4, bytes.data()) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayRetrieveSubset(array, 2, past_end_start, past_end_shape, 2 * 4, bytes.data()) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayRetrieveSubsetSharded(array, cache, 2, past_end_start, past_end_shape, 2 * 4,
+                                         bytes.data()) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayGetChunksInSubset(array, 2, past_end_start, past_end_shape, chunks_start,
+                                     chunks_shape) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+
+  // The end of the subset overflows a uint64_t in dimension 0
+  uint64_t overflow_start[] = {UINT64_MAX - 1, 0};
+  uint64_t overflow_shape[] = {4, 1};
+  assert(zarrsArrayValidateSubset(array, 2, overflow_start, overflow_shape) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert_last_error_contains("dimension 0");
+  assert(zarrsArrayStoreSubset(array, 2, overflow_start, overflow_shape, 4, bytes.data()) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayRetrieveSubset(array, 2, overflow_start, overflow_shape, 4, bytes.data()) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayRetrieveSubsetSharded(array, cache, 2, overflow_start, overflow_shape, 4,
+                                         bytes.data()) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayGetChunksInSubset(array, 2, overflow_start, overflow_shape, chunks_start,
+                                     chunks_shape) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  size_t chunk_count = 0;
+  uint64_t *chunk_indices = nullptr;
+  assert(zarrsArrayGetSubsetChunkIndices(array, 2, overflow_start, overflow_shape, &chunk_count,
+                                         &chunk_indices) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayGetSubsetChunkCount(array, 2, overflow_start, overflow_shape, &chunk_count) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  uint64_t chunk_zero[] = {0, 0};
+  uint64_t intersect_start[2];
+  uint64_t intersect_shape[2];
+  assert(zarrsArrayGetChunkIntersectWithSubset(array, 2, chunk_zero, overflow_start, overflow_shape,
+                                               intersect_start, intersect_shape) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayRetrieveSubsetAs(array, ZarrsDataType::ZARRS_UINT8, 2, overflow_start,
+                                    overflow_shape, 4, bytes.data()) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  int64_t strides[] = {1, 1};
+  assert(zarrsArrayRetrieveSubsetStrided(array, 2, overflow_start, overflow_shape, strides,
+                                         bytes.data(), bytes.size()) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  assert(zarrsArrayStoreSubsetStrided(array, 2, overflow_start, overflow_shape, strides,
+                                      bytes.data(), bytes.size()) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  auto callback = [](size_t, const uint64_t *, const uint64_t *, const uint8_t *, size_t,
+                     void *) -> int32_t { return 0; };
+  assert(zarrsArrayRetrieveSubsetCallback(array, 2, overflow_start, overflow_shape, callback,
+                                          nullptr, false) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+  char *json = nullptr;
+  assert(zarrsArrayGetSubsetAsJSON(array, 2, overflow_start, overflow_shape, &json) ==
+         ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS);
+
+  // The end of a chunk range overflows a uint64_t in dimension 0
+  uint64_t chunks_overflow_start[] = {UINT64_MAX - 1, 0};
+  uint64_t chunks_overflow_shape[] = {4, 1};
+  uint64_t array_start[2];
+  uint64_t array_shape[2];
+  assert(zarrsArrayGetChunksSubset(array, 2, chunks_overflow_start, chunks_overflow_shape,
+                                   array_start, array_shape) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert_last_error_contains("out of bounds of a chunk grid");
+  assert(zarrsArrayRetrieveChunks(array, 2, chunks_overflow_start, chunks_overflow_shape, 16,
+                                  bytes.data()) == ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayStoreChunks(array, 2, chunks_overflow_start, chunks_overflow_shape, 16,
+                               bytes.data()) == ZarrsResult::ZARRS_ERROR_INVALID_INDICES);
+  assert(zarrsArrayRetrieveChunks(array, 1, chunks_overflow_start, chunks_overflow_shape, 16,
+                                  bytes.data()) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  // Clip subsets to the array bounds
+  uint64_t clipped_start[2];
+  uint64_t clipped_shape[2];
+  bool is_empty = true;
+  zarrs_assert(zarrsArrayGetSubsetIntersection(array, 2, past_end_start, past_end_shape,
+                                               clipped_start, clipped_shape));
+  assert(clipped_start[0] == 0 && clipped_start[1] == 4);
+  assert(clipped_shape[0] == 2 && clipped_shape[1] == 2);
+  zarrs_assert(zarrsArrayValidateSubset(array, 2, clipped_start, clipped_shape));
+  zarrs_assert(zarrsArrayIsSubsetEmpty(array, 2, past_end_start, past_end_shape, &is_empty));
+  assert(!is_empty);
+  zarrs_assert(zarrsArrayGetSubsetIntersection(array, 2, overflow_start, overflow_shape,
+                                               clipped_start, clipped_shape));
+  assert(clipped_start[0] == 8 && clipped_start[1] == 0);
+  assert(clipped_shape[0] == 0 && clipped_shape[1] == 0);
+  zarrs_assert(zarrsArrayIsSubsetEmpty(array, 2, overflow_start, overflow_shape, &is_empty));
+  assert(is_empty);
+  uint64_t zero_shape[] = {3, 0};
+  zarrs_assert(zarrsArrayIsSubsetEmpty(array, 2, start, zero_shape, &is_empty));
+  assert(is_empty);
+  assert(zarrsArrayGetSubsetIntersection(array, 3, start, shape, clipped_start, clipped_shape) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  assert(zarrsArrayIsSubsetEmpty(array, 1, start, shape, &is_empty) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  assert(zarrsArrayValidateSubset(array, 1, start, shape) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  assert(zarrsArrayValidateSubset(nullptr, 2, start, shape) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
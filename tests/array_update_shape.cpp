@@ -0,0 +1,73 @@
+#include "zarrs.h"
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  uint64_t shape[2];
+
+  // Unchecked
+  uint64_t new_shape[] = {6, 5};
+  zarrs_assert(zarrsArrayUpdateShape(array, 2, new_shape));
+  zarrs_assert(zarrsArrayGetShape(array, 2, shape));
+  assert(shape[0] == 6 && shape[1] == 5);
+  uint64_t chunk_grid_shape[2];
+  zarrs_assert(zarrsArrayGetChunkGridShape(array, 2, chunk_grid_shape));
+  assert(chunk_grid_shape[0] == 3 && chunk_grid_shape[1] == 3);
+  uint64_t smaller_shape[] = {1, 1};
+  zarrs_assert(zarrsArrayUpdateShape(array, 2, smaller_shape));
+  zarrs_assert(zarrsArrayGetShape(array, 2, shape));
+  assert(shape[0] == 1 && shape[1] == 1);
+
+  // Checked
+  uint64_t shape_4x4[] = {4, 4};
+  zarrs_assert(zarrsArrayUpdateShapeChecked(array, 2, shape_4x4));
+  uint64_t shape_3x8[] = {3, 8};
+  zarrs_assert(zarrsArrayUpdateShapeChecked(array, 2, shape_3x8));
+  zarrs_assert(zarrsArrayGetShape(array, 2, shape));
+  assert(shape[0] == 3 && shape[1] == 8);
+  uint64_t shape_2x8[] = {2, 8};
+  assert(zarrsArrayUpdateShapeChecked(array, 2, shape_2x8) == ZarrsResult::ZARRS_ERROR_ARRAY);
+  zarrs_assert(zarrsArrayGetShape(array, 2, shape));
+  assert(shape[0] == 3 && shape[1] == 8);
+
+  // The dimensionality cannot change
+  uint64_t shape_3d[] = {4, 4, 4};
+  assert(zarrsArrayUpdateShape(array, 3, shape_3d) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+  assert(zarrsArrayUpdateShapeChecked(array, 3, shape_3d) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  // The shape is only persisted when the metadata is stored
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array", &array));
+  zarrs_assert(zarrsArrayGetShape(array, 2, shape));
+  assert(shape[0] == 3 && shape[1] == 8);
+  zarrs_assert(zarrsArrayUpdateShape(array, 2, shape_4x4));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array", &array));
+  zarrs_assert(zarrsArrayGetShape(array, 2, shape));
+  assert(shape[0] == 3 && shape[1] == 8);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  assert(zarrsArrayUpdateShape(nullptr, 2, shape_4x4) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,57 @@
+#include "zarrs.h"
+
+#include <vector>
+
+int main() {
+  const char *tmp_path = getenv("TMP_PATH_ARRAY_V2");
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageFilesystem(tmp_path, &storage));
+  uint32_t version = 0;
+  uint64_t indices[] = {0, 0};
+  uint64_t subset_start[] = {0, 0};
+  uint64_t subset_shape[] = {4, 4};
+  std::vector<uint16_t> elements(16);
+  uint8_t *bytes = reinterpret_cast<uint8_t *>(elements.data());
+
+  // Read the fixture
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsOpenArrayV2R(storage, "/array", &array));
+  zarrs_assert(zarrsArrayGetZarrVersion(array, &version));
+  assert(version == 2);
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 2, subset_start, subset_shape, 16 * sizeof(uint16_t), bytes));
+  for (uint16_t i = 0; i < 16; ++i) {
+    // The chunk at [1, 1] is missing, so it is populated with the fill value
+    bool missing = i / 4 >= 2 && i % 4 >= 2;
+    assert(elements[i] == (missing ? 0 : i));
+  }
+  assert(zarrsArrayStoreSubset(array, 2, subset_start, subset_shape, 16 * sizeof(uint16_t), bytes) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Write a chunk with a write-only handle
+  std::vector<uint16_t> chunk_elements = {100, 101, 102, 103};
+  uint64_t missing_chunk_indices[] = {1, 1};
+  zarrs_assert(zarrsOpenArrayV2W(storage, "/array", &array));
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, missing_chunk_indices, 4 * sizeof(uint16_t),
+                                    reinterpret_cast<uint8_t *>(chunk_elements.data())));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Round trip with a read/write handle
+  zarrs_assert(zarrsOpenArrayV2RW(storage, "/array", &array));
+  zarrs_assert(zarrsArrayGetZarrFormat(array, &version));
+  assert(version == 2);
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, missing_chunk_indices, 4 * sizeof(uint16_t), bytes));
+  assert(elements[0] == 100 && elements[1] == 101 && elements[2] == 102 && elements[3] == 103);
+  chunk_elements = {200, 201, 202, 203};
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, indices, 4 * sizeof(uint16_t),
+                                    reinterpret_cast<uint8_t *>(chunk_elements.data())));
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, indices, 4 * sizeof(uint16_t), bytes));
+  assert(elements[0] == 200 && elements[1] == 201 && elements[2] == 202 && elements[3] == 203);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Invalid metadata
+  assert(zarrsOpenArrayV2RW(storage, "/missing", &array) == ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  assert(zarrsOpenArrayV2RW(storage, "/invalid", &array) == ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
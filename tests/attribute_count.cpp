@@ -0,0 +1,84 @@
+#include "zarrs.h"
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+const char *group_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "group",
+    "attributes": {
+        "multiscales": [{"axes": [{"name": "y"}, {"name": "x"}]}],
+        "units": {"spatial": "micrometer"}
+    }
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  size_t count = 1;
+  bool exists = true;
+
+  // Empty attributes
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayGetAttributeCount(array, &count));
+  assert(count == 0);
+  zarrs_assert(zarrsArrayAttributeExists(array, "units", &exists));
+  assert(!exists);
+
+  // Single attribute
+  zarrs_assert(zarrsArraySetAttribute(array, "units", "\"m\""));
+  zarrs_assert(zarrsArrayGetAttributeCount(array, &count));
+  assert(count == 1);
+  zarrs_assert(zarrsArrayAttributeExists(array, "units", &exists));
+  assert(exists);
+
+  // Multiple nested attributes, only top-level keys are counted
+  zarrs_assert(zarrsArraySetAttributes(array, R"({"a": {"b": {"c": 1}}, "d": [1, 2], "e": null})"));
+  zarrs_assert(zarrsArrayGetAttributeCount(array, &count));
+  assert(count == 3);
+  zarrs_assert(zarrsArrayAttributeExists(array, "e", &exists));
+  assert(exists);
+  zarrs_assert(zarrsArrayAttributeExists(array, "b", &exists));
+  assert(!exists);
+  zarrs_assert(zarrsArrayAttributeExists(array, "units", &exists));
+  assert(!exists);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Groups
+  ZarrsGroup group = nullptr;
+  zarrs_assert(zarrsCreateGroupRW(storage, "/group", group_metadata, &group));
+  zarrs_assert(zarrsGroupGetAttributeCount(group, &count));
+  assert(count == 2);
+  zarrs_assert(zarrsGroupAttributeExists(group, "multiscales", &exists));
+  assert(exists);
+  zarrs_assert(zarrsGroupAttributeExists(group, "spatial", &exists));
+  assert(!exists);
+  zarrs_assert(zarrsGroupSetAttributes(group, "{}"));
+  zarrs_assert(zarrsGroupGetAttributeCount(group, &count));
+  assert(count == 0);
+  zarrs_assert(zarrsDestroyGroup(group));
+
+  // Null handles
+  assert(zarrsArrayGetAttributeCount(nullptr, &count) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsArrayAttributeExists(nullptr, "units", &exists) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsGroupGetAttributeCount(nullptr, &count) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsGroupAttributeExists(nullptr, "units", &exists) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,164 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+const char *array_metadata_sharded = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "bytes"}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+int main() {
+  // Null handles
+  size_t max_concurrency = 0;
+  bool store_empty_chunks = true;
+  assert(zarrsCodecOptionsGetMaxConcurrency(nullptr, &max_concurrency) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsCodecOptionsSetMaxConcurrency(nullptr, 1) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsCodecOptionsSetConcurrencyLimit(nullptr, 1) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsCodecOptionsGetStoreEmptyChunks(nullptr, &store_empty_chunks) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsCodecOptionsSetStoreEmptyChunks(nullptr, true) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  // Accessors
+  ZarrsCodecOptions codec_options = nullptr;
+  zarrs_assert(zarrsCreateCodecOptions(&codec_options));
+  zarrs_assert(zarrsCodecOptionsGetStoreEmptyChunks(codec_options, &store_empty_chunks));
+  assert(!store_empty_chunks);
+  zarrs_assert(zarrsCodecOptionsSetMaxConcurrency(codec_options, 3));
+  zarrs_assert(zarrsCodecOptionsGetMaxConcurrency(codec_options, &max_concurrency));
+  assert(max_concurrency == 3);
+  zarrs_assert(zarrsCodecOptionsSetConcurrencyLimit(codec_options, 1));
+  zarrs_assert(zarrsCodecOptionsGetMaxConcurrency(codec_options, &max_concurrency));
+  assert(max_concurrency == 1);
+  zarrs_assert(zarrsCodecOptionsSetStoreEmptyChunks(codec_options, true));
+  zarrs_assert(zarrsCodecOptionsGetStoreEmptyChunks(codec_options, &store_empty_chunks));
+  assert(store_empty_chunks);
+
+  // Storing a chunk of fill values only writes the chunk if empty chunks are stored
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  std::vector<uint8_t> chunk_bytes(4, 0);
+  uint64_t subset_start[] = {0, 0};
+  uint64_t subset_shape[] = {2, 2};
+  uint64_t chunk_indices[] = {0, 0};
+  bool exists = true;
+  zarrs_assert(zarrsArrayStoreSubsetOpt(array, 2, subset_start, subset_shape, chunk_bytes.size(),
+                                        chunk_bytes.data(), nullptr));
+  zarrs_assert(zarrsArrayChunkExists(array, 2, chunk_indices, &exists));
+  assert(!exists);
+  zarrs_assert(zarrsArrayStoreSubsetOpt(array, 2, subset_start, subset_shape, chunk_bytes.size(),
+                                        chunk_bytes.data(), codec_options));
+  zarrs_assert(zarrsArrayChunkExists(array, 2, chunk_indices, &exists));
+  assert(exists);
+
+  // Recommended concurrency is bounded by the number of chunks in the subset
+  size_t chunk_concurrency = 0;
+  size_t codec_concurrency = 0;
+  zarrs_assert(zarrsArrayGetRecommendedConcurrency(array, 2, subset_shape, &chunk_concurrency,
+                                                   &codec_concurrency));
+  assert(chunk_concurrency == 1);
+  assert(codec_concurrency >= 1);
+  uint64_t array_shape[] = {4, 4};
+  zarrs_assert(zarrsArrayGetRecommendedConcurrency(array, 2, array_shape, &chunk_concurrency,
+                                                   &codec_concurrency));
+  assert(chunk_concurrency >= 1 && chunk_concurrency <= 4);
+  assert(zarrsArrayGetRecommendedConcurrency(array, 1, array_shape, &chunk_concurrency,
+                                             &codec_concurrency) ==
+         ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY);
+
+  // Single-threaded reads match the default codec options
+  zarrs_assert(zarrsCodecOptionsSetConcurrencyLimit(codec_options, 1));
+  std::vector<uint8_t> array_bytes(16);
+  for (size_t i = 0; i < array_bytes.size(); ++i) {
+    array_bytes[i] = i;
+  }
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, subset_start, array_shape, array_bytes.size(),
+                                     array_bytes.data()));
+  uint64_t last_chunk_indices[] = {1, 1};
+  std::vector<uint8_t> chunk_default(4), chunk_single(4);
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, last_chunk_indices, chunk_default.size(),
+                                       chunk_default.data()));
+  zarrs_assert(zarrsArrayRetrieveChunkOpt(array, 2, last_chunk_indices, chunk_single.size(),
+                                          chunk_single.data(), codec_options));
+  assert(chunk_single == chunk_default);
+  assert(chunk_single == std::vector<uint8_t>({10, 11, 14, 15}));
+  zarrs_assert(zarrsArrayRetrieveChunkOpt(array, 2, last_chunk_indices, chunk_single.size(),
+                                          chunk_single.data(), nullptr));
+  assert(chunk_single == chunk_default);
+  uint64_t offset_subset_start[] = {1, 1};
+  uint64_t offset_subset_shape[] = {3, 3};
+  std::vector<uint8_t> subset_default(9), subset_single(9);
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 2, offset_subset_start, offset_subset_shape,
+                                        subset_default.size(), subset_default.data()));
+  zarrs_assert(zarrsArrayRetrieveSubsetOpt(array, 2, offset_subset_start, offset_subset_shape,
+                                           subset_single.size(), subset_single.data(),
+                                           codec_options));
+  assert(subset_single == subset_default);
+  assert(subset_single == std::vector<uint8_t>({5, 6, 7, 9, 10, 11, 13, 14, 15}));
+
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Sharded reads with single-threaded codec options
+  zarrs_assert(zarrsCodecOptionsSetConcurrencyLimit(codec_options, 1));
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array_sharded", array_metadata_sharded, &array));
+  std::vector<uint8_t> shard_bytes(16);
+  for (size_t i = 0; i < shard_bytes.size(); ++i) {
+    shard_bytes[i] = i;
+  }
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, shard_bytes.size(), shard_bytes.data()));
+  ZarrsShardIndexCache cache = nullptr;
+  zarrs_assert(zarrsCreateShardIndexCache(array, &cache));
+  uint64_t subchunk_indices[] = {1, 1};
+  std::vector<uint8_t> subchunk_bytes(4);
+  zarrs_assert(zarrsArrayRetrieveSubChunkOpt(array, cache, 2, subchunk_indices, subchunk_bytes.size(),
+                                             subchunk_bytes.data(), codec_options));
+  assert(subchunk_bytes == std::vector<uint8_t>({10, 11, 14, 15}));
+  zarrs_assert(zarrsArrayRetrieveSubChunkOpt(array, cache, 2, subchunk_indices, subchunk_bytes.size(),
+                                             subchunk_bytes.data(), nullptr));
+  assert(subchunk_bytes == std::vector<uint8_t>({10, 11, 14, 15}));
+  uint64_t sharded_subset_start[] = {1, 1};
+  std::vector<uint8_t> subset_bytes(4);
+  zarrs_assert(zarrsArrayRetrieveSubsetShardedOpt(array, cache, 2, sharded_subset_start, subset_shape,
+                                                  subset_bytes.size(), subset_bytes.data(),
+                                                  codec_options));
+  assert(subset_bytes == std::vector<uint8_t>({5, 6, 9, 10}));
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+  zarrs_assert(zarrsDestroyCodecOptions(codec_options));
+}
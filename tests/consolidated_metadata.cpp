@@ -0,0 +1,54 @@
+#include "zarrs.h"
+
+#include <string>
+
+const char *group_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "group",
+    "attributes": {}
+}
+)"""";
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+
+  ZarrsGroup group = nullptr;
+  zarrs_assert(zarrsCreateGroupRW(storage, "/", group_metadata, &group));
+  zarrs_assert(zarrsGroupStoreMetadata(group));
+  zarrs_assert(zarrsDestroyGroup(group));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  char *metadata_json = nullptr;
+  assert(zarrsArrayGetConsolidatedMetadataString(storage, "/", &metadata_json) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+
+  zarrs_assert(zarrsArrayConsolidateMetadata(storage, "/"));
+  zarrs_assert(zarrsArrayGetConsolidatedMetadataString(storage, "/", &metadata_json));
+  std::string metadata(metadata_json);
+  assert(metadata.find("\"array\"") != std::string::npos);
+  assert(metadata.find("\"uint8\"") != std::string::npos);
+  zarrs_assert(zarrsFreeString(metadata_json));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,77 @@
+#include "zarrs.h"
+
+#include <cstdio>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4],
+    "data_type": "%s",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": %s,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+struct Properties {
+  bool is_float;
+  bool is_complex;
+  bool is_integer;
+  bool is_signed;
+};
+
+Properties array_properties(ZarrsStorage storage, const char *data_type, const char *fill_value) {
+  char metadata[1024];
+  snprintf(metadata, sizeof(metadata), array_metadata, data_type, fill_value);
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", metadata, &array));
+  Properties properties;
+  zarrs_assert(zarrsArrayGetDataTypeIsFloat(array, &properties.is_float));
+  zarrs_assert(zarrsArrayGetDataTypeIsComplex(array, &properties.is_complex));
+  zarrs_assert(zarrsArrayGetDataTypeIsInteger(array, &properties.is_integer));
+  zarrs_assert(zarrsArrayGetDataTypeIsSigned(array, &properties.is_signed));
+  zarrs_assert(zarrsDestroyArray(array));
+  return properties;
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+
+  Properties properties = array_properties(storage, "int16", "0");
+  assert(!properties.is_float && !properties.is_complex && properties.is_integer && properties.is_signed);
+  properties = array_properties(storage, "uint32", "0");
+  assert(!properties.is_float && !properties.is_complex && properties.is_integer && !properties.is_signed);
+  properties = array_properties(storage, "float16", "0.0");
+  assert(properties.is_float && !properties.is_complex && !properties.is_integer && properties.is_signed);
+  properties = array_properties(storage, "complex64", "[0.0, 0.0]");
+  assert(!properties.is_float && properties.is_complex && !properties.is_integer && properties.is_signed);
+  properties = array_properties(storage, "bool", "false");
+  assert(!properties.is_float && !properties.is_complex && !properties.is_integer && !properties.is_signed);
+
+  bool is_float, is_complex, is_integer, is_signed;
+  size_t fixed_size;
+  zarrs_assert(zarrsDataTypeGetProperties(ZarrsDataType::ZARRS_BFLOAT16, &is_float, &is_complex,
+                                          &is_integer, &is_signed, &fixed_size));
+  assert(is_float && !is_complex && !is_integer && is_signed && fixed_size == 2);
+  zarrs_assert(zarrsDataTypeGetProperties(ZarrsDataType::ZARRS_UINT64, &is_float, &is_complex,
+                                          &is_integer, &is_signed, &fixed_size));
+  assert(!is_float && !is_complex && is_integer && !is_signed && fixed_size == 8);
+  zarrs_assert(zarrsDataTypeGetProperties(ZarrsDataType::ZARRS_COMPLEX128, &is_float, &is_complex,
+                                          &is_integer, &is_signed, &fixed_size));
+  assert(!is_float && is_complex && !is_integer && is_signed && fixed_size == 16);
+  zarrs_assert(zarrsDataTypeGetProperties(ZarrsDataType::ZARRS_RAW_BITS, &is_float, &is_complex,
+                                          &is_integer, &is_signed, &fixed_size));
+  assert(fixed_size == 0);
+  assert(zarrsDataTypeGetProperties(ZarrsDataType::ZARRS_UNDEFINED, &is_float, &is_complex,
+                                    &is_integer, &is_signed, &fixed_size) ==
+         ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE);
+
+  assert(zarrsArrayGetDataTypeIsFloat(nullptr, &is_float) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
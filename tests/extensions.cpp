@@ -0,0 +1,83 @@
+#include "zarrs.h"
+
+#include <cstring>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}],
+    "provenance": {"tool": "writer", "must_understand": false}
+}
+)"""";
+
+const char *group_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "group",
+    "attributes": {"units": "m"},
+    "provenance": {"tool": "writer", "must_understand": false}
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  bool has = false;
+  char *json = nullptr;
+
+  // Arrays
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayHasExtension(array, "provenance", &has));
+  assert(has);
+  zarrs_assert(zarrsArrayHasExtension(array, "shape", &has));
+  assert(!has);
+  zarrs_assert(zarrsArrayHasExtension(array, "missing", &has));
+  assert(!has);
+
+  zarrs_assert(zarrsArrayGetExtensionString(array, "provenance", false, &json));
+  assert(strstr(json, "\"tool\":\"writer\"") != nullptr);
+  assert(strstr(json, "\"must_understand\":false") != nullptr);
+  zarrs_assert(zarrsFreeString(json));
+  zarrs_assert(zarrsArrayGetExtensionString(array, "provenance", true, &json));
+  assert(strchr(json, '\n') != nullptr);
+  zarrs_assert(zarrsFreeString(json));
+
+  assert(zarrsArrayGetExtensionString(array, "missing", false, &json) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  char *error = zarrsLastError();
+  assert(strstr(error, "missing") != nullptr);
+  zarrs_assert(zarrsFreeString(error));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Groups
+  ZarrsGroup group = nullptr;
+  zarrs_assert(zarrsCreateGroupRW(storage, "/group", group_metadata, &group));
+  zarrs_assert(zarrsGroupHasExtension(group, "provenance", &has));
+  assert(has);
+  zarrs_assert(zarrsGroupHasExtension(group, "attributes", &has));
+  assert(!has);
+  zarrs_assert(zarrsGroupHasExtension(group, "missing", &has));
+  assert(!has);
+
+  zarrs_assert(zarrsGroupGetExtensionString(group, "provenance", false, &json));
+  assert(strstr(json, "\"tool\":\"writer\"") != nullptr);
+  zarrs_assert(zarrsFreeString(json));
+
+  assert(zarrsGroupGetExtensionString(group, "node_type", false, &json) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  assert(zarrsGroupGetExtensionString(group, "missing", false, &json) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  zarrs_assert(zarrsDestroyGroup(group));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
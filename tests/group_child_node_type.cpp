@@ -0,0 +1,73 @@
+#include "zarrs.h"
+
+const char *group_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "group",
+    "attributes": {}
+}
+)"""";
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+void store_array(ZarrsStorage storage, const char *path) {
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, path, array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsDestroyArray(array));
+}
+
+void store_group(ZarrsStorage storage, const char *path) {
+  ZarrsGroup group = nullptr;
+  zarrs_assert(zarrsCreateGroupRW(storage, path, group_metadata, &group));
+  zarrs_assert(zarrsGroupStoreMetadata(group));
+  zarrs_assert(zarrsDestroyGroup(group));
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  store_group(storage, "/");
+  store_array(storage, "/array");
+  store_group(storage, "/group");
+  store_array(storage, "/group/array");
+
+  ZarrsGroup root = nullptr;
+  zarrs_assert(zarrsOpenGroupRW(storage, "/", &root));
+  ZarrsNodeType node_type = ZarrsNodeType::ZARRS_NODE_TYPE_UNKNOWN;
+  zarrs_assert(zarrsGroupChildNodeType(root, "array", &node_type));
+  assert(node_type == ZarrsNodeType::ZARRS_NODE_TYPE_ARRAY);
+  zarrs_assert(zarrsGroupChildNodeType(root, "group", &node_type));
+  assert(node_type == ZarrsNodeType::ZARRS_NODE_TYPE_GROUP);
+  zarrs_assert(zarrsGroupChildNodeType(root, "group/array", &node_type));
+  assert(node_type == ZarrsNodeType::ZARRS_NODE_TYPE_ARRAY);
+  zarrs_assert(zarrsGroupChildNodeType(root, "missing", &node_type));
+  assert(node_type == ZarrsNodeType::ZARRS_NODE_TYPE_UNKNOWN);
+  assert(zarrsGroupChildNodeType(root, "", &node_type) == ZarrsResult::ZARRS_ERROR_NODE_PATH);
+  zarrs_assert(zarrsDestroyGroup(root));
+
+  // Paths are relative to the group
+  ZarrsGroup group = nullptr;
+  zarrs_assert(zarrsOpenGroupRW(storage, "/group", &group));
+  zarrs_assert(zarrsGroupChildNodeType(group, "array", &node_type));
+  assert(node_type == ZarrsNodeType::ZARRS_NODE_TYPE_ARRAY);
+  zarrs_assert(zarrsGroupChildNodeType(group, "group", &node_type));
+  assert(node_type == ZarrsNodeType::ZARRS_NODE_TYPE_UNKNOWN);
+  zarrs_assert(zarrsDestroyGroup(group));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,75 @@
+#include "zarrs.h"
+
+const char *group_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "group",
+    "attributes": {}
+}
+)"""";
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+void assert_chunk(ZarrsStorage storage, const char *path) {
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsOpenArrayRW(storage, path, &array));
+  uint64_t chunk_indices[] = {1, 0};
+  uint8_t chunk_elements[4] = {0};
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, chunk_indices, sizeof(chunk_elements),
+                                       chunk_elements));
+  assert(chunk_elements[0] == 1 && chunk_elements[3] == 4);
+  zarrs_assert(zarrsDestroyArray(array));
+}
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+
+  // Build a hierarchy and store the metadata of all nodes at once
+  ZarrsGroup root = nullptr;
+  zarrs_assert(zarrsCreateGroupRW(storage, "/", group_metadata, &root));
+  zarrs_assert(zarrsGroupStoreMetadata(root));
+  ZarrsGroup group = nullptr;
+  zarrs_assert(zarrsCreateGroupRW(storage, "/group", group_metadata, &group));
+  zarrs_assert(zarrsGroupStoreMetadata(group));
+  zarrs_assert(zarrsDestroyGroup(group));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/group/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  uint64_t chunk_indices[] = {1, 0};
+  uint8_t chunk_elements[] = {1, 2, 3, 4};
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, sizeof(chunk_elements),
+                                    chunk_elements));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsGroupStoreMetadataAll(root));
+
+  // Copy to another storage
+  ZarrsStorage target_storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&target_storage));
+  zarrs_assert(zarrsGroupCopyTo(root, target_storage, "/copy"));
+  assert_chunk(target_storage, "/copy/group/array");
+  zarrs_assert(zarrsDestroyStorage(target_storage));
+
+  // Copy within the source hierarchy
+  zarrs_assert(zarrsGroupCopyTo(root, storage, "/copy"));
+  assert_chunk(storage, "/copy/group/array");
+  ZarrsGroup copy = nullptr;
+  assert(zarrsOpenGroupRW(storage, "/copy/copy", &copy) == ZarrsResult::ZARRS_ERROR_GROUP);
+
+  zarrs_assert(zarrsDestroyGroup(root));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
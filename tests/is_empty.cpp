@@ -0,0 +1,62 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+const char *group_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "group"
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  bool empty = false;
+
+  ZarrsGroup group = nullptr;
+  zarrs_assert(zarrsCreateGroupRW(storage, "/group", group_metadata, &group));
+  zarrs_assert(zarrsGroupStoreMetadata(group));
+  zarrs_assert(zarrsGroupIsEmpty(group, &empty));
+  assert(empty);
+
+  // An array with no chunks is empty
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/group/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsArrayIsEmpty(array, &empty));
+  assert(empty);
+
+  // The group is no longer empty
+  zarrs_assert(zarrsGroupIsEmpty(group, &empty));
+  assert(!empty);
+
+  // An array with any chunk is not empty
+  std::vector<uint8_t> chunk_bytes(4, 1);
+  uint64_t chunk_indices[] = {1, 1};
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, chunk_bytes.size(), chunk_bytes.data()));
+  zarrs_assert(zarrsArrayIsEmpty(array, &empty));
+  assert(!empty);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  assert(zarrsArrayIsEmpty(nullptr, &empty) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsGroupIsEmpty(nullptr, &empty) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  zarrs_assert(zarrsDestroyGroup(group));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
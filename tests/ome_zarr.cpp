@@ -0,0 +1,135 @@
+#include "zarrs.h"
+
+#include <cstring>
+#include <string>
+
+const char *group_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "group",
+    "attributes": {
+        "ome": {
+            "version": "0.5",
+            "multiscales": [{
+                "axes": [{"name": "y", "type": "space"}, {"name": "x", "type": "space"}],
+                "datasets": [{"path": "0", "coordinateTransformations": [{"type": "scale", "scale": [1.0, 1.0]}]}]
+            }]
+        }
+    }
+}
+)"""";
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}],
+    "attributes": {
+        "multiscales": [{
+            "version": "0.4",
+            "axes": [{"name": "y", "type": "space"}, {"name": "x", "type": "space"}],
+            "datasets": [{"path": "0"}]
+        }]
+    }
+}
+)"""";
+
+const char *plain_array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  char *json = nullptr;
+
+  // OME-Zarr 0.5 group
+  ZarrsGroup group = nullptr;
+  zarrs_assert(zarrsCreateGroupRW(storage, "/", group_metadata, &group));
+  zarrs_assert(zarrsGroupStoreMetadata(group));
+  zarrs_assert(zarrsGroupGetOMEZarrMultiscalesString(group, false, &json));
+  assert(std::string(json).find("\"datasets\"") != std::string::npos);
+  zarrs_assert(zarrsFreeString(json));
+  zarrs_assert(zarrsDestroyGroup(group));
+
+  // OME-Zarr 0.4 array
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/0", array_metadata, &array));
+  zarrs_assert(zarrsArrayGetOMEZarrVersion(array, &json));
+  assert(strcmp(json, "0.4") == 0);
+  zarrs_assert(zarrsFreeString(json));
+  zarrs_assert(zarrsArrayGetOMEZarrAxesString(array, false, &json));
+  assert(strcmp(json, "[{\"name\":\"y\",\"type\":\"space\"},{\"name\":\"x\",\"type\":\"space\"}]") == 0);
+  zarrs_assert(zarrsFreeString(json));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // No OME-Zarr metadata
+  zarrs_assert(zarrsCreateArrayRW(storage, "/plain", plain_array_metadata, &array));
+  assert(zarrsArrayGetOMEZarrVersion(array, &json) == ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  assert(zarrsArrayGetOMEZarrAxesString(array, false, &json) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+
+  // Set multiscales
+  const char *multiscales = R""""(
+  [{
+      "axes": [{"name": "y", "type": "space"}, {"name": "x", "type": "space"}],
+      "datasets": [{"path": "0", "coordinateTransformations": [{"type": "scale", "scale": [0.5, 0.5]}]}]
+  }]
+  )"""";
+  zarrs_assert(zarrsArraySetOMEZarrMetadata(array, multiscales));
+  zarrs_assert(zarrsArrayGetOMEZarrAxesString(array, false, &json));
+  assert(strcmp(json, "[{\"name\":\"y\",\"type\":\"space\"},{\"name\":\"x\",\"type\":\"space\"}]") == 0);
+  zarrs_assert(zarrsFreeString(json));
+
+  // Axes do not match the array dimensionality
+  const char *multiscales_3d = R""""(
+  [{
+      "axes": [{"name": "z"}, {"name": "y"}, {"name": "x"}],
+      "datasets": [{"path": "0", "coordinateTransformations": [{"type": "scale", "scale": [1, 1, 1]}]}]
+  }]
+  )"""";
+  assert(zarrsArraySetOMEZarrMetadata(array, multiscales_3d) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+
+  // Coordinate transformations do not match the axes
+  const char *multiscales_bad_scale = R""""(
+  [{
+      "axes": [{"name": "y"}, {"name": "x"}],
+      "datasets": [{"path": "0", "coordinateTransformations": [{"type": "scale", "scale": [1]}]}]
+  }]
+  )"""";
+  assert(zarrsArraySetOMEZarrMetadata(array, multiscales_bad_scale) ==
+         ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Set group multiscales under the OME-Zarr 0.5 ome key
+  zarrs_assert(zarrsOpenGroupRW(storage, "/", &group));
+  zarrs_assert(zarrsGroupSetOMEZarrMetadata(group, multiscales_3d));
+  zarrs_assert(zarrsGroupGetOMEZarrMultiscalesString(group, false, &json));
+  assert(std::string(json).find("\"z\"") != std::string::npos);
+  zarrs_assert(zarrsFreeString(json));
+  assert(zarrsGroupSetOMEZarrMetadata(group, "{}") == ZarrsResult::ZARRS_ERROR_INVALID_METADATA);
+  zarrs_assert(zarrsDestroyGroup(group));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,104 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [8, 8],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [4, 4]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{
+        "name": "sharding_indexed",
+        "configuration": {
+            "chunk_shape": [2, 2],
+            "codecs": [{"name": "bytes"}],
+            "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}, {"name": "crc32c"}]
+        }
+    }]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  // Element (i, j) has the value i * 8 + j
+  std::vector<uint8_t> elements(64);
+  for (size_t i = 0; i < elements.size(); ++i) {
+    elements[i] = i;
+  }
+  uint64_t array_start[] = {0, 0};
+  uint64_t array_shape[] = {8, 8};
+  zarrs_assert(zarrsArrayStoreSubset(array, 2, array_start, array_shape, elements.size(), elements.data()));
+
+  // Capacity
+  ZarrsShardIndexCache cache = nullptr;
+  size_t capacity = 0;
+  assert(zarrsCreateShardIndexCacheWithCapacity(array, 0, &cache) == ZarrsResult::ZARRS_ERROR_ARRAY);
+  zarrs_assert(zarrsCreateShardIndexCacheWithCapacity(array, 1, &cache));
+  zarrs_assert(zarrsShardIndexCacheGetCapacity(cache, &capacity));
+  assert(capacity == 1);
+
+  // The least recently used shard index is evicted
+  ZarrsStorageStats stats;
+  std::vector<uint8_t> subchunk(4);
+  uint64_t subchunk_first_shard[] = {0, 1};
+  uint64_t subchunk_last_shard[] = {3, 2};
+  zarrs_assert(zarrsArrayRetrieveSubChunk(array, cache, 2, subchunk_first_shard, subchunk.size(), subchunk.data()));
+  assert(subchunk == std::vector<uint8_t>({2, 3, 10, 11}));
+  zarrs_assert(zarrsArrayRetrieveSubChunk(array, cache, 2, subchunk_last_shard, subchunk.size(), subchunk.data()));
+  assert(subchunk == std::vector<uint8_t>({52, 53, 60, 61}));
+  zarrs_assert(zarrsArrayRetrieveSubChunk(array, cache, 2, subchunk_first_shard, subchunk.size(), subchunk.data()));
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.cacheHits == 0);
+  assert(stats.cacheMisses == 3);
+  zarrs_assert(zarrsArrayRetrieveSubChunk(array, cache, 2, subchunk_first_shard, subchunk.size(), subchunk.data()));
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.cacheHits == 1);
+  assert(stats.cacheMisses == 3);
+
+  // Subsets spanning multiple shards
+  uint64_t subset_start[] = {3, 2};
+  uint64_t subset_shape[] = {2, 3};
+  std::vector<uint8_t> subset(6);
+  zarrs_assert(zarrsArrayRetrieveSubsetSharded(array, cache, 2, subset_start, subset_shape, subset.size(), subset.data()));
+  assert(subset == std::vector<uint8_t>({26, 27, 28, 34, 35, 36}));
+  std::vector<uint8_t> all(64);
+  zarrs_assert(zarrsArrayRetrieveSubsetSharded(array, cache, 2, array_start, array_shape, all.size(), all.data()));
+  assert(all == elements);
+  assert(zarrsArrayRetrieveSubsetSharded(array, cache, 2, subset_start, subset_shape, subset.size() - 1,
+                                         subset.data()) == ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH);
+
+  // Adjusting the capacity
+  assert(zarrsShardIndexCacheSetCapacity(cache, 0) == ZarrsResult::ZARRS_ERROR_ARRAY);
+  zarrs_assert(zarrsShardIndexCacheSetCapacity(cache, 4));
+  zarrs_assert(zarrsShardIndexCacheGetCapacity(cache, &capacity));
+  assert(capacity == 4);
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+
+  // Unbounded caches
+  zarrs_assert(zarrsCreateShardIndexCache(array, &cache));
+  zarrs_assert(zarrsShardIndexCacheGetCapacity(cache, &capacity));
+  assert(capacity == 0);
+  zarrs_assert(zarrsShardIndexCacheSetCapacity(cache, 2));
+  zarrs_assert(zarrsShardIndexCacheGetCapacity(cache, &capacity));
+  assert(capacity == 2);
+  zarrs_assert(zarrsArrayRetrieveSubChunk(array, cache, 2, subchunk_last_shard, subchunk.size(), subchunk.data()));
+  assert(subchunk == std::vector<uint8_t>({52, 53, 60, 61}));
+  zarrs_assert(zarrsDestroyShardIndexCache(cache));
+
+  assert(zarrsShardIndexCacheGetCapacity(nullptr, &capacity) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsShardIndexCacheSetCapacity(nullptr, 1) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,46 @@
+#include "zarrs.h"
+
+const char *array_metadata_v2 = R""""(
+{
+    "zarr_format": 2,
+    "shape": [4, 4],
+    "chunks": [2, 2],
+    "dtype": "<u2",
+    "compressor": null,
+    "fill_value": 0,
+    "order": "C",
+    "filters": null
+}
+)"""";
+
+int main() {
+  const uint32_t read = static_cast<uint32_t>(ZarrsStorageCapabilityFlags::ZARRS_CAP_READ);
+  const uint32_t write = static_cast<uint32_t>(ZarrsStorageCapabilityFlags::ZARRS_CAP_WRITE);
+  const uint32_t list = static_cast<uint32_t>(ZarrsStorageCapabilityFlags::ZARRS_CAP_LIST);
+  uint32_t flags = 0;
+
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  zarrs_assert(zarrsStorageGetCapabilityFlags(storage, &flags));
+  assert(flags == (read | write | list));
+  assert(zarrsStorageGetCapabilityFlags(nullptr, &flags) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata_v2, &array));
+  zarrs_assert(zarrsArrayGetStorageCapabilityFlags(array, &flags));
+  assert(flags == (read | write | list));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Array handles with reduced capabilities
+  zarrs_assert(zarrsOpenArrayV2R(storage, "/array", &array));
+  zarrs_assert(zarrsArrayGetStorageCapabilityFlags(array, &flags));
+  assert(flags == (read | list));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsOpenArrayV2W(storage, "/array", &array));
+  zarrs_assert(zarrsArrayGetStorageCapabilityFlags(array, &flags));
+  assert(flags == write);
+  zarrs_assert(zarrsDestroyArray(array));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
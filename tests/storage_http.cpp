@@ -0,0 +1,45 @@
+#include "zarrs.h"
+
+#include <cstring>
+#include <vector>
+
+int main() {
+  const char *url = getenv("TMP_PATH_STORAGE_HTTP_URL");
+  ZarrsStorage storage = nullptr;
+  ZarrsArray array = nullptr;
+  uint64_t chunk_indices[] = {0, 0};
+  std::vector<float> elements(16);
+  uint8_t *bytes = reinterpret_cast<uint8_t *>(elements.data());
+
+  // HTTP storage is read-only
+  zarrs_assert(zarrsStorageCreateHTTP(url, &storage));
+  char *storage_url = nullptr;
+  zarrs_assert(zarrsStorageGetURL(storage, &storage_url));
+  assert(strcmp(storage_url, url) == 0);
+  zarrs_assert(zarrsFreeString(storage_url));
+  assert(zarrsOpenArrayRW(storage, "/array", &array) == ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  zarrs_assert(zarrsOpenArrayR(storage, "/array", &array));
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, chunk_indices, 16 * sizeof(float), bytes));
+  for (size_t i = 0; i < 16; ++i) {
+    assert(elements[i] == static_cast<float>(i));
+  }
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+
+  // Missing chunks are populated with the fill value
+  zarrs_assert(zarrsStorageCreateHTTPWithOptions(url, 10, 2, &storage));
+  zarrs_assert(zarrsOpenArrayR(storage, "/array", &array));
+  uint64_t missing_chunk_indices[] = {1, 1};
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, missing_chunk_indices, 16 * sizeof(float), bytes));
+  for (size_t i = 0; i < 16; ++i) {
+    assert(elements[i] == -1.0f);
+  }
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+
+  // The URL storage constructor dispatches to HTTP storage
+  zarrs_assert(zarrsStorageCreateFromURL(url, &storage));
+  zarrs_assert(zarrsOpenArrayR(storage, "/array", &array));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,89 @@
+#include "zarrs.h"
+
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint8",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}]
+}
+)"""";
+
+int main() {
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+
+  ZarrsStorageStats stats;
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.bytesRead == 0 && stats.bytesWritten == 0);
+
+  // Store one chunk and retrieve the whole array, the other chunks do not exist in the store
+  std::vector<uint8_t> chunk_bytes(4, 1);
+  uint64_t chunk_indices[] = {0, 0};
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, chunk_bytes.size(), chunk_bytes.data()));
+  std::vector<uint8_t> subset_bytes(16);
+  uint64_t subset_start[] = {0, 0};
+  uint64_t subset_shape[] = {4, 4};
+  zarrs_assert(zarrsArrayRetrieveSubset(array, 2, subset_start, subset_shape, subset_bytes.size(), subset_bytes.data()));
+
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.bytesWritten == 4);
+  assert(stats.chunksEncoded == 1);
+  assert(stats.bytesRead == 4);
+  assert(stats.chunksDecoded == 4);
+
+  // Resetting the array statistics does not reset the storage statistics
+  zarrs_assert(zarrsArrayResetStorageStats(array));
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.bytesRead == 0 && stats.bytesWritten == 0);
+  assert(stats.chunksDecoded == 0 && stats.chunksEncoded == 0);
+  zarrs_assert(zarrsStorageGetStats(storage, &stats));
+  assert(stats.bytesWritten == 4);
+  assert(stats.bytesRead == 4);
+
+  // Single element and strided functions are also recorded
+  uint64_t element_indices[] = {3, 3};
+  uint8_t element = 7;
+  zarrs_assert(zarrsArraySetSingleElement(array, 2, element_indices, 1, &element));
+  zarrs_assert(zarrsArrayGetSingleElement(array, 2, element_indices, 1, &element));
+  assert(element == 7);
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.bytesWritten > 0 && stats.chunksEncoded == 1);
+  assert(stats.bytesRead > 0 && stats.chunksDecoded == 1);
+
+  zarrs_assert(zarrsArrayResetStorageStats(array));
+  int64_t column_major_strides[] = {1, 4};
+  zarrs_assert(zarrsArrayRetrieveSubsetStrided(array, 2, subset_start, subset_shape,
+                                               column_major_strides, subset_bytes.data(),
+                                               subset_bytes.size()));
+  assert(subset_bytes[15] == 7);
+  uint64_t half_shape[] = {2, 4};
+  zarrs_assert(zarrsArrayStoreSubsetStrided(array, 2, subset_start, half_shape,
+                                            column_major_strides, subset_bytes.data(),
+                                            subset_bytes.size()));
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.bytesRead > 0 && stats.chunksDecoded == 4);
+  assert(stats.bytesWritten > 0 && stats.chunksEncoded == 2);
+
+  // Storing an encoded chunk writes its bytes without encoding a chunk
+  zarrs_assert(zarrsArrayResetStorageStats(array));
+  uint64_t last_chunk_indices[] = {1, 1};
+  zarrs_assert(zarrsArrayStoreEncodedChunk(array, 2, last_chunk_indices, chunk_bytes.size(),
+                                           chunk_bytes.data(), true));
+  zarrs_assert(zarrsArrayGetStorageStats(array, &stats));
+  assert(stats.bytesWritten == 4 && stats.chunksEncoded == 0);
+
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
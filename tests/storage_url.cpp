@@ -0,0 +1,45 @@
+#include "zarrs.h"
+
+#include <cstring>
+#include <string>
+
+int main() {
+  const char *tmp_path = getenv("TMP_PATH_STORAGE_URL");
+  const std::string url = std::string("file://") + tmp_path;
+  char *storage_url = nullptr;
+
+  // Filesystem storage from a URL
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsStorageCreateFromURL(url.c_str(), &storage));
+  zarrs_assert(zarrsStorageGetURL(storage, &storage_url));
+  assert(url == storage_url);
+  zarrs_assert(zarrsFreeString(storage_url));
+  ZarrsGroup group = nullptr;
+  zarrs_assert(zarrsCreateGroupRW(storage, "/", R"({"zarr_format": 3, "node_type": "group"})", &group));
+  zarrs_assert(zarrsGroupStoreMetadata(group));
+  zarrs_assert(zarrsDestroyGroup(group));
+  zarrs_assert(zarrsDestroyStorage(storage));
+
+  // Filesystem storage from a path has an equivalent URL
+  zarrs_assert(zarrsCreateStorageFilesystem(tmp_path, &storage));
+  zarrs_assert(zarrsStorageGetURL(storage, &storage_url));
+  assert(url == storage_url);
+  zarrs_assert(zarrsFreeString(storage_url));
+  zarrs_assert(zarrsOpenGroupRW(storage, "/", &group));
+  zarrs_assert(zarrsDestroyGroup(group));
+  zarrs_assert(zarrsDestroyStorage(storage));
+
+  // In-memory storage does not have a URL
+  zarrs_assert(zarrsCreateStorageMemory(&storage));
+  assert(zarrsStorageGetURL(storage, &storage_url) == ZarrsResult::ZARRS_ERROR_NOT_FOUND);
+  zarrs_assert(zarrsDestroyStorage(storage));
+
+  // Unsupported URLs
+  assert(zarrsStorageCreateFromURL("s3://bucket/path", &storage) == ZarrsResult::ZARRS_ERROR_STORAGE);
+  assert(zarrsStorageCreateFromURL((url + "?region=us-east-1").c_str(), &storage) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE);
+  assert(zarrsStorageCreateFromURL("file://relative/path", &storage) == ZarrsResult::ZARRS_ERROR_STORAGE);
+  assert(zarrsStorageCreateFromURL("not a url", &storage) == ZarrsResult::ZARRS_ERROR_STORAGE);
+  assert(zarrsStorageCreateHTTP("not a url", &storage) == ZarrsResult::ZARRS_ERROR_STORE_PREFIX);
+  assert(zarrsStorageCreateHTTP("ftp://example.com/data.zarr", &storage) == ZarrsResult::ZARRS_ERROR_STORE_PREFIX);
+}
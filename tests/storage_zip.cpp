@@ -0,0 +1,82 @@
+#include "zarrs.h"
+
+#include <string>
+#include <vector>
+
+const char *array_metadata = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  const std::string tmp_path = getenv("TMP_PATH_STORAGE_ZIP");
+  const std::string zip_path = tmp_path + "/data.zarr.zip";
+  ZarrsStorage storage = nullptr;
+  ZarrsArray array = nullptr;
+  uint64_t chunk_indices[] = {0, 1};
+  std::vector<uint16_t> elements = {1, 2, 3, 4};
+  uint8_t *bytes = reinterpret_cast<uint8_t *>(elements.data());
+
+  // The archive must exist to be opened read-only
+  assert(zarrsStorageCreateZipR(zip_path.c_str(), &storage) == ZarrsResult::ZARRS_ERROR_STORE_PREFIX);
+  assert(zarrsStorageCreateZipRW((tmp_path + "/missing/data.zarr.zip").c_str(), &storage) ==
+         ZarrsResult::ZARRS_ERROR_STORE_PREFIX);
+
+  // Write a chunk to a new archive
+  zarrs_assert(zarrsStorageCreateZipRW(zip_path.c_str(), &storage));
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, chunk_indices, 4 * sizeof(uint16_t), bytes));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+
+  // Reopen the archive read-only
+  elements = {0, 0, 0, 0};
+  zarrs_assert(zarrsStorageCreateZipR(zip_path.c_str(), &storage));
+  assert(zarrsCreateArrayRW(storage, "/array", array_metadata, &array) ==
+         ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY);
+  zarrs_assert(zarrsOpenArrayR(storage, "/array", &array));
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, chunk_indices, 4 * sizeof(uint16_t), bytes));
+  assert(elements[0] == 1 && elements[1] == 2 && elements[2] == 3 && elements[3] == 4);
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+
+  // Update the existing archive
+  elements = {5, 6, 7, 8};
+  uint64_t other_chunk_indices[] = {1, 0};
+  zarrs_assert(zarrsStorageCreateZipRW(zip_path.c_str(), &storage));
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array", &array));
+  zarrs_assert(zarrsArrayStoreChunk(array, 2, other_chunk_indices, 4 * sizeof(uint16_t), bytes));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+
+  zarrs_assert(zarrsStorageCreateZipR(zip_path.c_str(), &storage));
+  zarrs_assert(zarrsOpenArrayR(storage, "/array", &array));
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, chunk_indices, 4 * sizeof(uint16_t), bytes));
+  assert(elements[0] == 1 && elements[1] == 2 && elements[2] == 3 && elements[3] == 4);
+  zarrs_assert(zarrsArrayRetrieveChunk(array, 2, other_chunk_indices, 4 * sizeof(uint16_t), bytes));
+  assert(elements[0] == 5 && elements[1] == 6 && elements[2] == 7 && elements[3] == 8);
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsDestroyStorage(storage));
+
+  // Write-only archives
+  const std::string empty_zip_path = tmp_path + "/empty.zarr.zip";
+  uint32_t flags = 0;
+  zarrs_assert(zarrsStorageCreateZipW(empty_zip_path.c_str(), &storage));
+  zarrs_assert(zarrsStorageGetCapabilityFlags(storage, &flags));
+  assert(flags == static_cast<uint32_t>(ZarrsStorageCapabilityFlags::ZARRS_CAP_WRITE));
+  zarrs_assert(zarrsDestroyStorage(storage));
+  zarrs_assert(zarrsStorageCreateZipR(empty_zip_path.c_str(), &storage));
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -0,0 +1,115 @@
+#include "zarrs.h"
+
+#include <cstring>
+
+const char *array_metadata_v2 = R""""(
+{
+    "zarr_format": 2,
+    "shape": [4, 4],
+    "chunks": [2, 2],
+    "dtype": "<u2",
+    "compressor": null,
+    "fill_value": 0,
+    "order": "C",
+    "filters": null
+}
+)"""";
+
+const char *array_metadata_v3 = R""""(
+{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4, 4],
+    "data_type": "uint16",
+    "chunk_grid": {
+        "name": "regular",
+        "configuration": {"chunk_shape": [2, 2]}
+    },
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}]
+}
+)"""";
+
+int main() {
+  const char *tmp_path = getenv("TMP_PATH_ZARR_FORMAT");
+  ZarrsStorage storage = nullptr;
+  zarrs_assert(zarrsCreateStorageFilesystem(tmp_path, &storage));
+  uint32_t version = 0;
+
+  // Arrays
+  ZarrsArray array = nullptr;
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array_v2", array_metadata_v2, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsCreateArrayRW(storage, "/array_v3", array_metadata_v3, &array));
+  zarrs_assert(zarrsArrayStoreMetadata(array));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  char *storage_transformers = nullptr;
+  char *metadata_key = nullptr;
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array_v2", &array));
+  zarrs_assert(zarrsArrayGetZarrFormat(array, &version));
+  assert(version == 2);
+  version = 0;
+  zarrs_assert(zarrsArrayGetMetadataVersion(array, &version));
+  assert(version == 2);
+  zarrs_assert(zarrsArrayGetMetadataStorageKey(array, &metadata_key));
+  assert(strcmp(metadata_key, "array_v2/.zarray") == 0);
+  zarrs_assert(zarrsFreeString(metadata_key));
+  zarrs_assert(zarrsArrayGetStorageTransformersString(array, &storage_transformers));
+  assert(strcmp(storage_transformers, "[]") == 0);
+  zarrs_assert(zarrsFreeString(storage_transformers));
+  zarrs_assert(zarrsDestroyArray(array));
+  zarrs_assert(zarrsOpenArrayRW(storage, "/array_v3", &array));
+  zarrs_assert(zarrsArrayGetZarrFormat(array, &version));
+  assert(version == 3);
+  version = 0;
+  zarrs_assert(zarrsArrayGetMetadataVersion(array, &version));
+  assert(version == 3);
+  zarrs_assert(zarrsArrayGetMetadataStorageKey(array, &metadata_key));
+  assert(strcmp(metadata_key, "array_v3/zarr.json") == 0);
+  zarrs_assert(zarrsFreeString(metadata_key));
+  zarrs_assert(zarrsArrayGetStorageTransformersString(array, &storage_transformers));
+  assert(strcmp(storage_transformers, "[]") == 0);
+  zarrs_assert(zarrsFreeString(storage_transformers));
+  zarrs_assert(zarrsDestroyArray(array));
+
+  // Null handles
+  assert(zarrsArrayGetMetadataVersion(nullptr, &version) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsArrayGetMetadataStorageKey(nullptr, &metadata_key) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+  assert(zarrsGroupGetMetadataStorageKey(nullptr, &metadata_key) == ZarrsResult::ZARRS_ERROR_NULL_PTR);
+
+  // Groups
+  ZarrsGroup group = nullptr;
+  zarrs_assert(zarrsCreateGroupRW(storage, "/group_v2", R"({"zarr_format": 2})", &group));
+  zarrs_assert(zarrsGroupStoreMetadata(group));
+  zarrs_assert(zarrsDestroyGroup(group));
+  zarrs_assert(zarrsOpenGroupRW(storage, "/group_v2", &group));
+  zarrs_assert(zarrsGroupGetZarrFormat(group, &version));
+  assert(version == 2);
+  version = 0;
+  zarrs_assert(zarrsGroupGetMetadataVersion(group, &version));
+  assert(version == 2);
+  zarrs_assert(zarrsGroupGetMetadataStorageKey(group, &metadata_key));
+  assert(strcmp(metadata_key, "group_v2/.zgroup") == 0);
+  zarrs_assert(zarrsFreeString(metadata_key));
+  zarrs_assert(zarrsDestroyGroup(group));
+  zarrs_assert(zarrsCreateGroupRW(storage, "/group_v3", R"({"zarr_format": 3, "node_type": "group"})", &group));
+  zarrs_assert(zarrsGroupGetZarrFormat(group, &version));
+  assert(version == 3);
+  version = 0;
+  zarrs_assert(zarrsGroupGetMetadataVersion(group, &version));
+  assert(version == 3);
+  zarrs_assert(zarrsGroupGetMetadataStorageKey(group, &metadata_key));
+  assert(strcmp(metadata_key, "group_v3/zarr.json") == 0);
+  zarrs_assert(zarrsFreeString(metadata_key));
+  zarrs_assert(zarrsDestroyGroup(group));
+  zarrs_assert(zarrsCreateGroupRW(storage, "/", R"({"zarr_format": 3, "node_type": "group"})", &group));
+  zarrs_assert(zarrsGroupGetMetadataStorageKey(group, &metadata_key));
+  assert(strcmp(metadata_key, "zarr.json") == 0);
+  zarrs_assert(zarrsFreeString(metadata_key));
+  zarrs_assert(zarrsDestroyGroup(group));
+
+  zarrs_assert(zarrsDestroyStorage(storage));
+}
@@ -18,6 +18,11 @@
 #define zarrs_assert(expr) assert(ZARRS_SUCCESS == expr)
 
 
+/**
+ * The maximum dimensionality of an array described by [`ZarrsArrayInfo`].
+ */
+#define ZARRS_MAX_DIMS 32
+
 enum ZarrsResult
 #ifdef __cplusplus
   : int32_t
@@ -38,11 +43,33 @@ enum ZarrsResult
   ZARRS_ERROR_UNSUPPORTED_DATA_TYPE = -12,
   ZARRS_ERROR_GROUP = -13,
   ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY = -14,
+  ZARRS_ERROR_OVERFLOW = -15,
+  ZARRS_ERROR_NOT_FOUND = -16,
+  ZARRS_ERROR_PARTIAL_FAILURE = -17,
+  ZARRS_ERROR_ABORTED = -18,
+  ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS = -19,
+  ZARRS_ERROR_UNKNOWN_POINTER = -20,
 };
 #ifndef __cplusplus
 typedef int32_t ZarrsResult;
 #endif // __cplusplus
 
+/**
+ * A zarrs chunk grid type.
+ */
+enum ZarrsChunkGridType
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  ZARRS_CHUNK_GRID_REGULAR = 0,
+  ZARRS_CHUNK_GRID_RECTANGULAR = 1,
+  ZARRS_CHUNK_GRID_OTHER = 2,
+};
+#ifndef __cplusplus
+typedef int32_t ZarrsChunkGridType;
+#endif // __cplusplus
+
 /**
  * A zarrs data type.
  */
@@ -68,15 +95,102 @@ enum ZarrsDataType
   ZARRS_COMPLEX128 = 13,
   ZARRS_RAW_BITS = 14,
   ZARRS_BFLOAT16 = 15,
+  ZARRS_STRING = 16,
 };
 #ifndef __cplusplus
 typedef int32_t ZarrsDataType;
 #endif // __cplusplus
 
+/**
+ * The memory layout of a multidimensional buffer.
+ */
+enum ZarrsOrder
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  /**
+   * C (row-major) order, where the last dimension is contiguous.
+   */
+  ZARRS_ORDER_C = 0,
+  /**
+   * Fortran (column-major) order, where the first dimension is contiguous.
+   */
+  ZARRS_ORDER_F = 1,
+};
+#ifndef __cplusplus
+typedef int32_t ZarrsOrder;
+#endif // __cplusplus
+
+/**
+ * The Zarr version of the metadata written by `zarrsArrayStoreMetadataOpt`.
+ */
+enum ZarrsMetadataVersion
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  /**
+   * Write the metadata in its current Zarr version.
+   */
+  ZARRS_METADATA_KEEP_CURRENT = 0,
+  /**
+   * Write Zarr V3 metadata, converting Zarr V2 metadata if necessary.
+   */
+  ZARRS_METADATA_FORCE_V3 = 1,
+  /**
+   * Write Zarr V2 metadata, converting Zarr V3 metadata if it can be expressed in Zarr V2.
+   */
+  ZARRS_METADATA_FORCE_V2 = 2,
+};
+#ifndef __cplusplus
+typedef int32_t ZarrsMetadataVersion;
+#endif // __cplusplus
+
+/**
+ * The type of a node in a Zarr hierarchy.
+ */
+enum ZarrsNodeType
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  ZARRS_NODE_TYPE_ARRAY = 0,
+  ZARRS_NODE_TYPE_GROUP = 1,
+  ZARRS_NODE_TYPE_UNKNOWN = 2,
+};
+#ifndef __cplusplus
+typedef int32_t ZarrsNodeType;
+#endif // __cplusplus
+
+/**
+ * Storage capability flags.
+ *
+ * The flags are combined with bitwise or into a `uint32_t` bitfield.
+ */
+enum ZarrsStorageCapabilityFlags
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  ZARRS_CAP_READ = 1,
+  ZARRS_CAP_WRITE = 2,
+  ZARRS_CAP_LIST = 4,
+};
+#ifndef __cplusplus
+typedef uint32_t ZarrsStorageCapabilityFlags;
+#endif // __cplusplus
+
 typedef struct ZarrsArray_T ZarrsArray_T;
 
+typedef struct ZarrsChunkCache_T ZarrsChunkCache_T;
+
+typedef struct ZarrsCodecOptions_T ZarrsCodecOptions_T;
+
 typedef struct ZarrsGroup_T ZarrsGroup_T;
 
+typedef struct ZarrsOperation_T ZarrsOperation_T;
+
 typedef struct ZarrsShardIndexCache_T ZarrsShardIndexCache_T;
 
 typedef struct ZarrsStorage_T ZarrsStorage_T;
@@ -87,515 +201,4420 @@ typedef struct ZarrsStorage_T ZarrsStorage_T;
 typedef struct ZarrsArray_T *ZarrsArray;
 
 /**
- * An opaque handle to a zarrs [`ArrayShardedReadableExtCache`].
+ * An opaque handle to a zarr store or storage transformer.
+ */
+typedef struct ZarrsStorage_T *ZarrsStorage;
+
+/**
+ * A summary of the properties of an array.
+ *
+ * Populated by `zarrsArrayGetInfo`.
+ * Only the first `dimensionality` elements of each shape are meaningful, the remaining elements are zero.
+ */
+typedef struct ZarrsArrayInfo {
+  /**
+   * The dimensionality of the array.
+   */
+  uint64_t dimensionality;
+  /**
+   * The shape of the array.
+   */
+  uint64_t shape[ZARRS_MAX_DIMS];
+  /**
+   * The data type of the array.
+   */
+  ZarrsDataType dataType;
+  /**
+   * The size of an element in bytes, or zero if the data type has a variable size.
+   */
+  uint64_t dataTypeSize;
+  /**
+   * The number of chunks along each dimension.
+   */
+  uint64_t chunkGridShape[ZARRS_MAX_DIMS];
+  /**
+   * True if the array has a regular chunk grid.
+   */
+  bool isRegularChunkGrid;
+  /**
+   * The chunk shape if the array has a regular chunk grid, otherwise zero.
+   */
+  uint64_t chunkShape[ZARRS_MAX_DIMS];
+  /**
+   * True if the array is sharded.
+   */
+  bool isSharded;
+} ZarrsArrayInfo;
+
+/**
+ * Cumulative I/O statistics of an array or storage handle.
+ *
+ * `bytesRead` and `bytesWritten` count the bytes read from and written to the store, including metadata.
+ * They depend on the codecs and on partial decoding, and a read served from a chunk or shard index cache reads no bytes.
+ *
+ * `chunksDecoded` and `chunksEncoded` count the chunks intersecting each retrieved and stored region.
+ * Chunks served from a decoded chunk cache are not counted.
+ *
+ * `cacheHits` and `cacheMisses` count chunk cache lookups by the chunk cache functions.
+ * Shard index cache hits and misses are inferred from the number of shard indexes loaded into the cache during a call.
+ */
+typedef struct ZarrsStorageStats {
+  uint64_t bytesRead;
+  uint64_t bytesWritten;
+  uint64_t chunksDecoded;
+  uint64_t chunksEncoded;
+  uint64_t cacheHits;
+  uint64_t cacheMisses;
+} ZarrsStorageStats;
+
+/**
+ * An opaque handle to a shard index cache.
+ *
+ * The cache is either unbounded (a zarrs [`ArrayShardedReadableExtCache`]) or holds a bounded number of shard indexes.
  */
 typedef struct ZarrsShardIndexCache_T *ZarrsShardIndexCache;
 
 /**
- * An opaque handle to a zarr store or storage transformer.
+ * An opaque handle to a chunk cache.
+ *
+ * The cache holds decoded or encoded chunks of the array it was created for, and evicts the least recently used chunks when it is full.
  */
-typedef struct ZarrsStorage_T *ZarrsStorage;
+typedef struct ZarrsChunkCache_T *ZarrsChunkCache;
+
+/**
+ * An opaque handle to zarrs [`CodecOptions`].
+ */
+typedef struct ZarrsCodecOptions_T *ZarrsCodecOptions;
+
+/**
+ * A callback invoked when an asynchronous operation completes.
+ *
+ * `result` is the result of the operation and `userData` is the user data passed to the function that started it.
+ */
+typedef void (*ZarrsCompletionCallback)(ZarrsResult result, void *userData);
+
+/**
+ * An opaque handle to an asynchronous operation.
+ */
+typedef struct ZarrsOperation_T *ZarrsOperation;
+
+/**
+ * A callback that receives a decoded part of a subset retrieved by `zarrsArrayRetrieveSubsetCallback`.
+ *
+ * - `dimensionality` is the dimensionality of the array.
+ * - `pPartStart` and `pPartShape` point to arrays of length `dimensionality` holding the start (in array coordinates) and shape of the part, which is the intersection of a chunk with the subset.
+ * - `pBytes` points to the decoded bytes of the part in C order, with length `bytesLen`.
+ * - `userData` is the user data passed to `zarrsArrayRetrieveSubsetCallback`.
+ *
+ * The pointers are only valid during the callback.
+ * Return zero to continue, or non-zero to abort the retrieval.
+ */
+typedef int32_t (*ZarrsSubsetChunkCallback)(size_t dimensionality, const uint64_t *pPartStart, const uint64_t *pPartShape, const uint8_t *pBytes, size_t bytesLen, void *userData);
 
 /**
  * An opaque handle to a zarr group.
  */
 typedef struct ZarrsGroup_T *ZarrsGroup;
 
+/**
+ * The requests made to a storage created with `zarrsCreateStoragePerformanceMetrics`.
+ *
+ * These count the requests that reach the underlying store.
+ * A read that is served from a chunk or shard index cache is not counted.
+ */
+typedef struct ZarrsStoragePerformanceMetrics {
+  /**
+   * The number of read requests (gets, partial gets, and size queries).
+   */
+  uint64_t reads;
+  /**
+   * The number of bytes read.
+   */
+  uint64_t bytesRead;
+  /**
+   * The number of write requests.
+   */
+  uint64_t writes;
+  /**
+   * The number of bytes written.
+   */
+  uint64_t bytesWritten;
+} ZarrsStoragePerformanceMetrics;
+
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
 
 /**
- * Get the array attributes as a JSON string.
+ * Check whether the array has a top-level attribute.
  *
- * The string must be freed with `zarrsFreeString`.
+ * `pExists` is set to true if the attributes contain `key`, otherwise false.
+ * The attribute value is not serialised, use `zarrsArrayGetAttribute` to retrieve it.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pExists` must be a valid pointer to a `bool`.
  */
-ZarrsResult zarrsArrayGetAttributes(ZarrsArray array, bool pretty, char **pAttributesString);
+ZarrsResult zarrsArrayAttributeExists(ZarrsArray array, const char* key, bool *pExists);
 
 /**
- * Get the array attributes as a JSON string.
+ * Check if a chunk exists in the store.
  *
- * The string must be freed with `zarrsFreeString`.
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * The chunk is not retrieved or decoded, only the existence of its key is checked.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk indices are invalid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
  *
  * # Safety
  * `array` must be a valid `ZarrsArray` handle.
+ * `pChunkIndices` must point to an array of length `dimensionality`.
+ * `pExists` must be a valid pointer to a `bool`.
  */
-ZarrsResult zarrsArrayGetAttributesString(ZarrsArray array, bool pretty, char **pAttributesString);
+ZarrsResult zarrsArrayChunkExists(ZarrsArray array,
+                                  size_t dimensionality,
+                                  const uint64_t *pChunkIndices,
+                                  bool *pExists);
 
 /**
- * Return the number of chunks in the chunk grid.
+ * Write consolidated metadata for the hierarchy under a group.
+ *
+ * The metadata of every node under `rootPath` is gathered and stored inline in the `consolidated_metadata` field of the root group `zarr.json`.
+ * This follows the convention established by `zarr-python` for Zarr V3, which is not part of the Zarr V3 specification.
+ * The root group must be a Zarr V3 group.
  *
  * # Errors
- * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
- * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read, write, and list capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_GROUP` if there is no group at `rootPath`.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the hierarchy could not be traversed or the metadata could not be stored.
  *
  * # Safety
- * If not null, `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkGridShape`.
+ * If not null, `storage` must be a valid `ZarrsStorage` handle.
  */
-ZarrsResult zarrsArrayGetChunkGridShape(ZarrsArray array,
-                                        size_t dimensionality,
-                                        uint64_t *pChunkGridShape);
+ZarrsResult zarrsArrayConsolidateMetadata(ZarrsStorage storage,
+                                          const char* rootPath);
 
 /**
- * Get the origin of a chunk.
+ * Erase a chunk.
  *
  * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
  *
+ * The store key of the chunk is erased, so the chunk is subsequently retrieved as the fill value.
+ * Erasing a chunk that is not stored succeeds.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are outside of the chunk grid or `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the chunk could not be erased from the store.
+ *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices` and `pChunkOrigin`.
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pChunkIndices` must point to an array of length `dimensionality`.
  */
-ZarrsResult zarrsArrayGetChunkOrigin(ZarrsArray array,
-                                     size_t dimensionality,
-                                     const uint64_t *pChunkIndices,
-                                     uint64_t *pChunkOrigin);
+ZarrsResult zarrsArrayEraseChunk(ZarrsArray array,
+                                 size_t dimensionality,
+                                 const uint64_t *pChunkIndices);
 
 /**
- * Get the shape of a chunk.
+ * Fill a buffer with the fill value of the array.
  *
- * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pBytes` is a pointer to an array of bytes of length `bytesCount`.
+ * The fill value is repeated to fill the buffer.
+ * If `bytesCount` is not a multiple of the data type size, the last element is truncated.
+ *
+ * This function does not access storage.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
  *
  * # Safety
  * `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices` and `pChunkShape`.
+ * `pBytes` must point to an array of length `bytesCount`.
  */
-ZarrsResult zarrsArrayGetChunkShape(ZarrsArray array,
-                                    size_t dimensionality,
-                                    const uint64_t *pChunkIndices,
-                                    uint64_t *pChunkShape);
+ZarrsResult zarrsArrayFillBuffer(ZarrsArray array, size_t bytesCount, uint8_t *pBytes);
 
 /**
- * Get the size of a chunk in bytes.
+ * Get the additional (extension) top-level fields of the array metadata as a JSON object string.
  *
- * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * The object is empty if the array has no additional fields.
+ * Fields that are not `must_understand` include `"must_understand": false`, as they appear in the array metadata.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the additional fields cannot be converted to a JSON string.
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pJson` must be a valid pointer to a `char*`.
  */
-ZarrsResult zarrsArrayGetChunkSize(ZarrsArray array,
-                                   size_t dimensionality,
-                                   const uint64_t *pChunkIndices,
-                                   size_t *chunkSize);
+ZarrsResult zarrsArrayGetAdditionalFieldsString(ZarrsArray array,
+                                                char **pJson);
 
 /**
- * Return the chunks indicating the chunks intersecting `array_subset`.
+ * Get an array attribute as a JSON string.
+ *
+ * `jsonPointer` is a JSON pointer (RFC 6901) resolved against the attributes, such as `/multiscales/0/axes`.
+ * The resolved value may be any JSON value, including a scalar.
+ * The string must be freed with `zarrsFreeString`.
  *
  * # Errors
  * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
- * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
- * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NOT_FOUND` if `jsonPointer` does not resolve to a value.
  *
  * # Safety
  * If not null, `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart`, `pSubsetShape`, `pChunksStart`, and `pChunksShape`.
+ * `pValueJson` must be a valid pointer to a `char*`.
  */
-ZarrsResult zarrsArrayGetChunksInSubset(ZarrsArray array,
-                                        size_t dimensionality,
-                                        const uint64_t *pSubsetStart,
-                                        const uint64_t *pSubsetShape,
-                                        uint64_t *pChunksStart,
-                                        uint64_t *pChunksShape);
+ZarrsResult zarrsArrayGetAttribute(ZarrsArray array,
+                                   const char* jsonPointer,
+                                   bool pretty,
+                                   char **pValueJson);
 
 /**
- * Returns the data type of the array.
+ * Get the number of top-level array attributes.
  *
  * # Errors
  * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
  *
  * # Safety
  * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pCount` must be a valid pointer to a `size_t`.
  */
-ZarrsResult zarrsArrayGetDataType(ZarrsArray array, ZarrsDataType *pDataType);
+ZarrsResult zarrsArrayGetAttributeCount(ZarrsArray array, size_t *pCount);
 
 /**
- * Returns the dimensionality of the array.
+ * Get the array attributes as a JSON string.
  *
- * # Errors
- * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * The string must be freed with `zarrsFreeString`.
  *
  * # Safety
- * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `array` must be a valid `ZarrsArray` handle.
  */
-ZarrsResult zarrsArrayGetDimensionality(ZarrsArray array, size_t *dimensionality);
+ZarrsResult zarrsArrayGetAttributes(ZarrsArray array, bool pretty, char **pAttributesString);
 
 /**
- * Get the array metadata as a JSON string.
+ * Get the array attributes as a JSON string.
  *
  * The string must be freed with `zarrsFreeString`.
  *
  * # Safety
  * `array` must be a valid `ZarrsArray` handle.
  */
-ZarrsResult zarrsArrayGetMetadataString(ZarrsArray array, bool pretty, char **pMetadataString);
+ZarrsResult zarrsArrayGetAttributesString(ZarrsArray array, bool pretty, char **pAttributesString);
 
 /**
- * Returns the shape of the array.
+ * Retrieve a chunk from an array as a JSON string.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pJsonString` is set to a JSON array of the chunk elements, with one level of nesting per dimension.
+ * Non-finite floats are represented as the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * This function is intended for diagnostics.
  *
  * # Errors
  * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
- * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length or cannot be converted to JSON.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the chunk could not be retrieved.
  *
  * # Safety
  * If not null, `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pShape`.
+ * `pChunkIndices` must point to an array of length `dimensionality`.
+ * `pJsonString` must be a valid pointer to a `char*`.
  */
-ZarrsResult zarrsArrayGetShape(ZarrsArray array,
-                               size_t dimensionality,
-                               uint64_t *pShape);
+ZarrsResult zarrsArrayGetChunkAsJSON(ZarrsArray array,
+                                     size_t dimensionality,
+                                     const uint64_t *pChunkIndices,
+                                     char **pJsonString);
 
 /**
- * Get the shape of the inner chunk grid of a sharded array.
+ * Get the bounds of a chunk as an array subset.
  *
- * If the array is not sharded, the contents of `pSubChunkGridShape` will equal the standard chunk grid shape.
+ * This is equivalent to `zarrsArrayGetChunkOriginAndShape`, with `pSubsetStart` and `pSubsetShape` set to the chunk origin and shape.
+ * The shape of chunks overhanging the array bounds is not clipped, see `zarrsArrayGetChunkSubset`.
  *
  * # Errors
  * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
- * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pSubChunkGridShape`.
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunkIndices`, `pSubsetStart`, and `pSubsetShape`.
  */
-ZarrsResult zarrsArrayGetSubChunkGridShape(ZarrsArray array,
-                                           size_t dimensionality,
-                                           uint64_t *pSubChunkGridShape);
+ZarrsResult zarrsArrayGetChunkBoundsAsSubset(ZarrsArray array,
+                                             size_t dimensionality,
+                                             const uint64_t *pChunkIndices,
+                                             uint64_t *pSubsetStart,
+                                             uint64_t *pSubsetShape);
 
 /**
- * Get the inner chunk shape for a sharded array.
+ * Get the byte offset of the first element of a chunk in a C order buffer holding the entire array.
  *
- * `pIsSharded` is set to true if the array is sharded, otherwise false.
- * If the array is not sharded, the contents of `pSubChunkShape` will be undefined.
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pByteOffset` is set to the byte offset of the chunk origin in a buffer holding the entire decoded array.
+ * Combined with `zarrsArrayGetChunkStride`, this can be used to decode chunks directly into a buffer holding the entire array.
+ * This is a pure computation and does not access storage.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pByteOffset` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the byte offset overflows a `uint64_t`.
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkShape`.
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pByteOffset` must be a valid pointer to a `uint64_t`.
+ * `pChunkIndices` must point to an array of length `dimensionality`.
  */
-ZarrsResult zarrsArrayGetSubChunkShape(ZarrsArray array,
-                                       size_t dimensionality,
-                                       bool *pIsSharded,
-                                       uint64_t *pSubChunkShape);
+ZarrsResult zarrsArrayGetChunkByteOffsetInArray(ZarrsArray array,
+                                                size_t dimensionality,
+                                                const uint64_t *pChunkIndices,
+                                                uint64_t *pByteOffset);
 
 /**
- * Get the size of a subset in bytes.
+ * Return the number of chunks along each dimension of the chunk grid.
  *
- * `pSubsetShape` is a pointer to an array of length `dimensionality` holding the shape of the subset.
+ * This is identical to `zarrsArrayGetChunkGridShape`, which is also the number of chunks along each dimension, but is restricted to regular and rectangular chunk grids.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the chunk grid is not regular or rectangular.
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pSubsetShape`.
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pChunkCount` must point to an array of length `dimensionality`.
  */
-ZarrsResult zarrsArrayGetSubsetSize(ZarrsArray array,
+ZarrsResult zarrsArrayGetChunkCount(ZarrsArray array,
                                     size_t dimensionality,
-                                    const uint64_t *pSubsetShape,
-                                    size_t *subsetSize);
+                                    uint64_t *pChunkCount);
 
 /**
- * Retrieve a chunk from an array.
+ * Return the total number of chunks in the chunk grid.
  *
- * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
- * `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
+ * This is the product of the chunk counts returned by `zarrsArrayGetChunkCount`.
  *
  * # Errors
- * Returns an error if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the chunk grid is not regular or rectangular.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of chunks overflows a `uint64_t`.
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pTotalChunkCount` must be a valid pointer to a `uint64_t`.
  */
-ZarrsResult zarrsArrayRetrieveChunk(ZarrsArray array,
-                                    size_t dimensionality,
-                                    const uint64_t *pChunkIndices,
-                                    size_t chunkBytesCount,
-                                    uint8_t *pChunkBytes);
+ZarrsResult zarrsArrayGetChunkCountProduct(ZarrsArray array,
+                                           uint64_t *pTotalChunkCount);
 
 /**
- * Retrieve an inner chunk from a sharded array (or outer chunk for an unsharded array).
+ * Get a bitmap indicating which chunks in a region of the chunk grid exist in the store.
  *
- * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
- * `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
+ * `pChunksStart` and `pChunksShape` are pointers to arrays of length `dimensionality` holding the start and shape of the region in the chunk grid.
+ * `pBitmap` is a pointer to a caller-allocated buffer of at least `ceil(n / 8)` bytes, where `n` is the number of chunks in the region.
+ *
+ * Chunks are enumerated in C order (last dimension fastest) over the region.
+ * The chunk with C order index `i` corresponds to bit `i % 8` (least significant bit first) of byte `i / 8` of `pBitmap`.
+ * A set bit indicates that the chunk exists in the store.
+ * Unused trailing bits of the last byte are cleared.
+ *
+ * The keys under the array are listed once if the storage has list capability, otherwise the key of each chunk is queried individually.
  *
  * # Errors
- * Returns an error if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the region is not within the chunk grid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
  *
  * # Safety
  * `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ * `pChunksStart` and `pChunksShape` must point to arrays of length `dimensionality`.
+ * `pBitmap` must point to a buffer of at least `ceil(n / 8)` bytes.
  */
-ZarrsResult zarrsArrayRetrieveSubChunk(ZarrsArray array,
-                                       ZarrsShardIndexCache cache,
-                                       size_t dimensionality,
-                                       const uint64_t *pChunkIndices,
-                                       size_t chunkBytesCount,
-                                       uint8_t *pChunkBytes);
+ZarrsResult zarrsArrayGetChunkExistsBitmap(ZarrsArray array,
+                                           size_t dimensionality,
+                                           const uint64_t *pChunksStart,
+                                           const uint64_t *pChunksShape,
+                                           uint8_t *pBitmap);
+
+/**
+ * Return the number of chunks in the chunk grid.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkGridShape`.
+ */
+ZarrsResult zarrsArrayGetChunkGridShape(ZarrsArray array,
+                                        size_t dimensionality,
+                                        uint64_t *pChunkGridShape);
+
+/**
+ * Get the chunk grid metadata as a JSON string.
+ *
+ * The chunk grid metadata holds the chunk grid `name` and `configuration`.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetChunkGridString(ZarrsArray array, char **pChunkGridJson);
+
+/**
+ * Returns the chunk grid type of the array.
+ *
+ * Chunk grids other than `regular` and `rectangular` are reported as `ZARRS_CHUNK_GRID_OTHER`.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetChunkGridType(ZarrsArray array, ZarrsChunkGridType *pType);
+
+/**
+ * Get the chunk containing an element and the offset of the element within that chunk.
+ *
+ * `pElementIndices` is a pointer to an array of length `dimensionality` holding the element indices.
+ * `pChunkIndices` is set to the indices of the chunk containing the element.
+ * `pWithinChunkOffset` is set to the indices of the element relative to the origin of the chunk.
+ * This is computed from the chunk grid, so it supports irregular chunk grids.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pElementIndices` are outside the array shape.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pElementIndices`, `pChunkIndices`, and `pWithinChunkOffset`.
+ */
+ZarrsResult zarrsArrayGetChunkIndicesForElement(ZarrsArray array,
+                                                size_t dimensionality,
+                                                const uint64_t *pElementIndices,
+                                                uint64_t *pChunkIndices,
+                                                uint64_t *pWithinChunkOffset);
+
+/**
+ * Get the intersection of the array subset of a chunk with an array subset.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the array subset start and shape respectively.
+ * `pIntersectStart` and `pIntersectShape` are set to the start and shape of the intersection in the array.
+ * If the chunk does not intersect the array subset, `pIntersectShape` will contain a zero.
+ * The intersection is within the array shape, even if the chunk extends past it.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are outside the chunk grid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pChunkIndices`, `pSubsetStart`, `pSubsetShape`, `pIntersectStart`, and `pIntersectShape`.
+ */
+ZarrsResult zarrsArrayGetChunkIntersectWithSubset(ZarrsArray array,
+                                                  size_t dimensionality,
+                                                  const uint64_t *pChunkIndices,
+                                                  const uint64_t *pSubsetStart,
+                                                  const uint64_t *pSubsetShape,
+                                                  uint64_t *pIntersectStart,
+                                                  uint64_t *pIntersectShape);
+
+/**
+ * Get the intersection of a chunk with an array subset for partial chunk I/O.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the array subset start and shape respectively.
+ * `pIntersectStart` and `pIntersectShape` are set to the start and shape of the intersection in the array.
+ * Unlike `zarrsArrayGetChunkIntersectWithSubset`, if the chunk does not intersect the array subset, `pIntersectShape` is set to all zeros.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are outside the chunk grid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pChunkIndices`, `pSubsetStart`, `pSubsetShape`, `pIntersectStart`, and `pIntersectShape`.
+ */
+ZarrsResult zarrsArrayGetChunkIntersectionWithSubset(ZarrsArray array,
+                                                     size_t dimensionality,
+                                                     const uint64_t *pChunkIndices,
+                                                     const uint64_t *pSubsetStart,
+                                                     const uint64_t *pSubsetShape,
+                                                     uint64_t *pIntersectStart,
+                                                     uint64_t *pIntersectShape);
+
+/**
+ * Get the chunk key encoding metadata as a JSON string.
+ *
+ * The chunk key encoding metadata holds the chunk key encoding `name` and `configuration`.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pJson` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the metadata cannot be serialised.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetChunkKeyEncodingMetadataString(ZarrsArray array,
+                                                        bool pretty,
+                                                        char **pJson);
+
+/**
+ * Get the name and separator of the chunk key encoding.
+ *
+ * `pEncodingName` is set to the chunk key encoding name, such as `default` or `v2`.
+ * `pSeparator` is set to the separator character, or `\0` if the chunk key encoding has no separator.
+ * The name must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array`, `pEncodingName`, or `pSeparator` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the chunk key encoding metadata cannot be interpreted.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetChunkKeyEncodingString(ZarrsArray array,
+                                                char **pEncodingName,
+                                                char *pSeparator);
+
+/**
+ * Get the origin of a chunk.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices` and `pChunkOrigin`.
+ */
+ZarrsResult zarrsArrayGetChunkOrigin(ZarrsArray array,
+                                     size_t dimensionality,
+                                     const uint64_t *pChunkIndices,
+                                     uint64_t *pChunkOrigin);
+
+/**
+ * Get the origin and shape of a chunk.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pChunkOrigin` and `pChunkShape` are set to the same values as `zarrsArrayGetChunkOrigin` and `zarrsArrayGetChunkShape` respectively.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunkIndices`, `pChunkOrigin`, and `pChunkShape`.
+ */
+ZarrsResult zarrsArrayGetChunkOriginAndShape(ZarrsArray array,
+                                             size_t dimensionality,
+                                             const uint64_t *pChunkIndices,
+                                             uint64_t *pChunkOrigin,
+                                             uint64_t *pChunkShape);
+
+/**
+ * Get the layout of the elements within the encoded chunks of an array as a JSON string.
+ *
+ * The layout is determined from the codec pipeline of the array, and is a JSON object with the fields:
+ * - `order`: `"C"` (row-major) if there is no `transpose` codec or it is the identity permutation, `"F"` (column-major) if the `transpose` codec reverses the dimensions, or otherwise the permutation of the `transpose` codec.
+ * - `endian`: the endianness of the `bytes` codec (`"little"` or `"big"`), or null if there is no `bytes` codec or it has no endianness.
+ *
+ * This describes the chunks as they are encoded in the store.
+ * Chunks and subsets decoded by zarrs are always in C order and native endianness, irrespective of the layout of the encoded chunks.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pJson` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the codec metadata cannot be interpreted.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pJson` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetChunkRepresentationString(ZarrsArray array,
+                                                   bool pretty,
+                                                   char **pJson);
+
+/**
+ * Get the shape of a chunk.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices` and `pChunkShape`.
+ */
+ZarrsResult zarrsArrayGetChunkShape(ZarrsArray array,
+                                    size_t dimensionality,
+                                    const uint64_t *pChunkIndices,
+                                    uint64_t *pChunkShape);
+
+/**
+ * Get the size of a chunk in bytes.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ */
+ZarrsResult zarrsArrayGetChunkSize(ZarrsArray array,
+                                   size_t dimensionality,
+                                   const uint64_t *pChunkIndices,
+                                   size_t *chunkSize);
+
+/**
+ * Get the number of elements in a chunk.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * Unlike `zarrsArrayGetChunkSize`, which returns the size of a chunk in bytes, this is independent of the data type.
+ * This does not access storage.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk indices are invalid.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ * `pNumElements` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetChunkSizeElements(ZarrsArray array,
+                                           size_t dimensionality,
+                                           const uint64_t *pChunkIndices,
+                                           uint64_t *pNumElements);
+
+/**
+ * Get the shape of the chunks within the array bounds on the upper boundary of the array.
+ *
+ * `pRegularChunkShape` is set to the chunk shape of the regular chunk grid, as returned by `zarrsArrayGetRegularChunkShape`.
+ * `pEdgeChunkShape` is set to the shape of the last chunk along each dimension that lies within the array, which equals `pRegularChunkShape` along dimensions where the chunk shape divides the array shape.
+ * `pHasEdgeChunks` is set to true if the chunk shape does not divide the array shape along any dimension.
+ *
+ * Chunks are always retrieved and stored with the regular chunk shape (see `zarrsArrayGetChunkSize`), including elements outside of the array bounds.
+ * The edge chunk shape is the part of those chunks within the array, as returned by `zarrsArrayGetChunkSubset` with `clipToArrayShape`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the array does not have a regular chunk grid.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pRegularChunkShape` and `pEdgeChunkShape` must point to arrays of length `dimensionality`.
+ * `pHasEdgeChunks` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayGetChunkSizeForBoundaryChunks(ZarrsArray array,
+                                                    size_t dimensionality,
+                                                    uint64_t *pRegularChunkShape,
+                                                    uint64_t *pEdgeChunkShape,
+                                                    bool *pHasEdgeChunks);
+
+/**
+ * Get the element stride between chunk boundaries along each dimension of a C order buffer holding the entire array.
+ *
+ * For dimension `i`, the stride is the chunk shape along dimension `i` multiplied by the product of the array shape along dimensions `i+1` through `N-1`.
+ * `pChunkStride` is a pointer to an array of length `dimensionality` in which the strides are returned.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the array does not have a regular chunk grid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if a stride overflows a `uint64_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pChunkStride` must point to an array of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayGetChunkStride(ZarrsArray array,
+                                     size_t dimensionality,
+                                     uint64_t *pChunkStride);
+
+/**
+ * Get the array subset of a chunk.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pSubsetStart` and `pSubsetShape` are set to the origin and shape of the chunk in the array.
+ * If `clipToArrayShape` is true, the shape of chunks overhanging the array bounds is clipped to the array shape.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are outside the chunk grid.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunkIndices`, `pSubsetStart`, and `pSubsetShape`.
+ */
+ZarrsResult zarrsArrayGetChunkSubset(ZarrsArray array,
+                                     size_t dimensionality,
+                                     const uint64_t *pChunkIndices,
+                                     bool clipToArrayShape,
+                                     uint64_t *pSubsetStart,
+                                     uint64_t *pSubsetShape);
+
+/**
+ * Get the origin and shape of a chunk as the start and shape of an array subset.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pSubsetStart` and `pSubsetShape` are set to the chunk origin and shape, ready to be passed to `zarrsArrayRetrieveSubset` or `zarrsArrayStoreSubset` when iterating over chunks.
+ * This is equivalent to calling `zarrsArrayGetChunkOrigin` and `zarrsArrayGetChunkShape`.
+ * The shape of chunks overhanging the array bounds is not clipped, see `zarrsArrayGetChunkSubset`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunkIndices`, `pSubsetStart`, and `pSubsetShape`.
+ */
+ZarrsResult zarrsArrayGetChunkSubsetFromIndices(ZarrsArray array,
+                                                size_t dimensionality,
+                                                const uint64_t *pChunkIndices,
+                                                uint64_t *pSubsetStart,
+                                                uint64_t *pSubsetShape);
+
+/**
+ * Return the chunks indicating the chunks intersecting `array_subset`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart`, `pSubsetShape`, `pChunksStart`, and `pChunksShape`.
+ */
+ZarrsResult zarrsArrayGetChunksInSubset(ZarrsArray array,
+                                        size_t dimensionality,
+                                        const uint64_t *pSubsetStart,
+                                        const uint64_t *pSubsetShape,
+                                        uint64_t *pChunksStart,
+                                        uint64_t *pChunksShape);
+
+/**
+ * Return the list of chunks intersecting an array subset.
+ *
+ * Unlike `zarrsArrayGetChunksInSubset`, which returns the bounding box of the intersecting chunks, this enumerates every chunk that intersects the subset.
+ * `pChunkCount` is set to the number of intersecting chunks, which is 0 if the subset is empty.
+ * `ppChunkIndicesList` is set to a buffer of `pChunkCount * dimensionality` chunk indices, with one row of `dimensionality` indices per chunk.
+ * Chunks are in C order (the last dimension varies fastest).
+ * The buffer must be freed with `zarrsFreeBytes`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array`, `pChunkCount`, or `ppChunkIndicesList` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined (e.g. for an irregular chunk grid).
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * If not null, `pChunkCount` must be a valid pointer to a `size_t` and `ppChunkIndicesList` must be a valid pointer to a `uint64_t*`.
+ */
+ZarrsResult zarrsArrayGetChunksInSubsetList(ZarrsArray array,
+                                            size_t dimensionality,
+                                            const uint64_t *pSubsetStart,
+                                            const uint64_t *pSubsetShape,
+                                            size_t *pChunkCount,
+                                            uint64_t **ppChunkIndicesList);
+
+/**
+ * Return the bounding box of the chunks intersecting an array subset and the number of chunks it contains.
+ *
+ * This is equivalent to `zarrsArrayGetChunksInSubset`, and additionally sets `pChunkCount` to the product of `pChunksShape`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pSubsetStart`, `pSubsetShape`, `pChunksStart`, and `pChunksShape`.
+ * `pChunkCount` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetChunksIntersectingBounds(ZarrsArray array,
+                                                  size_t dimensionality,
+                                                  const uint64_t *pSubsetStart,
+                                                  const uint64_t *pSubsetShape,
+                                                  uint64_t *pChunksStart,
+                                                  uint64_t *pChunksShape,
+                                                  uint64_t *pChunkCount);
+
+/**
+ * Return the number of chunks in the bounding box of the chunks intersecting an array subset.
+ *
+ * This is a count-only equivalent of `zarrsArrayGetChunksIntersectingBounds`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * `pCount` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetChunksIntersectingBoundsCount(ZarrsArray array,
+                                                       size_t dimensionality,
+                                                       const uint64_t *pSubsetStart,
+                                                       const uint64_t *pSubsetShape,
+                                                       uint64_t *pCount);
+
+/**
+ * Return the array subset covered by a range of chunks.
+ *
+ * `pChunksStart` and `pChunksShape` are pointers to arrays of length `dimensionality` holding the start and shape of the chunk range.
+ * `pArrayStart` and `pArrayShape` are set to the start and shape of the array subset covered by the chunks.
+ * The array subset is not clipped to the array shape.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk range extends beyond the chunk grid.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pChunksStart`, `pChunksShape`, `pArrayStart`, and `pArrayShape`.
+ */
+ZarrsResult zarrsArrayGetChunksSubset(ZarrsArray array,
+                                      size_t dimensionality,
+                                      const uint64_t *pChunksStart,
+                                      const uint64_t *pChunksShape,
+                                      uint64_t *pArrayStart,
+                                      uint64_t *pArrayShape);
+
+/**
+ * Get the names of the codecs in the codec pipeline of the array.
+ *
+ * `pCount` is set to the number of codecs.
+ * `ppNames` is set to an array of `pCount` codec names in pipeline order.
+ * The names must be freed with `zarrsFreeStringArray`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if a codec name cannot be converted to a C string.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetCodecNames(ZarrsArray array,
+                                    size_t *pCount,
+                                    char ***ppNames);
+
+/**
+ * Get the consolidated metadata of a group as a JSON string.
+ *
+ * Returns the `consolidated_metadata` field of the group at `rootPath` as written by `zarrsArrayConsolidateMetadata`.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_GROUP` if there is no group at `rootPath`.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the group does not have consolidated metadata.
+ *
+ * # Safety
+ * If not null, `storage` must be a valid `ZarrsStorage` handle.
+ * `pMetadataJson` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetConsolidatedMetadataString(ZarrsStorage storage,
+                                                    const char* rootPath,
+                                                    char **pMetadataJson);
+
+/**
+ * Get the order of the elements within the encoded chunks of an array.
+ *
+ * `pLayoutString` is set to `"C"` (row-major) or `"F"` (column-major), as reported in the `order` of `zarrsArrayGetChunkRepresentationString`.
+ * Chunks and subsets decoded by zarrs are always in C order, irrespective of this layout.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pLayoutString` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the codec metadata cannot be interpreted, or the `transpose` codec permutation is neither C nor F order.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pLayoutString` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetDataLayout(ZarrsArray array,
+                                    char **pLayoutString);
+
+/**
+ * Returns the data type of the array.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetDataType(ZarrsArray array, ZarrsDataType *pDataType);
+
+/**
+ * Check whether the data type of the array is a complex data type.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pIsComplex` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayGetDataTypeIsComplex(ZarrsArray array, bool *pIsComplex);
+
+/**
+ * Check whether the data type of the array is a real floating point data type.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pIsFloat` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayGetDataTypeIsFloat(ZarrsArray array, bool *pIsFloat);
+
+/**
+ * Check whether the data type of the array is a signed or unsigned integer data type.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pIsInteger` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayGetDataTypeIsInteger(ZarrsArray array, bool *pIsInteger);
+
+/**
+ * Check whether the data type of the array is signed.
+ *
+ * Signed integer, floating point, and complex data types are signed.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pIsSigned` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayGetDataTypeIsSigned(ZarrsArray array, bool *pIsSigned);
+
+/**
+ * Returns the decoded (uncompressed) size of the array in bytes.
+ *
+ * This is the number of elements multiplied by the data type size, regardless of which chunks are stored.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the size overflows a `uint64_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetDecodedSizeBytes(ZarrsArray array,
+                                          uint64_t *pBytes);
+
+/**
+ * Get the dimension names of the array.
+ *
+ * `ppNames` is set to an array of `dimensionality` dimension names.
+ * Unnamed dimensions are null, and every dimension is unnamed if the array has no dimension names.
+ * The names must be freed with `zarrsFreeStringArray`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if a dimension name cannot be converted to a C string.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `ppNames` must be a valid pointer to a `char**`.
+ */
+ZarrsResult zarrsArrayGetDimensionNames(ZarrsArray array,
+                                        size_t dimensionality,
+                                        char ***ppNames);
+
+/**
+ * Returns the dimensionality of the array.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetDimensionality(ZarrsArray array, size_t *dimensionality);
+
+/**
+ * Returns the dimensionality and the dimension names of the array.
+ *
+ * This combines `zarrsArrayGetDimensionality` and `zarrsArrayGetDimensionNames` in a single call.
+ * If `ppDimensionNames` is not null, it is set to an array of `*pDimensionality` dimension names, which must be freed with `zarrsFreeStringArray`.
+ * Unnamed dimensions are null, and every dimension is unnamed if the array has no dimension names.
+ * If `ppDimensionNames` is null, this is identical to `zarrsArrayGetDimensionality`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pDimensionality` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if a dimension name cannot be converted to a C string.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * If not null, `pDimensionality` must be a valid pointer to a `size_t` and `ppDimensionNames` must be a valid pointer to a `char**`.
+ */
+ZarrsResult zarrsArrayGetDimensionalityWithNames(ZarrsArray array,
+                                                 size_t *pDimensionality,
+                                                 char ***ppDimensionNames);
+
+/**
+ * Get the encoded size of a chunk in bytes.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * The size is queried from the store without retrieving or decoding the chunk.
+ * `pBytes` is set to zero if the chunk does not exist.
+ *
+ * # Errors
+ * Returns any error of `zarrsArrayGetStoredChunkSize()`.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pChunkIndices` must point to an array of length `dimensionality`.
+ * `pBytes` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetEncodedChunkSizeBytes(ZarrsArray array,
+                                               size_t dimensionality,
+                                               const uint64_t *pChunkIndices,
+                                               uint64_t *pBytes);
+
+/**
+ * Get the metadata of an array extension as a JSON string.
+ *
+ * Extensions that are not `must_understand` include `"must_understand": false`, as they appear in the array metadata.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the array metadata does not have the extension `extensionName`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pExtensionJson` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetExtensionString(ZarrsArray array,
+                                         const char* extensionName,
+                                         bool pretty,
+                                         char **pExtensionJson);
+
+/**
+ * Get the fill value of the array as a JSON string.
+ *
+ * The fill value is encoded as it appears in the array metadata (e.g. `0`, `3.14`, `"NaN"`, `false`).
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the fill value cannot be converted to a JSON string.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pFillValueJson` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetFillValueString(ZarrsArray array,
+                                         char **pFillValueJson);
+
+/**
+ * Fill a subset buffer with the fill value of the array.
+ *
+ * `pSubsetShape` is a pointer to an array of length `dimensionality` holding the shape of the subset.
+ * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ *
+ * This function does not access storage.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the size of the subset overflows a `size_t`.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `subsetBytesCount` does not match the expected size of the subset.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pSubsetShape` must point to an array of length `dimensionality`.
+ * `pSubsetBytes` must point to an array of length `subsetBytesCount`.
+ */
+ZarrsResult zarrsArrayGetFilledSubset(ZarrsArray array,
+                                      size_t dimensionality,
+                                      const uint64_t *pSubsetShape,
+                                      size_t subsetBytesCount,
+                                      uint8_t *pSubsetBytes);
+
+/**
+ * Return the indices of the first chunk of the chunk grid.
+ *
+ * `pChunkIndices` is set to zero in every dimension.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the chunk grid has no chunks.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pChunkIndices` must point to an array of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayGetFirstChunkIndices(ZarrsArray array,
+                                           size_t dimensionality,
+                                           uint64_t *pChunkIndices);
+
+/**
+ * Get a summary of the properties of an array.
+ *
+ * This populates `pInfo` with the dimensionality, shape, data type, chunk grid shape, regular chunk shape, and whether the array is sharded in a single call.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pInfo` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if the array dimensionality exceeds `ZARRS_MAX_DIMS`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pInfo` must be a valid pointer to a `ZarrsArrayInfo`.
+ */
+ZarrsResult zarrsArrayGetInfo(ZarrsArray array,
+                              ZarrsArrayInfo *pInfo);
+
+/**
+ * Return the indices of the last chunk of the chunk grid.
+ *
+ * `pChunkIndices` is set to the chunk grid shape minus one in every dimension.
+ * Iterating from `zarrsArrayGetFirstChunkIndices` to `zarrsArrayGetLastChunkIndices` (inclusive) visits every chunk.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the chunk grid has no chunks.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pChunkIndices` must point to an array of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayGetLastChunkIndices(ZarrsArray array,
+                                          size_t dimensionality,
+                                          uint64_t *pChunkIndices);
+
+/**
+ * Get the maximum size of a chunk of an array in bytes.
+ *
+ * This is the size of the largest buffer passed to `zarrsArrayRetrieveChunk` or `zarrsArrayStoreChunk` for any chunk of the array.
+ * For a regular chunk grid, this is the size of every chunk.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the chunk grid is not regular or rectangular.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the size of a chunk overflows a `size_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pMaxSize` must be a valid pointer to a `size_t`.
+ */
+ZarrsResult zarrsArrayGetMaxChunkSizeBytes(ZarrsArray array,
+                                           size_t *pMaxSize);
+
+/**
+ * Get the store key of the array metadata.
+ *
+ * This is `zarr.json` for Zarr V3 metadata and `.zarray` for Zarr V2 metadata under the array path, e.g. `my/array/zarr.json` for an array at `/my/array`.
+ * Zarr V2 attributes are stored separately in `.zattrs` under the same path.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pKey` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if the array path is not a valid store prefix.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pKey` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetMetadataStorageKey(ZarrsArray array,
+                                            char **pKey);
+
+/**
+ * Get the array metadata as a JSON string.
+ *
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetMetadataString(ZarrsArray array, bool pretty, char **pMetadataString);
+
+/**
+ * Get the array metadata converted to a Zarr V2 `.zarray` JSON string.
+ *
+ * The Zarr V2 metadata of a Zarr V2 array is returned as is.
+ * Attributes are not included, as they are stored separately in `.zattrs` in Zarr V2.
+ * The `dimension_separator` is taken from the chunk key encoding, but note that the `default` chunk key encoding prefixes chunk keys with `c`.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the array uses features that cannot be expressed in Zarr V2 (e.g. sharding, an irregular chunk grid, or an extension data type).
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pMetadataJson` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetMetadataV2String(ZarrsArray array,
+                                          bool pretty,
+                                          char **pMetadataJson);
+
+/**
+ * Returns the `"zarr_format"` of the array metadata.
+ *
+ * Unlike `zarrsArrayGetZarrFormat`, this reads the raw value of the `"zarr_format"` field of the serialized metadata rather than deriving it from the metadata variant.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the metadata does not have a `"zarr_format"` that is a positive integer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pVersion` must be a valid pointer to a `uint32_t`.
+ */
+ZarrsResult zarrsArrayGetMetadataVersion(ZarrsArray array,
+                                         uint32_t *pVersion);
+
+/**
+ * Returns the number of chunks in the chunk grid.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of chunks overflows a `uint64_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetNumChunks(ZarrsArray array, uint64_t *pNumChunks);
+
+/**
+ * Returns the number of elements in the array.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of elements overflows a `uint64_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayGetNumElements(ZarrsArray array, uint64_t *pNumElements);
+
+/**
+ * Get the OME-Zarr axes of an array as a JSON string.
+ *
+ * The axes are read from the first `multiscales` entry.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the attributes do not contain OME-Zarr multiscales with axes.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pAxesJson` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetOMEZarrAxesString(ZarrsArray array,
+                                           bool pretty,
+                                           char **pAxesJson);
+
+/**
+ * Get the OME-Zarr version of an array.
+ *
+ * The version is read from `ome.version` (OME-Zarr 0.5) or the `version` of the first `multiscales` entry (OME-Zarr 0.4 and earlier).
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the attributes do not contain OME-Zarr metadata with a version.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pVersion` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetOMEZarrVersion(ZarrsArray array,
+                                        char **pVersion);
+
+/**
+ * Get the recommended concurrency for retrieving or storing a subset of an array.
+ *
+ * `pSubsetShape` is a pointer to an array of length `dimensionality` holding the shape of the subset.
+ * `pChunkConcurrency` is set to the number of chunks zarrs would process concurrently for a subset of this shape at the array origin.
+ * `pCodecConcurrency` is set to the concurrency zarrs would use within the codecs of each chunk, including the inner chunks of a sharding codec.
+ * These are derived from the codec chain and the global concurrency target, and are informational only.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the codec concurrency cannot be determined.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the array pointed to by `pSubsetShape`.
+ */
+ZarrsResult zarrsArrayGetRecommendedConcurrency(ZarrsArray array,
+                                                size_t dimensionality,
+                                                const uint64_t *pSubsetShape,
+                                                size_t *pChunkConcurrency,
+                                                size_t *pCodecConcurrency);
+
+/**
+ * Get the chunk shape of a regular chunk grid.
+ *
+ * `pChunkShape` is a pointer to an array of length `dimensionality` in which the `chunk_shape` of the regular chunk grid configuration is returned.
+ * This is the shape returned by `zarrsArrayGetChunkShape` for the first chunk, but it is read from the chunk grid metadata without reference to any chunk indices.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_CHUNK_GRID_SHAPE` if the array does not have a regular chunk grid.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pChunkShape` must point to an array of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayGetRegularChunkShape(ZarrsArray array,
+                                           size_t dimensionality,
+                                           uint64_t *pChunkShape);
+
+/**
+ * Returns the shape of the array.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pShape`.
+ */
+ZarrsResult zarrsArrayGetShape(ZarrsArray array,
+                               size_t dimensionality,
+                               uint64_t *pShape);
+
+/**
+ * Returns the number of elements in the array, the product of its shape.
+ *
+ * This does not access storage.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of elements overflows a `uint64_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pTotalElements` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetShapeAsProduct(ZarrsArray array, uint64_t *pTotalElements);
+
+/**
+ * Retrieve a single element from an array.
+ *
+ * `pIndices` is a pointer to an array of length `dimensionality` holding the element indices.
+ * `pElementBytes` is a pointer to an array of bytes of length `elementBytesCount` that must match the size of the data type.
+ *
+ * The chunk containing the element is retrieved and decoded, so this is no more efficient than chunk or subset retrieval.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the element indices are out of bounds.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `elementBytesCount` does not match the size of the data type.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pIndices` must point to an array of length `dimensionality`.
+ * `pElementBytes` must point to an array of length `elementBytesCount`.
+ */
+ZarrsResult zarrsArrayGetSingleElement(ZarrsArray array,
+                                       size_t dimensionality,
+                                       const uint64_t *pIndices,
+                                       size_t elementBytesCount,
+                                       uint8_t *pElementBytes);
+
+/**
+ * Retrieve a single element from an array as a `double`.
+ *
+ * `pIndices` is a pointer to an array of length `dimensionality` holding the element indices.
+ * Boolean, integer, and floating point (including `float16` and `bfloat16`) elements are converted to a `double`.
+ * 64-bit integers with a magnitude above 2^53 lose precision.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type cannot be converted to a `double`.
+ * - Returns any error of `zarrsArrayGetSingleElement()`.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pIndices` must point to an array of length `dimensionality`.
+ * `pValue` must be a valid pointer to a `double`.
+ */
+ZarrsResult zarrsArrayGetSingleElementFloat64(ZarrsArray array,
+                                              size_t dimensionality,
+                                              const uint64_t *pIndices,
+                                              double *pValue);
+
+/**
+ * Get the capabilities of the storage underlying the array.
+ *
+ * `pFlags` is set to a bitfield of `ZarrsStorageCapabilityFlags`.
+ * These are the capabilities retained by the array handle, which may be fewer than those of the storage it was opened with.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pFlags` must be a valid pointer to a `uint32_t`.
+ */
+ZarrsResult zarrsArrayGetStorageCapabilityFlags(ZarrsArray array,
+                                                uint32_t *pFlags);
+
+/**
+ * Get the cumulative I/O statistics of an array handle.
+ *
+ * Statistics are accumulated since the array handle was created or last reset with `zarrsArrayResetStorageStats()`.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pStats` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pStats` must be a valid pointer to a `ZarrsStorageStats`.
+ */
+ZarrsResult zarrsArrayGetStorageStats(ZarrsArray array,
+                                      ZarrsStorageStats *pStats);
+
+/**
+ * Get the storage transformers of the array as a JSON string.
+ *
+ * `pJson` is set to the `storage_transformers` metadata array, which is empty if the array has no storage transformers.
+ * Zarr V2 arrays do not support storage transformers and always return an empty array.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the storage transformers cannot be converted to a JSON string.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pJson` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetStorageTransformersString(ZarrsArray array,
+                                                   char **pJson);
+
+/**
+ * Get the total size in bytes of the encoded chunks stored in the store, which may be approximate.
+ *
+ * `pIsExact` is set to false if the size is an estimate, which may be the case for storage backends that only track the approximate size of a prefix.
+ * The storage backends currently supported by zarrs_ffi compute the size of a prefix exactly, so the size is currently always exact and equal to `zarrsArrayGetStoredChunkBytesTotal`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read and list capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pTotalBytes` must be a valid pointer to a `uint64_t`.
+ * `pIsExact` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayGetStoredChunkBytesFast(ZarrsArray array,
+                                              uint64_t *pTotalBytes,
+                                              bool *pIsExact);
+
+/**
+ * Get the total size in bytes of the encoded chunks stored in the store.
+ *
+ * This is equal to `zarrsArrayGetTotalStoredSizeBytes`, but the size of the array prefix is queried from the storage, which can be cheaper than listing and querying every key for storage backends that track the total size of a prefix.
+ * Otherwise, the storage falls back to listing every key under the array prefix.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read and list capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pTotalBytes` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetStoredChunkBytesTotal(ZarrsArray array,
+                                               uint64_t *pTotalBytes);
+
+/**
+ * Get the number of chunks stored in the store.
+ *
+ * The array prefix is listed one directory at a time and the keys that are chunk keys of the array (per its chunk key encoding and chunk grid) are counted.
+ * Metadata, other keys, and child nodes under the array prefix are not counted.
+ * The keys of the whole array are not collected at once, but every directory under the array prefix is listed.
+ * For a sharded array, this is the number of stored shards, see `zarrsArrayGetStoredInnerChunkCount` to count the stored inner chunks.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have list capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be listed.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pCount` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetStoredChunkCount(ZarrsArray array,
+                                          uint64_t *pCount);
+
+/**
+ * Get the number of chunks stored in the store, which may be approximate.
+ *
+ * This is equal to `zarrsArrayGetStoredChunkCount`, except that the traversal of the array prefix stops after 1024 directories have been listed.
+ * `pIsExact` is set to false if the traversal stopped early, in which case the count is a lower bound on the number of stored chunks.
+ * This bounds the number of listing requests made to storage backends where each directory listing is a paginated request (e.g. object storage).
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have list capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be listed.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pCount` must be a valid pointer to a `uint64_t`.
+ * `pIsExact` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayGetStoredChunkCountApproximate(ZarrsArray array,
+                                                     uint64_t *pCount,
+                                                     bool *pIsExact);
+
+/**
+ * Get the stored (encoded) size of a chunk in bytes.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * The size is queried from the store without retrieving the chunk.
+ * If the chunk does not exist, `pExists` is set to `false` and `pSizeBytes` is set to zero.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk indices are invalid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pChunkIndices` must point to an array of length `dimensionality`.
+ * `pSizeBytes` and `pExists` must be valid pointers.
+ */
+ZarrsResult zarrsArrayGetStoredChunkSize(ZarrsArray array,
+                                         size_t dimensionality,
+                                         const uint64_t *pChunkIndices,
+                                         uint64_t *pSizeBytes,
+                                         bool *pExists);
+
+/**
+ * Get the number of inner chunks stored in the store for a sharded array.
+ *
+ * The stored shards are found as in `zarrsArrayGetStoredChunkCount`, and the index of each stored shard is read to count its stored inner chunks.
+ * An inner chunk is stored if its entry in the shard index has a byte offset and size, so inner chunks that were entirely the fill value when the shard was written are not counted.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read and list capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array is not sharded or a shard index could not be read.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be listed.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pCount` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetStoredInnerChunkCount(ZarrsArray array,
+                                               uint64_t *pCount);
+
+/**
+ * Get the inner chunks stored within a shard of a sharded array.
+ *
+ * `pShardIndices` is a pointer to an array of length `dimensionality` holding the shard indices.
+ * `pCount` is set to the number of stored inner chunks in the shard, which is 0 if the shard is not stored.
+ * `ppInnerChunkIndices` is set to a buffer of `pCount * dimensionality` inner chunk indices, with one row of `dimensionality` indices per inner chunk.
+ * The inner chunk indices are in the inner chunk grid of the array (as for `zarrsArrayRetrieveSubChunk`), not relative to the shard, and are in C order.
+ * The buffer must be freed with `zarrsFreeBytes`.
+ *
+ * The stored inner chunks are determined from the shard index alone, which is read through `cache`: an inner chunk is stored if its entry in the shard index has a byte offset and size.
+ * The encoded inner chunks are not retrieved, so a shard whose index is already cached is not read from storage again.
+ * Inner chunks that were entirely the fill value when the shard was written are not stored.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array`, `cache`, `pCount`, or `ppInnerChunkIndices` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pShardIndices` are outside of the chunk grid or `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array is not sharded or the shard cannot be read.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `cache` must be a valid `ZarrsShardIndexCache` handle.
+ * `pShardIndices` must point to an array of length `dimensionality`.
+ * If not null, `pCount` must be a valid pointer to a `size_t` and `ppInnerChunkIndices` must be a valid pointer to a `uint64_t*`.
+ */
+ZarrsResult zarrsArrayGetStoredInnerChunkIndicesForShard(ZarrsArray array,
+                                                         ZarrsShardIndexCache cache,
+                                                         size_t dimensionality,
+                                                         const uint64_t *pShardIndices,
+                                                         size_t *pCount,
+                                                         uint64_t **ppInnerChunkIndices);
+
+/**
+ * Get the number of elements in an inner chunk of a sharded array.
+ *
+ * `pSubChunkIndices` is a pointer to an array of length `dimensionality` holding the inner chunk indices.
+ * Inner chunks on the array boundary have the full inner chunk shape, consistent with `zarrsArrayRetrieveSubChunk`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array is not sharded.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pSubChunkIndices` are outside of the inner chunk grid or `dimensionality` does not match the array dimensionality.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pSubChunkIndices` must point to an array of length `dimensionality`.
+ * `pNumElements` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetSubChunkElements(ZarrsArray array,
+                                          size_t dimensionality,
+                                          const uint64_t *pSubChunkIndices,
+                                          uint64_t *pNumElements);
+
+/**
+ * Get the shape of the inner chunk grid of a sharded array.
+ *
+ * If the array is not sharded, the contents of `pSubChunkGridShape` will equal the standard chunk grid shape.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pSubChunkGridShape`.
+ */
+ZarrsResult zarrsArrayGetSubChunkGridShape(ZarrsArray array,
+                                           size_t dimensionality,
+                                           uint64_t *pSubChunkGridShape);
+
+/**
+ * Get the shape of the inner chunk grid within a shard of a sharded array.
+ *
+ * `pShardIndices` is a pointer to an array of length `dimensionality` holding the shard indices.
+ * `pInnerGridShape` is set to the number of inner chunks along each dimension of the shard that intersect the array.
+ * For shards within the array this is the shard shape divided by the inner chunk shape.
+ * For shards on the array boundary, inner chunks entirely outside of the array are excluded, so the shape may be smaller.
+ * Inner chunks that are partially outside of the array are included.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pShardIndices` are outside of the chunk grid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array is not sharded.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pShardIndices` and `pInnerGridShape`.
+ */
+ZarrsResult zarrsArrayGetSubChunkGridShapeForShard(ZarrsArray array,
+                                                   size_t dimensionality,
+                                                   const uint64_t *pShardIndices,
+                                                   uint64_t *pInnerGridShape);
+
+/**
+ * Get the inner chunk shape for a sharded array.
+ *
+ * `pIsSharded` is set to true if the array is sharded, otherwise false.
+ * If the array is not sharded, the contents of `pSubChunkShape` will be undefined.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkShape`.
+ */
+ZarrsResult zarrsArrayGetSubChunkShape(ZarrsArray array,
+                                       size_t dimensionality,
+                                       bool *pIsSharded,
+                                       uint64_t *pSubChunkShape);
+
+/**
+ * Get the size of an inner chunk of a sharded array in bytes.
+ *
+ * `pSubChunkIndices` is a pointer to an array of length `dimensionality` holding the inner chunk indices.
+ * Inner chunks on the array boundary have the full inner chunk shape, consistent with `zarrsArrayRetrieveSubChunk`.
+ * Use this to allocate the buffer passed to `zarrsArrayRetrieveSubChunk`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array is not sharded.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pSubChunkIndices` are outside of the inner chunk grid or `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pSubChunkIndices` must point to an array of length `dimensionality`.
+ * `pSubChunkSize` must be a valid pointer to a `usize`.
+ */
+ZarrsResult zarrsArrayGetSubChunkSize(ZarrsArray array,
+                                      size_t dimensionality,
+                                      const uint64_t *pSubChunkIndices,
+                                      size_t *pSubChunkSize);
+
+/**
+ * Retrieve a subset from an array as a JSON string.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * `pJsonString` is set to a JSON array of the subset elements, with one level of nesting per dimension.
+ * Non-finite floats are represented as the strings `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * This function is intended for diagnostics.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length or cannot be converted to JSON.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the subset could not be retrieved.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * `pJsonString` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsArrayGetSubsetAsJSON(ZarrsArray array,
+                                      size_t dimensionality,
+                                      const uint64_t *pSubsetStart,
+                                      const uint64_t *pSubsetShape,
+                                      char **pJsonString);
+
+/**
+ * Get the byte offset of an element in a C order buffer holding the entire array.
+ *
+ * `pSubsetStart` is a pointer to an array of length `dimensionality` holding the element indices, such as the start of a subset.
+ * `pByteOffset` is set to the byte offset of the element in a buffer holding the entire decoded array.
+ * This is a pure computation and does not access storage.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pByteOffset` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pSubsetStart` is out of bounds of the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the byte offset overflows a `uint64_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pByteOffset` must be a valid pointer to a `uint64_t`.
+ * `pSubsetStart` must point to an array of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayGetSubsetByteOffsetInArray(ZarrsArray array,
+                                                 size_t dimensionality,
+                                                 const uint64_t *pSubsetStart,
+                                                 uint64_t *pByteOffset);
+
+/**
+ * Return the number of chunks intersecting an array subset.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of chunks overflows a `size_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ */
+ZarrsResult zarrsArrayGetSubsetChunkCount(ZarrsArray array,
+                                          size_t dimensionality,
+                                          const uint64_t *pSubsetStart,
+                                          const uint64_t *pSubsetShape,
+                                          size_t *pCount);
+
+/**
+ * Return the indices of every chunk intersecting an array subset.
+ *
+ * `pChunkCount` is set to the number of intersecting chunks.
+ * `ppChunkIndices` is set to a buffer of `pChunkCount * dimensionality` chunk indices, with one row of `dimensionality` indices per chunk.
+ * Chunks are in C order (the last dimension varies fastest).
+ * The buffer must be freed with `zarrsFreeBytes`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_INTERSECTING_CHUNKS` if the intersecting chunks cannot be determined.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of chunk indices overflows a `size_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ */
+ZarrsResult zarrsArrayGetSubsetChunkIndices(ZarrsArray array,
+                                            size_t dimensionality,
+                                            const uint64_t *pSubsetStart,
+                                            const uint64_t *pSubsetShape,
+                                            size_t *pChunkCount,
+                                            uint64_t **ppChunkIndices);
+
+/**
+ * Get the intersection of a subset with the array bounds.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively, which may extend past the array shape.
+ * `pClippedStart` and `pClippedShape` are pointers to arrays of length `dimensionality` in which the start and shape of the intersection are returned.
+ * If the intersection is empty, every element of `pClippedShape` is set to zero.
+ * The end of the subset saturates rather than overflows, so the clipped subset is always valid for `zarrsArrayRetrieveSubset` and `zarrsArrayStoreSubset`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pSubsetStart`, `pSubsetShape`, `pClippedStart`, and `pClippedShape` must point to arrays of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayGetSubsetIntersection(ZarrsArray array,
+                                            size_t dimensionality,
+                                            const uint64_t *pSubsetStart,
+                                            const uint64_t *pSubsetShape,
+                                            uint64_t *pClippedStart,
+                                            uint64_t *pClippedShape);
+
+/**
+ * Get the size of a subset in bytes.
+ *
+ * `pSubsetShape` is a pointer to an array of length `dimensionality` holding the shape of the subset.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pSubsetShape`.
+ */
+ZarrsResult zarrsArrayGetSubsetSize(ZarrsArray array,
+                                    size_t dimensionality,
+                                    const uint64_t *pSubsetShape,
+                                    size_t *subsetSize);
+
+/**
+ * Get the number of elements in a subset.
+ *
+ * `pSubsetShape` is a pointer to an array of length `dimensionality` holding the shape of the subset.
+ * Unlike `zarrsArrayGetSubsetSize`, which returns the size of a subset in bytes, this is independent of the data type.
+ * This does not access storage.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the number of elements overflows a `uint64_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pSubsetShape` must point to an array of length `dimensionality`.
+ * `pNumElements` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetSubsetSizeElements(ZarrsArray array,
+                                            size_t dimensionality,
+                                            const uint64_t *pSubsetShape,
+                                            uint64_t *pNumElements);
+
+/**
+ * Get the element strides of a C order buffer holding a subset of an array.
+ *
+ * For dimension `i`, the stride is the product of the subset shape along dimensions `i+1` through `N-1`.
+ * `pSubsetShape` is a pointer to an array of length `dimensionality` holding the subset shape.
+ * `pSubsetStride` is a pointer to an array of length `dimensionality` in which the strides are returned.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if a stride overflows a `uint64_t`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pSubsetShape` and `pSubsetStride` must point to arrays of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayGetSubsetStride(ZarrsArray array,
+                                      size_t dimensionality,
+                                      const uint64_t *pSubsetShape,
+                                      uint64_t *pSubsetStride);
+
+/**
+ * Get the total size in bytes of the encoded chunks stored in the store.
+ *
+ * Keys under the array prefix are listed, excluding metadata, and their sizes are summed.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read and list capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be queried.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pBytes` must be a valid pointer to a `uint64_t`.
+ */
+ZarrsResult zarrsArrayGetTotalStoredSizeBytes(ZarrsArray array,
+                                              uint64_t *pBytes);
+
+/**
+ * Get the permutation order of the `transpose` codec in the codec pipeline of the array.
+ *
+ * `pHasTranspose` is set to true if the codec pipeline includes a `transpose` codec, otherwise false.
+ * If the codec pipeline does not include a `transpose` codec, the contents of `pPermutation` will be undefined.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the `transpose` codec configuration cannot be interpreted.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pPermutation`.
+ */
+ZarrsResult zarrsArrayGetTransposeCodecOrder(ZarrsArray array,
+                                             size_t dimensionality,
+                                             size_t *pPermutation,
+                                             bool *pHasTranspose);
+
+/**
+ * Returns the Zarr format (2 or 3) of the array metadata.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pVersion` must be a valid pointer to a `uint32_t`.
+ */
+ZarrsResult zarrsArrayGetZarrFormat(ZarrsArray array, uint32_t *pVersion);
+
+/**
+ * Returns the Zarr version (2 or 3) of the array metadata.
+ *
+ * This is equivalent to `zarrsArrayGetZarrFormat`.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pVersion` must be a valid pointer to a `uint32_t`.
+ */
+ZarrsResult zarrsArrayGetZarrVersion(ZarrsArray array, uint32_t *pVersion);
+
+/**
+ * Check whether the array metadata has an extension.
+ *
+ * Extensions are additional top-level fields in the array metadata (see `zarrsArraySetAdditionalField`).
+ * `pHas` is set to true if the array metadata has a field named `extensionName` that is not a core metadata field, otherwise false.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pHas` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayHasExtension(ZarrsArray array,
+                                   const char* extensionName,
+                                   bool *pHas);
+
+/**
+ * Check if an array has no stored chunks.
+ *
+ * Unlike `zarrsArrayGetStoredChunkCount`, this does not list every key under the array prefix.
+ * The store is listed one directory at a time and the check stops as soon as a stored chunk is found.
+ * As in `zarrsArrayGetStoredChunkCount`, only chunk keys of the array are considered, so an array with only child nodes or other keys under its prefix is empty.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have list capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be listed.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pEmpty` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayIsEmpty(ZarrsArray array,
+                              bool *pEmpty);
+
+/**
+ * Check whether the intersection of a subset with the array bounds is empty.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * `pIsEmpty` is set to true if the subset has a zero-length dimension or lies entirely outside of the array shape, i.e. if `zarrsArrayGetSubsetIntersection` returns an empty intersection.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pIsEmpty` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pIsEmpty` must be a valid pointer to a `bool`.
+ * `pSubsetStart` and `pSubsetShape` must point to arrays of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayIsSubsetEmpty(ZarrsArray array,
+                                    size_t dimensionality,
+                                    const uint64_t *pSubsetStart,
+                                    const uint64_t *pSubsetShape,
+                                    bool *pIsEmpty);
+
+/**
+ * Remove a single top-level array attribute.
+ *
+ * `pRemoved` is set to true if `key` was present and has been removed, otherwise false.
+ * This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pRemoved` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayRemoveAttribute(ZarrsArray array, const char* key, bool *pRemoved);
+
+/**
+ * Reset the cumulative I/O statistics of an array handle to zero.
+ *
+ * The statistics of the storage handle are not reset.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayResetStorageStats(ZarrsArray array);
+
+/**
+ * Retrieve a chunk from an array.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
+ * The chunk is decoded directly into `pChunkBytes` without an intermediate copy.
+ *
+ * # Errors
+ * Returns an error if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ * `pChunkBytes` must point to an array of length `chunkBytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveChunk(ZarrsArray array,
+                                    size_t dimensionality,
+                                    const uint64_t *pChunkIndices,
+                                    size_t chunkBytesCount,
+                                    uint8_t *pChunkBytes);
+
+/**
+ * Retrieve a chunk from an array into a buffer allocated by zarrs.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `ppBytes` is set to a buffer holding the decoded chunk and `pBytesLen` is set to its length in bytes.
+ * This avoids querying the chunk size with `zarrsArrayGetChunkSize()` and allocating the output buffer in advance.
+ *
+ * The buffer must be freed with `zarrsFreeBytes`, never with `free()`.
+ * Use `zarrsArrayRetrieveChunk` to decode into a buffer owned by the caller.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk indices are invalid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ * `ppBytes` must be a valid pointer to a `uint8_t*` and `pBytesLen` must be a valid pointer to a `size_t`.
+ */
+ZarrsResult zarrsArrayRetrieveChunkAlloc(ZarrsArray array,
+                                         size_t dimensionality,
+                                         const uint64_t *pChunkIndices,
+                                         uint8_t **ppBytes,
+                                         size_t *pBytesLen);
+
+/**
+ * Retrieve a chunk from an array using a chunk cache.
+ *
+ * This is equivalent to `zarrsArrayRetrieveChunk`, except that the chunk is taken from `cache` if present, and otherwise it is retrieved and inserted into `cache`.
+ * Cache lookups are recorded in the `cacheHits` and `cacheMisses` storage statistics, and only cache misses of a decoded chunk cache count towards `chunksDecoded`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `cache` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are not valid chunk indices.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `chunkBytesCount` does not match the size of the chunk.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `cache` was created for a different array handle or the chunk cannot be retrieved.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `cache` must be a valid `ZarrsChunkCache` handle.
+ * `pChunkIndices` must point to an array of length `dimensionality`.
+ * `pChunkBytes` must point to an array of length `chunkBytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveChunkCached(ZarrsArray array,
+                                          ZarrsChunkCache cache,
+                                          size_t dimensionality,
+                                          const uint64_t *pChunkIndices,
+                                          size_t chunkBytesCount,
+                                          uint8_t *pChunkBytes);
+
+/**
+ * Retrieve a chunk from an array if it exists.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
+ *
+ * `pExists` is set to true if the chunk exists and has been decoded into `pChunkBytes`.
+ * If the chunk does not exist, `pExists` is set to false and `pChunkBytes` is left untouched, rather than being filled with the fill value as in `zarrsArrayRetrieveChunk`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if the chunk exists and `chunkBytesCount` does not match its decoded size.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the chunk indices are invalid or the chunk cannot be retrieved or decoded.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ * `pChunkBytes` must point to an array of length `chunkBytesCount`.
+ * `pExists` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsArrayRetrieveChunkIfExists(ZarrsArray array,
+                                            size_t dimensionality,
+                                            const uint64_t *pChunkIndices,
+                                            size_t chunkBytesCount,
+                                            uint8_t *pChunkBytes,
+                                            bool *pExists);
+
+/**
+ * Retrieve a chunk from an array with codec options.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
+ * The chunk is decoded directly into `pChunkBytes` without an intermediate copy.
+ * If `codecOptions` is null, the default codec options are used.
+ *
+ * # Errors
+ * Returns an error if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ * `pChunkBytes` must point to an array of length `chunkBytesCount`.
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ */
+ZarrsResult zarrsArrayRetrieveChunkOpt(ZarrsArray array,
+                                       size_t dimensionality,
+                                       const uint64_t *pChunkIndices,
+                                       size_t chunkBytesCount,
+                                       uint8_t *pChunkBytes,
+                                       ZarrsCodecOptions codecOptions);
+
+/**
+ * Retrieve a sub-region of a chunk into a position within a larger buffer.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pSubsetStartInChunk` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the start (relative to the chunk origin) and shape of the sub-region.
+ *
+ * The sub-region is written to `pBuffer` as rows spanning its last dimension, in C order.
+ * Row `r` is written at byte offset `bufferOffset + r * bufferStride`, so `bufferStride` is the number of bytes between the starts of consecutive rows.
+ * Bytes of `pBuffer` between rows are left unchanged, which allows tiles to be composited into a larger image without a temporary buffer.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk indices are invalid or `pSubsetStartInChunk + pSubsetShape` exceeds the chunk shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bufferStride` is less than the length of a row or the rows exceed `bufferBytesCount`.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pChunkIndices`, `pSubsetStartInChunk`, and `pSubsetShape`.
+ * `pBuffer` must point to an array of length `bufferBytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveChunkSubsetIntoBuffer(ZarrsArray array,
+                                                    size_t dimensionality,
+                                                    const uint64_t *pChunkIndices,
+                                                    const uint64_t *pSubsetStartInChunk,
+                                                    const uint64_t *pSubsetShape,
+                                                    size_t bufferStride,
+                                                    size_t bufferOffset,
+                                                    size_t bufferBytesCount,
+                                                    uint8_t *pBuffer);
+
+/**
+ * Retrieve a rectangular region of chunks from an array.
+ *
+ * `pChunksStart` and `pChunksShape` are pointers to arrays of length `dimensionality` holding the start and shape of the chunk range.
+ * `pBytes` is a pointer to an array of bytes of length `bytesCount` that is filled with the decoded data of the array subset covered by the chunks in C order.
+ * `bytesCount` must match the size of the array subset returned by `zarrsArrayGetChunksSubset()` (its number of elements multiplied by the data type size).
+ *
+ * The chunks are retrieved and decoded in parallel.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk range extends beyond the chunk grid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the decoded size of the chunks.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the chunks cannot be retrieved or decoded.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunksStart` and `pChunksShape`.
+ * `pBytes` must point to an array of length `bytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveChunks(ZarrsArray array,
+                                     size_t dimensionality,
+                                     const uint64_t *pChunksStart,
+                                     const uint64_t *pChunksShape,
+                                     size_t bytesCount,
+                                     uint8_t *pBytes);
+
+/**
+ * Retrieve a batch of chunks from an array into separate buffers.
+ *
+ * `pChunkIndicesFlat` is a pointer to an array of length `numChunks * dimensionality` holding the indices of each chunk contiguously.
+ * `pChunkBuffers` is a pointer to an array of `numChunks` output buffers, and `pChunkBufferLens` holds their lengths.
+ * The length of each buffer must match the size of its chunk as returned by `zarrsArrayGetChunkSize()`.
+ * `pPerChunkResults` is a pointer to an array of length `numChunks` that is set to the result of retrieving each chunk, as if by `zarrsArrayRetrieveChunk`.
+ *
+ * The chunks are retrieved in parallel, and a chunk that fails to be retrieved does not prevent the others from being retrieved.
+ * If more than one chunk fails, the last error describes one of the failures.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality, in which case no chunks are retrieved.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_PARTIAL_FAILURE` if any chunk could not be retrieved, check `pPerChunkResults` for the result of each chunk.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pChunkIndicesFlat` must point to an array of length `numChunks * dimensionality`.
+ * `pChunkBuffers`, `pChunkBufferLens`, and `pPerChunkResults` must point to arrays of length `numChunks`.
+ * Each element of `pChunkBuffers` must point to an array of the corresponding length in `pChunkBufferLens`, and the buffers must not overlap.
+ */
+ZarrsResult zarrsArrayRetrieveChunksBatch(ZarrsArray array,
+                                          size_t dimensionality,
+                                          size_t numChunks,
+                                          const uint64_t *pChunkIndicesFlat,
+                                          uint8_t *const *pChunkBuffers,
+                                          const size_t *pChunkBufferLens,
+                                          ZarrsResult *pPerChunkResults);
+
+/**
+ * Retrieve elements from an array by orthogonal (outer) indexing.
+ *
+ * `pIndexLists` is a pointer to an array of length `dimensionality` holding a pointer to the list of selected indices along each dimension, and `pIndexListLens` holds the length of each list.
+ * The selected elements are the outer product of the index lists, written to `pBytes` in C order, i.e. `pBytes` holds an array with shape `pIndexListLens`.
+ * Index lists do not need to be sorted and may contain duplicates, and the output follows the order of the index lists.
+ * `pBytes` is a pointer to an array of bytes of length `bytesCount` that must match the size of the selected elements.
+ *
+ * Requested indices are grouped by chunk, so each chunk containing selected elements is retrieved exactly once and other chunks are not retrieved.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if an index is out of bounds, and the last error names the dimension.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the size of the selected elements.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pIndexLists` and `pIndexListLens`.
+ * Each `pIndexLists[i]` must point to an array of length `pIndexListLens[i]`.
+ * `pBytes` must point to an array of length `bytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveOrthogonal(ZarrsArray array,
+                                         size_t dimensionality,
+                                         const uint64_t *const *pIndexLists,
+                                         const size_t *pIndexListLens,
+                                         size_t bytesCount,
+                                         uint8_t *pBytes);
+
+/**
+ * Retrieve an inner chunk from a sharded array (or outer chunk for an unsharded array).
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetSubChunkSize()`.
+ *
+ * # Errors
+ * Returns an error if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ */
+ZarrsResult zarrsArrayRetrieveSubChunk(ZarrsArray array,
+                                       ZarrsShardIndexCache cache,
+                                       size_t dimensionality,
+                                       const uint64_t *pChunkIndices,
+                                       size_t chunkBytesCount,
+                                       uint8_t *pChunkBytes);
+
+/**
+ * Retrieve an inner chunk from a sharded array (or outer chunk for an unsharded array) with codec options.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetSubChunkSize()`.
+ * If `codecOptions` is null, the default codec options are used.
+ *
+ * # Errors
+ * Returns an error if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ */
+ZarrsResult zarrsArrayRetrieveSubChunkOpt(ZarrsArray array,
+                                          ZarrsShardIndexCache cache,
+                                          size_t dimensionality,
+                                          const uint64_t *pChunkIndices,
+                                          size_t chunkBytesCount,
+                                          uint8_t *pChunkBytes,
+                                          ZarrsCodecOptions codecOptions);
+
+/**
+ * Retrieve a subset from an array.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
+ * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ */
+ZarrsResult zarrsArrayRetrieveSubset(ZarrsArray array,
+                                     size_t dimensionality,
+                                     const uint64_t *pSubsetStart,
+                                     const uint64_t *pSubsetShape,
+                                     size_t subsetBytesCount,
+                                     uint8_t *pSubsetBytes);
+
+/**
+ * Retrieve a subset from an array into a buffer allocated by zarrs.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * `ppBytes` is set to a buffer holding the decoded subset and `pBytesLen` is set to its length in bytes.
+ * This avoids querying the subset size with `zarrsArrayGetSubsetSize()` and allocating the output buffer in advance.
+ *
+ * The buffer must be freed with `zarrsFreeBytes`, never with `free()`.
+ * Use `zarrsArrayRetrieveSubset` to decode into a buffer owned by the caller.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the subset cannot be retrieved.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * `ppBytes` must be a valid pointer to a `uint8_t*` and `pBytesLen` must be a valid pointer to a `size_t`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetAlloc(ZarrsArray array,
+                                          size_t dimensionality,
+                                          const uint64_t *pSubsetStart,
+                                          const uint64_t *pSubsetShape,
+                                          uint8_t **ppBytes,
+                                          size_t *pBytesLen);
+
+/**
+ * Retrieve a subset from an array, converting the elements to another data type.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * `pBytes` is a pointer to an array of bytes of length `bytesCount` that must match the number of elements in the subset multiplied by the size of `outputType`.
+ * Elements are written in the native endianness.
+ *
+ * Only lossless widening numeric conversions are supported, for example:
+ * - integers to wider integers of the same signedness, or unsigned integers to wider signed integers,
+ * - integers to floating point data types that represent every value exactly (e.g. `uint16` to `float32`, `int32` to `float64`),
+ * - `float16` and `bfloat16` to `float32` or `float64`, and `float32` to `float64`, and
+ * - `complex64` to `complex128`.
+ *
+ * Converting to the data type of the array is equivalent to `zarrsArrayRetrieveSubset`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the elements cannot be losslessly converted to `outputType`.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the size of the converted subset.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `outputType` must be a valid `ZarrsDataType`.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * `pBytes` must point to an array of length `bytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetAs(ZarrsArray array,
+                                       ZarrsDataType outputType,
+                                       size_t dimensionality,
+                                       const uint64_t *pSubsetStart,
+                                       const uint64_t *pSubsetShape,
+                                       size_t bytesCount,
+                                       uint8_t *pBytes);
+
+/**
+ * Retrieve a subset from an array on a background thread.
+ *
+ * This is the asynchronous counterpart of `zarrsArrayRetrieveSubset`.
+ * `pSubsetStart` and `pSubsetShape` are copied, so they only need to be valid for the duration of the call.
+ * `pOp` is a pointer to a handle in which the created `ZarrsOperation` is returned, which must be destroyed with `zarrsOperationDestroy`.
+ *
+ * The retrieval runs on a shared pool of background worker threads, so the calling thread never blocks on storage (including HTTP storage).
+ * The pool has one thread per available CPU and is created on first use.
+ * Operations started while all workers are busy are queued and started in order as workers become free.
+ * When the retrieval finishes, `onDone` (if not null) is invoked exactly once with the result of the retrieval and `userData`:
+ * - `onDone` is always invoked on a worker thread, never on the calling thread.
+ * - `onDone` must not wait for another operation, as that operation may be queued behind it.
+ * - `pSubsetBytes` is fully written before `onDone` is invoked.
+ * - `onDone` returns before `zarrsOperationWait` returns for the operation.
+ * - Callbacks of different operations may be invoked concurrently and in any order.
+ *
+ * If the retrieval fails, the last error is set before `onDone` is invoked, but it may be overwritten by other operations running concurrently.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pOp` is a null pointer, in which case no operation is started and `onDone` is not invoked.
+ * Errors from the retrieval itself (see `zarrsArrayRetrieveSubset`) are passed to `onDone` and returned by `zarrsOperationWait`.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * `pSubsetBytes` must be a valid pointer to an array of bytes of length `subsetBytesCount`.
+ * **`array`, `pSubsetBytes`, and `userData` must remain valid until the operation completes**, i.e. until `onDone` is invoked, `zarrsOperationWait` returns, or `zarrsOperationDestroy` returns.
+ * `pSubsetBytes` must not be accessed by the caller until then.
+ * `onDone` and `userData` must be safe to use from another thread.
+ * If not null, `pOp` must be a valid pointer to a `ZarrsOperation` handle.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetAsync(ZarrsArray array,
+                                          size_t dimensionality,
+                                          const uint64_t *pSubsetStart,
+                                          const uint64_t *pSubsetShape,
+                                          size_t subsetBytesCount,
+                                          uint8_t *pSubsetBytes,
+                                          ZarrsCompletionCallback onDone,
+                                          void *userData,
+                                          ZarrsOperation *pOp);
+
+/**
+ * Retrieve a subset from an array using a chunk cache.
+ *
+ * This is equivalent to `zarrsArrayRetrieveSubset`, except that each chunk intersecting the subset is taken from `cache` if present, and otherwise it is retrieved and inserted into `cache`.
+ * Repeated reads of subsets intersecting the same chunks therefore only retrieve each chunk once while it remains in the cache (and, for a decoded chunk cache, only decode it once).
+ * Cache lookups are recorded in the `cacheHits` and `cacheMisses` storage statistics, and only cache misses of a decoded chunk cache count towards `chunksDecoded`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `cache` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `subsetBytesCount` does not match the size of the subset.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `cache` was created for a different array handle or a chunk cannot be retrieved.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `cache` must be a valid `ZarrsChunkCache` handle.
+ * `pSubsetStart` and `pSubsetShape` must point to arrays of length `dimensionality`.
+ * `pSubsetBytes` must point to an array of length `subsetBytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetCached(ZarrsArray array,
+                                           ZarrsChunkCache cache,
+                                           size_t dimensionality,
+                                           const uint64_t *pSubsetStart,
+                                           const uint64_t *pSubsetShape,
+                                           size_t subsetBytesCount,
+                                           uint8_t *pSubsetBytes);
+
+/**
+ * Retrieve a subset from an array, passing it to a callback one chunk at a time.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * For each chunk intersecting the subset, the intersection is decoded and passed to `callback` along with `userData` (see `ZarrsSubsetChunkCallback`).
+ * This keeps peak memory usage to about one chunk (per thread) regardless of the size of the subset.
+ *
+ * If `concurrent` is false, `callback` is invoked serially on the calling thread with the chunks in C order.
+ * If `concurrent` is true, chunks are decoded in parallel and `callback` may be invoked concurrently from multiple threads in any order, so it and `userData` must be thread safe.
+ * Concurrent callbacks run on the threads of the global rayon thread pool used by the zarrs codecs (which may include the calling thread), not on threads created by the caller.
+ * They must not rely on thread-local state of the calling thread, and must not block waiting for another callback, as it may be queued on the same thread.
+ *
+ * If `callback` returns non-zero, no further callbacks are started and `ZarrsResult::ZARRS_ERROR_ABORTED` is returned.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `callback` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if a chunk cannot be retrieved.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ABORTED` if `callback` returned non-zero.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pSubsetStart` and `pSubsetShape` must point to arrays of length `dimensionality`.
+ * If `concurrent` is true, `callback` must be safe to call concurrently from multiple threads with `userData`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetCallback(ZarrsArray array,
+                                             size_t dimensionality,
+                                             const uint64_t *pSubsetStart,
+                                             const uint64_t *pSubsetShape,
+                                             ZarrsSubsetChunkCallback callback,
+                                             void *userData,
+                                             bool concurrent);
+
+/**
+ * Retrieve a subset from an array into a region of a larger destination buffer.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * `pDstBytes` is a pointer to an array of bytes of length `dstBytesCount` holding elements with shape `pDstShape` in C order.
+ * The subset is decoded directly into the region of the destination starting at `pDstOffset` with shape `pSubsetShape`, without an intermediate buffer.
+ * Elements of the destination outside of the region are not modified.
+ *
+ * This can be used to assemble a mosaic from several subset reads into a single preallocated buffer.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape, or the destination region extends past `pDstShape`.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `dstBytesCount` does not match the size of the destination.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart`, `pSubsetShape`, `pDstShape`, and `pDstOffset`.
+ * `pDstBytes` must point to an array of length `dstBytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetIntoRegion(ZarrsArray array,
+                                               size_t dimensionality,
+                                               const uint64_t *pSubsetStart,
+                                               const uint64_t *pSubsetShape,
+                                               const uint64_t *pDstShape,
+                                               const uint64_t *pDstOffset,
+                                               size_t dstBytesCount,
+                                               uint8_t *pDstBytes);
+
+/**
+ * Retrieve a subset from an array with codec options.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
+ * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ * If `codecOptions` is null, the default codec options are used.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetOpt(ZarrsArray array,
+                                        size_t dimensionality,
+                                        const uint64_t *pSubsetStart,
+                                        const uint64_t *pSubsetShape,
+                                        size_t subsetBytesCount,
+                                        uint8_t *pSubsetBytes,
+                                        ZarrsCodecOptions codecOptions);
+
+/**
+ * Retrieve a subset from an array in C or Fortran order.
+ *
+ * This is equivalent to `zarrsArrayRetrieveSubset`, except that `order` sets the memory layout of `pSubsetBytes`.
+ * With `ZarrsOrder::ZARRS_ORDER_F`, the subset is written in Fortran (column-major) order, where the first dimension is contiguous.
+ * The subset size is independent of the order.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `subsetBytesCount` does not match the size of the subset.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * `order` must be a valid `ZarrsOrder`.
+ * `pSubsetBytes` must point to an array of length `subsetBytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetOrd(ZarrsArray array,
+                                        size_t dimensionality,
+                                        const uint64_t *pSubsetStart,
+                                        const uint64_t *pSubsetShape,
+                                        ZarrsOrder order,
+                                        size_t subsetBytesCount,
+                                        uint8_t *pSubsetBytes);
+
+/**
+ * Retrieve a subset from an array with a concurrency limit.
+ *
+ * `maxConcurrency` caps both the number of chunks decoded in parallel and the parallelism of the codecs for this call only.
+ * If `maxConcurrency` is zero, the default codec options are used, as in `zarrsArrayRetrieveSubset`.
+ * This is a shorthand for `zarrsArrayRetrieveSubsetOpt` with codec options created by `zarrsCreateCodecOptions` and `zarrsCodecOptionsSetMaxConcurrency`.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ *
+ * # Errors
+ * Returns an error if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetParallel(ZarrsArray array,
+                                             size_t maxConcurrency,
+                                             size_t dimensionality,
+                                             const uint64_t *pSubsetStart,
+                                             const uint64_t *pSubsetShape,
+                                             size_t subsetBytesCount,
+                                             uint8_t *pSubsetBytes);
+
+/**
+ * Retrieve a subset from an array, applying a linear scale and offset to the elements.
+ *
+ * Each element is converted to `float64` and transformed to `value * scale + offset`, following the `scale_factor` and `add_offset` attributes of the CF conventions.
+ * Elements equal to the array fill value are written as NaN.
+ * `outputType` must be `ZARRS_FLOAT32` or `ZARRS_FLOAT64`, and elements are written in the native endianness.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * `pBytes` is a pointer to an array of bytes of length `bytesCount` that must match the number of elements in the subset multiplied by the size of `outputType`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if `outputType` is not a floating point data type or the array data type is not a real numeric data type.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the size of the converted subset.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `outputType` must be a valid `ZarrsDataType`.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * `pBytes` must point to an array of length `bytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetScaled(ZarrsArray array,
+                                           double scale,
+                                           double offset,
+                                           ZarrsDataType outputType,
+                                           size_t dimensionality,
+                                           const uint64_t *pSubsetStart,
+                                           const uint64_t *pSubsetShape,
+                                           size_t bytesCount,
+                                           uint8_t *pBytes);
+
+/**
+ * Retrieve a subset from an array, applying the `scale_factor` and `add_offset` attributes to the elements.
+ *
+ * This is equivalent to `zarrsArrayRetrieveSubsetScaled`, with the scale and offset read from the `scale_factor` and `add_offset` array attributes.
+ * If an attribute is absent, the scale defaults to 1 and the offset defaults to 0.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the `scale_factor` or `add_offset` attribute is not a number.
+ * - Returns the errors of `zarrsArrayRetrieveSubsetScaled`.
+ *
+ * # Safety
+ * The safety contract of `zarrsArrayRetrieveSubsetScaled` applies.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetScaledFromAttributes(ZarrsArray array,
+                                                         ZarrsDataType outputType,
+                                                         size_t dimensionality,
+                                                         const uint64_t *pSubsetStart,
+                                                         const uint64_t *pSubsetShape,
+                                                         size_t bytesCount,
+                                                         uint8_t *pBytes);
+
+/**
+ * Retrieve a subset from an array (with a shard index cache).
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
+ * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetSharded(ZarrsArray array,
+                                            ZarrsShardIndexCache cache,
+                                            size_t dimensionality,
+                                            const uint64_t *pSubsetStart,
+                                            const uint64_t *pSubsetShape,
+                                            size_t subsetBytesCount,
+                                            uint8_t *pSubsetBytes);
+
+/**
+ * Retrieve a subset from an array (with a shard index cache) with codec options.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
+ * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ * If `codecOptions` is null, the default codec options are used.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetShardedOpt(ZarrsArray array,
+                                               ZarrsShardIndexCache cache,
+                                               size_t dimensionality,
+                                               const uint64_t *pSubsetStart,
+                                               const uint64_t *pSubsetShape,
+                                               size_t subsetBytesCount,
+                                               uint8_t *pSubsetBytes,
+                                               ZarrsCodecOptions codecOptions);
+
+/**
+ * Retrieve a subset from an array with a step along each dimension.
+ *
+ * `pStart`, `pShapeOut`, and `pStep` are pointers to arrays of length `dimensionality`.
+ * Along each dimension, `pShapeOut[i]` elements are selected starting at `pStart[i]` and separated by `pStep[i]`, e.g. a step of 4 selects every 4th element.
+ * `pBytes` is a pointer to an array of bytes of length `bytesCount` that must match the size of the selected elements (the product of `pShapeOut` multiplied by the data type size).
+ *
+ * Only chunks that contain selected elements are retrieved, so steps larger than the chunk shape skip whole chunks.
+ * If every step is 1, this is equivalent to `zarrsArrayRetrieveSubset`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if a step is zero or a selected element is out of bounds.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the size of the selected elements.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pStart`, `pShapeOut`, and `pStep`.
+ * `pBytes` must point to an array of length `bytesCount`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetStep(ZarrsArray array,
+                                         size_t dimensionality,
+                                         const uint64_t *pStart,
+                                         const uint64_t *pShapeOut,
+                                         const uint64_t *pStep,
+                                         size_t bytesCount,
+                                         uint8_t *pBytes);
+
+/**
+ * Retrieve a subset from an array into a strided buffer.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * `pDstStridesBytes` is a pointer to an array of length `dimensionality` holding the byte stride of each dimension of the destination buffer.
+ * Strides may be negative, in which case the corresponding axis is reversed in the destination buffer.
+ * `pDstBase` is a pointer to the destination buffer of length `dstBufferLen`, which starts at the element with the lowest address.
+ * For example, the first element of the subset (all indices zero) is at `pDstBase` if all strides are positive, and the last row of a 2D buffer with a negative row stride holds the first row of the subset.
+ * Elements are written in the native endianness with the size of the data type.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if any element addressed by the strides is outside the destination buffer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pSubsetStart`, `pSubsetShape`, and `pDstStridesBytes` must point to arrays of length `dimensionality`.
+ * `pDstBase` must point to an array of length `dstBufferLen`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetStrided(ZarrsArray array,
+                                            size_t dimensionality,
+                                            const uint64_t *pSubsetStart,
+                                            const uint64_t *pSubsetShape,
+                                            const int64_t *pDstStridesBytes,
+                                            uint8_t *pDstBase,
+                                            size_t dstBufferLen);
+
+/**
+ * Retrieve a subset from an array with a variable size data type (e.g. `string`).
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * The elements are returned in C order as a buffer of concatenated bytes in `pBytes` with length `pBytesLen`.
+ * For the `string` data type, each element is UTF-8 encoded without a null terminator.
+ * The offsets of the elements are returned in `pOffsets` with length `pOffsetsLen`, which is the number of elements in the subset plus one.
+ * Element `i` spans the bytes from `pOffsets[i]` (inclusive) to `pOffsets[i + 1]` (exclusive).
+ *
+ * The bytes must be freed with `zarrsFreeBytes` and the offsets must be freed with `zarrsFreeOffsets`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a fixed size data type.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the subset cannot be retrieved.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pSubsetStart` and `pSubsetShape` must point to arrays of length `dimensionality`.
+ * `pBytes` and `pOffsets` must be valid pointers to a `uint8_t*` and `uint64_t*` respectively.
+ * `pBytesLen` and `pOffsetsLen` must be valid pointers to a `size_t`.
+ */
+ZarrsResult zarrsArrayRetrieveSubsetVlen(ZarrsArray array,
+                                         size_t dimensionality,
+                                         const uint64_t *pSubsetStart,
+                                         const uint64_t *pSubsetShape,
+                                         uint8_t **pBytes,
+                                         size_t *pBytesLen,
+                                         uint64_t **pOffsets,
+                                         size_t *pOffsetsLen);
+
+/**
+ * Set an additional (extension) top-level field in the array metadata from a JSON string.
+ *
+ * `valueJson` replaces the existing value of the field `name` if present.
+ * If `mustUnderstand` is false, `valueJson` must be a JSON object, and it is written with `"must_understand": false` so that implementations that do not support the extension can still open the array.
+ * If `mustUnderstand` is true, implementations that do not support the extension will refuse to open the array.
+ * This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if:
+ *   - `name` is empty or is a core metadata field (e.g. `shape`, `data_type`, `attributes`),
+ *   - `valueJson` is not valid JSON, or
+ *   - `mustUnderstand` is false and `valueJson` is not a JSON object.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArraySetAdditionalField(ZarrsArray array,
+                                         const char* name,
+                                         const char* valueJson,
+                                         bool mustUnderstand);
+
+/**
+ * Set a single top-level array attribute from a JSON string.
+ *
+ * `valueJson` may be any JSON value, and replaces the existing value of `key` if present.
+ * This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if `valueJson` is not valid JSON.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArraySetAttribute(ZarrsArray array, const char* key, const char* valueJson);
+
+/**
+ * Set the array attributes from a JSON string.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if attributes is not a valid JSON object (map).
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArraySetAttributes(ZarrsArray array,
+                                    const char* attributes);
+
+/**
+ * Set the dimension names of the array.
+ *
+ * `pNames` is a pointer to an array of length `dimensionality` holding the dimension names.
+ * Null entries are unnamed dimensions.
+ * If `pNames` is null, the dimension names are removed.
+ *
+ * This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if a dimension name is not valid UTF-8.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * If not null, `pNames` must point to an array of length `dimensionality` of null or valid C strings.
+ */
+ZarrsResult zarrsArraySetDimensionNames(ZarrsArray array,
+                                        size_t dimensionality,
+                                        const char *const *pNames);
+
+/**
+ * Set the fill value of the array from a JSON string.
+ *
+ * `fillValueJson` uses the same representation as the fill value in the array metadata (see `zarrsArrayGetFillValueString`).
+ * This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+ * The array handle is unchanged if the fill value is invalid.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if `fillValueJson` is not valid JSON or is not a valid fill value for the data type of the array.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArraySetFillValueFromString(ZarrsArray array,
+                                             const char* fillValueJson);
+
+/**
+ * Set the OME-Zarr multiscales of an array.
+ *
+ * `multiscalesJson` is a JSON array of OME-Zarr multiscales.
+ * It is validated against the OME-Zarr multiscales schema before being set as the `multiscales` attribute, under `ome` if present.
+ * The number of axes of each multiscale must match the dimensionality of the array.
+ *
+ * The attributes are only updated in memory, use `zarrsArrayStoreMetadata` to write them.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if `multiscalesJson` is not valid OME-Zarr multiscales metadata.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArraySetOMEZarrMetadata(ZarrsArray array,
+                                         const char* multiscalesJson);
+
+/**
+ * Store a single element in an array.
+ *
+ * `pIndices` is a pointer to an array of length `dimensionality` holding the element indices.
+ * `pElementBytes` is a pointer to an array of bytes of length `elementBytesCount` that must match the size of the data type.
+ *
+ * The chunk containing the element is retrieved, updated, and stored, so this is no more efficient than chunk or subset storage.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the element indices are out of bounds.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `elementBytesCount` does not match the size of the data type.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read/write capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pIndices` must point to an array of length `dimensionality`.
+ * `pElementBytes` must point to an array of length `elementBytesCount`.
+ */
+ZarrsResult zarrsArraySetSingleElement(ZarrsArray array,
+                                       size_t dimensionality,
+                                       const uint64_t *pIndices,
+                                       size_t elementBytesCount,
+                                       const uint8_t *pElementBytes);
+
+/**
+ * Store a single element in an array from a `double`.
+ *
+ * `pIndices` is a pointer to an array of length `dimensionality` holding the element indices.
+ * `value` is converted to the data type of the array, which may be boolean, integer, or floating point (including `float16` and `bfloat16`).
+ * Conversions to integer data types truncate towards zero and saturate at the bounds of the data type.
+ * Conversions to floating point data types round to the nearest representable value.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if a `double` cannot be converted to the data type.
+ * - Returns any error of `zarrsArraySetSingleElement()`.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pIndices` must point to an array of length `dimensionality`.
+ */
+ZarrsResult zarrsArraySetSingleElementFloat64(ZarrsArray array,
+                                              size_t dimensionality,
+                                              const uint64_t *pIndices,
+                                              double value);
+
+/**
+ * Store a chunk.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
+ *
+ * # Errors
+ * Returns an error if the array does not have write capability.
+ *
+ * # Safety
+ * `array`  must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ */
+ZarrsResult zarrsArrayStoreChunk(ZarrsArray array,
+                                 size_t dimensionality,
+                                 const uint64_t *pChunkIndices,
+                                 size_t chunkBytesCount,
+                                 const uint8_t *pChunkBytes);
+
+/**
+ * Store a rectangular region of chunks.
+ *
+ * `pChunksStart` and `pChunksShape` are pointers to arrays of length `dimensionality` holding the start and shape of the chunk range.
+ * `pBytes` is a pointer to an array of bytes of length `bytesCount` holding the data of the array subset covered by the chunks in C order.
+ * `bytesCount` must match the size of the array subset returned by `zarrsArrayGetChunksSubset()` (its number of elements multiplied by the data type size), as for `zarrsArrayRetrieveChunks`.
+ * Chunks on the array boundary are stored in full, so the array subset includes the elements of those chunks outside of the array shape.
+ *
+ * The chunks are split, encoded, and stored in parallel.
+ * Existing chunks in the range are replaced.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if the chunk range extends beyond the chunk grid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `bytesCount` does not match the size of the chunks.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the chunks cannot be encoded or stored.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pChunksStart` and `pChunksShape`.
+ * `pBytes` must point to an array of length `bytesCount`.
+ */
+ZarrsResult zarrsArrayStoreChunks(ZarrsArray array,
+                                  size_t dimensionality,
+                                  const uint64_t *pChunksStart,
+                                  const uint64_t *pChunksShape,
+                                  size_t bytesCount,
+                                  const uint8_t *pBytes);
+
+/**
+ * Store an encoded chunk.
+ *
+ * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
+ * `pEncodedBytes` is a pointer to an array of bytes of length `encodedBytesCount` holding the encoded chunk.
+ *
+ * The encoded bytes are written directly to the store key of the chunk without passing through the codec pipeline of the array.
+ * This is intended for importing chunks that have already been encoded (e.g. compressed) elsewhere.
+ * If `validate` is true, the bytes are decoded once before they are written to check that they are encoded according to the codec pipeline of the array and decode to the chunk shape.
+ * Otherwise, the caller is responsible for ensuring that the bytes are correctly encoded, and incorrectly encoded chunks will fail to decode when they are retrieved.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pChunkIndices` are outside of the chunk grid or `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `validate` is true and the encoded chunk does not decode to the chunk shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the encoded chunk could not be written to the store.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pChunkIndices` must point to an array of length `dimensionality`.
+ * `pEncodedBytes` must point to an array of length `encodedBytesCount`.
+ */
+ZarrsResult zarrsArrayStoreEncodedChunk(ZarrsArray array,
+                                        size_t dimensionality,
+                                        const uint64_t *pChunkIndices,
+                                        size_t encodedBytesCount,
+                                        const uint8_t *pEncodedBytes,
+                                        bool validate);
+
+/**
+ * Store a subset of a shard of a sharded array (or a subset of a chunk of an unsharded array).
+ *
+ * `pOuterChunkIndices` is a pointer to an array of length `dimensionality` holding the shard indices.
+ * `pInnerSubsetStart` and `pInnerSubsetShape` are pointers to arrays of length `dimensionality` holding the start and shape of the subset relative to the start of the shard.
+ * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` holding the elements of the subset in C order.
+ *
+ * The shard is retrieved, the inner chunks intersecting the subset are updated, and the shard is written back.
+ * Inner chunks of the shard that do not intersect the subset are preserved.
+ * This is the write counterpart of `zarrsArrayRetrieveSubsetSharded`.
+ * The index of the shard is dropped from `cache` (and an unbounded cache is cleared), so subsequent reads through `cache` observe the stored subset.
+ *
+ * Storing a subset is not atomic.
+ * Concurrent calls storing subsets of the same shard (through any handle or process) may overwrite each other's inner chunks, so they must be serialised by the caller.
+ * Calls storing subsets of different shards can run concurrently.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `cache` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_INDICES` if `pOuterChunkIndices` are outside of the chunk grid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the shard.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type has a variable size.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `subsetBytesCount` does not match the size of the subset.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read/write capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the subset cannot be stored.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `cache` must be a valid `ZarrsShardIndexCache` handle.
+ * `dimensionality` must match the length of the arrays pointed to by `pOuterChunkIndices`, `pInnerSubsetStart`, and `pInnerSubsetShape`.
+ * `pSubsetBytes` must point to an array of length `subsetBytesCount`.
+ */
+ZarrsResult zarrsArrayStoreInnerChunkSubset(ZarrsArray array,
+                                            ZarrsShardIndexCache cache,
+                                            size_t dimensionality,
+                                            const uint64_t *pOuterChunkIndices,
+                                            const uint64_t *pInnerSubsetStart,
+                                            const uint64_t *pInnerSubsetShape,
+                                            size_t subsetBytesCount,
+                                            const uint8_t *pSubsetBytes);
+
+/**
+ * Store array metadata.
+ *
+ * # Errors
+ * Returns an error if the array does not have write capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayStoreMetadata(ZarrsArray array);
+
+/**
+ * Store array metadata with a target Zarr version.
+ *
+ * `target` selects the Zarr version of the stored metadata:
+ * - `ZARRS_METADATA_KEEP_CURRENT` stores the metadata in its current version, equivalent to `zarrsArrayStoreMetadata`.
+ * - `ZARRS_METADATA_FORCE_V3` stores Zarr V3 metadata (`zarr.json`), converting Zarr V2 metadata.
+ * - `ZARRS_METADATA_FORCE_V2` stores Zarr V2 metadata (`.zarray` and `.zattrs`), converting Zarr V3 metadata as in `zarrsArrayGetMetadataV2String`.
+ *
+ * The array handle itself is not converted, so later calls to `zarrsArrayStoreMetadata` store the metadata in its current version.
+ * If `eraseOtherVersion` is true, the metadata of the other Zarr version is erased after storing (e.g. `.zarray` and `.zattrs` are erased after storing `zarr.json`).
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the metadata cannot be expressed in Zarr V2 (e.g. sharding or an extension data type).
+ *   The last error names the feature that prevents the conversion.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the metadata could not be stored or erased.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `target` must be a valid `ZarrsMetadataVersion`.
+ */
+ZarrsResult zarrsArrayStoreMetadataOpt(ZarrsArray array,
+                                       ZarrsMetadataVersion target,
+                                       bool eraseOtherVersion);
+
+/**
+ * Store an array subset.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
+ * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ *
+ * Storing a subset that partially covers a chunk requires reading the chunk, so arrays with only write capability are limited to chunk-aligned subsets.
+ * A subset is chunk-aligned if it starts on a chunk boundary and ends on a chunk boundary or the array shape along every dimension.
+ * The elements of edge chunks outside of the array shape are then set to the fill value.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability, or only has write capability and the subset is not chunk-aligned.
+ *
+ * # Safety
+ * `array`  must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ */
+ZarrsResult zarrsArrayStoreSubset(ZarrsArray array,
+                                  size_t dimensionality,
+                                  const uint64_t *pSubsetStart,
+                                  const uint64_t *pSubsetShape,
+                                  size_t subsetBytesCount,
+                                  const uint8_t *pSubsetBytes);
+
+/**
+ * Store an array subset with codec options.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
+ * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ *
+ * Storing a subset that partially covers a chunk requires reading the chunk, so arrays with only write capability are limited to chunk-aligned subsets.
+ * A subset is chunk-aligned if it starts on a chunk boundary and ends on a chunk boundary or the array shape along every dimension.
+ * The elements of edge chunks outside of the array shape are then set to the fill value.
+ * If `codecOptions` is null, the default codec options are used.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have write capability, or only has write capability and the subset is not chunk-aligned.
+ *
+ * # Safety
+ * `array`  must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ */
+ZarrsResult zarrsArrayStoreSubsetOpt(ZarrsArray array,
+                                     size_t dimensionality,
+                                     const uint64_t *pSubsetStart,
+                                     const uint64_t *pSubsetShape,
+                                     size_t subsetBytesCount,
+                                     const uint8_t *pSubsetBytes,
+                                     ZarrsCodecOptions codecOptions);
+
+/**
+ * Store an array subset in C or Fortran order.
+ *
+ * This is equivalent to `zarrsArrayStoreSubset`, except that `order` sets the memory layout of `pSubsetBytes`.
+ * With `ZarrsOrder::ZARRS_ORDER_F`, the subset is read in Fortran (column-major) order, where the first dimension is contiguous.
+ * The subset size is independent of the order.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+ * - Returns `ZarrsResult::ZARRS_ERROR_OVERFLOW` if the size of the subset overflows a `size_t`.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if `subsetBytesCount` does not match the size of the subset.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read/write capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * `order` must be a valid `ZarrsOrder`.
+ * `pSubsetBytes` must point to an array of length `subsetBytesCount`.
+ */
+ZarrsResult zarrsArrayStoreSubsetOrd(ZarrsArray array,
+                                     size_t dimensionality,
+                                     const uint64_t *pSubsetStart,
+                                     const uint64_t *pSubsetShape,
+                                     ZarrsOrder order,
+                                     size_t subsetBytesCount,
+                                     const uint8_t *pSubsetBytes);
+
+/**
+ * Store an array subset from a strided buffer.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * `pSrcStridesBytes` is a pointer to an array of length `dimensionality` holding the byte stride of each dimension of the source buffer.
+ * Strides may be negative, in which case the corresponding axis is reversed in the source buffer.
+ * `pSrcBase` is a pointer to the source buffer of length `srcBufferLen`, which starts at the element with the lowest address.
+ * The layout of the source buffer matches the destination buffer of `zarrsArrayRetrieveSubsetStrided()` with the same strides.
+ * Elements are read in the native endianness with the size of the data type.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the data type is variable-length.
+ * - Returns `ZarrsResult::ZARRS_ERROR_BUFFER_LENGTH` if any element addressed by the strides is outside the source buffer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read/write capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ * `pSubsetStart`, `pSubsetShape`, and `pSrcStridesBytes` must point to arrays of length `dimensionality`.
+ * `pSrcBase` must point to an array of length `srcBufferLen`.
+ */
+ZarrsResult zarrsArrayStoreSubsetStrided(ZarrsArray array,
+                                         size_t dimensionality,
+                                         const uint64_t *pSubsetStart,
+                                         const uint64_t *pSubsetShape,
+                                         const int64_t *pSrcStridesBytes,
+                                         const uint8_t *pSrcBase,
+                                         size_t srcBufferLen);
+
+/**
+ * Update the array attributes by merging a JSON string.
+ *
+ * `attributesJson` must be a JSON object, which is merged into the existing attributes following JSON merge patch (RFC 7396) semantics:
+ * - object values are merged recursively into existing object values,
+ * - `null` values remove the key, and
+ * - all other values (including arrays) replace the existing value.
+ *
+ * Attributes not present in `attributesJson` are unchanged.
+ * This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if `attributesJson` is not a valid JSON object (map).
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsArrayUpdateAttributes(ZarrsArray array,
+                                       const char* attributesJson);
+
+/**
+ * Replace the shape of the array in memory without validation.
+ *
+ * This is intended for expert use, such as updating the shape to reflect data that has already been written with a larger extent.
+ * Chunks outside of the new shape are not erased, and the new shape is not checked against the chunks already written.
+ * This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the chunk grid does not support the new shape (e.g. it exceeds the extent of a rectangular chunk grid).
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pNewShape` must point to an array of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayUpdateShape(ZarrsArray array,
+                                  size_t dimensionality,
+                                  const uint64_t *pNewShape);
+
+/**
+ * Replace the shape of the array in memory, checking that it covers the current chunk grid.
+ *
+ * The new shape must not reduce the number of chunks along any dimension, so every chunk in the current chunk grid remains within the array.
+ * The array is unchanged on failure.
+ * This only updates the array metadata in memory, call `zarrsArrayStoreMetadata` to persist it.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the new shape would reduce the chunk grid shape, or the chunk grid does not support the new shape.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pNewShape` must point to an array of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayUpdateShapeChecked(ZarrsArray array,
+                                         size_t dimensionality,
+                                         const uint64_t *pNewShape);
+
+/**
+ * Validate that an array subset is within the bounds of an array.
+ *
+ * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the subset start and shape respectively.
+ * This applies the same validation as `zarrsArrayRetrieveSubset` and `zarrsArrayStoreSubset`, and does not access storage.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INCOMPATIBLE_DIMENSIONALITY` if `dimensionality` does not match the array dimensionality.
+ * - Returns `ZarrsResult::ZARRS_ERROR_SUBSET_OUT_OF_BOUNDS` if the subset extends past the array shape (or its end overflows a `uint64_t`), and the last error names the offending dimension.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pSubsetStart` and `pSubsetShape` must point to arrays of length `dimensionality`.
+ */
+ZarrsResult zarrsArrayValidateSubset(ZarrsArray array,
+                                     size_t dimensionality,
+                                     const uint64_t *pSubsetStart,
+                                     const uint64_t *pSubsetShape);
+
+/**
+ * Evict all chunks from a chunk cache.
+ *
+ * The hit and miss counts reported by `zarrsChunkCacheStats` are not reset.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `cache` is a null pointer.
+ *
+ * # Safety
+ * If not null, `cache` must be a valid `ZarrsChunkCache` handle.
+ */
+ZarrsResult zarrsChunkCacheClear(ZarrsChunkCache cache);
+
+/**
+ * Get the statistics of a chunk cache.
+ *
+ * `pHits` and `pMisses` are set to the number of chunk lookups that were and were not in the cache since it was created.
+ * `pCurrentBytes` is set to the total size of the chunks in the cache, which are decoded or encoded depending on the cache.
+ * `pEntryCount` is set to the number of chunks in the cache.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `cache` is a null pointer.
+ *
+ * # Safety
+ * If not null, `cache` must be a valid `ZarrsChunkCache` handle.
+ * `pHits`, `pMisses`, `pCurrentBytes`, and `pEntryCount` must be valid pointers to a `uint64_t`.
+ */
+ZarrsResult zarrsChunkCacheStats(ZarrsChunkCache cache,
+                                 uint64_t *pHits,
+                                 uint64_t *pMisses,
+                                 uint64_t *pCurrentBytes,
+                                 uint64_t *pEntryCount);
+
+/**
+ * Get the maximum concurrency (concurrent target) of codec options.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+ *
+ * # Safety
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ * `pMaxConcurrency` must be a valid pointer to a `size_t`.
+ */
+ZarrsResult zarrsCodecOptionsGetMaxConcurrency(ZarrsCodecOptions codecOptions,
+                                               size_t *pMaxConcurrency);
+
+/**
+ * Get whether codec options store empty chunks.
+ *
+ * If false (the default), chunks composed entirely of the fill value are erased rather than stored.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+ *
+ * # Safety
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ * `pStoreEmptyChunks` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsCodecOptionsGetStoreEmptyChunks(ZarrsCodecOptions codecOptions,
+                                                 bool *pStoreEmptyChunks);
+
+/**
+ * Set the concurrency limit of codec options.
+ *
+ * This is an alias of `zarrsCodecOptionsSetMaxConcurrency()`, both set the concurrent target of the codec options.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+ *
+ * # Safety
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ */
+ZarrsResult zarrsCodecOptionsSetConcurrencyLimit(ZarrsCodecOptions codecOptions,
+                                                 size_t limit);
+
+/**
+ * Set the maximum concurrency (concurrent target) of codec options.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+ *
+ * # Safety
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ */
+ZarrsResult zarrsCodecOptionsSetMaxConcurrency(ZarrsCodecOptions codecOptions,
+                                               size_t maxConcurrency);
+
+/**
+ * Set whether codec options store empty chunks.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+ *
+ * # Safety
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ */
+ZarrsResult zarrsCodecOptionsSetStoreEmptyChunks(ZarrsCodecOptions codecOptions,
+                                                 bool storeEmptyChunks);
+
+/**
+ * Create a handle to a new array (read/write capability).
+ *
+ * `metadata` is expected to be a JSON string representing a zarr V3 array `zarr.json`.
+ * `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
+ *
+ * # Safety
+ * `pArray` must be a valid pointer to a `ZarrsArray` handle.
+ */
+ZarrsResult zarrsCreateArrayRW(ZarrsStorage storage,
+                               const char* path,
+                               const char* metadata,
+                               ZarrsArray *pArray);
+
+/**
+ * Create a handle to a decoded chunk cache holding at most `capacityBytes` bytes of decoded chunks.
+ *
+ * The cache is used with `zarrsArrayRetrieveChunkCached` and `zarrsArrayRetrieveSubsetCached`, which only retrieve and decode chunks that are not in the cache.
+ * When the cache is full, the least recently used chunks are evicted, and a chunk larger than `capacityBytes` is never cached.
+ * The cache can be shared by concurrent reads of `array` from multiple threads.
+ *
+ * The cache is not updated when the array is written, so it should be destroyed (or not used) after chunks it may hold are stored.
+ * The cache must only be used with `array` and must be destroyed with `zarrsDestroyChunkCache` before `array` is destroyed.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pCache` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `capacityBytes` is zero.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pCache` must be a valid pointer to a `ZarrsChunkCache` handle.
+ */
+ZarrsResult zarrsCreateChunkCacheDecodedLru(ZarrsArray array,
+                                            uint64_t capacityBytes,
+                                            ZarrsChunkCache *pCache);
+
+/**
+ * Create a handle to a decoded chunk cache holding at most `capacityChunks` decoded chunks.
+ *
+ * This is equivalent to `zarrsCreateChunkCacheDecodedLru`, except that the capacity is a number of chunks rather than bytes.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pCache` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `capacityChunks` is zero.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pCache` must be a valid pointer to a `ZarrsChunkCache` handle.
+ */
+ZarrsResult zarrsCreateChunkCacheDecodedLruChunkLimit(ZarrsArray array,
+                                                      uint64_t capacityChunks,
+                                                      ZarrsChunkCache *pCache);
+
+/**
+ * Create a handle to an encoded chunk cache holding at most `capacityBytes` bytes of encoded chunks.
+ *
+ * This is equivalent to `zarrsCreateChunkCacheDecodedLru`, except that chunks are cached as they are stored (still encoded) and are decoded on every retrieval.
+ * This avoids repeatedly retrieving chunks from slow (e.g. remote) storage while using less memory than caching decoded chunks.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` or `pCache` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `capacityBytes` is zero.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if the array has a variable size data type.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle and `pCache` must be a valid pointer to a `ZarrsChunkCache` handle.
+ */
+ZarrsResult zarrsCreateChunkCacheEncodedLru(ZarrsArray array,
+                                            uint64_t capacityBytes,
+                                            ZarrsChunkCache *pCache);
+
+/**
+ * Create a handle to new default codec options.
+ *
+ * `pCodecOptions` is a pointer to a handle in which the created `ZarrsCodecOptions` is returned.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `pCodecOptions` is a null pointer.
+ *
+ * # Safety
+ * If not null, `pCodecOptions` must be a valid pointer to a `ZarrsCodecOptions` handle.
+ */
+ZarrsResult zarrsCreateCodecOptions(ZarrsCodecOptions *pCodecOptions);
+
+/**
+ * Create a handle to a new group (read/write capability).
+ *
+ * `metadata` is expected to be a JSON string representing a zarr V3 group `zarr.json`.
+ * `pGroup` is a pointer to a handle in which the created `ZarrsGroup` is returned.
+ *
+ * # Safety
+ * `pGroup` must be a valid pointer to a `ZarrsGroup` handle.
+ */
+ZarrsResult zarrsCreateGroupRW(ZarrsStorage storage,
+                               const char* path,
+                               const char* metadata,
+                               ZarrsGroup *pGroup);
+
+/**
+ * Create a handle to a new unbounded shard index cache.
+ *
+ * The cache holds the index of every shard that is read through it, so it can consume unbounded memory for large sharded arrays.
+ * Use `zarrsCreateShardIndexCacheWithCapacity` to limit the number of cached shard indexes.
+ *
+ * # Errors
+ * Returns an error if the array does not have read capability.
+ *
+ * # Safety
+ * `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsCreateShardIndexCache(ZarrsArray array,
+                                       ZarrsShardIndexCache *pShardIndexCache);
+
+/**
+ * Create a handle to a new shard index cache holding at most `maxEntries` shard indexes.
+ *
+ * When a shard index is read and the cache is full, the least recently used shard index is evicted.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `maxEntries` is zero.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the array does not have read capability.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ * `pShardIndexCache` must be a valid pointer to a `ZarrsShardIndexCache` handle.
+ */
+ZarrsResult zarrsCreateShardIndexCacheWithCapacity(ZarrsArray array,
+                                                   size_t maxEntries,
+                                                   ZarrsShardIndexCache *pShardIndexCache);
+
+/**
+ * Create a storage handle to a filesystem store (read/write/list capability).
+ *
+ * `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+ *
+ * # Safety
+ * `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+ */
+ZarrsResult zarrsCreateStorageFilesystem(const char* path, ZarrsStorage *pStorage);
+
+/**
+ * Create a storage handle to an in-memory store (read/write/list capability).
+ *
+ * `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+ *
+ * # Safety
+ * `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+ */
+ZarrsResult zarrsCreateStorageMemory(ZarrsStorage *pStorage);
+
+/**
+ * Create a storage handle that counts the requests made to another storage.
+ *
+ * The created storage has the same capabilities and URL as `storage`.
+ * `storage` remains valid and must still be destroyed with `zarrsDestroyStorage`; requests made through it directly are not counted.
+ * The counts are retrieved with `zarrsStorageGetPerformanceMetrics`.
+ *
+ * `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ *
+ * # Safety
+ * If not null, `storage` must be a valid `ZarrsStorage` handle.
+ * `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+ */
+ZarrsResult zarrsCreateStoragePerformanceMetrics(ZarrsStorage storage,
+                                                 ZarrsStorage *pStorage);
+
+/**
+ * Get the properties of a data type.
+ *
+ * - `pIsFloat` is set to true for real floating point data types (`float16`, `bfloat16`, `float32`, `float64`).
+ * - `pIsComplex` is set to true for complex data types.
+ * - `pIsInteger` is set to true for signed and unsigned integer data types.
+ * - `pIsSigned` is set to true for signed integer, floating point, and complex data types.
+ * - `pFixedSize` is set to the size of an element in bytes, or zero for `ZARRS_RAW_BITS` as its size depends on the array metadata and `ZARRS_STRING` as it has a variable size.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_UNSUPPORTED_DATA_TYPE` if `dataType` is `ZARRS_UNDEFINED`.
+ *
+ * # Safety
+ * `dataType` must be a valid `ZarrsDataType`.
+ * `pIsFloat`, `pIsComplex`, `pIsInteger`, and `pIsSigned` must be valid pointers to a `bool`.
+ * `pFixedSize` must be a valid pointer to a `size_t`.
+ */
+ZarrsResult zarrsDataTypeGetProperties(ZarrsDataType dataType,
+                                       bool *pIsFloat,
+                                       bool *pIsComplex,
+                                       bool *pIsInteger,
+                                       bool *pIsSigned,
+                                       size_t *pFixedSize);
+
+/**
+ * Destroy array.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ *
+ * # Safety
+ * If not null, `array` must be a valid `ZarrsArray` handle.
+ */
+ZarrsResult zarrsDestroyArray(ZarrsArray array);
+
+/**
+ * Destroy a chunk cache.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `cache` is a null pointer.
+ *
+ * # Safety
+ * If not null, `cache` must be a valid `ZarrsChunkCache` handle that is not used after this call.
+ */
+ZarrsResult zarrsDestroyChunkCache(ZarrsChunkCache cache);
+
+/**
+ * Destroy codec options.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `codecOptions` is a null pointer.
+ *
+ * # Safety
+ * If not null, `codecOptions` must be a valid `ZarrsCodecOptions` handle.
+ */
+ZarrsResult zarrsDestroyCodecOptions(ZarrsCodecOptions codecOptions);
+
+/**
+ * Destroy group.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ *
+ * # Safety
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
+ */
+ZarrsResult zarrsDestroyGroup(ZarrsGroup group);
+
+/**
+ * Destroy a shard index cache.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `shardIndexCache` is a null pointer.
+ *
+ * # Safety
+ * If not null, `shardIndexCache` must be a valid `ZarrsShardIndexCache` handle.
+ */
+ZarrsResult zarrsDestroyShardIndexCache(ZarrsShardIndexCache shardIndexCache);
+
+/**
+ * Destroy storage.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the storage could not be finalized (e.g. writing a ZIP archive created with `zarrsStorageCreateZipRW` failed).
+ *   The storage handle is destroyed regardless.
+ *
+ * # Safety
+ * If not null, `storage` must be a valid storage device created with a `zarrsStorage` function.
+ */
+ZarrsResult zarrsDestroyStorage(ZarrsStorage storage);
+
+/**
+ * Free a buffer created by zarrs.
+ *
+ * Buffers returned by zarrs (e.g. by `zarrsArrayRetrieveSubsetAlloc` or `zarrsArrayGetStoredInnerChunkIndicesForShard`) are recorded in a process-wide registry keyed by their pointer, which holds their length and element type.
+ * This is why no length is passed: `pBytes` is looked up in the registry rather than dereferenced, so a pointer that zarrs did not return (including a pointer offset into a zarrs buffer) or that was already freed is detected rather than corrupting memory.
+ * Buffers can be freed from any thread.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `pBytes` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_POINTER` if `pBytes` is not a buffer created by zarrs or it has already been freed.
+ *
+ * # Safety
+ * The buffer must not be accessed after it is freed.
+ */
+ZarrsResult zarrsFreeBytes(void *pBytes);
+
+/**
+ * Free an offsets buffer created by zarrs (e.g. by `zarrsArrayRetrieveSubsetVlen`).
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `pOffsets` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_UNKNOWN_POINTER` if `pOffsets` is not a buffer created by zarrs or it has already been freed.
+ *
+ * # Safety
+ * The buffer must not be accessed after it is freed.
+ */
+ZarrsResult zarrsFreeOffsets(uint64_t *pOffsets);
+
+/**
+ * Free a string created by zarrs.
+ *
+ * # Safety
+ * `array` must be a valid string created by zarrs.
+ */
+ZarrsResult zarrsFreeString(char *string);
+
+/**
+ * Free an array of strings created by zarrs.
+ *
+ * `count` must be the number of strings in the array, as returned by the function that created it.
+ *
+ * # Safety
+ * `strings` must be a valid array of `count` strings created by zarrs.
+ */
+ZarrsResult zarrsFreeStringArray(char **strings, size_t count);
+
+/**
+ * Check whether the group has a top-level attribute.
+ *
+ * `pExists` is set to true if the attributes contain `key`, otherwise false.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ *
+ * # Safety
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
+ * `pExists` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsGroupAttributeExists(ZarrsGroup group, const char* key, bool *pExists);
+
+/**
+ * Get the type of a child node of a group without opening it.
+ *
+ * `childPath` is the path of the child relative to the group (e.g. `array` or `subgroup/array`).
+ * The type is determined from the child's `zarr.json`: the child is an array if it has a `data_type` field, otherwise it is a group.
+ * Zarr V2 children are identified by the presence of `.zarray` or `.zgroup`.
+ * `pNodeType` is set to `ZARRS_NODE_TYPE_UNKNOWN` if the child has no node metadata.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the group does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NODE_PATH` if `childPath` is empty or invalid.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the child's `zarr.json` is not a JSON object.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the child metadata could not be read.
+ *
+ * # Safety
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
+ * `pNodeType` must be a valid pointer to a `ZarrsNodeType`.
+ */
+ZarrsResult zarrsGroupChildNodeType(ZarrsGroup group,
+                                    const char* childPath,
+                                    ZarrsNodeType *pNodeType);
+
+/**
+ * Deep copy a group hierarchy to a path in a storage.
+ *
+ * The metadata of the group and all of its descendant arrays and groups is copied, along with all stored chunks.
+ * The target may be the same storage as the source, at a different path.
+ * The hierarchy is collected before anything is written, so the target path may be inside the source hierarchy.
+ * Chunks are decoded and re-encoded with the codecs of the copied array metadata.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `sourceGroup` or `targetStorage` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `sourceGroup` does not have read, write, and list capability, or `targetStorage` does not have write capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_GROUP` if any node could not be copied.
+ *
+ * # Safety
+ * If not null, `sourceGroup` must be a valid `ZarrsGroup` handle.
+ * If not null, `targetStorage` must be a valid `ZarrsStorage` handle.
+ */
+ZarrsResult zarrsGroupCopyTo(ZarrsGroup sourceGroup,
+                             ZarrsStorage targetStorage,
+                             const char* targetPath);
+
+/**
+ * Get the number of top-level group attributes.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ *
+ * # Safety
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
+ * `pCount` must be a valid pointer to a `size_t`.
+ */
+ZarrsResult zarrsGroupGetAttributeCount(ZarrsGroup group, size_t *pCount);
+
+/**
+ * Get the group attributes as a JSON string.
+ *
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Safety
+ * `group` must be a valid `ZarrsGroup` handle.
+ */
+ZarrsResult zarrsGroupGetAttributes(ZarrsGroup group, bool pretty, char **pAttributesString);
+
+/**
+ * Get the metadata of a group extension as a JSON string.
+ *
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the group metadata does not have the extension `extensionName`.
+ *
+ * # Safety
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
+ * `pExtensionJson` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsGroupGetExtensionString(ZarrsGroup group,
+                                         const char* extensionName,
+                                         bool pretty,
+                                         char **pExtensionJson);
+
+/**
+ * Get the store key of the group metadata.
+ *
+ * This is `zarr.json` for Zarr V3 metadata and `.zgroup` for Zarr V2 metadata under the group path, e.g. `my/group/zarr.json` for a group at `/my/group` and `zarr.json` for the root group.
+ * Zarr V2 attributes are stored separately in `.zattrs` under the same path.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` or `pKey` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if the group path is not a valid store prefix.
+ *
+ * # Safety
+ * If not null, `group` must be a valid `ZarrsGroup` handle and `pKey` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsGroupGetMetadataStorageKey(ZarrsGroup group,
+                                            char **pKey);
 
 /**
- * Retrieve a subset from an array.
+ * Returns the `"zarr_format"` of the group metadata.
  *
- * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
- * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ * Unlike `zarrsGroupGetZarrFormat`, this reads the raw value of the `"zarr_format"` field of the serialized metadata rather than deriving it from the metadata variant.
  *
  * # Errors
- * Returns an error if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the metadata does not have a `"zarr_format"` that is a positive integer.
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
+ * `pVersion` must be a valid pointer to a `uint32_t`.
  */
-ZarrsResult zarrsArrayRetrieveSubset(ZarrsArray array,
-                                     size_t dimensionality,
-                                     const uint64_t *pSubsetStart,
-                                     const uint64_t *pSubsetShape,
-                                     size_t subsetBytesCount,
-                                     uint8_t *pSubsetBytes);
+ZarrsResult zarrsGroupGetMetadataVersion(ZarrsGroup group,
+                                         uint32_t *pVersion);
 
 /**
- * Retrieve a subset from an array (with a shard index cache).
+ * Get the OME-Zarr multiscales of a group as a JSON string.
  *
- * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
- * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ * The multiscales are read from `ome.multiscales` (OME-Zarr 0.5) or `multiscales` (OME-Zarr 0.4 and earlier).
+ * The string must be freed with `zarrsFreeString`.
  *
  * # Errors
- * Returns an error if the array does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if the attributes do not contain OME-Zarr multiscales.
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
+ * `pMultiscalesJson` must be a valid pointer to a `char*`.
  */
-ZarrsResult zarrsArrayRetrieveSubsetSharded(ZarrsArray array,
-                                            ZarrsShardIndexCache cache,
-                                            size_t dimensionality,
-                                            const uint64_t *pSubsetStart,
-                                            const uint64_t *pSubsetShape,
-                                            size_t subsetBytesCount,
-                                            uint8_t *pSubsetBytes);
+ZarrsResult zarrsGroupGetOMEZarrMultiscalesString(ZarrsGroup group,
+                                                  bool pretty,
+                                                  char **pMultiscalesJson);
 
 /**
- * Set the array attributes from a JSON string.
+ * Returns the Zarr format (2 or 3) of the group metadata.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ *
+ * # Safety
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
+ * `pVersion` must be a valid pointer to a `uint32_t`.
+ */
+ZarrsResult zarrsGroupGetZarrFormat(ZarrsGroup group, uint32_t *pVersion);
+
+/**
+ * Check whether the group metadata has an extension.
+ *
+ * Extensions are additional top-level fields in the group metadata.
+ * `pHas` is set to true if the group metadata has a field named `extensionName` that is not a core metadata field, otherwise false.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ *
+ * # Safety
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
+ * `pHas` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsGroupHasExtension(ZarrsGroup group,
+                                   const char* extensionName,
+                                   bool *pHas);
+
+/**
+ * Check if a group has no child arrays or groups.
+ *
+ * The check stops as soon as a child node is found, and the metadata of child nodes is not read.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the group does not have list capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the store could not be listed.
+ *
+ * # Safety
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
+ * `pEmpty` must be a valid pointer to a `bool`.
+ */
+ZarrsResult zarrsGroupIsEmpty(ZarrsGroup group,
+                              bool *pEmpty);
+
+/**
+ * Set the group attributes from a JSON string.
  *
  * # Errors
  * Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if attributes is not a valid JSON object (map).
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
+ * `group` must be a valid `ZarrsGroup` handle.
  */
-ZarrsResult zarrsArraySetAttributes(ZarrsArray array,
+ZarrsResult zarrsGroupSetAttributes(ZarrsGroup group,
                                     const char* attributes);
 
 /**
- * Store a chunk.
+ * Set the OME-Zarr multiscales of a group.
  *
- * `pChunkIndices` is a pointer to an array of length `dimensionality` holding the chunk indices.
- * `pChunkBytes` is a pointer to an array of bytes of length `chunkBytesCount` that must match the expected size of the chunk as returned by `zarrsArrayGetChunkSize()`.
+ * `multiscalesJson` is a JSON array of OME-Zarr multiscales.
+ * It is validated against the OME-Zarr multiscales schema before being set as the `multiscales` attribute, under `ome` if present.
+ *
+ * The attributes are only updated in memory, use `zarrsGroupStoreMetadata` to write them.
  *
  * # Errors
- * Returns an error if the array does not have write capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if `multiscalesJson` is not valid OME-Zarr multiscales metadata.
  *
  * # Safety
- * `array`  must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the array pointed to by `pChunkIndices`.
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
  */
-ZarrsResult zarrsArrayStoreChunk(ZarrsArray array,
-                                 size_t dimensionality,
-                                 const uint64_t *pChunkIndices,
-                                 size_t chunkBytesCount,
-                                 const uint8_t *pChunkBytes);
+ZarrsResult zarrsGroupSetOMEZarrMetadata(ZarrsGroup group,
+                                         const char* multiscalesJson);
 
 /**
- * Store array metadata.
+ * Store group metadata.
  *
  * # Errors
- * Returns an error if the array does not have write capability.
+ * Returns an error if the group does not have write capability.
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
+ * `group` must be a valid `ZarrsGroup` handle.
  */
-ZarrsResult zarrsArrayStoreMetadata(ZarrsArray array);
+ZarrsResult zarrsGroupStoreMetadata(ZarrsGroup group);
 
 /**
- * Store an array subset.
+ * Store the metadata of a group and all of its descendant arrays and groups.
  *
- * `pSubsetStart` and `pSubsetShape` are pointers to arrays of length `dimensionality` holding the chunk start and shape respectively.
- * `pSubsetBytes` is a pointer to an array of bytes of length `subsetBytesCount` that must match the expected size of the subset as returned by `zarrsArrayGetSubsetSize()`.
+ * Descendants are discovered by listing the storage of the group.
  *
  * # Errors
- * Returns an error if the array does not have read/write capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if the group does not have read, write, and list capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the descendants could not be listed or the metadata of any node could not be stored.
+ *   The last error lists the paths of the nodes that failed, other nodes are still stored.
  *
  * # Safety
- * `array`  must be a valid `ZarrsArray` handle.
- * `dimensionality` must match the dimensionality of the array and the length of the arrays pointed to by `pSubsetStart` and `pSubsetShape`.
+ * If not null, `group` must be a valid `ZarrsGroup` handle.
  */
-ZarrsResult zarrsArrayStoreSubset(ZarrsArray array,
-                                  size_t dimensionality,
-                                  const uint64_t *pSubsetStart,
-                                  const uint64_t *pSubsetShape,
-                                  size_t subsetBytesCount,
-                                  const uint8_t *pSubsetBytes);
+ZarrsResult zarrsGroupStoreMetadataAll(ZarrsGroup group);
 
 /**
- * Create a handle to a new array (read/write capability).
+ * Get the last error string.
  *
- * `metadata` is expected to be a JSON string representing a zarr V3 array `zarr.json`.
+ * The string must be freed with `zarrsFreeString`.
+ */
+char *zarrsLastError(void);
+
+/**
+ * Create a handle to an existing array (read capability).
+ *
+ * The list capability of the storage is retained.
  * `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
  *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if the array could not be opened.
+ *
  * # Safety
+ * If not null, `storage` must be a valid `ZarrsStorage` handle.
  * `pArray` must be a valid pointer to a `ZarrsArray` handle.
  */
-ZarrsResult zarrsCreateArrayRW(ZarrsStorage storage,
+ZarrsResult zarrsOpenArrayR(ZarrsStorage storage,
+                            const char* path,
+                            ZarrsArray *pArray);
+
+/**
+ * Create a handle to an existing array (read/write capability).
+ *
+ * `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
+ *
+ * # Safety
+ * `pArray` must be a valid pointer to a `ZarrsArray` handle.
+ */
+ZarrsResult zarrsOpenArrayRW(ZarrsStorage storage, const char* path, ZarrsArray *pArray);
+
+/**
+ * Create a handle to an existing Zarr V2 array (read-only capability).
+ *
+ * The array metadata is read from `.zarray` (and `.zattrs`), even if Zarr V3 metadata is present at `path`.
+ * `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if there is no valid Zarr V2 array metadata at `path`.
+ *
+ * # Safety
+ * If not null, `storage` must be a valid `ZarrsStorage` handle.
+ * `pArray` must be a valid pointer to a `ZarrsArray` handle.
+ */
+ZarrsResult zarrsOpenArrayV2R(ZarrsStorage storage,
+                              const char* path,
+                              ZarrsArray *pArray);
+
+/**
+ * Create a handle to an existing Zarr V2 array (read/write capability).
+ *
+ * The array metadata is read from `.zarray` (and `.zattrs`), even if Zarr V3 metadata is present at `path`.
+ * All array functions operate on the returned handle as they would for a Zarr V3 array.
+ * `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read and write capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if there is no valid Zarr V2 array metadata at `path`.
+ *
+ * # Safety
+ * If not null, `storage` must be a valid `ZarrsStorage` handle.
+ * `pArray` must be a valid pointer to a `ZarrsArray` handle.
+ */
+ZarrsResult zarrsOpenArrayV2RW(ZarrsStorage storage,
                                const char* path,
-                               const char* metadata,
                                ZarrsArray *pArray);
 
 /**
- * Create a handle to a new group (read/write capability).
+ * Create a handle to an existing Zarr V2 array (write-only capability).
+ *
+ * The array metadata is read from `.zarray` (and `.zattrs`), so `storage` must also have read capability.
+ * The returned handle only has write capability.
+ * `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE_CAPABILITY` if `storage` does not have read and write capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if there is no valid Zarr V2 array metadata at `path`.
+ *
+ * # Safety
+ * If not null, `storage` must be a valid `ZarrsStorage` handle.
+ * `pArray` must be a valid pointer to a `ZarrsArray` handle.
+ */
+ZarrsResult zarrsOpenArrayV2W(ZarrsStorage storage,
+                              const char* path,
+                              ZarrsArray *pArray);
+
+/**
+ * Create a handle to an existing group (read/write capability).
  *
- * `metadata` is expected to be a JSON string representing a zarr V3 group `zarr.json`.
  * `pGroup` is a pointer to a handle in which the created `ZarrsGroup` is returned.
  *
  * # Safety
  * `pGroup` must be a valid pointer to a `ZarrsGroup` handle.
  */
-ZarrsResult zarrsCreateGroupRW(ZarrsStorage storage,
-                               const char* path,
-                               const char* metadata,
-                               ZarrsGroup *pGroup);
+ZarrsResult zarrsOpenGroupRW(ZarrsStorage storage, const char* path, ZarrsGroup *pGroup);
 
 /**
- * Create a handle to a new shard index cache.
+ * Destroy an asynchronous operation.
+ *
+ * If the operation has not completed, this waits for it to complete (including invoking its completion callback) before returning.
  *
  * # Errors
- * Returns an error if the array does not have read capability.
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `op` is a null pointer.
  *
  * # Safety
- * `array` must be a valid `ZarrsArray` handle.
+ * If not null, `op` must be a valid `ZarrsOperation` handle that is not used after this call.
  */
-ZarrsResult zarrsCreateShardIndexCache(ZarrsArray array, ZarrsShardIndexCache *pShardIndexCache);
+ZarrsResult zarrsOperationDestroy(ZarrsOperation op);
 
 /**
- * Create a storage handle to a filesystem store.
+ * Wait for an asynchronous operation to complete.
  *
- * `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+ * `pResult` is set to the result of the operation.
+ * This can be called multiple times and from multiple threads, and returns immediately if the operation has already completed.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `op` or `pResult` is a null pointer.
  *
  * # Safety
- * `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
+ * If not null, `op` must be a valid `ZarrsOperation` handle and `pResult` must be a valid pointer to a `ZarrsResult`.
  */
-ZarrsResult zarrsCreateStorageFilesystem(const char* path, ZarrsStorage *pStorage);
+ZarrsResult zarrsOperationWait(ZarrsOperation op,
+                               ZarrsResult *pResult);
 
 /**
- * Destroy array.
+ * Get the maximum number of shard indexes held by a shard index cache.
+ *
+ * `pCapacity` is set to zero if the cache is unbounded.
  *
  * # Errors
- * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `array` is a null pointer.
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `cache` is a null pointer.
  *
  * # Safety
- * If not null, `array` must be a valid `ZarrsArray` handle.
+ * If not null, `cache` must be a valid `ZarrsShardIndexCache` handle.
+ * `pCapacity` must be a valid pointer to a `size_t`.
  */
-ZarrsResult zarrsDestroyArray(ZarrsArray array);
+ZarrsResult zarrsShardIndexCacheGetCapacity(ZarrsShardIndexCache cache, size_t *pCapacity);
 
 /**
- * Destroy group.
+ * Set the maximum number of shard indexes held by a shard index cache.
+ *
+ * If the cache holds more than `newCapacity` shard indexes, the least recently used are evicted.
+ * An unbounded cache becomes bounded, and the shard indexes it holds are discarded.
  *
  * # Errors
- * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `group` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `cache` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_ARRAY` if `newCapacity` is zero.
  *
  * # Safety
- * If not null, `group` must be a valid `ZarrsGroup` handle.
+ * If not null, `cache` must be a valid `ZarrsShardIndexCache` handle.
  */
-ZarrsResult zarrsDestroyGroup(ZarrsGroup group);
+ZarrsResult zarrsShardIndexCacheSetCapacity(ZarrsShardIndexCache cache, size_t newCapacity);
 
 /**
- * Destroy a shard index cache.
+ * Create a storage handle from a URL.
+ *
+ * The storage backend is chosen based on the URL scheme:
+ * - `file://` creates a filesystem store (read/write/list capability). The URL must have an empty or `localhost` host and an absolute path.
+ * - `http://` and `https://` create a HTTP store (read capability), equivalent to `zarrsStorageCreateHTTP`. These require the `http` feature.
+ *
+ * Object storage schemes (e.g. `s3://`, `gs://`, `az://`) are not supported.
+ * None of the supported backends accept configuration through URL query parameters, so URLs with a query are rejected rather than silently ignoring it.
+ * `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
  *
  * # Errors
- * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `shardIndexCache` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the URL scheme is unsupported, the URL is invalid, or the storage could not be created.
+ *   The last error lists the URL schemes supported by this build.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if a `http://` or `https://` URL is malformed.
  *
  * # Safety
- * If not null, `shardIndexCache` must be a valid `ZarrsShardIndexCache` handle.
+ * `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
  */
-ZarrsResult zarrsDestroyShardIndexCache(ZarrsShardIndexCache shardIndexCache);
+ZarrsResult zarrsStorageCreateFromURL(const char* url,
+                                      ZarrsStorage *pStorage);
 
 /**
- * Destroy storage.
+ * Create a storage handle to a HTTP store (read capability).
+ *
+ * `baseUrl` is the `http://` or `https://` URL of the root of the store.
+ * `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+ *
+ * HTTP storage requires zarrs_ffi to be built with the `http` feature.
  *
  * # Errors
- * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if `baseUrl` is not a valid `http://` or `https://` URL.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the storage could not be created or the `http` feature is not enabled.
  *
  * # Safety
- * If not null, `storage` must be a valid storage device created with a `zarrsStorage` function.
+ * `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
  */
-ZarrsResult zarrsDestroyStorage(ZarrsStorage storage);
+ZarrsResult zarrsStorageCreateHTTP(const char* baseUrl,
+                                   ZarrsStorage *pStorage);
 
 /**
- * Free a string created by zarrs.
+ * Create a storage handle to a HTTP store (read capability) with connection options.
+ *
+ * `baseUrl` is the `http://` or `https://` URL of the root of the store.
+ * `timeoutSeconds` is the timeout of each request, and `maxRetries` is the maximum number of times a failed request is retried.
+ * A `timeoutSeconds` of zero uses the default timeout.
+ * `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+ *
+ * HTTP storage requires zarrs_ffi to be built with the `http` feature.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if `baseUrl` is not a valid `http://` or `https://` URL.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the storage could not be created or the `http` feature is not enabled.
  *
  * # Safety
- * `array` must be a valid string created by zarrs.
+ * `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
  */
-ZarrsResult zarrsFreeString(char *string);
+ZarrsResult zarrsStorageCreateHTTPWithOptions(const char* baseUrl,
+                                              uint32_t timeoutSeconds,
+                                              uint32_t maxRetries,
+                                              ZarrsStorage *pStorage);
 
 /**
- * Get the group attributes as a JSON string.
+ * Create a storage handle to a ZIP archive (read/list capability).
  *
- * The string must be freed with `zarrsFreeString`.
+ * `path` is the path to an existing ZIP archive (e.g. `data.zarr.zip`) containing a Zarr hierarchy at its root.
+ * `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+ *
+ * ZIP storage requires zarrs_ffi to be built with the `zip` feature.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if `path` does not exist or is not a valid ZIP archive.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the `zip` feature is not enabled.
  *
  * # Safety
- * `group` must be a valid `ZarrsGroup` handle.
+ * `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
  */
-ZarrsResult zarrsGroupGetAttributes(ZarrsGroup group, bool pretty, char **pAttributesString);
+ZarrsResult zarrsStorageCreateZipR(const char* path,
+                                   ZarrsStorage *pStorage);
 
 /**
- * Set the group attributes from a JSON string.
+ * Create a storage handle to a new or existing ZIP archive (read/write/list capability).
+ *
+ * If the archive at `path` exists, its contents are loaded into memory.
+ * Writes are staged in memory, and the archive at `path` is (re)written when the storage handle is destroyed with `zarrsDestroyStorage`.
+ * Array and group handles created from the storage should store their data before the storage handle is destroyed.
+ * `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+ *
+ * ZIP storage requires zarrs_ffi to be built with the `zip` feature.
  *
  * # Errors
- * Returns `ZarrsResult::ZARRS_ERROR_INVALID_METADATA` if attributes is not a valid JSON object (map).
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if `path` exists but is not a valid ZIP archive, or its parent directory does not exist.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the `zip` feature is not enabled.
  *
  * # Safety
- * `group` must be a valid `ZarrsGroup` handle.
+ * `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
  */
-ZarrsResult zarrsGroupSetAttributes(ZarrsGroup group,
-                                    const char* attributes);
+ZarrsResult zarrsStorageCreateZipRW(const char* path,
+                                    ZarrsStorage *pStorage);
 
 /**
- * Store group metadata.
+ * Create a storage handle to a new ZIP archive (write capability).
+ *
+ * Writes are staged in memory, and the archive at `path` is created (or overwritten) when the storage handle is destroyed with `zarrsDestroyStorage`.
+ * Array and group handles created from the storage should store their data before the storage handle is destroyed.
+ * `pStorage` is a pointer to a handle in which the created `ZarrsStorage` is returned.
+ *
+ * ZIP storage requires zarrs_ffi to be built with the `zip` feature.
  *
  * # Errors
- * Returns an error if the group does not have write capability.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORE_PREFIX` if the parent directory of `path` does not exist.
+ * - Returns `ZarrsResult::ZARRS_ERROR_STORAGE` if the `zip` feature is not enabled.
  *
  * # Safety
- * `group` must be a valid `ZarrsGroup` handle.
+ * `pStorage` must be a valid pointer to a `ZarrsStorage` handle.
  */
-ZarrsResult zarrsGroupStoreMetadata(ZarrsGroup group);
+ZarrsResult zarrsStorageCreateZipW(const char* path,
+                                   ZarrsStorage *pStorage);
 
 /**
- * Get the last error string.
+ * Get the capabilities of the storage.
  *
- * The string must be freed with `zarrsFreeString`.
+ * `pFlags` is set to a bitfield of `ZarrsStorageCapabilityFlags`.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ *
+ * # Safety
+ * If not null, `storage` must be a valid `ZarrsStorage` handle.
+ * `pFlags` must be a valid pointer to a `uint32_t`.
  */
-char *zarrsLastError(void);
+ZarrsResult zarrsStorageGetCapabilityFlags(ZarrsStorage storage, uint32_t *pFlags);
 
 /**
- * Create a handle to an existing array (read/write capability).
+ * Get the requests made to a storage created with `zarrsCreateStoragePerformanceMetrics`.
  *
- * `pArray` is a pointer to a handle in which the created `ZarrsArray` is returned.
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` or `pMetrics` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NOT_FOUND` if `storage` was not created with `zarrsCreateStoragePerformanceMetrics`.
  *
  * # Safety
- * `pArray` must be a valid pointer to a `ZarrsArray` handle.
+ * If not null, `storage` must be a valid `ZarrsStorage` handle and `pMetrics` must be a valid pointer to a `ZarrsStoragePerformanceMetrics`.
  */
-ZarrsResult zarrsOpenArrayRW(ZarrsStorage storage, const char* path, ZarrsArray *pArray);
+ZarrsResult zarrsStorageGetPerformanceMetrics(ZarrsStorage storage,
+                                              ZarrsStoragePerformanceMetrics *pMetrics);
 
 /**
- * Create a handle to an existing group (read/write capability).
+ * Get the cumulative I/O statistics of a storage handle.
  *
- * `pGroup` is a pointer to a handle in which the created `ZarrsGroup` is returned.
+ * Statistics are accumulated over all requests made through the storage handle, including those of the array handles created from it.
+ *
+ * # Errors
+ * Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` or `pStats` is a null pointer.
  *
  * # Safety
- * `pGroup` must be a valid pointer to a `ZarrsGroup` handle.
+ * If not null, `storage` must be a valid `ZarrsStorage` handle and `pStats` must be a valid pointer to a `ZarrsStorageStats`.
  */
-ZarrsResult zarrsOpenGroupRW(ZarrsStorage storage, const char* path, ZarrsGroup *pGroup);
+ZarrsResult zarrsStorageGetStats(ZarrsStorage storage,
+                                 ZarrsStorageStats *pStats);
+
+/**
+ * Get the URL of a storage handle.
+ *
+ * This is the reverse of `zarrsStorageCreateFromURL`.
+ * Filesystem storage created with `zarrsCreateStorageFilesystem` has a `file://` URL with an absolute path.
+ * The string must be freed with `zarrsFreeString`.
+ *
+ * # Errors
+ * - Returns `ZarrsResult::ZARRS_ERROR_NULL_PTR` if `storage` is a null pointer.
+ * - Returns `ZarrsResult::ZARRS_ERROR_NOT_FOUND` if the storage does not have a URL (e.g. in-memory storage).
+ *
+ * # Safety
+ * If not null, `storage` must be a valid `ZarrsStorage` handle.
+ * `pUrl` must be a valid pointer to a `char*`.
+ */
+ZarrsResult zarrsStorageGetURL(ZarrsStorage storage,
+                               char **pUrl);
 
 /**
  * Get the zarrs version.